@@ -15,6 +15,8 @@ pub struct Model {
     pub owner_id: Uuid,
     pub created_at: DateTimeWithTimeZone,
     pub updated_at: DateTimeWithTimeZone,
+    pub archived_at: Option<DateTimeWithTimeZone>,
+    pub version: i32,
 }
 
 #[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
@@ -31,6 +33,12 @@ pub enum Relation {
         on_delete = "Cascade"
     )]
     User,
+    #[sea_orm(has_many = "super::attachment::Entity")]
+    Attachment,
+    #[sea_orm(has_many = "super::label::Entity")]
+    Label,
+    #[sea_orm(has_many = "super::custom_field::Entity")]
+    CustomField,
 }
 
 impl Related<super::board_member::Entity> for Entity {
@@ -51,4 +59,22 @@ impl Related<super::user::Entity> for Entity {
     }
 }
 
+impl Related<super::attachment::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::Attachment.def()
+    }
+}
+
+impl Related<super::label::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::Label.def()
+    }
+}
+
+impl Related<super::custom_field::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::CustomField.def()
+    }
+}
+
 impl ActiveModelBehavior for ActiveModel {}
@@ -14,6 +14,10 @@ pub struct Model {
     pub board_id: Uuid,
     pub created_at: DateTimeWithTimeZone,
     pub updated_at: DateTimeWithTimeZone,
+    pub archived_at: Option<DateTimeWithTimeZone>,
+    pub version: i32,
+    pub wip_limit: Option<i32>,
+    pub is_done: bool,
 }
 
 #[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
@@ -2,11 +2,25 @@
 
 pub mod prelude;
 
+pub mod attachment;
 pub mod board;
+pub mod board_activity;
+pub mod board_event_sequence;
 pub mod board_member;
+pub mod checklist;
+pub mod checklist_item;
 pub mod column;
+pub mod comment;
+pub mod comment_revision;
+pub mod custom_field;
+pub mod event_outbox;
+pub mod label;
 pub mod sea_orm_active_enums;
 pub mod task;
+pub mod task_assignee;
+pub mod task_label;
+pub mod task_relation;
+pub mod time_entry;
 pub mod user;
 
 pub use user::ActiveModel as UserActiveModel;
@@ -39,4 +53,91 @@ pub use task::Entity as TaskEntity;
 pub use task::Model as TaskModel;
 pub use task::Relation as TaskRelation;
 
+pub use task_assignee::ActiveModel as TaskAssigneeActiveModel;
+pub use task_assignee::Column as TaskAssigneeColumn;
+pub use task_assignee::Entity as TaskAssigneeEntity;
+pub use task_assignee::Model as TaskAssigneeModel;
+pub use task_assignee::Relation as TaskAssigneeRelation;
+
+pub use label::ActiveModel as LabelActiveModel;
+pub use label::Column as LabelColumn;
+pub use label::Entity as LabelEntity;
+pub use label::Model as LabelModel;
+pub use label::Relation as LabelRelation;
+
+pub use custom_field::ActiveModel as CustomFieldActiveModel;
+pub use custom_field::Column as CustomFieldColumn;
+pub use custom_field::Entity as CustomFieldEntity;
+pub use custom_field::Model as CustomFieldModel;
+pub use custom_field::Relation as CustomFieldRelation;
+
+pub use task_label::ActiveModel as TaskLabelActiveModel;
+pub use task_label::Column as TaskLabelColumn;
+pub use task_label::Entity as TaskLabelEntity;
+pub use task_label::Model as TaskLabelModel;
+pub use task_label::Relation as TaskLabelRelation;
+
+pub use task_relation::ActiveModel as TaskRelationActiveModel;
+pub use task_relation::Column as TaskRelationColumn;
+pub use task_relation::Entity as TaskRelationEntity;
+pub use task_relation::Model as TaskRelationModel;
+pub use task_relation::Relation as TaskRelationRelation;
+
+pub use time_entry::ActiveModel as TimeEntryActiveModel;
+pub use time_entry::Column as TimeEntryColumn;
+pub use time_entry::Entity as TimeEntryEntity;
+pub use time_entry::Model as TimeEntryModel;
+pub use time_entry::Relation as TimeEntryRelation;
+
+pub use comment::ActiveModel as CommentActiveModel;
+pub use comment::Column as CommentColumn;
+pub use comment::Entity as CommentEntity;
+pub use comment::Model as CommentModel;
+pub use comment::Relation as CommentRelation;
+
+pub use comment_revision::ActiveModel as CommentRevisionActiveModel;
+pub use comment_revision::Column as CommentRevisionColumn;
+pub use comment_revision::Entity as CommentRevisionEntity;
+pub use comment_revision::Model as CommentRevisionModel;
+pub use comment_revision::Relation as CommentRevisionRelation;
+
+pub use checklist::ActiveModel as ChecklistActiveModel;
+pub use checklist::Column as ChecklistColumn;
+pub use checklist::Entity as ChecklistEntity;
+pub use checklist::Model as ChecklistModel;
+pub use checklist::Relation as ChecklistRelation;
+
+pub use checklist_item::ActiveModel as ChecklistItemActiveModel;
+pub use checklist_item::Column as ChecklistItemColumn;
+pub use checklist_item::Entity as ChecklistItemEntity;
+pub use checklist_item::Model as ChecklistItemModel;
+pub use checklist_item::Relation as ChecklistItemRelation;
+
+pub use attachment::ActiveModel as AttachmentActiveModel;
+pub use attachment::Column as AttachmentColumn;
+pub use attachment::Entity as AttachmentEntity;
+pub use attachment::Model as AttachmentModel;
+pub use attachment::Relation as AttachmentRelation;
+
+pub use board_activity::ActiveModel as BoardActivityActiveModel;
+pub use board_activity::Column as BoardActivityColumn;
+pub use board_activity::Entity as BoardActivityEntity;
+pub use board_activity::Model as BoardActivityModel;
+pub use board_activity::Relation as BoardActivityRelation;
+
+pub use board_event_sequence::ActiveModel as BoardEventSequenceActiveModel;
+pub use board_event_sequence::Column as BoardEventSequenceColumn;
+pub use board_event_sequence::Entity as BoardEventSequenceEntity;
+pub use board_event_sequence::Model as BoardEventSequenceModel;
+pub use board_event_sequence::Relation as BoardEventSequenceRelation;
+
+pub use event_outbox::ActiveModel as EventOutboxActiveModel;
+pub use event_outbox::Column as EventOutboxColumn;
+pub use event_outbox::Entity as EventOutboxEntity;
+pub use event_outbox::Model as EventOutboxModel;
+pub use event_outbox::Relation as EventOutboxRelation;
+
 pub use sea_orm_active_enums::BoardMemberRoleEnum;
+pub use sea_orm_active_enums::CustomFieldTypeEnum;
+pub use sea_orm_active_enums::TaskPriorityEnum;
+pub use sea_orm_active_enums::TaskRelationTypeEnum;
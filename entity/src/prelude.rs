@@ -1,7 +1,21 @@
 //! `SeaORM` Entity, @generated by sea-orm-codegen 1.1.17
 
+pub use super::attachment::Entity as Attachment;
 pub use super::board::Entity as Board;
+pub use super::board_activity::Entity as BoardActivity;
+pub use super::board_event_sequence::Entity as BoardEventSequence;
 pub use super::board_member::Entity as BoardMember;
+pub use super::checklist::Entity as Checklist;
+pub use super::checklist_item::Entity as ChecklistItem;
 pub use super::column::Entity as Column;
+pub use super::comment::Entity as Comment;
+pub use super::comment_revision::Entity as CommentRevision;
+pub use super::custom_field::Entity as CustomField;
+pub use super::event_outbox::Entity as EventOutbox;
+pub use super::label::Entity as Label;
 pub use super::task::Entity as Task;
+pub use super::task_assignee::Entity as TaskAssignee;
+pub use super::task_label::Entity as TaskLabel;
+pub use super::task_relation::Entity as TaskRelation;
+pub use super::time_entry::Entity as TimeEntry;
 pub use super::user::Entity as User;
@@ -31,3 +31,81 @@ impl BoardMemberRoleEnum {
         }
     }
 }
+
+#[derive(
+    Debug, Clone, PartialEq, Eq, EnumIter, DeriveActiveEnum, Serialize, Deserialize, ToSchema,
+)]
+#[serde(rename_all = "camelCase")]
+#[sea_orm(rs_type = "String", db_type = "Enum", enum_name = "task_priority_enum")]
+pub enum TaskPriorityEnum {
+    #[sea_orm(string_value = "low")]
+    Low,
+    #[sea_orm(string_value = "medium")]
+    Medium,
+    #[sea_orm(string_value = "high")]
+    High,
+    #[sea_orm(string_value = "urgent")]
+    Urgent,
+}
+
+#[derive(
+    Debug,
+    Clone,
+    Copy,
+    PartialEq,
+    Eq,
+    Hash,
+    EnumIter,
+    DeriveActiveEnum,
+    Serialize,
+    Deserialize,
+    ToSchema,
+)]
+#[serde(rename_all = "camelCase")]
+#[sea_orm(
+    rs_type = "String",
+    db_type = "Enum",
+    enum_name = "task_relation_type_enum"
+)]
+pub enum TaskRelationTypeEnum {
+    #[sea_orm(string_value = "blocks")]
+    Blocks,
+    #[sea_orm(string_value = "duplicates")]
+    Duplicates,
+    #[sea_orm(string_value = "relates_to")]
+    RelatesTo,
+}
+
+#[derive(
+    Debug,
+    Clone,
+    Copy,
+    PartialEq,
+    Eq,
+    Hash,
+    EnumIter,
+    DeriveActiveEnum,
+    Serialize,
+    Deserialize,
+    ToSchema,
+)]
+#[serde(rename_all = "camelCase")]
+#[sea_orm(
+    rs_type = "String",
+    db_type = "Enum",
+    enum_name = "custom_field_type_enum"
+)]
+pub enum CustomFieldTypeEnum {
+    #[sea_orm(string_value = "text")]
+    Text,
+    #[sea_orm(string_value = "number")]
+    Number,
+    #[sea_orm(string_value = "date")]
+    Date,
+    #[sea_orm(string_value = "single_select")]
+    SingleSelect,
+    #[sea_orm(string_value = "multi_select")]
+    MultiSelect,
+    #[sea_orm(string_value = "user")]
+    User,
+}
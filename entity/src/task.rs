@@ -1,5 +1,6 @@
 //! `SeaORM` Entity, @generated by sea-orm-codegen 1.1.17
 
+use super::sea_orm_active_enums::TaskPriorityEnum;
 use sea_orm::entity::prelude::*;
 use serde::Serialize;
 
@@ -12,11 +13,19 @@ pub struct Model {
     pub title: String,
     #[sea_orm(column_type = "Text", nullable)]
     pub description: Option<String>,
-    pub tags: Option<Vec<String>>,
     pub position: String,
     pub column_id: Uuid,
     pub created_at: DateTimeWithTimeZone,
     pub updated_at: DateTimeWithTimeZone,
+    pub priority: TaskPriorityEnum,
+    pub start_at: Option<DateTimeWithTimeZone>,
+    pub due_at: Option<DateTimeWithTimeZone>,
+    pub archived_at: Option<DateTimeWithTimeZone>,
+    pub version: i32,
+    pub completed_at: Option<DateTimeWithTimeZone>,
+    #[sea_orm(column_type = "JsonBinary")]
+    pub custom_fields: Json,
+    pub estimate_minutes: Option<i32>,
 }
 
 #[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
@@ -29,6 +38,18 @@ pub enum Relation {
         on_delete = "Cascade"
     )]
     Column,
+    #[sea_orm(has_many = "super::task_assignee::Entity")]
+    TaskAssignee,
+    #[sea_orm(has_many = "super::comment::Entity")]
+    Comment,
+    #[sea_orm(has_many = "super::attachment::Entity")]
+    Attachment,
+    #[sea_orm(has_many = "super::checklist::Entity")]
+    Checklist,
+    #[sea_orm(has_many = "super::task_label::Entity")]
+    TaskLabel,
+    #[sea_orm(has_many = "super::time_entry::Entity")]
+    TimeEntry,
 }
 
 impl Related<super::column::Entity> for Entity {
@@ -37,4 +58,40 @@ impl Related<super::column::Entity> for Entity {
     }
 }
 
+impl Related<super::task_assignee::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::TaskAssignee.def()
+    }
+}
+
+impl Related<super::comment::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::Comment.def()
+    }
+}
+
+impl Related<super::attachment::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::Attachment.def()
+    }
+}
+
+impl Related<super::checklist::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::Checklist.def()
+    }
+}
+
+impl Related<super::task_label::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::TaskLabel.def()
+    }
+}
+
+impl Related<super::time_entry::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::TimeEntry.def()
+    }
+}
+
 impl ActiveModelBehavior for ActiveModel {}
@@ -26,6 +26,22 @@ pub enum Relation {
     Board,
     #[sea_orm(has_many = "super::board_member::Entity")]
     BoardMember,
+    #[sea_orm(has_many = "super::task_assignee::Entity")]
+    TaskAssignee,
+    #[sea_orm(has_many = "super::comment::Entity")]
+    Comment,
+    #[sea_orm(has_many = "super::comment_revision::Entity")]
+    CommentRevision,
+    #[sea_orm(has_many = "super::attachment::Entity")]
+    Attachment,
+    #[sea_orm(has_many = "super::board_activity::Entity")]
+    BoardActivity,
+    #[sea_orm(has_many = "super::checklist_item::Entity")]
+    ChecklistItem,
+    #[sea_orm(has_many = "super::task_relation::Entity")]
+    TaskRelation,
+    #[sea_orm(has_many = "super::time_entry::Entity")]
+    TimeEntry,
 }
 
 impl Related<super::board::Entity> for Entity {
@@ -40,4 +56,52 @@ impl Related<super::board_member::Entity> for Entity {
     }
 }
 
+impl Related<super::task_assignee::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::TaskAssignee.def()
+    }
+}
+
+impl Related<super::comment::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::Comment.def()
+    }
+}
+
+impl Related<super::comment_revision::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::CommentRevision.def()
+    }
+}
+
+impl Related<super::attachment::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::Attachment.def()
+    }
+}
+
+impl Related<super::board_activity::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::BoardActivity.def()
+    }
+}
+
+impl Related<super::checklist_item::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::ChecklistItem.def()
+    }
+}
+
+impl Related<super::task_relation::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::TaskRelation.def()
+    }
+}
+
+impl Related<super::time_entry::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::TimeEntry.def()
+    }
+}
+
 impl ActiveModelBehavior for ActiveModel {}
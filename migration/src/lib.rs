@@ -3,6 +3,22 @@ mod m20251102_201124_create_board_table;
 mod m20251102_201821_create_column_table;
 mod m20251102_202640_create_task_table;
 mod m20251108_111856_create_board_member_table;
+mod m20251112_184512_create_task_assignee_table;
+mod m20251114_093027_add_task_schedule_and_priority;
+mod m20251116_151204_create_comment_table;
+mod m20251119_101522_create_attachment_table;
+mod m20251121_143018_add_archived_at_columns;
+mod m20251123_091544_create_board_activity_table;
+mod m20251125_160233_add_board_event_sequence;
+mod m20251127_083412_create_event_outbox_table;
+mod m20251129_094215_add_version_columns;
+mod m20251201_101437_add_column_wip_limit;
+mod m20251203_142806_add_done_columns_and_task_completion;
+mod m20251205_093512_create_checklist_tables;
+mod m20251207_104129_create_task_relation_table;
+mod m20251209_081745_create_label_tables;
+mod m20251211_093012_create_custom_field_table;
+mod m20251213_101524_create_time_entry_table;
 
 pub use sea_orm_migration::prelude::*;
 
@@ -17,6 +33,22 @@ impl MigratorTrait for Migrator {
             Box::new(m20251102_201821_create_column_table::Migration),
             Box::new(m20251102_202640_create_task_table::Migration),
             Box::new(m20251108_111856_create_board_member_table::Migration),
+            Box::new(m20251112_184512_create_task_assignee_table::Migration),
+            Box::new(m20251114_093027_add_task_schedule_and_priority::Migration),
+            Box::new(m20251116_151204_create_comment_table::Migration),
+            Box::new(m20251119_101522_create_attachment_table::Migration),
+            Box::new(m20251121_143018_add_archived_at_columns::Migration),
+            Box::new(m20251123_091544_create_board_activity_table::Migration),
+            Box::new(m20251125_160233_add_board_event_sequence::Migration),
+            Box::new(m20251127_083412_create_event_outbox_table::Migration),
+            Box::new(m20251129_094215_add_version_columns::Migration),
+            Box::new(m20251201_101437_add_column_wip_limit::Migration),
+            Box::new(m20251203_142806_add_done_columns_and_task_completion::Migration),
+            Box::new(m20251205_093512_create_checklist_tables::Migration),
+            Box::new(m20251207_104129_create_task_relation_table::Migration),
+            Box::new(m20251209_081745_create_label_tables::Migration),
+            Box::new(m20251211_093012_create_custom_field_table::Migration),
+            Box::new(m20251213_101524_create_time_entry_table::Migration),
         ]
     }
 }
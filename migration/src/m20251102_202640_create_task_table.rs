@@ -56,7 +56,7 @@ impl MigrationTrait for Migration {
 }
 
 #[derive(DeriveIden)]
-enum Task {
+pub enum Task {
     Table,
     Id,
     Title,
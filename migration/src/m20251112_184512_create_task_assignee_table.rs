@@ -0,0 +1,71 @@
+use crate::{m20251102_200527_create_user_table::User, m20251102_202640_create_task_table::Task};
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_table(
+                Table::create()
+                    .table(TaskAssignee::Table)
+                    .if_not_exists()
+                    .col(
+                        ColumnDef::new(TaskAssignee::Id)
+                            .uuid()
+                            .not_null()
+                            .primary_key()
+                            .extra("DEFAULT uuidv7()"),
+                    )
+                    .col(ColumnDef::new(TaskAssignee::TaskId).uuid().not_null())
+                    .col(ColumnDef::new(TaskAssignee::UserId).uuid().not_null())
+                    .col(
+                        ColumnDef::new(TaskAssignee::CreatedAt)
+                            .timestamp_with_time_zone()
+                            .not_null()
+                            .extra("DEFAULT NOW()"),
+                    )
+                    .index(
+                        Index::create()
+                            .name("idx_task_assignee_task_user_unique")
+                            .table(TaskAssignee::Table)
+                            .col(TaskAssignee::TaskId)
+                            .col(TaskAssignee::UserId)
+                            .unique(),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk_task_assignee_task")
+                            .from(TaskAssignee::Table, TaskAssignee::TaskId)
+                            .to(Task::Table, Task::Id)
+                            .on_delete(ForeignKeyAction::Cascade),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk_task_assignee_user")
+                            .from(TaskAssignee::Table, TaskAssignee::UserId)
+                            .to(User::Table, User::Id)
+                            .on_delete(ForeignKeyAction::Cascade),
+                    )
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(Table::drop().table(TaskAssignee::Table).to_owned())
+            .await
+    }
+}
+
+#[derive(DeriveIden)]
+pub enum TaskAssignee {
+    Table,
+    Id,
+    TaskId,
+    UserId,
+    CreatedAt,
+}
@@ -0,0 +1,75 @@
+use crate::m20251102_202640_create_task_table::Task;
+use sea_orm::{EnumIter, Iterable};
+use sea_orm_migration::prelude::{extension::postgres::Type, *};
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_type(
+                Type::create()
+                    .as_enum(TaskPriorityEnum)
+                    .values(Priority::iter())
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(Task::Table)
+                    .add_column(
+                        ColumnDef::new(TaskSchedule::Priority)
+                            .enumeration(Alias::new("task_priority_enum"), Priority::iter())
+                            .not_null()
+                            .default(Priority::Medium.to_string()),
+                    )
+                    .add_column(ColumnDef::new(TaskSchedule::StartAt).timestamp_with_time_zone())
+                    .add_column(ColumnDef::new(TaskSchedule::DueAt).timestamp_with_time_zone())
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(Task::Table)
+                    .drop_column(TaskSchedule::Priority)
+                    .drop_column(TaskSchedule::StartAt)
+                    .drop_column(TaskSchedule::DueAt)
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .drop_type(Type::drop().name(TaskPriorityEnum).to_owned())
+            .await
+    }
+}
+
+#[derive(DeriveIden)]
+enum TaskSchedule {
+    Priority,
+    StartAt,
+    DueAt,
+}
+
+#[derive(DeriveIden)]
+pub struct TaskPriorityEnum;
+
+#[derive(EnumIter, Iden)]
+pub enum Priority {
+    #[iden = "low"]
+    Low,
+    #[iden = "medium"]
+    Medium,
+    #[iden = "high"]
+    High,
+    #[iden = "urgent"]
+    Urgent,
+}
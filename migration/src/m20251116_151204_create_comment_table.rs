@@ -0,0 +1,144 @@
+use crate::{m20251102_200527_create_user_table::User, m20251102_202640_create_task_table::Task};
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_table(
+                Table::create()
+                    .table(Comment::Table)
+                    .if_not_exists()
+                    .col(
+                        ColumnDef::new(Comment::Id)
+                            .uuid()
+                            .not_null()
+                            .primary_key()
+                            .extra("DEFAULT uuidv7()"),
+                    )
+                    .col(ColumnDef::new(Comment::TaskId).uuid().not_null())
+                    .col(ColumnDef::new(Comment::ParentId).uuid())
+                    .col(ColumnDef::new(Comment::AuthorId).uuid().not_null())
+                    .col(ColumnDef::new(Comment::Content).text().not_null())
+                    .col(
+                        ColumnDef::new(Comment::CreatedAt)
+                            .timestamp_with_time_zone()
+                            .not_null()
+                            .extra("DEFAULT NOW()"),
+                    )
+                    .col(
+                        ColumnDef::new(Comment::UpdatedAt)
+                            .timestamp_with_time_zone()
+                            .not_null()
+                            .extra("DEFAULT NOW()"),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk_comment_task")
+                            .from(Comment::Table, Comment::TaskId)
+                            .to(Task::Table, Task::Id)
+                            .on_delete(ForeignKeyAction::Cascade),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk_comment_parent")
+                            .from(Comment::Table, Comment::ParentId)
+                            .to(Comment::Table, Comment::Id)
+                            .on_delete(ForeignKeyAction::Cascade),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk_comment_author")
+                            .from(Comment::Table, Comment::AuthorId)
+                            .to(User::Table, User::Id)
+                            .on_delete(ForeignKeyAction::Cascade),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx_comment_task")
+                    .table(Comment::Table)
+                    .col(Comment::TaskId)
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_table(
+                Table::create()
+                    .table(CommentRevision::Table)
+                    .if_not_exists()
+                    .col(
+                        ColumnDef::new(CommentRevision::Id)
+                            .uuid()
+                            .not_null()
+                            .primary_key()
+                            .extra("DEFAULT uuidv7()"),
+                    )
+                    .col(ColumnDef::new(CommentRevision::CommentId).uuid().not_null())
+                    .col(ColumnDef::new(CommentRevision::Content).text().not_null())
+                    .col(ColumnDef::new(CommentRevision::EditedBy).uuid().not_null())
+                    .col(
+                        ColumnDef::new(CommentRevision::CreatedAt)
+                            .timestamp_with_time_zone()
+                            .not_null()
+                            .extra("DEFAULT NOW()"),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk_comment_revision_comment")
+                            .from(CommentRevision::Table, CommentRevision::CommentId)
+                            .to(Comment::Table, Comment::Id)
+                            .on_delete(ForeignKeyAction::Cascade),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk_comment_revision_user")
+                            .from(CommentRevision::Table, CommentRevision::EditedBy)
+                            .to(User::Table, User::Id)
+                            .on_delete(ForeignKeyAction::Cascade),
+                    )
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(Table::drop().table(CommentRevision::Table).to_owned())
+            .await?;
+
+        manager
+            .drop_table(Table::drop().table(Comment::Table).to_owned())
+            .await
+    }
+}
+
+#[derive(DeriveIden)]
+pub enum Comment {
+    Table,
+    Id,
+    TaskId,
+    ParentId,
+    AuthorId,
+    Content,
+    CreatedAt,
+    UpdatedAt,
+}
+
+#[derive(DeriveIden)]
+pub enum CommentRevision {
+    Table,
+    Id,
+    CommentId,
+    Content,
+    EditedBy,
+    CreatedAt,
+}
@@ -0,0 +1,116 @@
+use crate::{
+    m20251102_200527_create_user_table::User, m20251102_201124_create_board_table::Board,
+    m20251102_202640_create_task_table::Task,
+};
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_table(
+                Table::create()
+                    .table(Attachment::Table)
+                    .if_not_exists()
+                    .col(
+                        ColumnDef::new(Attachment::Id)
+                            .uuid()
+                            .not_null()
+                            .primary_key()
+                            .extra("DEFAULT uuidv7()"),
+                    )
+                    .col(ColumnDef::new(Attachment::TaskId).uuid().not_null())
+                    .col(ColumnDef::new(Attachment::BoardId).uuid().not_null())
+                    .col(ColumnDef::new(Attachment::UploadedBy).uuid().not_null())
+                    .col(
+                        ColumnDef::new(Attachment::FileName)
+                            .string_len(255)
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(Attachment::ContentType)
+                            .string_len(255)
+                            .not_null(),
+                    )
+                    .col(ColumnDef::new(Attachment::Size).big_integer().not_null())
+                    .col(
+                        ColumnDef::new(Attachment::StorageKey)
+                            .string_len(512)
+                            .not_null()
+                            .unique_key(),
+                    )
+                    .col(
+                        ColumnDef::new(Attachment::CreatedAt)
+                            .timestamp_with_time_zone()
+                            .not_null()
+                            .extra("DEFAULT NOW()"),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk_attachment_task")
+                            .from(Attachment::Table, Attachment::TaskId)
+                            .to(Task::Table, Task::Id)
+                            .on_delete(ForeignKeyAction::Cascade),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk_attachment_board")
+                            .from(Attachment::Table, Attachment::BoardId)
+                            .to(Board::Table, Board::Id)
+                            .on_delete(ForeignKeyAction::Cascade),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk_attachment_uploaded_by")
+                            .from(Attachment::Table, Attachment::UploadedBy)
+                            .to(User::Table, User::Id)
+                            .on_delete(ForeignKeyAction::Cascade),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx_attachment_task")
+                    .table(Attachment::Table)
+                    .col(Attachment::TaskId)
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx_attachment_board")
+                    .table(Attachment::Table)
+                    .col(Attachment::BoardId)
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(Table::drop().table(Attachment::Table).to_owned())
+            .await
+    }
+}
+
+#[derive(DeriveIden)]
+pub enum Attachment {
+    Table,
+    Id,
+    TaskId,
+    BoardId,
+    UploadedBy,
+    FileName,
+    ContentType,
+    Size,
+    StorageKey,
+    CreatedAt,
+}
@@ -0,0 +1,81 @@
+use crate::m20251102_200527_create_user_table::User;
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        // The board is intentionally not referenced by a foreign key, so that the
+        // audit trail (including the deletion event itself) outlives the board
+        manager
+            .create_table(
+                Table::create()
+                    .table(BoardActivity::Table)
+                    .if_not_exists()
+                    .col(
+                        ColumnDef::new(BoardActivity::Id)
+                            .uuid()
+                            .not_null()
+                            .primary_key()
+                            .extra("DEFAULT uuidv7()"),
+                    )
+                    .col(ColumnDef::new(BoardActivity::BoardId).uuid().not_null())
+                    .col(ColumnDef::new(BoardActivity::ActorId).uuid())
+                    .col(
+                        ColumnDef::new(BoardActivity::EventType)
+                            .string_len(50)
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(BoardActivity::Payload)
+                            .json_binary()
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(BoardActivity::CreatedAt)
+                            .timestamp_with_time_zone()
+                            .not_null()
+                            .extra("DEFAULT NOW()"),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk_board_activity_actor")
+                            .from(BoardActivity::Table, BoardActivity::ActorId)
+                            .to(User::Table, User::Id)
+                            .on_delete(ForeignKeyAction::SetNull),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx_board_activity_board_id")
+                    .table(BoardActivity::Table)
+                    .col(BoardActivity::BoardId)
+                    .col(BoardActivity::Id)
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(Table::drop().table(BoardActivity::Table).to_owned())
+            .await
+    }
+}
+
+#[derive(DeriveIden)]
+pub enum BoardActivity {
+    Table,
+    Id,
+    BoardId,
+    ActorId,
+    EventType,
+    Payload,
+    CreatedAt,
+}
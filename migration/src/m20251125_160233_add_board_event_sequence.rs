@@ -0,0 +1,113 @@
+use crate::m20251123_091544_create_board_activity_table::BoardActivity;
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_table(
+                Table::create()
+                    .table(BoardEventSequence::Table)
+                    .if_not_exists()
+                    .col(
+                        ColumnDef::new(BoardEventSequence::BoardId)
+                            .uuid()
+                            .not_null()
+                            .primary_key(),
+                    )
+                    .col(
+                        ColumnDef::new(BoardEventSequence::LastSequence)
+                            .big_integer()
+                            .not_null(),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(BoardActivity::Table)
+                    .add_column(ColumnDef::new(Sequence::Sequence).big_integer())
+                    .to_owned(),
+            )
+            .await?;
+
+        // Number already recorded activity in insertion order and seed the counters from it
+        let db = manager.get_connection();
+
+        db.execute_unprepared(
+            "UPDATE board_activity SET sequence = numbered.sequence \
+             FROM (SELECT id, ROW_NUMBER() OVER (PARTITION BY board_id ORDER BY id) AS sequence \
+                   FROM board_activity) AS numbered \
+             WHERE board_activity.id = numbered.id",
+        )
+        .await?;
+
+        db.execute_unprepared(
+            "INSERT INTO board_event_sequence (board_id, last_sequence) \
+             SELECT board_id, MAX(sequence) FROM board_activity GROUP BY board_id",
+        )
+        .await?;
+
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(BoardActivity::Table)
+                    .modify_column(ColumnDef::new(Sequence::Sequence).big_integer().not_null())
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx_board_activity_board_sequence_unique")
+                    .table(BoardActivity::Table)
+                    .col(BoardActivity::BoardId)
+                    .col(Sequence::Sequence)
+                    .unique()
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_index(
+                Index::drop()
+                    .name("idx_board_activity_board_sequence_unique")
+                    .table(BoardActivity::Table)
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(BoardActivity::Table)
+                    .drop_column(Sequence::Sequence)
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .drop_table(Table::drop().table(BoardEventSequence::Table).to_owned())
+            .await
+    }
+}
+
+#[derive(DeriveIden)]
+enum Sequence {
+    Sequence,
+}
+
+#[derive(DeriveIden)]
+pub enum BoardEventSequence {
+    Table,
+    BoardId,
+    LastSequence,
+}
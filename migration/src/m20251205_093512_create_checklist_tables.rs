@@ -0,0 +1,168 @@
+use crate::{m20251102_200527_create_user_table::User, m20251102_202640_create_task_table::Task};
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_table(
+                Table::create()
+                    .table(Checklist::Table)
+                    .if_not_exists()
+                    .col(
+                        ColumnDef::new(Checklist::Id)
+                            .uuid()
+                            .not_null()
+                            .primary_key()
+                            .extra("DEFAULT uuidv7()"),
+                    )
+                    .col(ColumnDef::new(Checklist::TaskId).uuid().not_null())
+                    .col(ColumnDef::new(Checklist::Title).string_len(100).not_null())
+                    .col(
+                        ColumnDef::new(Checklist::Position)
+                            .string_len(50)
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(Checklist::CreatedAt)
+                            .timestamp_with_time_zone()
+                            .not_null()
+                            .extra("DEFAULT NOW()"),
+                    )
+                    .col(
+                        ColumnDef::new(Checklist::UpdatedAt)
+                            .timestamp_with_time_zone()
+                            .not_null()
+                            .extra("DEFAULT NOW()"),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk_checklist_task")
+                            .from(Checklist::Table, Checklist::TaskId)
+                            .to(Task::Table, Task::Id)
+                            .on_delete(ForeignKeyAction::Cascade),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx_checklist_task")
+                    .table(Checklist::Table)
+                    .col(Checklist::TaskId)
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_table(
+                Table::create()
+                    .table(ChecklistItem::Table)
+                    .if_not_exists()
+                    .col(
+                        ColumnDef::new(ChecklistItem::Id)
+                            .uuid()
+                            .not_null()
+                            .primary_key()
+                            .extra("DEFAULT uuidv7()"),
+                    )
+                    .col(ColumnDef::new(ChecklistItem::ChecklistId).uuid().not_null())
+                    .col(
+                        ColumnDef::new(ChecklistItem::Content)
+                            .string_len(500)
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(ChecklistItem::Position)
+                            .string_len(50)
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(ChecklistItem::IsCompleted)
+                            .boolean()
+                            .not_null()
+                            .default(false),
+                    )
+                    .col(ColumnDef::new(ChecklistItem::CompletedBy).uuid())
+                    .col(ColumnDef::new(ChecklistItem::CompletedAt).timestamp_with_time_zone())
+                    .col(
+                        ColumnDef::new(ChecklistItem::CreatedAt)
+                            .timestamp_with_time_zone()
+                            .not_null()
+                            .extra("DEFAULT NOW()"),
+                    )
+                    .col(
+                        ColumnDef::new(ChecklistItem::UpdatedAt)
+                            .timestamp_with_time_zone()
+                            .not_null()
+                            .extra("DEFAULT NOW()"),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk_checklist_item_checklist")
+                            .from(ChecklistItem::Table, ChecklistItem::ChecklistId)
+                            .to(Checklist::Table, Checklist::Id)
+                            .on_delete(ForeignKeyAction::Cascade),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk_checklist_item_completed_by")
+                            .from(ChecklistItem::Table, ChecklistItem::CompletedBy)
+                            .to(User::Table, User::Id)
+                            .on_delete(ForeignKeyAction::SetNull),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx_checklist_item_checklist")
+                    .table(ChecklistItem::Table)
+                    .col(ChecklistItem::ChecklistId)
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(Table::drop().table(ChecklistItem::Table).to_owned())
+            .await?;
+
+        manager
+            .drop_table(Table::drop().table(Checklist::Table).to_owned())
+            .await
+    }
+}
+
+#[derive(DeriveIden)]
+pub enum Checklist {
+    Table,
+    Id,
+    TaskId,
+    Title,
+    Position,
+    CreatedAt,
+    UpdatedAt,
+}
+
+#[derive(DeriveIden)]
+pub enum ChecklistItem {
+    Table,
+    Id,
+    ChecklistId,
+    Content,
+    Position,
+    IsCompleted,
+    CompletedBy,
+    CompletedAt,
+    CreatedAt,
+    UpdatedAt,
+}
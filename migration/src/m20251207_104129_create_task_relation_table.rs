@@ -0,0 +1,131 @@
+use crate::{m20251102_200527_create_user_table::User, m20251102_202640_create_task_table::Task};
+use sea_orm::{EnumIter, Iterable};
+use sea_orm_migration::prelude::{extension::postgres::Type, *};
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_type(
+                Type::create()
+                    .as_enum(TaskRelationTypeEnum)
+                    .values(RelationType::iter())
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_table(
+                Table::create()
+                    .table(TaskRelation::Table)
+                    .if_not_exists()
+                    .col(
+                        ColumnDef::new(TaskRelation::Id)
+                            .uuid()
+                            .not_null()
+                            .primary_key()
+                            .extra("DEFAULT uuidv7()"),
+                    )
+                    .col(ColumnDef::new(TaskRelation::SourceTaskId).uuid().not_null())
+                    .col(ColumnDef::new(TaskRelation::TargetTaskId).uuid().not_null())
+                    .col(
+                        ColumnDef::new(TaskRelation::RelationType)
+                            .enumeration(
+                                Alias::new("task_relation_type_enum"),
+                                RelationType::iter(),
+                            )
+                            .not_null(),
+                    )
+                    .col(ColumnDef::new(TaskRelation::CreatedBy).uuid())
+                    .col(
+                        ColumnDef::new(TaskRelation::CreatedAt)
+                            .timestamp_with_time_zone()
+                            .not_null()
+                            .extra("DEFAULT NOW()"),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk_task_relation_source_task")
+                            .from(TaskRelation::Table, TaskRelation::SourceTaskId)
+                            .to(Task::Table, Task::Id)
+                            .on_delete(ForeignKeyAction::Cascade),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk_task_relation_target_task")
+                            .from(TaskRelation::Table, TaskRelation::TargetTaskId)
+                            .to(Task::Table, Task::Id)
+                            .on_delete(ForeignKeyAction::Cascade),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk_task_relation_created_by")
+                            .from(TaskRelation::Table, TaskRelation::CreatedBy)
+                            .to(User::Table, User::Id)
+                            .on_delete(ForeignKeyAction::SetNull),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx_task_relation_unique")
+                    .table(TaskRelation::Table)
+                    .col(TaskRelation::SourceTaskId)
+                    .col(TaskRelation::TargetTaskId)
+                    .col(TaskRelation::RelationType)
+                    .unique()
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx_task_relation_target_task")
+                    .table(TaskRelation::Table)
+                    .col(TaskRelation::TargetTaskId)
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(Table::drop().table(TaskRelation::Table).to_owned())
+            .await?;
+
+        manager
+            .drop_type(Type::drop().name(TaskRelationTypeEnum).to_owned())
+            .await
+    }
+}
+
+#[derive(DeriveIden)]
+pub enum TaskRelation {
+    Table,
+    Id,
+    SourceTaskId,
+    TargetTaskId,
+    RelationType,
+    CreatedBy,
+    CreatedAt,
+}
+
+#[derive(DeriveIden)]
+pub struct TaskRelationTypeEnum;
+
+#[derive(EnumIter, Iden)]
+pub enum RelationType {
+    #[iden = "blocks"]
+    Blocks,
+    #[iden = "duplicates"]
+    Duplicates,
+    #[iden = "relates_to"]
+    RelatesTo,
+}
@@ -0,0 +1,220 @@
+use crate::{m20251102_201124_create_board_table::Board, m20251102_202640_create_task_table::Task};
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_table(
+                Table::create()
+                    .table(Label::Table)
+                    .if_not_exists()
+                    .col(
+                        ColumnDef::new(Label::Id)
+                            .uuid()
+                            .not_null()
+                            .primary_key()
+                            .extra("DEFAULT uuidv7()"),
+                    )
+                    .col(ColumnDef::new(Label::BoardId).uuid().not_null())
+                    .col(ColumnDef::new(Label::Name).string_len(50).not_null())
+                    .col(
+                        ColumnDef::new(Label::Color)
+                            .string_len(7)
+                            .not_null()
+                            .default(DEFAULT_LABEL_COLOR),
+                    )
+                    .col(
+                        ColumnDef::new(Label::CreatedAt)
+                            .timestamp_with_time_zone()
+                            .not_null()
+                            .extra("DEFAULT NOW()"),
+                    )
+                    .col(
+                        ColumnDef::new(Label::UpdatedAt)
+                            .timestamp_with_time_zone()
+                            .not_null()
+                            .extra("DEFAULT NOW()"),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk_label_board")
+                            .from(Label::Table, Label::BoardId)
+                            .to(Board::Table, Board::Id)
+                            .on_delete(ForeignKeyAction::Cascade),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        // Names are unique per board regardless of case, which sea-query can't express
+        manager
+            .get_connection()
+            .execute_unprepared(
+                "CREATE UNIQUE INDEX idx_label_board_name ON label (board_id, lower(name))",
+            )
+            .await?;
+
+        manager
+            .create_table(
+                Table::create()
+                    .table(TaskLabel::Table)
+                    .if_not_exists()
+                    .col(
+                        ColumnDef::new(TaskLabel::Id)
+                            .uuid()
+                            .not_null()
+                            .primary_key()
+                            .extra("DEFAULT uuidv7()"),
+                    )
+                    .col(ColumnDef::new(TaskLabel::TaskId).uuid().not_null())
+                    .col(ColumnDef::new(TaskLabel::LabelId).uuid().not_null())
+                    .col(
+                        ColumnDef::new(TaskLabel::CreatedAt)
+                            .timestamp_with_time_zone()
+                            .not_null()
+                            .extra("DEFAULT NOW()"),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk_task_label_task")
+                            .from(TaskLabel::Table, TaskLabel::TaskId)
+                            .to(Task::Table, Task::Id)
+                            .on_delete(ForeignKeyAction::Cascade),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk_task_label_label")
+                            .from(TaskLabel::Table, TaskLabel::LabelId)
+                            .to(Label::Table, Label::Id)
+                            .on_delete(ForeignKeyAction::Cascade),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx_task_label_unique")
+                    .table(TaskLabel::Table)
+                    .col(TaskLabel::TaskId)
+                    .col(TaskLabel::LabelId)
+                    .unique()
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx_task_label_label")
+                    .table(TaskLabel::Table)
+                    .col(TaskLabel::LabelId)
+                    .to_owned(),
+            )
+            .await?;
+
+        // Tags spelled differently only by case or surrounding whitespace become one label,
+        // named after the spelling used on the board's oldest task
+        manager
+            .get_connection()
+            .execute_unprepared(
+                r#"
+                INSERT INTO label (board_id, name)
+                SELECT DISTINCT ON (c.board_id, lower(btrim(t.tag))) c.board_id, btrim(t.tag)
+                FROM task
+                CROSS JOIN LATERAL unnest(task.tags) AS t(tag)
+                JOIN "column" c ON c.id = task.column_id
+                WHERE btrim(t.tag) <> ''
+                ORDER BY c.board_id, lower(btrim(t.tag)), task.created_at
+                "#,
+            )
+            .await?;
+
+        manager
+            .get_connection()
+            .execute_unprepared(
+                r#"
+                INSERT INTO task_label (task_id, label_id)
+                SELECT DISTINCT task.id, l.id
+                FROM task
+                CROSS JOIN LATERAL unnest(task.tags) AS t(tag)
+                JOIN "column" c ON c.id = task.column_id
+                JOIN label l ON l.board_id = c.board_id AND lower(l.name) = lower(btrim(t.tag))
+                "#,
+            )
+            .await?;
+
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(Task::Table)
+                    .drop_column(Task::Tags)
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(Task::Table)
+                    .add_column(
+                        ColumnDef::new(Task::Tags).array(ColumnType::String(StringLen::N(50))),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .get_connection()
+            .execute_unprepared(
+                r#"
+                UPDATE task SET tags = labels.names
+                FROM (
+                    SELECT tl.task_id, array_agg(l.name ORDER BY tl.created_at) AS names
+                    FROM task_label tl
+                    JOIN label l ON l.id = tl.label_id
+                    GROUP BY tl.task_id
+                ) AS labels
+                WHERE task.id = labels.task_id
+                "#,
+            )
+            .await?;
+
+        manager
+            .drop_table(Table::drop().table(TaskLabel::Table).to_owned())
+            .await?;
+
+        manager
+            .drop_table(Table::drop().table(Label::Table).to_owned())
+            .await
+    }
+}
+
+const DEFAULT_LABEL_COLOR: &str = "#94a3b8";
+
+#[derive(DeriveIden)]
+pub enum Label {
+    Table,
+    Id,
+    BoardId,
+    Name,
+    Color,
+    CreatedAt,
+    UpdatedAt,
+}
+
+#[derive(DeriveIden)]
+pub enum TaskLabel {
+    Table,
+    Id,
+    TaskId,
+    LabelId,
+    CreatedAt,
+}
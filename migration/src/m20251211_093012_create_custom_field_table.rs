@@ -0,0 +1,155 @@
+use crate::{m20251102_201124_create_board_table::Board, m20251102_202640_create_task_table::Task};
+use sea_orm::{EnumIter, Iterable};
+use sea_orm_migration::prelude::{extension::postgres::Type, *};
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_type(
+                Type::create()
+                    .as_enum(CustomFieldTypeEnum)
+                    .values(FieldType::iter())
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_table(
+                Table::create()
+                    .table(CustomField::Table)
+                    .if_not_exists()
+                    .col(
+                        ColumnDef::new(CustomField::Id)
+                            .uuid()
+                            .not_null()
+                            .primary_key()
+                            .extra("DEFAULT uuidv7()"),
+                    )
+                    .col(ColumnDef::new(CustomField::BoardId).uuid().not_null())
+                    .col(ColumnDef::new(CustomField::Name).string_len(50).not_null())
+                    .col(
+                        ColumnDef::new(CustomField::FieldType)
+                            .enumeration(Alias::new("custom_field_type_enum"), FieldType::iter())
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(CustomField::Options)
+                            .array(ColumnType::String(StringLen::N(50)))
+                            .not_null()
+                            .extra("DEFAULT '{}'"),
+                    )
+                    .col(
+                        ColumnDef::new(CustomField::CreatedAt)
+                            .timestamp_with_time_zone()
+                            .not_null()
+                            .extra("DEFAULT NOW()"),
+                    )
+                    .col(
+                        ColumnDef::new(CustomField::UpdatedAt)
+                            .timestamp_with_time_zone()
+                            .not_null()
+                            .extra("DEFAULT NOW()"),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk_custom_field_board")
+                            .from(CustomField::Table, CustomField::BoardId)
+                            .to(Board::Table, Board::Id)
+                            .on_delete(ForeignKeyAction::Cascade),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .get_connection()
+            .execute_unprepared(
+                "CREATE UNIQUE INDEX idx_custom_field_board_name ON custom_field (board_id, lower(name))",
+            )
+            .await?;
+
+        // Values are keyed by field ID, e.g. {"<field id>": 5}
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(Task::Table)
+                    .add_column(
+                        ColumnDef::new(CustomFieldValues::CustomFields)
+                            .json_binary()
+                            .not_null()
+                            .extra("DEFAULT '{}'"),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        // Serves the containment (@>) lookups of the task search
+        manager
+            .get_connection()
+            .execute_unprepared(
+                "CREATE INDEX idx_task_custom_fields ON task USING GIN (custom_fields jsonb_path_ops)",
+            )
+            .await?;
+
+        Ok(())
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(Task::Table)
+                    .drop_column(CustomFieldValues::CustomFields)
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .drop_table(Table::drop().table(CustomField::Table).to_owned())
+            .await?;
+
+        manager
+            .drop_type(Type::drop().name(CustomFieldTypeEnum).to_owned())
+            .await
+    }
+}
+
+#[derive(DeriveIden)]
+pub enum CustomField {
+    Table,
+    Id,
+    BoardId,
+    Name,
+    FieldType,
+    Options,
+    CreatedAt,
+    UpdatedAt,
+}
+
+#[derive(DeriveIden)]
+enum CustomFieldValues {
+    CustomFields,
+}
+
+#[derive(DeriveIden)]
+pub struct CustomFieldTypeEnum;
+
+#[derive(EnumIter, Iden)]
+pub enum FieldType {
+    #[iden = "text"]
+    Text,
+    #[iden = "number"]
+    Number,
+    #[iden = "date"]
+    Date,
+    #[iden = "single_select"]
+    SingleSelect,
+    #[iden = "multi_select"]
+    MultiSelect,
+    #[iden = "user"]
+    User,
+}
@@ -0,0 +1,124 @@
+use crate::{m20251102_200527_create_user_table::User, m20251102_202640_create_task_table::Task};
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(Task::Table)
+                    .add_column(ColumnDef::new(Estimate::EstimateMinutes).integer())
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_table(
+                Table::create()
+                    .table(TimeEntry::Table)
+                    .if_not_exists()
+                    .col(
+                        ColumnDef::new(TimeEntry::Id)
+                            .uuid()
+                            .not_null()
+                            .primary_key()
+                            .extra("DEFAULT uuidv7()"),
+                    )
+                    .col(ColumnDef::new(TimeEntry::TaskId).uuid().not_null())
+                    .col(ColumnDef::new(TimeEntry::UserId).uuid().not_null())
+                    .col(
+                        ColumnDef::new(TimeEntry::StartedAt)
+                            .timestamp_with_time_zone()
+                            .not_null(),
+                    )
+                    .col(ColumnDef::new(TimeEntry::EndedAt).timestamp_with_time_zone())
+                    .col(ColumnDef::new(TimeEntry::Note).string_len(500))
+                    .col(
+                        ColumnDef::new(TimeEntry::CreatedAt)
+                            .timestamp_with_time_zone()
+                            .not_null()
+                            .extra("DEFAULT NOW()"),
+                    )
+                    .col(
+                        ColumnDef::new(TimeEntry::UpdatedAt)
+                            .timestamp_with_time_zone()
+                            .not_null()
+                            .extra("DEFAULT NOW()"),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk_time_entry_task")
+                            .from(TimeEntry::Table, TimeEntry::TaskId)
+                            .to(Task::Table, Task::Id)
+                            .on_delete(ForeignKeyAction::Cascade),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk_time_entry_user")
+                            .from(TimeEntry::Table, TimeEntry::UserId)
+                            .to(User::Table, User::Id)
+                            .on_delete(ForeignKeyAction::Cascade),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx_time_entry_task")
+                    .table(TimeEntry::Table)
+                    .col(TimeEntry::TaskId)
+                    .col(TimeEntry::StartedAt)
+                    .to_owned(),
+            )
+            .await?;
+
+        // A running timer is an entry without an end, and each user can only have one
+        manager
+            .get_connection()
+            .execute_unprepared(
+                "CREATE UNIQUE INDEX idx_time_entry_running_user ON time_entry (user_id) WHERE ended_at IS NULL",
+            )
+            .await?;
+
+        Ok(())
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(Table::drop().table(TimeEntry::Table).to_owned())
+            .await?;
+
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(Task::Table)
+                    .drop_column(Estimate::EstimateMinutes)
+                    .to_owned(),
+            )
+            .await
+    }
+}
+
+#[derive(DeriveIden)]
+pub enum TimeEntry {
+    Table,
+    Id,
+    TaskId,
+    UserId,
+    StartedAt,
+    EndedAt,
+    Note,
+    CreatedAt,
+    UpdatedAt,
+}
+
+#[derive(DeriveIden)]
+enum Estimate {
+    EstimateMinutes,
+}
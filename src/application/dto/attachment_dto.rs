@@ -0,0 +1,41 @@
+use chrono::{DateTime, FixedOffset};
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+use uuid::Uuid;
+
+use crate::domain::repositories::Attachment;
+
+#[derive(Debug, ToSchema)]
+#[allow(dead_code)]
+pub struct UploadAttachmentDto {
+    #[schema(value_type = String, format = Binary)]
+    pub file: Vec<u8>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct AttachmentDto {
+    pub id: Uuid,
+    pub task_id: Uuid,
+    pub board_id: Uuid,
+    pub uploaded_by: Uuid,
+    pub file_name: String,
+    pub content_type: String,
+    pub size: i64,
+    pub created_at: DateTime<FixedOffset>,
+}
+
+impl AttachmentDto {
+    pub fn from_domain(attachment: Attachment) -> Self {
+        Self {
+            id: attachment.id,
+            task_id: attachment.task_id,
+            board_id: attachment.board_id,
+            uploaded_by: attachment.uploaded_by,
+            file_name: attachment.file_name,
+            content_type: attachment.content_type,
+            size: attachment.size,
+            created_at: attachment.created_at,
+        }
+    }
+}
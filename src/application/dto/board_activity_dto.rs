@@ -0,0 +1,59 @@
+use chrono::{DateTime, FixedOffset};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use utoipa::ToSchema;
+use uuid::Uuid;
+use validator::Validate;
+
+use crate::domain::repositories::BoardActivity;
+
+#[derive(Debug, Clone, Default, Deserialize, Serialize, Validate, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct BoardActivityQueryDto {
+    pub cursor: Option<Uuid>,
+    #[validate(range(min = 1, max = 100, message = "Limit must be between 1 and 100"))]
+    pub limit: Option<u64>,
+    pub actor_id: Option<Uuid>,
+    #[validate(length(
+        min = 1,
+        max = 50,
+        message = "Event type must be between 1 and 50 characters long"
+    ))]
+    pub event_type: Option<String>,
+    pub from: Option<DateTime<FixedOffset>>,
+    pub to: Option<DateTime<FixedOffset>>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct BoardActivityDto {
+    pub id: Uuid,
+    pub board_id: Uuid,
+    pub actor_id: Option<Uuid>,
+    pub sequence: i64,
+    pub event_type: String,
+    #[schema(value_type = Object)]
+    pub payload: Value,
+    pub created_at: DateTime<FixedOffset>,
+}
+
+impl BoardActivityDto {
+    pub fn from_domain(activity: BoardActivity) -> Self {
+        Self {
+            id: activity.id,
+            board_id: activity.board_id,
+            actor_id: activity.actor_id,
+            sequence: activity.sequence,
+            event_type: activity.event_type,
+            payload: activity.payload,
+            created_at: activity.created_at,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct BoardActivityPageDto {
+    pub items: Vec<BoardActivityDto>,
+    pub next_cursor: Option<Uuid>,
+}
@@ -0,0 +1,64 @@
+use chrono::{DateTime, FixedOffset, NaiveDate};
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+use uuid::Uuid;
+
+#[derive(Debug, Clone, Default, Deserialize, Serialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct BoardAnalyticsQueryDto {
+    pub from: Option<NaiveDate>,
+    pub to: Option<NaiveDate>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct TaskFlowMetricsDto {
+    pub task_id: Uuid,
+    pub created_at: DateTime<FixedOffset>,
+    pub started_at: Option<DateTime<FixedOffset>>,
+    pub completed_at: DateTime<FixedOffset>,
+    pub lead_time_hours: f64,
+    pub cycle_time_hours: Option<f64>,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct FlowTimePercentilesDto {
+    pub p50: Option<f64>,
+    pub p85: Option<f64>,
+    pub p95: Option<f64>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct WeeklyThroughputDto {
+    pub week_start: NaiveDate,
+    pub completed: usize,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct ColumnTaskCountDto {
+    pub column_id: Uuid,
+    pub count: usize,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct CumulativeFlowDayDto {
+    pub date: NaiveDate,
+    pub columns: Vec<ColumnTaskCountDto>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct BoardAnalyticsDto {
+    pub board_id: Uuid,
+    pub from: NaiveDate,
+    pub to: NaiveDate,
+    pub tasks: Vec<TaskFlowMetricsDto>,
+    pub lead_time: FlowTimePercentilesDto,
+    pub cycle_time: FlowTimePercentilesDto,
+    pub throughput: Vec<WeeklyThroughputDto>,
+    pub cumulative_flow: Vec<CumulativeFlowDayDto>,
+}
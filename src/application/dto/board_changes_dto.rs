@@ -0,0 +1,56 @@
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+use uuid::Uuid;
+use validator::Validate;
+
+use crate::application::dto::{
+    BoardDto, BoardMemberDto, ColumnDto, CustomFieldDto, LabelDto, TaskDto,
+};
+
+#[derive(Debug, Clone, Default, Deserialize, Serialize, Validate, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct BoardChangesQueryDto {
+    pub cursor: Option<i64>,
+    #[validate(range(min = 1, max = 1000, message = "Limit must be between 1 and 1000"))]
+    pub limit: Option<u64>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "camelCase", tag = "type", content = "data")]
+pub enum BoardChangeDto {
+    BoardUpserted(BoardDto),
+    MemberUpserted(BoardMemberDto),
+    MemberDeleted(MemberTombstoneDto),
+    ColumnUpserted(ColumnDto),
+    /// The tasks of a deleted column are deleted with it
+    ColumnDeleted(TombstoneDto),
+    TaskUpserted(Box<TaskDto>),
+    TaskDeleted(TombstoneDto),
+    LabelUpserted(LabelDto),
+    /// Deleted labels are removed from their tasks, which are reported as well
+    LabelDeleted(TombstoneDto),
+    CustomFieldUpserted(CustomFieldDto),
+    /// Values of deleted fields are removed from their tasks, which are reported as well
+    CustomFieldDeleted(TombstoneDto),
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct TombstoneDto {
+    pub id: Uuid,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct MemberTombstoneDto {
+    pub user_id: Uuid,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct BoardChangesPageDto {
+    pub changes: Vec<BoardChangeDto>,
+    /// Pass as `cursor` to fetch the following changes, or as `since` to the board WebSocket
+    pub next_cursor: i64,
+    pub has_more: bool,
+}
@@ -47,6 +47,8 @@ pub struct BoardDto {
     pub owner_id: Uuid,
     pub created_at: DateTime<FixedOffset>,
     pub updated_at: DateTime<FixedOffset>,
+    pub archived_at: Option<DateTime<FixedOffset>>,
+    pub version: i32,
 }
 
 impl BoardDto {
@@ -58,6 +60,8 @@ impl BoardDto {
             owner_id: board.owner_id,
             created_at: board.created_at,
             updated_at: board.updated_at,
+            archived_at: board.archived_at,
+            version: board.version,
         }
     }
 }
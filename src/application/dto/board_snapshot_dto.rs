@@ -0,0 +1,26 @@
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+use crate::application::dto::{
+    BoardDto, BoardMemberDto, ColumnDto, CustomFieldDto, LabelDto, TaskDto,
+};
+
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct BoardSnapshotColumnDto {
+    #[serde(flatten)]
+    pub column: ColumnDto,
+    pub tasks: Vec<TaskDto>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct BoardSnapshotDto {
+    pub board: BoardDto,
+    pub members: Vec<BoardMemberDto>,
+    pub columns: Vec<BoardSnapshotColumnDto>,
+    pub labels: Vec<LabelDto>,
+    pub custom_fields: Vec<CustomFieldDto>,
+    /// Pass as `since` when connecting to the board WebSocket to receive every later change
+    pub sequence: i64,
+}
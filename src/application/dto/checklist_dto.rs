@@ -0,0 +1,116 @@
+use chrono::{DateTime, FixedOffset};
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+use uuid::Uuid;
+use validator::Validate;
+
+use crate::domain::repositories::{Checklist, ChecklistItem};
+
+#[derive(Debug, Clone, Serialize, Deserialize, Validate, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct CreateChecklistDto {
+    pub task_id: Uuid,
+    #[validate(length(
+        min = 1,
+        max = 100,
+        message = "Checklist title must be between 1 and 100 characters long"
+    ))]
+    pub title: String,
+    /// Index among the task's checklists (0-based), appended at the end when omitted
+    pub position: Option<usize>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, Validate, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct UpdateChecklistDto {
+    #[validate(length(
+        min = 1,
+        max = 100,
+        message = "Checklist title must be between 1 and 100 characters long"
+    ))]
+    pub title: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, Validate, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct CreateChecklistItemDto {
+    #[validate(length(
+        min = 1,
+        max = 500,
+        message = "Checklist item content must be between 1 and 500 characters long"
+    ))]
+    pub content: String,
+    /// Index among the checklist's items (0-based), appended at the end when omitted
+    pub position: Option<usize>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, Validate, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct UpdateChecklistItemDto {
+    #[validate(length(
+        min = 1,
+        max = 500,
+        message = "Checklist item content must be between 1 and 500 characters long"
+    ))]
+    pub content: Option<String>,
+    pub is_completed: Option<bool>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct ChecklistItemDto {
+    pub id: Uuid,
+    pub checklist_id: Uuid,
+    pub content: String,
+    pub position: String,
+    pub is_completed: bool,
+    pub completed_by: Option<Uuid>,
+    pub completed_at: Option<DateTime<FixedOffset>>,
+    pub created_at: DateTime<FixedOffset>,
+    pub updated_at: DateTime<FixedOffset>,
+}
+
+impl ChecklistItemDto {
+    pub fn from_domain(item: ChecklistItem) -> Self {
+        Self {
+            id: item.id,
+            checklist_id: item.checklist_id,
+            content: item.content,
+            position: item.position,
+            is_completed: item.is_completed,
+            completed_by: item.completed_by,
+            completed_at: item.completed_at,
+            created_at: item.created_at,
+            updated_at: item.updated_at,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct ChecklistDto {
+    pub id: Uuid,
+    pub task_id: Uuid,
+    pub title: String,
+    pub position: String,
+    pub items: Vec<ChecklistItemDto>,
+    pub created_at: DateTime<FixedOffset>,
+    pub updated_at: DateTime<FixedOffset>,
+}
+
+impl ChecklistDto {
+    pub fn from_domain(checklist: Checklist, items: Vec<ChecklistItem>) -> Self {
+        Self {
+            id: checklist.id,
+            task_id: checklist.task_id,
+            title: checklist.title,
+            position: checklist.position,
+            items: items
+                .into_iter()
+                .map(ChecklistItemDto::from_domain)
+                .collect(),
+            created_at: checklist.created_at,
+            updated_at: checklist.updated_at,
+        }
+    }
+}
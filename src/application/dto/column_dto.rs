@@ -16,6 +16,11 @@ pub struct CreateColumnDto {
     ))]
     pub name: String,
     pub board_id: Uuid,
+    /// Maximum number of active tasks in the column, `0` or omitted means no limit
+    #[validate(range(min = 0, message = "WIP limit must not be negative"))]
+    pub wip_limit: Option<i32>,
+    /// Tasks moved into a done column are marked as completed
+    pub is_done: Option<bool>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, Validate, ToSchema)]
@@ -27,6 +32,11 @@ pub struct UpdateColumnDto {
         message = "Column name must be between 1 and 100 characters long"
     ))]
     pub name: Option<String>,
+    /// Maximum number of active tasks in the column, `0` removes the limit
+    #[validate(range(min = 0, message = "WIP limit must not be negative"))]
+    pub wip_limit: Option<i32>,
+    /// Only affects tasks moved into or out of the column afterwards
+    pub is_done: Option<bool>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
@@ -38,6 +48,10 @@ pub struct ColumnDto {
     pub board_id: Uuid,
     pub created_at: DateTime<FixedOffset>,
     pub updated_at: DateTime<FixedOffset>,
+    pub archived_at: Option<DateTime<FixedOffset>>,
+    pub version: i32,
+    pub wip_limit: Option<i32>,
+    pub is_done: bool,
 }
 
 impl ColumnDto {
@@ -49,6 +63,10 @@ impl ColumnDto {
             board_id: column.board_id,
             created_at: column.created_at,
             updated_at: column.updated_at,
+            archived_at: column.archived_at,
+            version: column.version,
+            wip_limit: column.wip_limit,
+            is_done: column.is_done,
         }
     }
 }
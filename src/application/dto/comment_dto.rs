@@ -0,0 +1,79 @@
+use chrono::{DateTime, FixedOffset};
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+use uuid::Uuid;
+use validator::Validate;
+
+use crate::domain::repositories::{Comment, CommentRevision};
+
+#[derive(Debug, Clone, Serialize, Deserialize, Validate, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct CreateCommentDto {
+    #[validate(length(
+        min = 1,
+        max = 5000,
+        message = "Comment content must be between 1 and 5000 characters long"
+    ))]
+    pub content: String,
+    pub task_id: Uuid,
+    pub parent_id: Option<Uuid>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, Validate, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct UpdateCommentDto {
+    #[validate(length(
+        min = 1,
+        max = 5000,
+        message = "Comment content must be between 1 and 5000 characters long"
+    ))]
+    pub content: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct CommentDto {
+    pub id: Uuid,
+    pub task_id: Uuid,
+    pub parent_id: Option<Uuid>,
+    pub author_id: Uuid,
+    pub content: String,
+    pub created_at: DateTime<FixedOffset>,
+    pub updated_at: DateTime<FixedOffset>,
+}
+
+impl CommentDto {
+    pub fn from_domain(comment: Comment) -> Self {
+        Self {
+            id: comment.id,
+            task_id: comment.task_id,
+            parent_id: comment.parent_id,
+            author_id: comment.author_id,
+            content: comment.content,
+            created_at: comment.created_at,
+            updated_at: comment.updated_at,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct CommentRevisionDto {
+    pub id: Uuid,
+    pub comment_id: Uuid,
+    pub content: String,
+    pub edited_by: Uuid,
+    pub created_at: DateTime<FixedOffset>,
+}
+
+impl CommentRevisionDto {
+    pub fn from_domain(revision: CommentRevision) -> Self {
+        Self {
+            id: revision.id,
+            comment_id: revision.comment_id,
+            content: revision.content,
+            edited_by: revision.edited_by,
+            created_at: revision.created_at,
+        }
+    }
+}
@@ -0,0 +1,86 @@
+use chrono::{DateTime, FixedOffset};
+use entity::CustomFieldTypeEnum;
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+use uuid::Uuid;
+use validator::{Validate, ValidationError};
+
+use crate::domain::repositories::CustomField;
+
+#[derive(Debug, Clone, Serialize, Deserialize, Validate, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct CreateCustomFieldDto {
+    pub board_id: Uuid,
+    #[validate(length(
+        min = 1,
+        max = 50,
+        message = "Custom field name must be between 1 and 50 characters long"
+    ))]
+    pub name: String,
+    pub field_type: CustomFieldTypeEnum,
+    /// Choices of single- and multi-select fields, required for those and rejected otherwise
+    #[validate(custom(
+        function = validate_options,
+        message = "Up to 100 options of 1 to 50 characters each can be given"
+    ))]
+    pub options: Option<Vec<String>>,
+}
+
+/// The type of a field can't be changed once tasks may hold values for it
+#[derive(Debug, Clone, Serialize, Deserialize, Validate, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct UpdateCustomFieldDto {
+    #[validate(length(
+        min = 1,
+        max = 50,
+        message = "Custom field name must be between 1 and 50 characters long"
+    ))]
+    pub name: Option<String>,
+    /// Replaces the choices of a select field; options still held by tasks can't be removed
+    #[validate(custom(
+        function = validate_options,
+        message = "Up to 100 options of 1 to 50 characters each can be given"
+    ))]
+    pub options: Option<Vec<String>>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct CustomFieldDto {
+    pub id: Uuid,
+    pub board_id: Uuid,
+    pub name: String,
+    pub field_type: CustomFieldTypeEnum,
+    pub options: Vec<String>,
+    pub created_at: DateTime<FixedOffset>,
+    pub updated_at: DateTime<FixedOffset>,
+}
+
+impl CustomFieldDto {
+    pub fn from_domain(field: CustomField) -> Self {
+        Self {
+            id: field.id,
+            board_id: field.board_id,
+            name: field.name,
+            field_type: field.field_type,
+            options: field.options,
+            created_at: field.created_at,
+            updated_at: field.updated_at,
+        }
+    }
+}
+
+fn validate_options(options: &[String]) -> Result<(), ValidationError> {
+    if options.len() > 100 {
+        return Err(ValidationError::new("options"));
+    }
+
+    for option in options {
+        let option = option.trim();
+        if option.is_empty() || option.chars().count() > 50 {
+            return Err(ValidationError::new("options"));
+        }
+    }
+
+    Ok(())
+}
@@ -0,0 +1,65 @@
+use chrono::{DateTime, FixedOffset};
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+use uuid::Uuid;
+use validator::Validate;
+
+use crate::{domain::repositories::Label, shared::utils::constants::RE_HEX_COLOR};
+
+#[derive(Debug, Clone, Serialize, Deserialize, Validate, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct CreateLabelDto {
+    pub board_id: Uuid,
+    #[validate(length(
+        min = 1,
+        max = 50,
+        message = "Label name must be between 1 and 50 characters long"
+    ))]
+    pub name: String,
+    /// Hex colour such as `#1e90ff`, a neutral grey when omitted
+    #[validate(regex(
+        path = RE_HEX_COLOR,
+        message = "Label color must be a hex color such as #1e90ff"
+    ))]
+    pub color: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, Validate, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct UpdateLabelDto {
+    #[validate(length(
+        min = 1,
+        max = 50,
+        message = "Label name must be between 1 and 50 characters long"
+    ))]
+    pub name: Option<String>,
+    #[validate(regex(
+        path = RE_HEX_COLOR,
+        message = "Label color must be a hex color such as #1e90ff"
+    ))]
+    pub color: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct LabelDto {
+    pub id: Uuid,
+    pub board_id: Uuid,
+    pub name: String,
+    pub color: String,
+    pub created_at: DateTime<FixedOffset>,
+    pub updated_at: DateTime<FixedOffset>,
+}
+
+impl LabelDto {
+    pub fn from_domain(label: Label) -> Self {
+        Self {
+            id: label.id,
+            board_id: label.board_id,
+            name: label.name,
+            color: label.color,
+            created_at: label.created_at,
+            updated_at: label.updated_at,
+        }
+    }
+}
@@ -1,18 +1,57 @@
+pub mod archive_dto;
+pub mod attachment_dto;
 pub mod auth_dto;
+pub mod board_activity_dto;
+pub mod board_analytics_dto;
+pub mod board_changes_dto;
 pub mod board_dto;
 pub mod board_member_dto;
+pub mod board_snapshot_dto;
+pub mod checklist_dto;
 pub mod column_dto;
+pub mod comment_dto;
+pub mod custom_field_dto;
+pub mod label_dto;
 pub mod task_dto;
+pub mod time_entry_dto;
 pub mod user_dto;
+pub mod websocket_dto;
 
+pub use archive_dto::ArchiveQueryDto;
+pub use attachment_dto::{AttachmentDto, UploadAttachmentDto};
 pub use auth_dto::{
     ActivationQueryDto, ForgotPasswordQueryDto, LoginDto, ResendActivationQueryDto,
     ResetPasswordDto,
 };
+pub use board_activity_dto::{BoardActivityDto, BoardActivityPageDto, BoardActivityQueryDto};
+pub use board_analytics_dto::{
+    BoardAnalyticsDto, BoardAnalyticsQueryDto, ColumnTaskCountDto, CumulativeFlowDayDto,
+    FlowTimePercentilesDto, TaskFlowMetricsDto, WeeklyThroughputDto,
+};
+pub use board_changes_dto::{
+    BoardChangeDto, BoardChangesPageDto, BoardChangesQueryDto, MemberTombstoneDto, TombstoneDto,
+};
 pub use board_dto::{BoardDto, CreateBoardDto, UpdateBoardDto};
 pub use board_member_dto::{
     AddBoardMemberDto, BoardMemberDto, DeleteBoardMemberDto, UpdateBoardMemberRoleDto,
 };
+pub use board_snapshot_dto::{BoardSnapshotColumnDto, BoardSnapshotDto};
+pub use checklist_dto::{
+    ChecklistDto, ChecklistItemDto, CreateChecklistDto, CreateChecklistItemDto, UpdateChecklistDto,
+    UpdateChecklistItemDto,
+};
 pub use column_dto::{ColumnDto, CreateColumnDto, UpdateColumnDto};
-pub use task_dto::{CreateTaskDto, TaskDto, UpdateTaskDto};
+pub use comment_dto::{CommentDto, CommentRevisionDto, CreateCommentDto, UpdateCommentDto};
+pub use custom_field_dto::{CreateCustomFieldDto, CustomFieldDto, UpdateCustomFieldDto};
+pub use label_dto::{CreateLabelDto, LabelDto, UpdateLabelDto};
+pub use task_dto::{
+    BulkTaskDto, BulkTaskOperationDto, BulkTaskResultDto, CreateTaskDto, CreateTaskRelationDto,
+    CustomFieldFilterDto, CustomFieldOperatorDto, DuplicateTaskDto, MoveTaskQueryDto, TaskDto,
+    TaskRelationDto, TaskRelationTypeDto, TaskSearchDto, TaskSearchPageDto, UpdateTaskDto,
+};
+pub use time_entry_dto::{
+    CreateTimeEntryDto, DailyTimeDto, StartTimerDto, TaskTimeDto, TimeEntryDto, TimeReportDto,
+    TimeReportQueryDto, UpdateTimeEntryDto, UserTimeDto,
+};
 pub use user_dto::{CreateUserDto, UserDto};
+pub use websocket_dto::{ResyncRequiredDto, WebSocketControlMessage, WebSocketQueryDto};
@@ -1,9 +1,15 @@
-use crate::domain::repositories::Task;
+use crate::{
+    domain::repositories::{Task, TaskRelation},
+    shared::utils::nullable,
+};
 use chrono::{DateTime, FixedOffset};
+use entity::{TaskPriorityEnum, TaskRelationTypeEnum};
 use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::collections::HashMap;
 use utoipa::ToSchema;
 use uuid::Uuid;
-use validator::{Validate, ValidationError};
+use validator::Validate;
 
 #[derive(Debug, Clone, Serialize, Deserialize, Validate, ToSchema)]
 #[serde(rename_all = "camelCase")]
@@ -15,12 +21,25 @@ pub struct CreateTaskDto {
     ))]
     pub title: String,
     pub description: Option<String>,
-    #[validate(custom(
-        function = validate_tags,
-        message = "Each tag must be between 1 and 50 characters long"
+    /// Labels of the task's board
+    pub label_ids: Option<Vec<Uuid>>,
+    /// Values of the board's custom fields, keyed by field ID
+    #[schema(value_type = Option<Object>)]
+    pub custom_fields: Option<HashMap<Uuid, Value>>,
+    pub assignee_ids: Option<Vec<Uuid>>,
+    pub priority: Option<TaskPriorityEnum>,
+    pub start_at: Option<DateTime<FixedOffset>>,
+    pub due_at: Option<DateTime<FixedOffset>>,
+    /// Expected effort in minutes, compared against the time logged on the task
+    #[validate(range(
+        min = 1,
+        max = 100000,
+        message = "Estimate must be between 1 and 100000 minutes"
     ))]
-    pub tags: Option<Vec<String>>,
+    pub estimate_minutes: Option<i32>,
     pub column_id: Uuid,
+    /// Lets owners and moderators add the task to a column that has reached its WIP limit
+    pub override_wip_limit: Option<bool>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, Validate, ToSchema)]
@@ -33,11 +52,223 @@ pub struct UpdateTaskDto {
     ))]
     pub title: Option<String>,
     pub description: Option<String>,
-    #[validate(custom(
-        function = validate_tags,
-        message = "Each tag must be between 1 and 50 characters long"
+    /// Labels of the task's board
+    pub label_ids: Option<Vec<Uuid>>,
+    /// Values to change, keyed by field ID; `null` clears a value and omitted fields are kept
+    #[schema(value_type = Option<Object>)]
+    pub custom_fields: Option<HashMap<Uuid, Value>>,
+    pub assignee_ids: Option<Vec<Uuid>>,
+    pub priority: Option<TaskPriorityEnum>,
+    /// `null` clears the start date, omitting the field keeps it
+    #[serde(default, deserialize_with = "nullable::deserialize")]
+    #[schema(value_type = Option<DateTime<FixedOffset>>)]
+    pub start_at: Option<Option<DateTime<FixedOffset>>>,
+    /// `null` clears the due date, omitting the field keeps it
+    #[serde(default, deserialize_with = "nullable::deserialize")]
+    #[schema(value_type = Option<DateTime<FixedOffset>>)]
+    pub due_at: Option<Option<DateTime<FixedOffset>>>,
+    /// Expected effort in minutes; `null` clears the estimate, omitting the field keeps it
+    #[validate(range(
+        min = 1,
+        max = 100000,
+        message = "Estimate must be between 1 and 100000 minutes"
+    ))]
+    #[serde(default, deserialize_with = "nullable::deserialize")]
+    #[schema(value_type = Option<i32>)]
+    pub estimate_minutes: Option<Option<i32>>,
+}
+
+#[derive(Debug, Clone, Default, Deserialize, Serialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct MoveTaskQueryDto {
+    pub override_wip_limit: Option<bool>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct DuplicateTaskDto {
+    pub column_id: Uuid,
+    /// Position in the target column (0-based), the copy is placed last when omitted
+    pub position: Option<usize>,
+    pub include_comments: Option<bool>,
+    pub include_attachments: Option<bool>,
+    /// Copies the checklists with all of their items marked as open
+    pub include_checklists: Option<bool>,
+    /// Lets owners and moderators add the copy to a column that has reached its WIP limit
+    pub override_wip_limit: Option<bool>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, Validate, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct BulkTaskDto {
+    #[validate(length(
+        min = 1,
+        max = 100,
+        message = "Between 1 and 100 task IDs must be given"
     ))]
-    pub tags: Option<Vec<String>>,
+    pub task_ids: Vec<Uuid>,
+    pub operation: BulkTaskOperationDto,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+#[serde(
+    rename_all = "camelCase",
+    rename_all_fields = "camelCase",
+    tag = "type"
+)]
+pub enum BulkTaskOperationDto {
+    /// Appends the tasks to the end of the column, in the order they were given
+    Move {
+        column_id: Uuid,
+        override_wip_limit: Option<bool>,
+    },
+    Archive,
+    Delete,
+    /// Adds each label to the tasks of the label's board
+    AddLabels {
+        label_ids: Vec<Uuid>,
+    },
+    RemoveLabels {
+        label_ids: Vec<Uuid>,
+    },
+    /// Adds the users to the assignees of every task
+    Assign {
+        user_ids: Vec<Uuid>,
+    },
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct BulkTaskResultDto {
+    pub updated: Vec<TaskDto>,
+    pub deleted: Vec<Uuid>,
+}
+
+/// Every given criterion must match; labels and assignees match when the task has any of them
+#[derive(Debug, Clone, Default, Serialize, Deserialize, Validate, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct TaskSearchDto {
+    /// Case-insensitive text searched for in the title and description
+    #[validate(length(
+        min = 1,
+        max = 254,
+        message = "Search text must be between 1 and 254 characters long"
+    ))]
+    pub query: Option<String>,
+    pub label_ids: Option<Vec<Uuid>>,
+    pub assignee_ids: Option<Vec<Uuid>>,
+    #[validate(length(max = 20, message = "Up to 20 custom field filters can be given"))]
+    pub custom_fields: Option<Vec<CustomFieldFilterDto>>,
+    pub include_archived: Option<bool>,
+    /// `nextCursor` of the previous page
+    pub cursor: Option<Uuid>,
+    #[validate(range(min = 1, max = 100, message = "Limit must be between 1 and 100"))]
+    pub limit: Option<u64>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct CustomFieldFilterDto {
+    pub field_id: Uuid,
+    pub operator: CustomFieldOperatorDto,
+    /// Required by every operator except `isSet` and `isNotSet`
+    #[schema(value_type = Option<Object>)]
+    pub value: Option<Value>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub enum CustomFieldOperatorDto {
+    /// Equal value; for multi-select fields, one of the selected options
+    Equals,
+    /// Case-insensitive substring, text fields only
+    Contains,
+    /// Greater than or equal, number and date fields only
+    Gte,
+    /// Less than or equal, number and date fields only
+    Lte,
+    IsSet,
+    IsNotSet,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct TaskSearchPageDto {
+    pub items: Vec<TaskDto>,
+    pub next_cursor: Option<Uuid>,
+}
+
+/// Relation type as seen from the task the relation is listed on
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub enum TaskRelationTypeDto {
+    Blocks,
+    BlockedBy,
+    Duplicates,
+    DuplicatedBy,
+    RelatesTo,
+}
+
+impl TaskRelationTypeDto {
+    /// Returns the stored source task, target task and type for a relation of this type
+    /// from `task_id` to `related_task_id`
+    pub fn to_directed(
+        self,
+        task_id: Uuid,
+        related_task_id: Uuid,
+    ) -> (Uuid, Uuid, TaskRelationTypeEnum) {
+        match self {
+            Self::Blocks => (task_id, related_task_id, TaskRelationTypeEnum::Blocks),
+            Self::BlockedBy => (related_task_id, task_id, TaskRelationTypeEnum::Blocks),
+            Self::Duplicates => (task_id, related_task_id, TaskRelationTypeEnum::Duplicates),
+            Self::DuplicatedBy => (related_task_id, task_id, TaskRelationTypeEnum::Duplicates),
+            Self::RelatesTo => (task_id, related_task_id, TaskRelationTypeEnum::RelatesTo),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct CreateTaskRelationDto {
+    pub related_task_id: Uuid,
+    /// Read from the task in the path, e.g. `blockedBy` means the related task blocks it
+    pub relation_type: TaskRelationTypeDto,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct TaskRelationDto {
+    pub id: Uuid,
+    pub related_task_id: Uuid,
+    pub relation_type: TaskRelationTypeDto,
+    pub created_by: Option<Uuid>,
+    pub created_at: DateTime<FixedOffset>,
+}
+
+impl TaskRelationDto {
+    /// Describes the relation from the point of view of `task_id`
+    pub fn from_domain(relation: TaskRelation, task_id: Uuid) -> Self {
+        let is_source = relation.source_task_id == task_id;
+        let relation_type = match (relation.relation_type, is_source) {
+            (TaskRelationTypeEnum::Blocks, true) => TaskRelationTypeDto::Blocks,
+            (TaskRelationTypeEnum::Blocks, false) => TaskRelationTypeDto::BlockedBy,
+            (TaskRelationTypeEnum::Duplicates, true) => TaskRelationTypeDto::Duplicates,
+            (TaskRelationTypeEnum::Duplicates, false) => TaskRelationTypeDto::DuplicatedBy,
+            (TaskRelationTypeEnum::RelatesTo, _) => TaskRelationTypeDto::RelatesTo,
+        };
+
+        Self {
+            id: relation.id,
+            related_task_id: if is_source {
+                relation.target_task_id
+            } else {
+                relation.source_task_id
+            },
+            relation_type,
+            created_by: relation.created_by,
+            created_at: relation.created_at,
+        }
+    }
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
@@ -46,11 +277,25 @@ pub struct TaskDto {
     pub id: Uuid,
     pub title: String,
     pub description: Option<String>,
-    pub tags: Option<Vec<String>>,
+    pub label_ids: Vec<Uuid>,
+    /// Values of the board's custom fields, keyed by field ID
+    #[schema(value_type = Object)]
+    pub custom_fields: HashMap<Uuid, Value>,
     pub position: String,
     pub column_id: Uuid,
+    pub assignee_ids: Vec<Uuid>,
+    pub priority: TaskPriorityEnum,
+    pub start_at: Option<DateTime<FixedOffset>>,
+    pub due_at: Option<DateTime<FixedOffset>>,
+    pub estimate_minutes: Option<i32>,
     pub created_at: DateTime<FixedOffset>,
     pub updated_at: DateTime<FixedOffset>,
+    pub archived_at: Option<DateTime<FixedOffset>>,
+    pub completed_at: Option<DateTime<FixedOffset>>,
+    pub completed_checklist_items: u64,
+    pub total_checklist_items: u64,
+    pub relations: Vec<TaskRelationDto>,
+    pub version: i32,
 }
 
 impl TaskDto {
@@ -59,21 +304,27 @@ impl TaskDto {
             id: task.id,
             title: task.title,
             description: task.description,
-            tags: task.tags,
+            label_ids: task.label_ids,
+            custom_fields: task.custom_fields,
             position: task.position,
             column_id: task.column_id,
+            assignee_ids: task.assignee_ids,
+            priority: task.priority,
+            start_at: task.start_at,
+            due_at: task.due_at,
+            estimate_minutes: task.estimate_minutes,
             created_at: task.created_at,
             updated_at: task.updated_at,
+            archived_at: task.archived_at,
+            completed_at: task.completed_at,
+            completed_checklist_items: task.checklist_progress.completed_items,
+            total_checklist_items: task.checklist_progress.total_items,
+            relations: task
+                .relations
+                .into_iter()
+                .map(|relation| TaskRelationDto::from_domain(relation, task.id))
+                .collect(),
+            version: task.version,
         }
     }
 }
-
-fn validate_tags(tags: &[String]) -> Result<(), ValidationError> {
-    for tag in tags {
-        if tag.is_empty() || tag.len() > 50 {
-            return Err(ValidationError::new("tags"));
-        }
-    }
-
-    Ok(())
-}
@@ -0,0 +1,122 @@
+use chrono::{DateTime, FixedOffset, NaiveDate};
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+use uuid::Uuid;
+use validator::Validate;
+
+use crate::domain::repositories::TimeEntry;
+
+/// Logs time spent earlier; either the end or the duration of the entry must be given
+#[derive(Debug, Clone, Serialize, Deserialize, Validate, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct CreateTimeEntryDto {
+    pub task_id: Uuid,
+    pub started_at: DateTime<FixedOffset>,
+    pub ended_at: Option<DateTime<FixedOffset>>,
+    #[validate(range(
+        min = 1,
+        max = 1440,
+        message = "Duration must be between 1 and 1440 minutes"
+    ))]
+    pub duration_minutes: Option<i64>,
+    #[validate(length(max = 500, message = "Note can be up to 500 characters long"))]
+    pub note: Option<String>,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize, Validate, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct StartTimerDto {
+    #[validate(length(max = 500, message = "Note can be up to 500 characters long"))]
+    pub note: Option<String>,
+}
+
+/// The end or duration of a running timer is set by stopping it
+#[derive(Debug, Clone, Serialize, Deserialize, Validate, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct UpdateTimeEntryDto {
+    pub started_at: Option<DateTime<FixedOffset>>,
+    pub ended_at: Option<DateTime<FixedOffset>>,
+    #[validate(range(
+        min = 1,
+        max = 1440,
+        message = "Duration must be between 1 and 1440 minutes"
+    ))]
+    pub duration_minutes: Option<i64>,
+    #[validate(length(max = 500, message = "Note can be up to 500 characters long"))]
+    pub note: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct TimeEntryDto {
+    pub id: Uuid,
+    pub task_id: Uuid,
+    pub user_id: Uuid,
+    pub started_at: DateTime<FixedOffset>,
+    /// Unset while the timer is running
+    pub ended_at: Option<DateTime<FixedOffset>>,
+    pub duration_seconds: Option<i64>,
+    pub note: Option<String>,
+    pub created_at: DateTime<FixedOffset>,
+    pub updated_at: DateTime<FixedOffset>,
+}
+
+impl TimeEntryDto {
+    pub fn from_domain(entry: TimeEntry) -> Self {
+        Self {
+            duration_seconds: entry.duration_seconds(),
+            id: entry.id,
+            task_id: entry.task_id,
+            user_id: entry.user_id,
+            started_at: entry.started_at,
+            ended_at: entry.ended_at,
+            note: entry.note,
+            created_at: entry.created_at,
+            updated_at: entry.updated_at,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Default, Deserialize, Serialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct TimeReportQueryDto {
+    pub user_id: Option<Uuid>,
+    pub from: Option<NaiveDate>,
+    pub to: Option<NaiveDate>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct UserTimeDto {
+    pub user_id: Uuid,
+    pub total_seconds: i64,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct TaskTimeDto {
+    pub task_id: Uuid,
+    pub title: String,
+    pub estimate_minutes: Option<i32>,
+    pub total_seconds: i64,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct DailyTimeDto {
+    pub date: NaiveDate,
+    pub total_seconds: i64,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct TimeReportDto {
+    pub board_id: Uuid,
+    pub user_id: Option<Uuid>,
+    pub from: NaiveDate,
+    pub to: NaiveDate,
+    pub total_seconds: i64,
+    pub users: Vec<UserTimeDto>,
+    pub tasks: Vec<TaskTimeDto>,
+    pub days: Vec<DailyTimeDto>,
+}
@@ -0,0 +1,311 @@
+use crate::{
+    application::dto::AttachmentDto,
+    domain::{
+        events::{AttachmentAddedEvent, AttachmentDeletedEvent, BoardEvent},
+        repositories::{
+            Attachment, AttachmentRepository, BoardMemberRepository, ColumnRepository,
+            OutboxMessage, TaskRepository, UnitOfWorkFactory,
+        },
+        services::{StorageService, StorageStream},
+    },
+    shared::{error::ApplicationError, utils::constants::ATTACHMENT_BOARD_QUOTA},
+};
+use bytes::Bytes;
+use chrono::Utc;
+use entity::BoardMemberRoleEnum;
+use std::sync::Arc;
+use tracing::warn;
+use uuid::Uuid;
+
+pub struct AttachmentService {
+    attachment_repository: Arc<dyn AttachmentRepository>,
+    task_repository: Arc<dyn TaskRepository>,
+    column_repository: Arc<dyn ColumnRepository>,
+    board_member_repository: Arc<dyn BoardMemberRepository>,
+    storage_service: Arc<dyn StorageService>,
+    unit_of_work_factory: Arc<dyn UnitOfWorkFactory>,
+}
+
+impl AttachmentService {
+    pub fn new(
+        attachment_repository: Arc<dyn AttachmentRepository>,
+        task_repository: Arc<dyn TaskRepository>,
+        column_repository: Arc<dyn ColumnRepository>,
+        board_member_repository: Arc<dyn BoardMemberRepository>,
+        storage_service: Arc<dyn StorageService>,
+        unit_of_work_factory: Arc<dyn UnitOfWorkFactory>,
+    ) -> Self {
+        Self {
+            attachment_repository,
+            task_repository,
+            column_repository,
+            board_member_repository,
+            storage_service,
+            unit_of_work_factory,
+        }
+    }
+
+    pub async fn upload_attachment(
+        &self,
+        task_id: Uuid,
+        file_name: String,
+        content_type: String,
+        data: Bytes,
+        user_id: Uuid,
+    ) -> Result<AttachmentDto, ApplicationError> {
+        let board_id = self.find_board_id(task_id).await?;
+
+        self.ensure_member(board_id, user_id).await?;
+
+        if data.is_empty() {
+            return Err(ApplicationError::BadRequest {
+                message: "Uploaded file is empty".to_string(),
+            });
+        }
+
+        // Checked up front to avoid storing a blob that can't fit, and again under the board
+        // lock before the attachment is recorded
+        let size = data.len() as i64;
+        let used_size = self
+            .attachment_repository
+            .sum_size_by_board_id(board_id)
+            .await?;
+
+        Self::ensure_quota(used_size, size)?;
+
+        let attachment = Attachment::new(
+            Uuid::now_v7(),
+            task_id,
+            board_id,
+            user_id,
+            file_name,
+            content_type,
+            size,
+        );
+
+        self.storage_service
+            .put_object(&attachment.storage_key, &attachment.content_type, data)
+            .await
+            .map_err(|err| ApplicationError::InternalError { message: err })?;
+
+        let storage_key = attachment.storage_key.clone();
+        let result: Result<Attachment, ApplicationError> = async {
+            let unit_of_work = self.unit_of_work_factory.begin().await?;
+
+            // Locking the board serializes concurrent uploads, so they can't exceed the quota
+            // together
+            unit_of_work
+                .board_repository()
+                .find_by_id_for_update(board_id)
+                .await?
+                .ok_or_else(|| ApplicationError::NotFound {
+                    message: "Board with the given ID not found".to_string(),
+                })?;
+
+            let used_size = unit_of_work
+                .attachment_repository()
+                .sum_size_by_board_id(board_id)
+                .await?;
+
+            Self::ensure_quota(used_size, size)?;
+
+            let saved_attachment = unit_of_work
+                .attachment_repository()
+                .create(attachment)
+                .await?;
+
+            unit_of_work
+                .outbox_repository()
+                .create(OutboxMessage::new(
+                    Uuid::now_v7(),
+                    board_id,
+                    BoardEvent::AttachmentAdded(AttachmentAddedEvent {
+                        attachment_id: saved_attachment.id,
+                        task_id,
+                        file_name: saved_attachment.file_name.clone(),
+                        content_type: saved_attachment.content_type.clone(),
+                        size: saved_attachment.size,
+                        uploaded_by: user_id,
+                        timestamp: saved_attachment.created_at,
+                    }),
+                ))
+                .await?;
+
+            unit_of_work.commit().await?;
+
+            Ok(saved_attachment)
+        }
+        .await;
+
+        let saved_attachment = match result {
+            Ok(saved_attachment) => saved_attachment,
+            Err(err) => {
+                if let Err(err) = self.storage_service.delete_object(&storage_key).await {
+                    warn!(
+                        "Failed to remove orphaned attachment '{}': {}",
+                        storage_key, err
+                    );
+                }
+                return Err(err);
+            }
+        };
+
+        Ok(AttachmentDto::from_domain(saved_attachment))
+    }
+
+    pub async fn get_task_attachments(
+        &self,
+        task_id: Uuid,
+        user_id: Uuid,
+    ) -> Result<Vec<AttachmentDto>, ApplicationError> {
+        let board_id = self.find_board_id(task_id).await?;
+
+        self.ensure_member(board_id, user_id).await?;
+
+        let attachments = self.attachment_repository.find_by_task_id(task_id).await?;
+
+        Ok(attachments
+            .into_iter()
+            .map(AttachmentDto::from_domain)
+            .collect())
+    }
+
+    pub async fn download_attachment(
+        &self,
+        attachment_id: Uuid,
+        user_id: Uuid,
+    ) -> Result<(AttachmentDto, StorageStream), ApplicationError> {
+        let attachment = self.find_attachment(attachment_id).await?;
+
+        self.ensure_member(attachment.board_id, user_id).await?;
+
+        let stream = self
+            .storage_service
+            .get_object(&attachment.storage_key)
+            .await
+            .map_err(|err| ApplicationError::InternalError { message: err })?;
+
+        Ok((AttachmentDto::from_domain(attachment), stream))
+    }
+
+    pub async fn delete_attachment(
+        &self,
+        attachment_id: Uuid,
+        user_id: Uuid,
+    ) -> Result<u64, ApplicationError> {
+        let attachment = self.find_attachment(attachment_id).await?;
+
+        let allowed_roles = if attachment.uploaded_by == user_id {
+            vec![
+                BoardMemberRoleEnum::Owner,
+                BoardMemberRoleEnum::Moderator,
+                BoardMemberRoleEnum::Member,
+            ]
+        } else {
+            vec![BoardMemberRoleEnum::Owner, BoardMemberRoleEnum::Moderator]
+        };
+
+        if !self
+            .board_member_repository
+            .check_permissions(attachment.board_id, user_id, allowed_roles)
+            .await?
+        {
+            return Err(ApplicationError::Forbidden {
+                message: "You don't have permission to perform this action".to_string(),
+            });
+        }
+
+        let unit_of_work = self.unit_of_work_factory.begin().await?;
+
+        let deleted_attachment = unit_of_work
+            .attachment_repository()
+            .delete(attachment_id)
+            .await?;
+
+        unit_of_work
+            .outbox_repository()
+            .create(OutboxMessage::new(
+                Uuid::now_v7(),
+                attachment.board_id,
+                BoardEvent::AttachmentDeleted(AttachmentDeletedEvent {
+                    attachment_id,
+                    task_id: attachment.task_id,
+                    deleted_by: user_id,
+                    timestamp: Utc::now().fixed_offset(),
+                }),
+            ))
+            .await?;
+
+        unit_of_work.commit().await?;
+
+        if let Err(err) = self
+            .storage_service
+            .delete_object(&attachment.storage_key)
+            .await
+        {
+            warn!(
+                "Failed to remove attachment '{}' from storage: {}",
+                attachment.storage_key, err
+            );
+        }
+
+        Ok(deleted_attachment)
+    }
+
+    async fn find_attachment(&self, attachment_id: Uuid) -> Result<Attachment, ApplicationError> {
+        self.attachment_repository
+            .find_by_id(attachment_id)
+            .await?
+            .ok_or_else(|| ApplicationError::NotFound {
+                message: "Attachment with the given ID not found".to_string(),
+            })
+    }
+
+    async fn find_board_id(&self, task_id: Uuid) -> Result<Uuid, ApplicationError> {
+        let task = self
+            .task_repository
+            .find_by_id(task_id)
+            .await?
+            .ok_or_else(|| ApplicationError::NotFound {
+                message: "Task with the given ID not found".to_string(),
+            })?;
+
+        let column = self
+            .column_repository
+            .find_by_id(task.column_id)
+            .await?
+            .ok_or_else(|| ApplicationError::NotFound {
+                message: "Column with the given ID not found".to_string(),
+            })?;
+
+        Ok(column.board_id)
+    }
+
+    async fn ensure_member(&self, board_id: Uuid, user_id: Uuid) -> Result<(), ApplicationError> {
+        if self
+            .board_member_repository
+            .find_by_board_and_user_id(board_id, user_id)
+            .await?
+            .is_none()
+        {
+            return Err(ApplicationError::Forbidden {
+                message: "You don't have access to this board".to_string(),
+            });
+        }
+
+        Ok(())
+    }
+
+    fn ensure_quota(used_size: i64, size: i64) -> Result<(), ApplicationError> {
+        if (used_size + size) as u64 > *ATTACHMENT_BOARD_QUOTA {
+            return Err(ApplicationError::PayloadTooLarge {
+                message: format!(
+                    "Board attachment quota of {} bytes would be exceeded",
+                    *ATTACHMENT_BOARD_QUOTA
+                ),
+            });
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,88 @@
+use crate::{
+    application::dto::{BoardActivityDto, BoardActivityPageDto, BoardActivityQueryDto},
+    domain::repositories::{BoardActivityFilter, BoardActivityRepository, BoardMemberRepository},
+    shared::error::ApplicationError,
+};
+use std::sync::Arc;
+use uuid::Uuid;
+use validator::Validate;
+
+const DEFAULT_PAGE_SIZE: u64 = 50;
+
+pub struct BoardActivityService {
+    board_activity_repository: Arc<dyn BoardActivityRepository>,
+    board_member_repository: Arc<dyn BoardMemberRepository>,
+}
+
+impl BoardActivityService {
+    pub fn new(
+        board_activity_repository: Arc<dyn BoardActivityRepository>,
+        board_member_repository: Arc<dyn BoardMemberRepository>,
+    ) -> Self {
+        Self {
+            board_activity_repository,
+            board_member_repository,
+        }
+    }
+
+    pub async fn get_board_activity(
+        &self,
+        board_id: Uuid,
+        query: BoardActivityQueryDto,
+        user_id: Uuid,
+    ) -> Result<BoardActivityPageDto, ApplicationError> {
+        query.validate()?;
+
+        if let (Some(from), Some(to)) = (query.from, query.to)
+            && from > to
+        {
+            return Err(ApplicationError::BadRequest {
+                message: "Start of the date range must be before its end".to_string(),
+            });
+        }
+
+        if self
+            .board_member_repository
+            .find_by_board_and_user_id(board_id, user_id)
+            .await?
+            .is_none()
+        {
+            return Err(ApplicationError::Forbidden {
+                message: "You don't have access to this board".to_string(),
+            });
+        }
+
+        let limit = query.limit.unwrap_or(DEFAULT_PAGE_SIZE);
+
+        // One extra row tells whether another page follows
+        let mut activities = self
+            .board_activity_repository
+            .find_by_board_id(
+                board_id,
+                BoardActivityFilter {
+                    actor_id: query.actor_id,
+                    event_type: query.event_type,
+                    from: query.from,
+                    to: query.to,
+                    cursor: query.cursor,
+                    limit: limit + 1,
+                },
+            )
+            .await?;
+
+        let next_cursor = if activities.len() as u64 > limit {
+            activities.truncate(limit as usize);
+            activities.last().map(|activity| activity.id)
+        } else {
+            None
+        };
+
+        Ok(BoardActivityPageDto {
+            items: activities
+                .into_iter()
+                .map(BoardActivityDto::from_domain)
+                .collect(),
+            next_cursor,
+        })
+    }
+}
@@ -0,0 +1,354 @@
+use crate::{
+    application::dto::{
+        BoardAnalyticsDto, BoardAnalyticsQueryDto, ColumnTaskCountDto, CumulativeFlowDayDto,
+        FlowTimePercentilesDto, TaskFlowMetricsDto, WeeklyThroughputDto,
+    },
+    domain::{
+        events::{BoardEvent, BulkTaskOperation},
+        repositories::{BoardActivityRepository, BoardMemberRepository, ColumnRepository},
+    },
+    shared::error::ApplicationError,
+};
+use chrono::{DateTime, Datelike, Days, FixedOffset, NaiveDate, Utc};
+use std::{
+    collections::{BTreeMap, HashMap},
+    sync::Arc,
+};
+use uuid::Uuid;
+
+const DEFAULT_RANGE_DAYS: u64 = 30;
+const MAX_RANGE_DAYS: i64 = 366;
+const PERCENTILES: [f64; 3] = [0.5, 0.85, 0.95];
+
+/// Events that make up the flow history of the tasks of a board
+const FLOW_EVENT_TYPES: [&str; 9] = [
+    "taskCreated",
+    "taskMoved",
+    "taskCompleted",
+    "taskReopened",
+    "taskArchived",
+    "taskRestored",
+    "taskDeleted",
+    "taskRemoved",
+    "tasksBulkUpdated",
+];
+
+/// Flow history of a single task rebuilt from the activity log
+#[derive(Debug)]
+struct TaskHistory {
+    created_at: DateTime<FixedOffset>,
+    created_in: Uuid,
+    started_at: Option<DateTime<FixedOffset>>,
+    completed_at: Option<DateTime<FixedOffset>>,
+    /// Column the task entered at each point in time, `None` while it's archived or once it
+    /// left the board
+    transitions: Vec<(DateTime<FixedOffset>, Option<Uuid>)>,
+}
+
+impl TaskHistory {
+    fn column_id(&self) -> Option<Uuid> {
+        self.transitions
+            .last()
+            .and_then(|(_, column_id)| *column_id)
+    }
+
+    fn column_at(&self, instant: DateTime<FixedOffset>) -> Option<Uuid> {
+        self.transitions
+            .iter()
+            .take_while(|(timestamp, _)| *timestamp < instant)
+            .last()
+            .and_then(|(_, column_id)| *column_id)
+    }
+
+    /// Work starts once the task leaves the column it was created in
+    fn move_to(&mut self, column_id: Uuid, timestamp: DateTime<FixedOffset>) {
+        if self.column_id() == Some(column_id) {
+            return;
+        }
+
+        if column_id != self.created_in {
+            self.started_at.get_or_insert(timestamp);
+        }
+
+        self.transitions.push((timestamp, Some(column_id)));
+    }
+
+    fn leave(&mut self, timestamp: DateTime<FixedOffset>) {
+        self.transitions.push((timestamp, None));
+    }
+}
+
+pub struct BoardAnalyticsService {
+    board_activity_repository: Arc<dyn BoardActivityRepository>,
+    board_member_repository: Arc<dyn BoardMemberRepository>,
+    column_repository: Arc<dyn ColumnRepository>,
+}
+
+impl BoardAnalyticsService {
+    pub fn new(
+        board_activity_repository: Arc<dyn BoardActivityRepository>,
+        board_member_repository: Arc<dyn BoardMemberRepository>,
+        column_repository: Arc<dyn ColumnRepository>,
+    ) -> Self {
+        Self {
+            board_activity_repository,
+            board_member_repository,
+            column_repository,
+        }
+    }
+
+    pub async fn get_board_analytics(
+        &self,
+        board_id: Uuid,
+        query: BoardAnalyticsQueryDto,
+        user_id: Uuid,
+    ) -> Result<BoardAnalyticsDto, ApplicationError> {
+        let to = query.to.unwrap_or_else(|| Utc::now().date_naive());
+        let from = match query.from {
+            Some(from) => from,
+            None => to
+                .checked_sub_days(Days::new(DEFAULT_RANGE_DAYS - 1))
+                .unwrap_or(to),
+        };
+
+        if from > to {
+            return Err(ApplicationError::BadRequest {
+                message: "Start of the date range must be before its end".to_string(),
+            });
+        }
+        if (to - from).num_days() >= MAX_RANGE_DAYS {
+            return Err(ApplicationError::BadRequest {
+                message: format!("Date range can't span more than {} days", MAX_RANGE_DAYS),
+            });
+        }
+
+        if self
+            .board_member_repository
+            .find_by_board_and_user_id(board_id, user_id)
+            .await?
+            .is_none()
+        {
+            return Err(ApplicationError::Forbidden {
+                message: "You don't have access to this board".to_string(),
+            });
+        }
+
+        let range_start = Self::start_of_day(from);
+        let range_end = Self::start_of_day(to + Days::new(1));
+
+        let columns = self
+            .column_repository
+            .find_by_board_id(board_id, true)
+            .await?;
+
+        let activities = self
+            .board_activity_repository
+            .find_task_history(
+                board_id,
+                FLOW_EVENT_TYPES
+                    .iter()
+                    .map(|event_type| event_type.to_string())
+                    .collect(),
+                range_start,
+                range_end,
+            )
+            .await?;
+
+        let mut histories: HashMap<Uuid, TaskHistory> = HashMap::new();
+        for activity in activities {
+            let envelope =
+                activity
+                    .to_envelope()
+                    .map_err(|err| ApplicationError::InternalError {
+                        message: format!("Failed to read activity '{}': {}", activity.id, err),
+                    })?;
+
+            match envelope.event {
+                // Lead time counts from the original creation, also for tasks that arrived
+                // from another board
+                BoardEvent::TaskCreated(event) => {
+                    histories.insert(
+                        event.task_id,
+                        TaskHistory {
+                            created_at: event.created_at.unwrap_or(event.timestamp),
+                            created_in: event.column_id,
+                            started_at: None,
+                            completed_at: None,
+                            transitions: vec![(event.timestamp, Some(event.column_id))],
+                        },
+                    );
+                }
+                BoardEvent::TaskMoved(event) => {
+                    if let Some(history) = histories.get_mut(&event.task_id) {
+                        history.move_to(event.new_column_id, event.timestamp);
+                    }
+                }
+                // Done state follows the completion events, the done flags of the columns may
+                // have changed since
+                BoardEvent::TaskCompleted(event) => {
+                    if let Some(history) = histories.get_mut(&event.task_id) {
+                        history.completed_at = Some(event.timestamp);
+                    }
+                }
+                BoardEvent::TaskReopened(event) => {
+                    if let Some(history) = histories.get_mut(&event.task_id) {
+                        history.completed_at = None;
+                    }
+                }
+                // Archived tasks drop out of the cumulative flow until they're restored
+                BoardEvent::TaskArchived(event) => {
+                    if let Some(history) = histories.get_mut(&event.task_id) {
+                        history.leave(event.timestamp);
+                    }
+                }
+                BoardEvent::TaskRestored(event) => {
+                    if let Some(history) = histories.get_mut(&event.task_id) {
+                        history
+                            .transitions
+                            .push((event.timestamp, Some(event.column_id)));
+                    }
+                }
+                BoardEvent::TaskDeleted(event) => {
+                    if let Some(history) = histories.get_mut(&event.task_id) {
+                        history.leave(event.timestamp);
+                    }
+                }
+                BoardEvent::TaskRemoved(event) => {
+                    if let Some(history) = histories.get_mut(&event.task_id) {
+                        history.leave(event.timestamp);
+                    }
+                }
+                BoardEvent::TasksBulkUpdated(event) => match event.operation {
+                    BulkTaskOperation::Move { column_id, .. } => {
+                        for task_id in event.task_ids {
+                            if let Some(history) = histories.get_mut(&task_id) {
+                                history.move_to(column_id, event.timestamp);
+                            }
+                        }
+                    }
+                    BulkTaskOperation::Archive | BulkTaskOperation::Delete => {
+                        for task_id in event.task_ids {
+                            if let Some(history) = histories.get_mut(&task_id) {
+                                history.leave(event.timestamp);
+                            }
+                        }
+                    }
+                    _ => {}
+                },
+                _ => {}
+            }
+        }
+
+        let mut tasks: Vec<TaskFlowMetricsDto> = histories
+            .iter()
+            .filter_map(|(task_id, history)| {
+                let completed_at = history
+                    .completed_at
+                    .filter(|completed_at| *completed_at >= range_start)?;
+                let started_at = history
+                    .started_at
+                    .filter(|started_at| *started_at <= completed_at);
+
+                Some(TaskFlowMetricsDto {
+                    task_id: *task_id,
+                    created_at: history.created_at,
+                    started_at,
+                    completed_at,
+                    lead_time_hours: Self::hours_between(history.created_at, completed_at),
+                    cycle_time_hours: started_at
+                        .map(|started_at| Self::hours_between(started_at, completed_at)),
+                })
+            })
+            .collect();
+        tasks.sort_by_key(|task| (task.completed_at, task.task_id));
+
+        let lead_time = Self::percentiles(tasks.iter().map(|task| task.lead_time_hours).collect());
+        let cycle_time = Self::percentiles(
+            tasks
+                .iter()
+                .filter_map(|task| task.cycle_time_hours)
+                .collect(),
+        );
+
+        let mut weeks: BTreeMap<NaiveDate, usize> = BTreeMap::new();
+        let mut week_start = Self::week_start(from);
+        while week_start <= to {
+            weeks.insert(week_start, 0);
+            week_start = week_start + Days::new(7);
+        }
+        for task in &tasks {
+            let week_start = Self::week_start(task.completed_at.with_timezone(&Utc).date_naive());
+            *weeks.entry(week_start).or_default() += 1;
+        }
+        let throughput = weeks
+            .into_iter()
+            .map(|(week_start, completed)| WeeklyThroughputDto {
+                week_start,
+                completed,
+            })
+            .collect();
+
+        let cumulative_flow = from
+            .iter_days()
+            .take_while(|date| *date <= to)
+            .map(|date| {
+                let end_of_day = Self::start_of_day(date + Days::new(1));
+                let mut counts: HashMap<Uuid, usize> = HashMap::new();
+                for history in histories.values() {
+                    if let Some(column_id) = history.column_at(end_of_day) {
+                        *counts.entry(column_id).or_default() += 1;
+                    }
+                }
+
+                CumulativeFlowDayDto {
+                    date,
+                    columns: columns
+                        .iter()
+                        .map(|column| ColumnTaskCountDto {
+                            column_id: column.id,
+                            count: counts.get(&column.id).copied().unwrap_or_default(),
+                        })
+                        .collect(),
+                }
+            })
+            .collect();
+
+        Ok(BoardAnalyticsDto {
+            board_id,
+            from,
+            to,
+            tasks,
+            lead_time,
+            cycle_time,
+            throughput,
+            cumulative_flow,
+        })
+    }
+
+    fn start_of_day(date: NaiveDate) -> DateTime<FixedOffset> {
+        date.and_time(Default::default()).and_utc().fixed_offset()
+    }
+
+    fn week_start(date: NaiveDate) -> NaiveDate {
+        date - Days::new(date.weekday().num_days_from_monday() as u64)
+    }
+
+    fn hours_between(start: DateTime<FixedOffset>, end: DateTime<FixedOffset>) -> f64 {
+        (end - start).num_seconds() as f64 / 3600.0
+    }
+
+    /// Nearest-rank percentiles of the given durations
+    fn percentiles(mut values: Vec<f64>) -> FlowTimePercentilesDto {
+        if values.is_empty() {
+            return FlowTimePercentilesDto::default();
+        }
+
+        values.sort_by(f64::total_cmp);
+        let [p50, p85, p95] = PERCENTILES.map(|percentile| {
+            let rank = (percentile * values.len() as f64).ceil() as usize;
+            Some(values[rank.clamp(1, values.len()) - 1])
+        });
+
+        FlowTimePercentilesDto { p50, p85, p95 }
+    }
+}
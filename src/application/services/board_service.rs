@@ -5,18 +5,22 @@ use crate::{
     },
     domain::{
         events::{
-            BoardCreatedEvent, BoardDeletedEvent, BoardEvent, BoardUpdatedEvent, MemberAddedEvent,
-            MemberRemovedEvent, MemberRoleChangedEvent, SharedEventBus,
+            BoardArchivedEvent, BoardCreatedEvent, BoardDeletedEvent, BoardEvent,
+            BoardRestoredEvent, BoardUpdatedEvent, MemberAddedEvent, MemberRemovedEvent,
+            MemberRoleChangedEvent, TaskUnassignedEvent,
         },
         repositories::{
-            Board, BoardMember, BoardMemberRepository, BoardRepository, UserRepository,
+            AttachmentRepository, Board, BoardMember, BoardMemberRepository, BoardRepository,
+            OutboxMessage, UnitOfWork, UnitOfWorkFactory, UserRepository,
         },
+        services::StorageService,
     },
     shared::error::ApplicationError,
 };
 use chrono::Utc;
 use entity::BoardMemberRoleEnum;
 use std::sync::Arc;
+use tracing::warn;
 use uuid::Uuid;
 use validator::Validate;
 
@@ -24,7 +28,9 @@ pub struct BoardService {
     user_repository: Arc<dyn UserRepository>,
     board_repository: Arc<dyn BoardRepository>,
     board_member_repository: Arc<dyn BoardMemberRepository>,
-    event_bus: SharedEventBus,
+    attachment_repository: Arc<dyn AttachmentRepository>,
+    storage_service: Arc<dyn StorageService>,
+    unit_of_work_factory: Arc<dyn UnitOfWorkFactory>,
 }
 
 impl BoardService {
@@ -32,13 +38,17 @@ impl BoardService {
         user_repository: Arc<dyn UserRepository>,
         board_repository: Arc<dyn BoardRepository>,
         board_member_repository: Arc<dyn BoardMemberRepository>,
-        event_bus: SharedEventBus,
+        attachment_repository: Arc<dyn AttachmentRepository>,
+        storage_service: Arc<dyn StorageService>,
+        unit_of_work_factory: Arc<dyn UnitOfWorkFactory>,
     ) -> Self {
         Self {
             user_repository,
             board_repository,
             board_member_repository,
-            event_bus,
+            attachment_repository,
+            storage_service,
+            unit_of_work_factory,
         }
     }
 
@@ -52,7 +62,9 @@ impl BoardService {
         let board_id = Uuid::now_v7();
         let board = Board::new(board_id, dto.name, dto.description, owner_id);
 
-        let saved_board = self.board_repository.create(board).await?;
+        let unit_of_work = self.unit_of_work_factory.begin().await?;
+
+        let saved_board = unit_of_work.board_repository().create(board).await?;
 
         let board_member = BoardMember::new(
             Uuid::now_v7(),
@@ -61,10 +73,15 @@ impl BoardService {
             BoardMemberRoleEnum::Owner,
         );
 
-        self.board_member_repository.create(board_member).await?;
+        unit_of_work
+            .board_member_repository()
+            .create(board_member)
+            .await?;
 
-        self.event_bus
-            .publish(
+        unit_of_work
+            .outbox_repository()
+            .create(OutboxMessage::new(
+                Uuid::now_v7(),
                 board_id,
                 BoardEvent::BoardCreated(BoardCreatedEvent {
                     board_id,
@@ -73,8 +90,10 @@ impl BoardService {
                     owner_id,
                     timestamp: saved_board.created_at,
                 }),
-            )
-            .await;
+            ))
+            .await?;
+
+        unit_of_work.commit().await?;
 
         Ok(BoardDto::from_domain(saved_board))
     }
@@ -98,8 +117,12 @@ impl BoardService {
     pub async fn get_boards_by_membership(
         &self,
         user_id: Uuid,
+        include_archived: bool,
     ) -> Result<Vec<BoardDto>, ApplicationError> {
-        let boards = self.board_repository.find_by_membership(user_id).await?;
+        let boards = self
+            .board_repository
+            .find_by_membership(user_id, include_archived)
+            .await?;
 
         Ok(boards.into_iter().map(BoardDto::from_domain).collect())
     }
@@ -109,6 +132,7 @@ impl BoardService {
         dto: UpdateBoardDto,
         board_id: Uuid,
         user_id: Uuid,
+        expected_version: Option<i32>,
     ) -> Result<BoardDto, ApplicationError> {
         dto.validate()?;
 
@@ -134,16 +158,22 @@ impl BoardService {
             });
         }
 
+        ApplicationError::ensure_version("The board", expected_version, board.version)?;
+
         if let Some(name) = dto.name {
             board.name = name;
         }
         board.description = dto.description;
         board.updated_at = Utc::now().fixed_offset();
 
-        let updated_board = self.board_repository.update(board).await?;
+        let unit_of_work = self.unit_of_work_factory.begin().await?;
 
-        self.event_bus
-            .publish(
+        let updated_board = unit_of_work.board_repository().update(board).await?;
+
+        unit_of_work
+            .outbox_repository()
+            .create(OutboxMessage::new(
+                Uuid::now_v7(),
                 board_id,
                 BoardEvent::BoardUpdated(BoardUpdatedEvent {
                     board_id,
@@ -152,8 +182,10 @@ impl BoardService {
                     updated_by: user_id,
                     timestamp: updated_board.updated_at,
                 }),
-            )
-            .await;
+            ))
+            .await?;
+
+        unit_of_work.commit().await?;
 
         Ok(BoardDto::from_domain(updated_board))
     }
@@ -173,22 +205,116 @@ impl BoardService {
             });
         }
 
-        let deleted_board = self.board_repository.delete(board_id).await?;
+        let storage_keys = self
+            .attachment_repository
+            .find_by_board_id(board_id)
+            .await?
+            .into_iter()
+            .map(|attachment| attachment.storage_key)
+            .collect();
+
+        let unit_of_work = self.unit_of_work_factory.begin().await?;
+
+        let deleted_board = unit_of_work.board_repository().delete(board_id).await?;
 
-        self.event_bus
-            .publish(
+        unit_of_work
+            .outbox_repository()
+            .create(OutboxMessage::new(
+                Uuid::now_v7(),
                 board_id,
                 BoardEvent::BoardDeleted(BoardDeletedEvent {
                     board_id,
                     deleted_by: user_id,
                     timestamp: Utc::now().fixed_offset(),
                 }),
-            )
-            .await;
+            ))
+            .await?;
+
+        unit_of_work.commit().await?;
+
+        self.remove_attachment_blobs(storage_keys).await;
 
         Ok(deleted_board)
     }
 
+    pub async fn archive_board(
+        &self,
+        board_id: Uuid,
+        user_id: Uuid,
+    ) -> Result<BoardDto, ApplicationError> {
+        let mut board = self.find_owned_board(board_id, user_id).await?;
+
+        if board.archived_at.is_some() {
+            return Err(ApplicationError::Conflict {
+                message: "Board is already archived".to_string(),
+            });
+        }
+
+        let now = Utc::now().fixed_offset();
+        board.archived_at = Some(now);
+        board.updated_at = now;
+
+        let unit_of_work = self.unit_of_work_factory.begin().await?;
+
+        let archived_board = unit_of_work.board_repository().update(board).await?;
+
+        unit_of_work
+            .outbox_repository()
+            .create(OutboxMessage::new(
+                Uuid::now_v7(),
+                board_id,
+                BoardEvent::BoardArchived(BoardArchivedEvent {
+                    board_id,
+                    archived_by: user_id,
+                    timestamp: now,
+                }),
+            ))
+            .await?;
+
+        unit_of_work.commit().await?;
+
+        Ok(BoardDto::from_domain(archived_board))
+    }
+
+    pub async fn unarchive_board(
+        &self,
+        board_id: Uuid,
+        user_id: Uuid,
+    ) -> Result<BoardDto, ApplicationError> {
+        let mut board = self.find_owned_board(board_id, user_id).await?;
+
+        if board.archived_at.is_none() {
+            return Err(ApplicationError::Conflict {
+                message: "Board is not archived".to_string(),
+            });
+        }
+
+        let now = Utc::now().fixed_offset();
+        board.archived_at = None;
+        board.updated_at = now;
+
+        let unit_of_work = self.unit_of_work_factory.begin().await?;
+
+        let restored_board = unit_of_work.board_repository().update(board).await?;
+
+        unit_of_work
+            .outbox_repository()
+            .create(OutboxMessage::new(
+                Uuid::now_v7(),
+                board_id,
+                BoardEvent::BoardRestored(BoardRestoredEvent {
+                    board_id,
+                    restored_by: user_id,
+                    timestamp: now,
+                }),
+            ))
+            .await?;
+
+        unit_of_work.commit().await?;
+
+        Ok(BoardDto::from_domain(restored_board))
+    }
+
     pub async fn add_board_member(
         &self,
         dto: AddBoardMemberDto,
@@ -196,8 +322,10 @@ impl BoardService {
     ) -> Result<BoardMemberDto, ApplicationError> {
         dto.validate()?;
 
-        if !self
-            .board_member_repository
+        let unit_of_work = self.unit_of_work_factory.begin().await?;
+
+        if !unit_of_work
+            .board_member_repository()
             .check_permissions(
                 dto.board_id,
                 user_id,
@@ -223,10 +351,15 @@ impl BoardService {
             BoardMemberRoleEnum::Member,
         );
 
-        let saved_board_member = self.board_member_repository.create(board_member).await?;
+        let saved_board_member = unit_of_work
+            .board_member_repository()
+            .create(board_member)
+            .await?;
 
-        self.event_bus
-            .publish(
+        unit_of_work
+            .outbox_repository()
+            .create(OutboxMessage::new(
+                Uuid::now_v7(),
                 dto.board_id,
                 BoardEvent::MemberAdded(MemberAddedEvent {
                     board_id: saved_board_member.board_id,
@@ -235,8 +368,10 @@ impl BoardService {
                     added_by: user_id,
                     timestamp: saved_board_member.created_at,
                 }),
-            )
-            .await;
+            ))
+            .await?;
+
+        unit_of_work.commit().await?;
 
         Ok(BoardMemberDto::from_domain(saved_board_member))
     }
@@ -260,31 +395,34 @@ impl BoardService {
             });
         }
 
-        if !self
-            .board_member_repository
-            .check_permissions(dto.board_id, user_id, vec![BoardMemberRoleEnum::Owner])
-            .await?
-        {
+        let unit_of_work = self.unit_of_work_factory.begin().await?;
+
+        let (requester, board_member) =
+            Self::lock_board_members(unit_of_work.as_ref(), dto.board_id, user_id, dto.user_id)
+                .await?;
+
+        if requester.is_none_or(|requester| requester.role != BoardMemberRoleEnum::Owner) {
             return Err(ApplicationError::Forbidden {
                 message: "You don't have permission to perform this action".to_string(),
             });
         }
 
-        let mut board_member = self
-            .board_member_repository
-            .find_by_board_and_user_id(dto.board_id, dto.user_id)
-            .await?
-            .ok_or_else(|| ApplicationError::NotFound {
-                message: "The specified user is not a member of this board".to_string(),
-            })?;
+        let mut board_member = board_member.ok_or_else(|| ApplicationError::NotFound {
+            message: "The specified user is not a member of this board".to_string(),
+        })?;
 
         board_member.role = dto.role;
         board_member.updated_at = Utc::now().fixed_offset();
 
-        let updated_board_member = self.board_member_repository.update(board_member).await?;
+        let updated_board_member = unit_of_work
+            .board_member_repository()
+            .update(board_member)
+            .await?;
 
-        self.event_bus
-            .publish(
+        unit_of_work
+            .outbox_repository()
+            .create(OutboxMessage::new(
+                Uuid::now_v7(),
                 dto.board_id,
                 BoardEvent::MemberRoleChanged(MemberRoleChangedEvent {
                     board_id: updated_board_member.board_id,
@@ -293,8 +431,10 @@ impl BoardService {
                     changed_by: user_id,
                     timestamp: updated_board_member.updated_at,
                 }),
-            )
-            .await;
+            ))
+            .await?;
+
+        unit_of_work.commit().await?;
 
         Ok(BoardMemberDto::from_domain(updated_board_member))
     }
@@ -312,35 +452,30 @@ impl BoardService {
             });
         }
 
-        if !self
-            .board_member_repository
-            .check_permissions(
-                dto.board_id,
-                user_id,
-                vec![BoardMemberRoleEnum::Owner, BoardMemberRoleEnum::Moderator],
-            )
-            .await?
-        {
-            return Err(ApplicationError::Forbidden {
-                message: "You don't have permission to perform this action".to_string(),
-            });
-        }
+        let unit_of_work = self.unit_of_work_factory.begin().await?;
 
-        let requester_role = self
-            .board_member_repository
-            .get_role(dto.board_id, user_id)
-            .await?
+        let (requester, target) =
+            Self::lock_board_members(unit_of_work.as_ref(), dto.board_id, user_id, dto.user_id)
+                .await?;
+
+        let requester_role = requester
+            .map(|requester| requester.role)
+            .filter(|role| {
+                matches!(
+                    role,
+                    BoardMemberRoleEnum::Owner | BoardMemberRoleEnum::Moderator
+                )
+            })
             .ok_or_else(|| ApplicationError::Forbidden {
-                message: "You are not a member of this board".to_string(),
+                message: "You don't have permission to perform this action".to_string(),
             })?;
 
-        let target_role = self
-            .board_member_repository
-            .get_role(dto.board_id, dto.user_id)
-            .await?
-            .ok_or_else(|| ApplicationError::NotFound {
-                message: "The specified user is not a member of this board".to_string(),
-            })?;
+        let target_role =
+            target
+                .map(|target| target.role)
+                .ok_or_else(|| ApplicationError::NotFound {
+                    message: "The specified user is not a member of this board".to_string(),
+                })?;
 
         if requester_role.hierarchy_value() <= target_role.hierarchy_value() {
             return Err(ApplicationError::Forbidden {
@@ -348,23 +483,116 @@ impl BoardService {
             });
         }
 
-        let deleted_board_member = self
-            .board_member_repository
+        let unassigned_task_ids = unit_of_work
+            .task_repository()
+            .remove_assignee_from_board(dto.board_id, dto.user_id)
+            .await?;
+
+        let deleted_board_member = unit_of_work
+            .board_member_repository()
             .delete(dto.board_id, dto.user_id)
             .await?;
 
-        self.event_bus
-            .publish(
+        let timestamp = Utc::now().fixed_offset();
+
+        for task_id in unassigned_task_ids {
+            unit_of_work
+                .outbox_repository()
+                .create(OutboxMessage::new(
+                    Uuid::now_v7(),
+                    dto.board_id,
+                    BoardEvent::TaskUnassigned(TaskUnassignedEvent {
+                        task_id,
+                        user_id: dto.user_id,
+                        unassigned_by: user_id,
+                        timestamp,
+                    }),
+                ))
+                .await?;
+        }
+
+        unit_of_work
+            .outbox_repository()
+            .create(OutboxMessage::new(
+                Uuid::now_v7(),
                 dto.board_id,
                 BoardEvent::MemberRemoved(MemberRemovedEvent {
                     board_id: dto.board_id,
                     user_id: dto.user_id,
                     removed_by: user_id,
-                    timestamp: Utc::now().fixed_offset(),
+                    timestamp,
                 }),
-            )
-            .await;
+            ))
+            .await?;
+
+        unit_of_work.commit().await?;
 
         Ok(deleted_board_member)
     }
+
+    async fn find_owned_board(
+        &self,
+        board_id: Uuid,
+        user_id: Uuid,
+    ) -> Result<Board, ApplicationError> {
+        let board = self
+            .board_repository
+            .find_by_id(board_id, user_id)
+            .await?
+            .ok_or_else(|| ApplicationError::NotFound {
+                message: "Board with the given ID not found".to_string(),
+            })?;
+
+        if !self
+            .board_member_repository
+            .check_permissions(board_id, user_id, vec![BoardMemberRoleEnum::Owner])
+            .await?
+        {
+            return Err(ApplicationError::Forbidden {
+                message: "You don't have permission to perform this action".to_string(),
+            });
+        }
+
+        Ok(board)
+    }
+
+    /// Locks the memberships of the requester and the target user in a stable order, so
+    /// concurrent member changes on the same board cannot deadlock
+    async fn lock_board_members(
+        unit_of_work: &dyn UnitOfWork,
+        board_id: Uuid,
+        requester_id: Uuid,
+        target_id: Uuid,
+    ) -> Result<(Option<BoardMember>, Option<BoardMember>), ApplicationError> {
+        let board_member_repository = unit_of_work.board_member_repository();
+
+        let (first_id, second_id) = if requester_id < target_id {
+            (requester_id, target_id)
+        } else {
+            (target_id, requester_id)
+        };
+
+        let first = board_member_repository
+            .find_by_board_and_user_id_for_update(board_id, first_id)
+            .await?;
+        let second = board_member_repository
+            .find_by_board_and_user_id_for_update(board_id, second_id)
+            .await?;
+
+        if first_id == requester_id {
+            Ok((first, second))
+        } else {
+            Ok((second, first))
+        }
+    }
+
+    async fn remove_attachment_blobs(&self, storage_keys: Vec<String>) {
+        if storage_keys.is_empty() {
+            return;
+        }
+
+        if let Err(err) = self.storage_service.delete_objects(&storage_keys).await {
+            warn!("Failed to remove attachments of deleted board: {}", err);
+        }
+    }
 }
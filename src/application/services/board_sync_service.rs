@@ -0,0 +1,424 @@
+use crate::{
+    application::dto::{
+        BoardChangeDto, BoardChangesPageDto, BoardChangesQueryDto, BoardDto, BoardMemberDto,
+        BoardSnapshotColumnDto, BoardSnapshotDto, ColumnDto, CustomFieldDto, LabelDto,
+        MemberTombstoneDto, TaskDto, TombstoneDto,
+    },
+    domain::{
+        events::BoardEvent,
+        repositories::{
+            BoardActivityRepository, BoardMemberRepository, BoardRepository, ColumnRepository,
+            CustomFieldRepository, LabelRepository, TaskRepository,
+        },
+    },
+    shared::error::ApplicationError,
+};
+use std::{
+    collections::{HashMap, HashSet},
+    sync::Arc,
+};
+use uuid::Uuid;
+use validator::Validate;
+
+const DEFAULT_CHANGES_PAGE_SIZE: u64 = 500;
+
+/// Entity of a board whose latest state is part of the changes feed
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+enum ChangedEntity {
+    Board,
+    Member(Uuid),
+    Column(Uuid),
+    Task(Uuid),
+    Label(Uuid),
+    CustomField(Uuid),
+}
+
+impl ChangedEntity {
+    /// Comments, attachments and checklists are loaded per task and are not part of the feed,
+    /// only changes to a task's checklist progress are. WIP limit overrides don't change any
+    /// state of their own
+    fn from_event(event: &BoardEvent) -> Vec<Self> {
+        match event {
+            BoardEvent::BoardCreated(_)
+            | BoardEvent::BoardUpdated(_)
+            | BoardEvent::BoardDeleted(_)
+            | BoardEvent::BoardArchived(_)
+            | BoardEvent::BoardRestored(_) => vec![ChangedEntity::Board],
+            BoardEvent::MemberAdded(event) => vec![ChangedEntity::Member(event.user_id)],
+            BoardEvent::MemberRoleChanged(event) => vec![ChangedEntity::Member(event.user_id)],
+            BoardEvent::MemberRemoved(event) => vec![ChangedEntity::Member(event.user_id)],
+            BoardEvent::ColumnCreated(event) => vec![ChangedEntity::Column(event.column_id)],
+            BoardEvent::ColumnUpdated(event) => vec![ChangedEntity::Column(event.column_id)],
+            BoardEvent::ColumnMoved(event) => vec![ChangedEntity::Column(event.column_id)],
+            BoardEvent::ColumnDeleted(event) => vec![ChangedEntity::Column(event.column_id)],
+            BoardEvent::ColumnArchived(event) => vec![ChangedEntity::Column(event.column_id)],
+            BoardEvent::ColumnRestored(event) => vec![ChangedEntity::Column(event.column_id)],
+            BoardEvent::TaskCreated(event) => vec![ChangedEntity::Task(event.task_id)],
+            BoardEvent::TaskUpdated(event) => vec![ChangedEntity::Task(event.task_id)],
+            BoardEvent::TaskMoved(event) => vec![ChangedEntity::Task(event.task_id)],
+            BoardEvent::TaskDeleted(event) => vec![ChangedEntity::Task(event.task_id)],
+            BoardEvent::TaskRemoved(event) => vec![ChangedEntity::Task(event.task_id)],
+            BoardEvent::TaskArchived(event) => vec![ChangedEntity::Task(event.task_id)],
+            BoardEvent::TaskRestored(event) => vec![ChangedEntity::Task(event.task_id)],
+            BoardEvent::TaskCompleted(event) => vec![ChangedEntity::Task(event.task_id)],
+            BoardEvent::TaskReopened(event) => vec![ChangedEntity::Task(event.task_id)],
+            BoardEvent::TaskAssigned(event) => vec![ChangedEntity::Task(event.task_id)],
+            BoardEvent::TaskUnassigned(event) => vec![ChangedEntity::Task(event.task_id)],
+            BoardEvent::TaskRelationCreated(event) => vec![
+                ChangedEntity::Task(event.source_task_id),
+                ChangedEntity::Task(event.target_task_id),
+            ],
+            BoardEvent::TaskRelationDeleted(event) => vec![
+                ChangedEntity::Task(event.source_task_id),
+                ChangedEntity::Task(event.target_task_id),
+            ],
+            BoardEvent::LabelCreated(event) => vec![ChangedEntity::Label(event.label_id)],
+            BoardEvent::LabelUpdated(event) => vec![ChangedEntity::Label(event.label_id)],
+            BoardEvent::LabelDeleted(event) => {
+                std::iter::once(ChangedEntity::Label(event.label_id))
+                    .chain(
+                        event
+                            .task_ids
+                            .iter()
+                            .map(|task_id| ChangedEntity::Task(*task_id)),
+                    )
+                    .collect()
+            }
+            BoardEvent::CustomFieldCreated(event) => {
+                vec![ChangedEntity::CustomField(event.field_id)]
+            }
+            BoardEvent::CustomFieldUpdated(event) => {
+                vec![ChangedEntity::CustomField(event.field_id)]
+            }
+            BoardEvent::CustomFieldDeleted(event) => {
+                std::iter::once(ChangedEntity::CustomField(event.field_id))
+                    .chain(
+                        event
+                            .task_ids
+                            .iter()
+                            .map(|task_id| ChangedEntity::Task(*task_id)),
+                    )
+                    .collect()
+            }
+            BoardEvent::TasksBulkUpdated(event) => event
+                .task_ids
+                .iter()
+                .map(|task_id| ChangedEntity::Task(*task_id))
+                .collect(),
+            BoardEvent::PositionsRebalanced(event) => event
+                .positions
+                .iter()
+                .map(|entry| match event.column_id {
+                    Some(_) => ChangedEntity::Task(entry.id),
+                    None => ChangedEntity::Column(entry.id),
+                })
+                .collect(),
+            BoardEvent::ChecklistDeleted(event) => vec![ChangedEntity::Task(event.task_id)],
+            BoardEvent::ChecklistItemCreated(event) => vec![ChangedEntity::Task(event.task_id)],
+            BoardEvent::ChecklistItemToggled(event) => vec![ChangedEntity::Task(event.task_id)],
+            BoardEvent::ChecklistItemDeleted(event) => vec![ChangedEntity::Task(event.task_id)],
+            BoardEvent::WipLimitExceeded(_)
+            | BoardEvent::ChecklistCreated(_)
+            | BoardEvent::ChecklistUpdated(_)
+            | BoardEvent::ChecklistMoved(_)
+            | BoardEvent::ChecklistItemUpdated(_)
+            | BoardEvent::ChecklistItemMoved(_)
+            | BoardEvent::CommentAdded(_)
+            | BoardEvent::CommentEdited(_)
+            | BoardEvent::CommentDeleted(_)
+            | BoardEvent::AttachmentAdded(_)
+            | BoardEvent::AttachmentDeleted(_)
+            | BoardEvent::TimerStarted(_)
+            | BoardEvent::TimerStopped(_)
+            | BoardEvent::TimeEntryLogged(_)
+            | BoardEvent::TimeEntryUpdated(_)
+            | BoardEvent::TimeEntryDeleted(_) => Vec::new(),
+        }
+    }
+}
+
+pub struct BoardSyncService {
+    board_repository: Arc<dyn BoardRepository>,
+    board_member_repository: Arc<dyn BoardMemberRepository>,
+    column_repository: Arc<dyn ColumnRepository>,
+    task_repository: Arc<dyn TaskRepository>,
+    label_repository: Arc<dyn LabelRepository>,
+    custom_field_repository: Arc<dyn CustomFieldRepository>,
+    board_activity_repository: Arc<dyn BoardActivityRepository>,
+}
+
+impl BoardSyncService {
+    pub fn new(
+        board_repository: Arc<dyn BoardRepository>,
+        board_member_repository: Arc<dyn BoardMemberRepository>,
+        column_repository: Arc<dyn ColumnRepository>,
+        task_repository: Arc<dyn TaskRepository>,
+        label_repository: Arc<dyn LabelRepository>,
+        custom_field_repository: Arc<dyn CustomFieldRepository>,
+        board_activity_repository: Arc<dyn BoardActivityRepository>,
+    ) -> Self {
+        Self {
+            board_repository,
+            board_member_repository,
+            column_repository,
+            task_repository,
+            label_repository,
+            custom_field_repository,
+            board_activity_repository,
+        }
+    }
+
+    pub async fn get_board_snapshot(
+        &self,
+        board_id: Uuid,
+        include_archived: bool,
+        user_id: Uuid,
+    ) -> Result<BoardSnapshotDto, ApplicationError> {
+        // Read before the board state: events are recorded after their change is committed, so
+        // every change missing from the snapshot has a later sequence and is replayed
+        let sequence = self
+            .board_activity_repository
+            .find_latest_sequence(board_id)
+            .await?;
+
+        let board = self
+            .board_repository
+            .find_by_id(board_id, user_id)
+            .await?
+            .ok_or_else(|| ApplicationError::NotFound {
+                message: "Board with the given ID not found".to_string(),
+            })?;
+
+        let members = self
+            .board_member_repository
+            .find_by_board_id(board_id)
+            .await?;
+
+        let columns = self
+            .column_repository
+            .find_by_board_id(board_id, include_archived)
+            .await?;
+
+        let mut tasks_by_column: HashMap<Uuid, Vec<TaskDto>> = HashMap::new();
+        for task in self
+            .task_repository
+            .find_by_board_id(board_id, include_archived)
+            .await?
+        {
+            tasks_by_column
+                .entry(task.column_id)
+                .or_default()
+                .push(TaskDto::from_domain(task));
+        }
+
+        let labels = self.label_repository.find_by_board_id(board_id).await?;
+
+        let custom_fields = self
+            .custom_field_repository
+            .find_by_board_id(board_id)
+            .await?;
+
+        Ok(BoardSnapshotDto {
+            board: BoardDto::from_domain(board),
+            members: members
+                .into_iter()
+                .map(BoardMemberDto::from_domain)
+                .collect(),
+            columns: columns
+                .into_iter()
+                .map(|column| BoardSnapshotColumnDto {
+                    tasks: tasks_by_column.remove(&column.id).unwrap_or_default(),
+                    column: ColumnDto::from_domain(column),
+                })
+                .collect(),
+            labels: labels.into_iter().map(LabelDto::from_domain).collect(),
+            custom_fields: custom_fields
+                .into_iter()
+                .map(CustomFieldDto::from_domain)
+                .collect(),
+            sequence,
+        })
+    }
+
+    pub async fn get_board_changes(
+        &self,
+        board_id: Uuid,
+        query: BoardChangesQueryDto,
+        user_id: Uuid,
+    ) -> Result<BoardChangesPageDto, ApplicationError> {
+        query.validate()?;
+
+        let board = self
+            .board_repository
+            .find_by_id(board_id, user_id)
+            .await?
+            .ok_or_else(|| ApplicationError::NotFound {
+                message: "Board with the given ID not found".to_string(),
+            })?;
+
+        let cursor = query.cursor.unwrap_or(0);
+        let limit = query.limit.unwrap_or(DEFAULT_CHANGES_PAGE_SIZE);
+
+        // One extra event tells whether more changes follow
+        let mut activities = self
+            .board_activity_repository
+            .find_since_sequence(board_id, cursor, limit + 1)
+            .await?;
+
+        let has_more = activities.len() as u64 > limit;
+        activities.truncate(limit as usize);
+
+        let next_cursor = activities
+            .last()
+            .map(|activity| activity.sequence)
+            .unwrap_or(cursor);
+
+        // Every entity is reported once with its latest state, at the position of its last event
+        let mut last_changed_at = HashMap::new();
+        for activity in &activities {
+            let envelope =
+                activity
+                    .to_envelope()
+                    .map_err(|err| ApplicationError::InternalError {
+                        message: format!("Failed to read activity '{}': {}", activity.id, err),
+                    })?;
+
+            for entity in ChangedEntity::from_event(&envelope.event) {
+                last_changed_at.insert(entity, activity.sequence);
+            }
+        }
+
+        let mut changed_entities: Vec<(ChangedEntity, i64)> = last_changed_at.into_iter().collect();
+        changed_entities.sort_by_key(|(_, sequence)| *sequence);
+
+        let mut members: HashMap<Uuid, _> = if changed_entities
+            .iter()
+            .any(|(entity, _)| matches!(entity, ChangedEntity::Member(_)))
+        {
+            self.board_member_repository
+                .find_by_board_id(board_id)
+                .await?
+                .into_iter()
+                .map(|member| (member.user_id, member))
+                .collect()
+        } else {
+            HashMap::new()
+        };
+
+        let task_ids: Vec<Uuid> = changed_entities
+            .iter()
+            .filter_map(|(entity, _)| match entity {
+                ChangedEntity::Task(task_id) => Some(*task_id),
+                _ => None,
+            })
+            .collect();
+
+        let mut columns: HashMap<Uuid, _> = if changed_entities
+            .iter()
+            .any(|(entity, _)| matches!(entity, ChangedEntity::Column(_) | ChangedEntity::Task(_)))
+        {
+            self.column_repository
+                .find_by_board_id(board_id, true)
+                .await?
+                .into_iter()
+                .map(|column| (column.id, column))
+                .collect()
+        } else {
+            HashMap::new()
+        };
+
+        // Tasks no longer in one of the board's columns are reported as deleted
+        let board_column_ids: HashSet<Uuid> = columns.keys().copied().collect();
+        let mut tasks: HashMap<Uuid, _> = if task_ids.is_empty() {
+            HashMap::new()
+        } else {
+            self.task_repository
+                .find_by_ids(task_ids)
+                .await?
+                .into_iter()
+                .filter(|task| board_column_ids.contains(&task.column_id))
+                .map(|task| (task.id, task))
+                .collect()
+        };
+
+        let label_ids: Vec<Uuid> = changed_entities
+            .iter()
+            .filter_map(|(entity, _)| match entity {
+                ChangedEntity::Label(label_id) => Some(*label_id),
+                _ => None,
+            })
+            .collect();
+
+        let mut labels: HashMap<Uuid, _> = if label_ids.is_empty() {
+            HashMap::new()
+        } else {
+            self.label_repository
+                .find_by_ids(label_ids)
+                .await?
+                .into_iter()
+                .map(|label| (label.id, label))
+                .collect()
+        };
+
+        let field_ids: Vec<Uuid> = changed_entities
+            .iter()
+            .filter_map(|(entity, _)| match entity {
+                ChangedEntity::CustomField(field_id) => Some(*field_id),
+                _ => None,
+            })
+            .collect();
+
+        let mut custom_fields: HashMap<Uuid, _> = if field_ids.is_empty() {
+            HashMap::new()
+        } else {
+            self.custom_field_repository
+                .find_by_ids(field_ids)
+                .await?
+                .into_iter()
+                .map(|field| (field.id, field))
+                .collect()
+        };
+
+        let mut board = Some(board);
+        let changes = changed_entities
+            .into_iter()
+            .filter_map(|(entity, _)| match entity {
+                ChangedEntity::Board => board
+                    .take()
+                    .map(|board| BoardChangeDto::BoardUpserted(BoardDto::from_domain(board))),
+                ChangedEntity::Member(user_id) => Some(match members.remove(&user_id) {
+                    Some(member) => {
+                        BoardChangeDto::MemberUpserted(BoardMemberDto::from_domain(member))
+                    }
+                    None => BoardChangeDto::MemberDeleted(MemberTombstoneDto { user_id }),
+                }),
+                ChangedEntity::Column(id) => Some(match columns.remove(&id) {
+                    Some(column) => BoardChangeDto::ColumnUpserted(ColumnDto::from_domain(column)),
+                    None => BoardChangeDto::ColumnDeleted(TombstoneDto { id }),
+                }),
+                ChangedEntity::Task(id) => Some(match tasks.remove(&id) {
+                    Some(task) => {
+                        BoardChangeDto::TaskUpserted(Box::new(TaskDto::from_domain(task)))
+                    }
+                    None => BoardChangeDto::TaskDeleted(TombstoneDto { id }),
+                }),
+                ChangedEntity::Label(id) => Some(match labels.remove(&id) {
+                    Some(label) => BoardChangeDto::LabelUpserted(LabelDto::from_domain(label)),
+                    None => BoardChangeDto::LabelDeleted(TombstoneDto { id }),
+                }),
+                ChangedEntity::CustomField(id) => Some(match custom_fields.remove(&id) {
+                    Some(field) => {
+                        BoardChangeDto::CustomFieldUpserted(CustomFieldDto::from_domain(field))
+                    }
+                    None => BoardChangeDto::CustomFieldDeleted(TombstoneDto { id }),
+                }),
+            })
+            .collect();
+
+        Ok(BoardChangesPageDto {
+            changes,
+            next_cursor,
+            has_more,
+        })
+    }
+}
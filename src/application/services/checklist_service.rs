@@ -0,0 +1,789 @@
+use crate::{
+    application::dto::{
+        ChecklistDto, ChecklistItemDto, CreateChecklistDto, CreateChecklistItemDto,
+        UpdateChecklistDto, UpdateChecklistItemDto,
+    },
+    domain::{
+        events::{
+            BoardEvent, ChecklistCreatedEvent, ChecklistDeletedEvent, ChecklistItemCreatedEvent,
+            ChecklistItemDeletedEvent, ChecklistItemMovedEvent, ChecklistItemToggledEvent,
+            ChecklistItemUpdatedEvent, ChecklistMovedEvent, ChecklistUpdatedEvent, EntityPosition,
+        },
+        repositories::{
+            BoardMemberRepository, Checklist, ChecklistItem, ChecklistRepository, ColumnRepository,
+            OutboxMessage, TaskRepository, UnitOfWork, UnitOfWorkFactory,
+        },
+    },
+    shared::{error::ApplicationError, utils::fractional_indexing::FractionalIndexGenerator},
+};
+use chrono::Utc;
+use entity::BoardMemberRoleEnum;
+use std::{collections::HashMap, sync::Arc};
+use uuid::Uuid;
+use validator::Validate;
+
+pub struct ChecklistService {
+    checklist_repository: Arc<dyn ChecklistRepository>,
+    task_repository: Arc<dyn TaskRepository>,
+    column_repository: Arc<dyn ColumnRepository>,
+    board_member_repository: Arc<dyn BoardMemberRepository>,
+    unit_of_work_factory: Arc<dyn UnitOfWorkFactory>,
+}
+
+impl ChecklistService {
+    pub fn new(
+        checklist_repository: Arc<dyn ChecklistRepository>,
+        task_repository: Arc<dyn TaskRepository>,
+        column_repository: Arc<dyn ColumnRepository>,
+        board_member_repository: Arc<dyn BoardMemberRepository>,
+        unit_of_work_factory: Arc<dyn UnitOfWorkFactory>,
+    ) -> Self {
+        Self {
+            checklist_repository,
+            task_repository,
+            column_repository,
+            board_member_repository,
+            unit_of_work_factory,
+        }
+    }
+
+    pub async fn create_checklist(
+        &self,
+        dto: CreateChecklistDto,
+        user_id: Uuid,
+    ) -> Result<ChecklistDto, ApplicationError> {
+        dto.validate()?;
+
+        let board_id = self.find_board_id(dto.task_id).await?;
+
+        self.ensure_member(board_id, user_id).await?;
+
+        // Locking the task serializes concurrent changes to its checklists, so positions are
+        // generated from the current ones
+        let unit_of_work = self.unit_of_work_factory.begin().await?;
+
+        Self::lock_task(unit_of_work.as_ref(), dto.task_id).await?;
+
+        let checklists = unit_of_work
+            .checklist_repository()
+            .find_by_task_id(dto.task_id)
+            .await?;
+        let target_index = dto.position.unwrap_or(checklists.len());
+        let checklist_id = Uuid::now_v7();
+
+        let (position, rebalanced_positions) = Self::place(
+            checklists
+                .into_iter()
+                .map(|checklist| (checklist.id, checklist.position))
+                .collect(),
+            checklist_id,
+            target_index,
+        )?;
+
+        let checklist = Checklist::new(checklist_id, dto.task_id, dto.title, position);
+
+        unit_of_work
+            .checklist_repository()
+            .update_positions(Self::to_pairs(&rebalanced_positions))
+            .await?;
+
+        let saved_checklist = unit_of_work
+            .checklist_repository()
+            .create(checklist)
+            .await?;
+
+        unit_of_work
+            .outbox_repository()
+            .create(OutboxMessage::new(
+                Uuid::now_v7(),
+                board_id,
+                BoardEvent::ChecklistCreated(ChecklistCreatedEvent {
+                    checklist_id,
+                    task_id: saved_checklist.task_id,
+                    title: saved_checklist.title.clone(),
+                    position: saved_checklist.position.clone(),
+                    rebalanced_positions,
+                    created_by: user_id,
+                    timestamp: saved_checklist.created_at,
+                }),
+            ))
+            .await?;
+
+        unit_of_work.commit().await?;
+
+        Ok(ChecklistDto::from_domain(saved_checklist, Vec::new()))
+    }
+
+    pub async fn get_checklist_by_id(
+        &self,
+        checklist_id: Uuid,
+        user_id: Uuid,
+    ) -> Result<ChecklistDto, ApplicationError> {
+        let checklist = self.find_checklist(checklist_id).await?;
+        let board_id = self.find_board_id(checklist.task_id).await?;
+
+        self.ensure_member(board_id, user_id).await?;
+
+        let items = self
+            .checklist_repository
+            .find_items_by_checklist_id(checklist_id)
+            .await?;
+
+        Ok(ChecklistDto::from_domain(checklist, items))
+    }
+
+    pub async fn get_task_checklists(
+        &self,
+        task_id: Uuid,
+        user_id: Uuid,
+    ) -> Result<Vec<ChecklistDto>, ApplicationError> {
+        let board_id = self.find_board_id(task_id).await?;
+
+        self.ensure_member(board_id, user_id).await?;
+
+        let checklists = self.checklist_repository.find_by_task_id(task_id).await?;
+
+        let mut items_by_checklist: HashMap<Uuid, Vec<ChecklistItem>> = HashMap::new();
+        for item in self
+            .checklist_repository
+            .find_items_by_task_id(task_id)
+            .await?
+        {
+            items_by_checklist
+                .entry(item.checklist_id)
+                .or_default()
+                .push(item);
+        }
+
+        Ok(checklists
+            .into_iter()
+            .map(|checklist| {
+                let items = items_by_checklist.remove(&checklist.id).unwrap_or_default();
+                ChecklistDto::from_domain(checklist, items)
+            })
+            .collect())
+    }
+
+    pub async fn update_checklist(
+        &self,
+        dto: UpdateChecklistDto,
+        checklist_id: Uuid,
+        user_id: Uuid,
+    ) -> Result<ChecklistDto, ApplicationError> {
+        dto.validate()?;
+
+        let mut checklist = self.find_checklist(checklist_id).await?;
+        let board_id = self.find_board_id(checklist.task_id).await?;
+
+        self.ensure_member(board_id, user_id).await?;
+
+        checklist.title = dto.title;
+        checklist.updated_at = Utc::now().fixed_offset();
+
+        let unit_of_work = self.unit_of_work_factory.begin().await?;
+
+        let updated_checklist = unit_of_work
+            .checklist_repository()
+            .update(checklist)
+            .await?;
+
+        unit_of_work
+            .outbox_repository()
+            .create(OutboxMessage::new(
+                Uuid::now_v7(),
+                board_id,
+                BoardEvent::ChecklistUpdated(ChecklistUpdatedEvent {
+                    checklist_id,
+                    task_id: updated_checklist.task_id,
+                    title: updated_checklist.title.clone(),
+                    updated_by: user_id,
+                    timestamp: updated_checklist.updated_at,
+                }),
+            ))
+            .await?;
+
+        let items = unit_of_work
+            .checklist_repository()
+            .find_items_by_checklist_id(checklist_id)
+            .await?;
+
+        unit_of_work.commit().await?;
+
+        Ok(ChecklistDto::from_domain(updated_checklist, items))
+    }
+
+    pub async fn move_checklist(
+        &self,
+        target_position: usize,
+        checklist_id: Uuid,
+        user_id: Uuid,
+    ) -> Result<ChecklistDto, ApplicationError> {
+        let checklist = self.find_checklist(checklist_id).await?;
+        let board_id = self.find_board_id(checklist.task_id).await?;
+
+        self.ensure_member(board_id, user_id).await?;
+
+        let unit_of_work = self.unit_of_work_factory.begin().await?;
+
+        Self::lock_task(unit_of_work.as_ref(), checklist.task_id).await?;
+
+        let checklists = unit_of_work
+            .checklist_repository()
+            .find_by_task_id(checklist.task_id)
+            .await?;
+
+        let current_index = checklists
+            .iter()
+            .position(|c| c.id == checklist_id)
+            .ok_or_else(|| ApplicationError::InternalError {
+                message: "Failed to determine current checklist index".to_string(),
+            })?;
+
+        if target_position >= checklists.len() {
+            return Err(ApplicationError::BadRequest {
+                message: format!(
+                    "Target position is out of bounds (0 - {})",
+                    checklists.len() - 1
+                ),
+            });
+        }
+
+        let mut checklist = checklists[current_index].clone();
+
+        let items = unit_of_work
+            .checklist_repository()
+            .find_items_by_checklist_id(checklist_id)
+            .await?;
+
+        if current_index == target_position {
+            return Ok(ChecklistDto::from_domain(checklist, items));
+        }
+
+        let (position, rebalanced_positions) = Self::place(
+            checklists
+                .into_iter()
+                .filter(|c| c.id != checklist_id)
+                .map(|c| (c.id, c.position))
+                .collect(),
+            checklist_id,
+            target_position,
+        )?;
+
+        checklist.position = position;
+        checklist.updated_at = Utc::now().fixed_offset();
+
+        unit_of_work
+            .checklist_repository()
+            .update_positions(Self::to_pairs(&rebalanced_positions))
+            .await?;
+
+        let updated_checklist = unit_of_work
+            .checklist_repository()
+            .update(checklist)
+            .await?;
+
+        unit_of_work
+            .outbox_repository()
+            .create(OutboxMessage::new(
+                Uuid::now_v7(),
+                board_id,
+                BoardEvent::ChecklistMoved(ChecklistMovedEvent {
+                    checklist_id,
+                    task_id: updated_checklist.task_id,
+                    old_position: current_index,
+                    new_position: target_position,
+                    position: updated_checklist.position.clone(),
+                    rebalanced_positions,
+                    moved_by: user_id,
+                    timestamp: updated_checklist.updated_at,
+                }),
+            ))
+            .await?;
+
+        unit_of_work.commit().await?;
+
+        Ok(ChecklistDto::from_domain(updated_checklist, items))
+    }
+
+    pub async fn delete_checklist(
+        &self,
+        checklist_id: Uuid,
+        user_id: Uuid,
+    ) -> Result<u64, ApplicationError> {
+        let checklist = self.find_checklist(checklist_id).await?;
+        let board_id = self.find_board_id(checklist.task_id).await?;
+
+        self.ensure_member(board_id, user_id).await?;
+
+        let unit_of_work = self.unit_of_work_factory.begin().await?;
+
+        let deleted_checklist = unit_of_work
+            .checklist_repository()
+            .delete(checklist_id)
+            .await?;
+
+        let progress = unit_of_work
+            .checklist_repository()
+            .find_progress(checklist.task_id)
+            .await?;
+
+        unit_of_work
+            .outbox_repository()
+            .create(OutboxMessage::new(
+                Uuid::now_v7(),
+                board_id,
+                BoardEvent::ChecklistDeleted(ChecklistDeletedEvent {
+                    checklist_id,
+                    task_id: checklist.task_id,
+                    completed_items: progress.completed_items,
+                    total_items: progress.total_items,
+                    deleted_by: user_id,
+                    timestamp: Utc::now().fixed_offset(),
+                }),
+            ))
+            .await?;
+
+        unit_of_work.commit().await?;
+
+        Ok(deleted_checklist)
+    }
+
+    pub async fn create_checklist_item(
+        &self,
+        dto: CreateChecklistItemDto,
+        checklist_id: Uuid,
+        user_id: Uuid,
+    ) -> Result<ChecklistItemDto, ApplicationError> {
+        dto.validate()?;
+
+        let checklist = self.find_checklist(checklist_id).await?;
+        let board_id = self.find_board_id(checklist.task_id).await?;
+
+        self.ensure_member(board_id, user_id).await?;
+
+        // Locking the checklist serializes concurrent changes to its items, so positions are
+        // generated from the current ones
+        let unit_of_work = self.unit_of_work_factory.begin().await?;
+
+        Self::lock_checklist(unit_of_work.as_ref(), checklist_id).await?;
+
+        let items = unit_of_work
+            .checklist_repository()
+            .find_items_by_checklist_id(checklist_id)
+            .await?;
+        let target_index = dto.position.unwrap_or(items.len());
+        let item_id = Uuid::now_v7();
+
+        let (position, rebalanced_positions) = Self::place(
+            items
+                .into_iter()
+                .map(|item| (item.id, item.position))
+                .collect(),
+            item_id,
+            target_index,
+        )?;
+
+        let item = ChecklistItem::new(item_id, checklist_id, dto.content, position);
+
+        unit_of_work
+            .checklist_repository()
+            .update_item_positions(Self::to_pairs(&rebalanced_positions))
+            .await?;
+
+        let saved_item = unit_of_work
+            .checklist_repository()
+            .create_item(item)
+            .await?;
+
+        let progress = unit_of_work
+            .checklist_repository()
+            .find_progress(checklist.task_id)
+            .await?;
+
+        unit_of_work
+            .outbox_repository()
+            .create(OutboxMessage::new(
+                Uuid::now_v7(),
+                board_id,
+                BoardEvent::ChecklistItemCreated(ChecklistItemCreatedEvent {
+                    item_id,
+                    checklist_id,
+                    task_id: checklist.task_id,
+                    content: saved_item.content.clone(),
+                    position: saved_item.position.clone(),
+                    rebalanced_positions,
+                    completed_items: progress.completed_items,
+                    total_items: progress.total_items,
+                    created_by: user_id,
+                    timestamp: saved_item.created_at,
+                }),
+            ))
+            .await?;
+
+        unit_of_work.commit().await?;
+
+        Ok(ChecklistItemDto::from_domain(saved_item))
+    }
+
+    pub async fn update_checklist_item(
+        &self,
+        dto: UpdateChecklistItemDto,
+        item_id: Uuid,
+        user_id: Uuid,
+    ) -> Result<ChecklistItemDto, ApplicationError> {
+        dto.validate()?;
+
+        let mut item = self.find_item(item_id).await?;
+        let checklist = self.find_checklist(item.checklist_id).await?;
+        let board_id = self.find_board_id(checklist.task_id).await?;
+
+        self.ensure_member(board_id, user_id).await?;
+
+        let now = Utc::now().fixed_offset();
+
+        let content_changed = dto
+            .content
+            .as_ref()
+            .is_some_and(|content| *content != item.content);
+        let toggled = dto
+            .is_completed
+            .is_some_and(|is_completed| is_completed != item.is_completed);
+
+        if !content_changed && !toggled {
+            return Ok(ChecklistItemDto::from_domain(item));
+        }
+
+        if let Some(content) = dto.content {
+            item.content = content;
+        }
+        if toggled {
+            item.is_completed = !item.is_completed;
+            item.completed_by = item.is_completed.then_some(user_id);
+            item.completed_at = item.is_completed.then_some(now);
+        }
+        item.updated_at = now;
+
+        let unit_of_work = self.unit_of_work_factory.begin().await?;
+
+        let updated_item = unit_of_work
+            .checklist_repository()
+            .update_item(item)
+            .await?;
+
+        if content_changed {
+            unit_of_work
+                .outbox_repository()
+                .create(OutboxMessage::new(
+                    Uuid::now_v7(),
+                    board_id,
+                    BoardEvent::ChecklistItemUpdated(ChecklistItemUpdatedEvent {
+                        item_id,
+                        checklist_id: checklist.id,
+                        task_id: checklist.task_id,
+                        content: updated_item.content.clone(),
+                        updated_by: user_id,
+                        timestamp: now,
+                    }),
+                ))
+                .await?;
+        }
+
+        if toggled {
+            let progress = unit_of_work
+                .checklist_repository()
+                .find_progress(checklist.task_id)
+                .await?;
+
+            unit_of_work
+                .outbox_repository()
+                .create(OutboxMessage::new(
+                    Uuid::now_v7(),
+                    board_id,
+                    BoardEvent::ChecklistItemToggled(ChecklistItemToggledEvent {
+                        item_id,
+                        checklist_id: checklist.id,
+                        task_id: checklist.task_id,
+                        is_completed: updated_item.is_completed,
+                        completed_items: progress.completed_items,
+                        total_items: progress.total_items,
+                        toggled_by: user_id,
+                        timestamp: now,
+                    }),
+                ))
+                .await?;
+        }
+
+        unit_of_work.commit().await?;
+
+        Ok(ChecklistItemDto::from_domain(updated_item))
+    }
+
+    pub async fn move_checklist_item(
+        &self,
+        target_position: usize,
+        item_id: Uuid,
+        user_id: Uuid,
+    ) -> Result<ChecklistItemDto, ApplicationError> {
+        let item = self.find_item(item_id).await?;
+        let checklist = self.find_checklist(item.checklist_id).await?;
+        let board_id = self.find_board_id(checklist.task_id).await?;
+
+        self.ensure_member(board_id, user_id).await?;
+
+        let unit_of_work = self.unit_of_work_factory.begin().await?;
+
+        Self::lock_checklist(unit_of_work.as_ref(), checklist.id).await?;
+
+        let items = unit_of_work
+            .checklist_repository()
+            .find_items_by_checklist_id(checklist.id)
+            .await?;
+
+        let current_index = items.iter().position(|i| i.id == item_id).ok_or_else(|| {
+            ApplicationError::InternalError {
+                message: "Failed to determine current checklist item index".to_string(),
+            }
+        })?;
+
+        if target_position >= items.len() {
+            return Err(ApplicationError::BadRequest {
+                message: format!("Target position is out of bounds (0 - {})", items.len() - 1),
+            });
+        }
+
+        let mut item = items[current_index].clone();
+
+        if current_index == target_position {
+            return Ok(ChecklistItemDto::from_domain(item));
+        }
+
+        let (position, rebalanced_positions) = Self::place(
+            items
+                .into_iter()
+                .filter(|i| i.id != item_id)
+                .map(|i| (i.id, i.position))
+                .collect(),
+            item_id,
+            target_position,
+        )?;
+
+        item.position = position;
+        item.updated_at = Utc::now().fixed_offset();
+
+        unit_of_work
+            .checklist_repository()
+            .update_item_positions(Self::to_pairs(&rebalanced_positions))
+            .await?;
+
+        let updated_item = unit_of_work
+            .checklist_repository()
+            .update_item(item)
+            .await?;
+
+        unit_of_work
+            .outbox_repository()
+            .create(OutboxMessage::new(
+                Uuid::now_v7(),
+                board_id,
+                BoardEvent::ChecklistItemMoved(ChecklistItemMovedEvent {
+                    item_id,
+                    checklist_id: checklist.id,
+                    task_id: checklist.task_id,
+                    old_position: current_index,
+                    new_position: target_position,
+                    position: updated_item.position.clone(),
+                    rebalanced_positions,
+                    moved_by: user_id,
+                    timestamp: updated_item.updated_at,
+                }),
+            ))
+            .await?;
+
+        unit_of_work.commit().await?;
+
+        Ok(ChecklistItemDto::from_domain(updated_item))
+    }
+
+    pub async fn delete_checklist_item(
+        &self,
+        item_id: Uuid,
+        user_id: Uuid,
+    ) -> Result<u64, ApplicationError> {
+        let item = self.find_item(item_id).await?;
+        let checklist = self.find_checklist(item.checklist_id).await?;
+        let board_id = self.find_board_id(checklist.task_id).await?;
+
+        self.ensure_member(board_id, user_id).await?;
+
+        let unit_of_work = self.unit_of_work_factory.begin().await?;
+
+        let deleted_item = unit_of_work
+            .checklist_repository()
+            .delete_item(item_id)
+            .await?;
+
+        let progress = unit_of_work
+            .checklist_repository()
+            .find_progress(checklist.task_id)
+            .await?;
+
+        unit_of_work
+            .outbox_repository()
+            .create(OutboxMessage::new(
+                Uuid::now_v7(),
+                board_id,
+                BoardEvent::ChecklistItemDeleted(ChecklistItemDeletedEvent {
+                    item_id,
+                    checklist_id: checklist.id,
+                    task_id: checklist.task_id,
+                    completed_items: progress.completed_items,
+                    total_items: progress.total_items,
+                    deleted_by: user_id,
+                    timestamp: Utc::now().fixed_offset(),
+                }),
+            ))
+            .await?;
+
+        unit_of_work.commit().await?;
+
+        Ok(deleted_item)
+    }
+
+    async fn lock_task(
+        unit_of_work: &dyn UnitOfWork,
+        task_id: Uuid,
+    ) -> Result<(), ApplicationError> {
+        unit_of_work
+            .task_repository()
+            .find_by_id_for_update(task_id)
+            .await?
+            .ok_or_else(|| ApplicationError::NotFound {
+                message: "Task with the given ID not found".to_string(),
+            })?;
+
+        Ok(())
+    }
+
+    async fn lock_checklist(
+        unit_of_work: &dyn UnitOfWork,
+        checklist_id: Uuid,
+    ) -> Result<(), ApplicationError> {
+        unit_of_work
+            .checklist_repository()
+            .find_by_id_for_update(checklist_id)
+            .await?
+            .ok_or_else(|| ApplicationError::NotFound {
+                message: "Checklist with the given ID not found".to_string(),
+            })?;
+
+        Ok(())
+    }
+
+    /// Returns the key for `placed_id` at `target_index` among `others`, which must be sorted
+    /// by position. When the key would grow too long every entity gets a fresh, evenly spread
+    /// key instead and the new keys of the others are returned as well.
+    fn place(
+        others: Vec<(Uuid, String)>,
+        placed_id: Uuid,
+        target_index: usize,
+    ) -> Result<(String, Vec<EntityPosition>), ApplicationError> {
+        let existing: Vec<String> = others.iter().map(|(_, p)| p.clone()).collect();
+
+        let position = FractionalIndexGenerator::generate_for_position(&existing, target_index)
+            .map_err(|err| ApplicationError::BadRequest {
+                message: format!("Failed to calculate new position: {}", err),
+            })?;
+
+        if !FractionalIndexGenerator::needs_rebalance(&position) {
+            return Ok((position, Vec::new()));
+        }
+
+        let mut ids: Vec<Uuid> = others.into_iter().map(|(id, _)| id).collect();
+        ids.insert(target_index.min(ids.len()), placed_id);
+
+        let mut placed_position = String::new();
+        let mut rebalanced_positions = Vec::with_capacity(ids.len() - 1);
+        let keys = FractionalIndexGenerator::sequence(existing.len() + 1).map_err(|err| {
+            ApplicationError::InternalError {
+                message: format!("Failed to rebalance checklist positions: {}", err),
+            }
+        })?;
+
+        for (id, position) in ids.into_iter().zip(keys) {
+            if id == placed_id {
+                placed_position = position;
+            } else {
+                rebalanced_positions.push(EntityPosition { id, position });
+            }
+        }
+
+        Ok((placed_position, rebalanced_positions))
+    }
+
+    fn to_pairs(positions: &[EntityPosition]) -> Vec<(Uuid, String)> {
+        positions
+            .iter()
+            .map(|entry| (entry.id, entry.position.clone()))
+            .collect()
+    }
+
+    async fn find_checklist(&self, checklist_id: Uuid) -> Result<Checklist, ApplicationError> {
+        self.checklist_repository
+            .find_by_id(checklist_id)
+            .await?
+            .ok_or_else(|| ApplicationError::NotFound {
+                message: "Checklist with the given ID not found".to_string(),
+            })
+    }
+
+    async fn find_item(&self, item_id: Uuid) -> Result<ChecklistItem, ApplicationError> {
+        self.checklist_repository
+            .find_item_by_id(item_id)
+            .await?
+            .ok_or_else(|| ApplicationError::NotFound {
+                message: "Checklist item with the given ID not found".to_string(),
+            })
+    }
+
+    async fn find_board_id(&self, task_id: Uuid) -> Result<Uuid, ApplicationError> {
+        let task = self
+            .task_repository
+            .find_by_id(task_id)
+            .await?
+            .ok_or_else(|| ApplicationError::NotFound {
+                message: "Task with the given ID not found".to_string(),
+            })?;
+
+        let column = self
+            .column_repository
+            .find_by_id(task.column_id)
+            .await?
+            .ok_or_else(|| ApplicationError::NotFound {
+                message: "Column with the given ID not found".to_string(),
+            })?;
+
+        Ok(column.board_id)
+    }
+
+    async fn ensure_member(&self, board_id: Uuid, user_id: Uuid) -> Result<(), ApplicationError> {
+        if !self
+            .board_member_repository
+            .check_permissions(
+                board_id,
+                user_id,
+                vec![
+                    BoardMemberRoleEnum::Owner,
+                    BoardMemberRoleEnum::Moderator,
+                    BoardMemberRoleEnum::Member,
+                ],
+            )
+            .await?
+        {
+            return Err(ApplicationError::Forbidden {
+                message: "You don't have access to this board".to_string(),
+            });
+        }
+
+        Ok(())
+    }
+}
@@ -2,35 +2,50 @@ use crate::{
     application::dto::{ColumnDto, CreateColumnDto, UpdateColumnDto},
     domain::{
         events::{
-            BoardEvent, ColumnCreatedEvent, ColumnDeletedEvent, ColumnMovedEvent,
-            ColumnUpdatedEvent, SharedEventBus,
+            BoardEvent, ColumnArchivedEvent, ColumnCreatedEvent, ColumnDeletedEvent,
+            ColumnMovedEvent, ColumnRestoredEvent, ColumnUpdatedEvent, EntityPosition,
+            PositionsRebalancedEvent,
         },
-        repositories::{BoardMemberRepository, Column, ColumnRepository},
+        repositories::{
+            AttachmentRepository, BoardMemberRepository, Column, ColumnRepository, OutboxMessage,
+            UnitOfWork, UnitOfWorkFactory,
+        },
+        services::StorageService,
+    },
+    shared::{
+        error::ApplicationError,
+        utils::{FractionalIndexGenerator, fractional_indexing::MAX_POSITION_LENGTH},
     },
-    shared::{error::ApplicationError, utils::FractionalIndexGenerator},
 };
 use chrono::Utc;
 use entity::BoardMemberRoleEnum;
 use std::sync::Arc;
+use tracing::warn;
 use uuid::Uuid;
 use validator::Validate;
 
 pub struct ColumnService {
     column_repository: Arc<dyn ColumnRepository>,
     board_member_repository: Arc<dyn BoardMemberRepository>,
-    event_bus: SharedEventBus,
+    attachment_repository: Arc<dyn AttachmentRepository>,
+    storage_service: Arc<dyn StorageService>,
+    unit_of_work_factory: Arc<dyn UnitOfWorkFactory>,
 }
 
 impl ColumnService {
     pub fn new(
         column_repository: Arc<dyn ColumnRepository>,
         board_member_repository: Arc<dyn BoardMemberRepository>,
-        event_bus: SharedEventBus,
+        attachment_repository: Arc<dyn AttachmentRepository>,
+        storage_service: Arc<dyn StorageService>,
+        unit_of_work_factory: Arc<dyn UnitOfWorkFactory>,
     ) -> Self {
         Self {
             column_repository,
             board_member_repository,
-            event_bus,
+            attachment_repository,
+            storage_service,
+            unit_of_work_factory,
         }
     }
 
@@ -57,7 +72,7 @@ impl ColumnService {
 
         let mut existing_columns = self
             .column_repository
-            .find_by_board_id(dto.board_id)
+            .find_by_board_id(dto.board_id, true)
             .await?;
 
         existing_columns.sort_by(|a, b| a.position.cmp(&b.position));
@@ -66,7 +81,7 @@ impl ColumnService {
             .map(|c| c.position.clone())
             .collect();
 
-        let position = if existing_positions.is_empty() {
+        let mut position = if existing_positions.is_empty() {
             FractionalIndexGenerator::first()
         } else {
             FractionalIndexGenerator::after(&existing_positions[existing_positions.len() - 1])
@@ -75,23 +90,64 @@ impl ColumnService {
                 })?
         };
 
-        let column = Column::new(Uuid::now_v7(), dto.name, position, dto.board_id);
+        let column_id = Uuid::now_v7();
+        let unit_of_work = self.unit_of_work_factory.begin().await?;
+
+        let rebalanced_positions = if FractionalIndexGenerator::needs_rebalance(&position) {
+            let (placed_position, positions) = Self::rebalance_board_columns(
+                unit_of_work.as_ref(),
+                dto.board_id,
+                column_id,
+                &position,
+            )
+            .await?;
+            position = placed_position;
+            Some(positions)
+        } else {
+            None
+        };
+
+        let mut column = Column::new(column_id, dto.name, position, dto.board_id);
+        column.wip_limit = dto.wip_limit.filter(|wip_limit| *wip_limit > 0);
+        column.is_done = dto.is_done.unwrap_or(false);
 
-        let saved_column = self.column_repository.create(column).await?;
+        let saved_column = unit_of_work.column_repository().create(column).await?;
 
-        self.event_bus
-            .publish(
+        unit_of_work
+            .outbox_repository()
+            .create(OutboxMessage::new(
+                Uuid::now_v7(),
                 dto.board_id,
                 BoardEvent::ColumnCreated(ColumnCreatedEvent {
                     column_id: saved_column.id,
                     name: saved_column.name.clone(),
                     position: saved_column.position.clone(),
                     board_id: saved_column.board_id,
+                    wip_limit: saved_column.wip_limit,
+                    is_done: saved_column.is_done,
                     created_by: user_id,
                     timestamp: saved_column.created_at,
                 }),
-            )
-            .await;
+            ))
+            .await?;
+
+        if let Some(positions) = rebalanced_positions {
+            unit_of_work
+                .outbox_repository()
+                .create(OutboxMessage::new(
+                    Uuid::now_v7(),
+                    dto.board_id,
+                    BoardEvent::PositionsRebalanced(PositionsRebalancedEvent {
+                        column_id: None,
+                        positions,
+                        rebalanced_by: user_id,
+                        timestamp: saved_column.created_at,
+                    }),
+                ))
+                .await?;
+        }
+
+        unit_of_work.commit().await?;
 
         Ok(ColumnDto::from_domain(saved_column))
     }
@@ -126,6 +182,7 @@ impl ColumnService {
     pub async fn get_board_columns(
         &self,
         board_id: Uuid,
+        include_archived: bool,
         user_id: Uuid,
     ) -> Result<Vec<ColumnDto>, ApplicationError> {
         if self
@@ -139,7 +196,10 @@ impl ColumnService {
             });
         }
 
-        let mut columns = self.column_repository.find_by_board_id(board_id).await?;
+        let mut columns = self
+            .column_repository
+            .find_by_board_id(board_id, include_archived)
+            .await?;
 
         columns.sort_by(|a, b| a.position.cmp(&b.position));
 
@@ -151,6 +211,7 @@ impl ColumnService {
         dto: UpdateColumnDto,
         column_id: Uuid,
         user_id: Uuid,
+        expected_version: Option<i32>,
     ) -> Result<ColumnDto, ApplicationError> {
         dto.validate()?;
 
@@ -176,24 +237,40 @@ impl ColumnService {
             });
         }
 
+        ApplicationError::ensure_version("The column", expected_version, column.version)?;
+
         if let Some(name) = dto.name {
             column.name = name;
         }
+        if let Some(wip_limit) = dto.wip_limit {
+            column.wip_limit = (wip_limit > 0).then_some(wip_limit);
+        }
+        if let Some(is_done) = dto.is_done {
+            column.is_done = is_done;
+        }
         column.updated_at = Utc::now().fixed_offset();
 
-        let updated_column = self.column_repository.update(column).await?;
+        let unit_of_work = self.unit_of_work_factory.begin().await?;
+
+        let updated_column = unit_of_work.column_repository().update(column).await?;
 
-        self.event_bus
-            .publish(
+        unit_of_work
+            .outbox_repository()
+            .create(OutboxMessage::new(
+                Uuid::now_v7(),
                 updated_column.board_id,
                 BoardEvent::ColumnUpdated(ColumnUpdatedEvent {
                     column_id,
                     name: Some(updated_column.name.clone()),
+                    wip_limit: updated_column.wip_limit,
+                    is_done: updated_column.is_done,
                     updated_by: user_id,
                     timestamp: updated_column.updated_at,
                 }),
-            )
-            .await;
+            ))
+            .await?;
+
+        unit_of_work.commit().await?;
 
         Ok(ColumnDto::from_domain(updated_column))
     }
@@ -203,6 +280,7 @@ impl ColumnService {
         target_position: usize,
         column_id: Uuid,
         user_id: Uuid,
+        expected_version: Option<i32>,
     ) -> Result<ColumnDto, ApplicationError> {
         let column = self
             .column_repository
@@ -226,9 +304,17 @@ impl ColumnService {
             });
         }
 
+        ApplicationError::ensure_version("The column", expected_version, column.version)?;
+
+        if column.archived_at.is_some() {
+            return Err(ApplicationError::BadRequest {
+                message: "Archived columns cannot be moved".to_string(),
+            });
+        }
+
         let mut all_columns = self
             .column_repository
-            .find_by_board_id(column.board_id)
+            .find_by_board_id(column.board_id, false)
             .await?;
 
         all_columns.sort_by(|a, b| a.position.cmp(&b.position));
@@ -259,20 +345,41 @@ impl ColumnService {
             .map(|c| c.position.clone())
             .collect();
 
-        let new_position =
+        let mut new_position =
             FractionalIndexGenerator::generate_for_position(&other_columns, target_position)
                 .map_err(|err| ApplicationError::BadRequest {
                     message: format!("Failed to calculate new position: {}", err),
                 })?;
 
+        let unit_of_work = self.unit_of_work_factory.begin().await?;
+
+        let rebalanced_positions = if FractionalIndexGenerator::needs_rebalance(&new_position) {
+            let (placed_position, positions) = Self::rebalance_board_columns(
+                unit_of_work.as_ref(),
+                column.board_id,
+                column_id,
+                &new_position,
+            )
+            .await?;
+            new_position = placed_position;
+            Some(positions)
+        } else {
+            None
+        };
+
         let mut updated_column = column.clone();
         updated_column.position = new_position;
         updated_column.updated_at = Utc::now().fixed_offset();
 
-        let saved_column = self.column_repository.update(updated_column).await?;
+        let saved_column = unit_of_work
+            .column_repository()
+            .update(updated_column)
+            .await?;
 
-        self.event_bus
-            .publish(
+        unit_of_work
+            .outbox_repository()
+            .create(OutboxMessage::new(
+                Uuid::now_v7(),
                 saved_column.board_id,
                 BoardEvent::ColumnMoved(ColumnMovedEvent {
                     column_id,
@@ -281,12 +388,108 @@ impl ColumnService {
                     moved_by: user_id,
                     timestamp: saved_column.updated_at,
                 }),
-            )
-            .await;
+            ))
+            .await?;
+
+        if let Some(positions) = rebalanced_positions {
+            unit_of_work
+                .outbox_repository()
+                .create(OutboxMessage::new(
+                    Uuid::now_v7(),
+                    saved_column.board_id,
+                    BoardEvent::PositionsRebalanced(PositionsRebalancedEvent {
+                        column_id: None,
+                        positions,
+                        rebalanced_by: user_id,
+                        timestamp: saved_column.updated_at,
+                    }),
+                ))
+                .await?;
+        }
+
+        unit_of_work.commit().await?;
 
         Ok(ColumnDto::from_domain(saved_column))
     }
 
+    pub async fn archive_column(
+        &self,
+        column_id: Uuid,
+        user_id: Uuid,
+    ) -> Result<ColumnDto, ApplicationError> {
+        let mut column = self.find_managed_column(column_id, user_id).await?;
+
+        if column.archived_at.is_some() {
+            return Err(ApplicationError::Conflict {
+                message: "Column is already archived".to_string(),
+            });
+        }
+
+        let now = Utc::now().fixed_offset();
+        column.archived_at = Some(now);
+        column.updated_at = now;
+
+        let unit_of_work = self.unit_of_work_factory.begin().await?;
+
+        let archived_column = unit_of_work.column_repository().update(column).await?;
+
+        unit_of_work
+            .outbox_repository()
+            .create(OutboxMessage::new(
+                Uuid::now_v7(),
+                archived_column.board_id,
+                BoardEvent::ColumnArchived(ColumnArchivedEvent {
+                    column_id,
+                    archived_by: user_id,
+                    timestamp: now,
+                }),
+            ))
+            .await?;
+
+        unit_of_work.commit().await?;
+
+        Ok(ColumnDto::from_domain(archived_column))
+    }
+
+    pub async fn unarchive_column(
+        &self,
+        column_id: Uuid,
+        user_id: Uuid,
+    ) -> Result<ColumnDto, ApplicationError> {
+        let mut column = self.find_managed_column(column_id, user_id).await?;
+
+        if column.archived_at.is_none() {
+            return Err(ApplicationError::Conflict {
+                message: "Column is not archived".to_string(),
+            });
+        }
+
+        let now = Utc::now().fixed_offset();
+        column.archived_at = None;
+        column.updated_at = now;
+
+        let unit_of_work = self.unit_of_work_factory.begin().await?;
+
+        let restored_column = unit_of_work.column_repository().update(column).await?;
+
+        unit_of_work
+            .outbox_repository()
+            .create(OutboxMessage::new(
+                Uuid::now_v7(),
+                restored_column.board_id,
+                BoardEvent::ColumnRestored(ColumnRestoredEvent {
+                    column_id,
+                    restored_by: user_id,
+                    timestamp: now,
+                }),
+            ))
+            .await?;
+
+        unit_of_work.commit().await?;
+
+        Ok(ColumnDto::from_domain(restored_column))
+    }
+
     pub async fn delete_column(
         &self,
         column_id: Uuid,
@@ -314,19 +517,134 @@ impl ColumnService {
             });
         }
 
-        let deleted_column = self.column_repository.delete(column_id).await?;
+        let storage_keys = self
+            .attachment_repository
+            .find_by_column_id(column_id)
+            .await?
+            .into_iter()
+            .map(|attachment| attachment.storage_key)
+            .collect();
+
+        let unit_of_work = self.unit_of_work_factory.begin().await?;
 
-        self.event_bus
-            .publish(
+        let deleted_column = unit_of_work.column_repository().delete(column_id).await?;
+
+        unit_of_work
+            .outbox_repository()
+            .create(OutboxMessage::new(
+                Uuid::now_v7(),
                 column.board_id,
                 BoardEvent::ColumnDeleted(ColumnDeletedEvent {
                     column_id,
                     deleted_by: user_id,
                     timestamp: Utc::now().fixed_offset(),
                 }),
-            )
-            .await;
+            ))
+            .await?;
+
+        unit_of_work.commit().await?;
+
+        self.remove_attachment_blobs(storage_keys).await;
 
         Ok(deleted_column)
     }
+
+    async fn find_managed_column(
+        &self,
+        column_id: Uuid,
+        user_id: Uuid,
+    ) -> Result<Column, ApplicationError> {
+        let column = self
+            .column_repository
+            .find_by_id(column_id)
+            .await?
+            .ok_or_else(|| ApplicationError::NotFound {
+                message: "Column with the given ID not found".to_string(),
+            })?;
+
+        if !self
+            .board_member_repository
+            .check_permissions(
+                column.board_id,
+                user_id,
+                vec![BoardMemberRoleEnum::Owner, BoardMemberRoleEnum::Moderator],
+            )
+            .await?
+        {
+            return Err(ApplicationError::Forbidden {
+                message: "You don't have permission to perform this action".to_string(),
+            });
+        }
+
+        Ok(column)
+    }
+
+    /// Gives every column of the board a fresh, short key once a generated key grows too long.
+    /// `placed_column_id` is the column being created or moved; it is ordered by `placed_position`
+    /// and its new key is returned for the caller to persist, all other columns are updated here.
+    async fn rebalance_board_columns(
+        unit_of_work: &dyn UnitOfWork,
+        board_id: Uuid,
+        placed_column_id: Uuid,
+        placed_position: &str,
+    ) -> Result<(String, Vec<EntityPosition>), ApplicationError> {
+        let mut ordered_columns: Vec<(Uuid, String)> = unit_of_work
+            .column_repository()
+            .find_by_board_id(board_id, true)
+            .await?
+            .into_iter()
+            .filter(|c| c.id != placed_column_id)
+            .map(|c| (c.id, c.position))
+            .collect();
+        ordered_columns.push((placed_column_id, placed_position.to_string()));
+        ordered_columns.sort_by(|a, b| a.1.cmp(&b.1));
+
+        let keys = FractionalIndexGenerator::sequence(ordered_columns.len()).map_err(|err| {
+            ApplicationError::InternalError {
+                message: format!("Failed to rebalance column positions: {}", err),
+            }
+        })?;
+        let positions: Vec<EntityPosition> = ordered_columns
+            .into_iter()
+            .zip(keys)
+            .map(|((id, _), position)| EntityPosition { id, position })
+            .collect();
+
+        if positions
+            .iter()
+            .any(|p| p.position.len() > MAX_POSITION_LENGTH)
+        {
+            return Err(ApplicationError::BadRequest {
+                message: "The board holds too many columns to rebalance their positions"
+                    .to_string(),
+            });
+        }
+
+        let mut placed_key = String::new();
+        let mut other_positions = Vec::with_capacity(positions.len());
+        for p in &positions {
+            if p.id == placed_column_id {
+                placed_key = p.position.clone();
+            } else {
+                other_positions.push((p.id, p.position.clone()));
+            }
+        }
+
+        unit_of_work
+            .column_repository()
+            .update_positions(other_positions)
+            .await?;
+
+        Ok((placed_key, positions))
+    }
+
+    async fn remove_attachment_blobs(&self, storage_keys: Vec<String>) {
+        if storage_keys.is_empty() {
+            return;
+        }
+
+        if let Err(err) = self.storage_service.delete_objects(&storage_keys).await {
+            warn!("Failed to remove attachments of deleted column: {}", err);
+        }
+    }
 }
@@ -0,0 +1,308 @@
+use crate::{
+    application::dto::{CommentDto, CommentRevisionDto, CreateCommentDto, UpdateCommentDto},
+    domain::{
+        events::{BoardEvent, CommentAddedEvent, CommentDeletedEvent, CommentEditedEvent},
+        repositories::{
+            BoardMemberRepository, ColumnRepository, Comment, CommentRepository, CommentRevision,
+            OutboxMessage, TaskRepository, UnitOfWorkFactory,
+        },
+    },
+    shared::error::ApplicationError,
+};
+use chrono::Utc;
+use entity::BoardMemberRoleEnum;
+use std::sync::Arc;
+use uuid::Uuid;
+use validator::Validate;
+
+pub struct CommentService {
+    comment_repository: Arc<dyn CommentRepository>,
+    task_repository: Arc<dyn TaskRepository>,
+    column_repository: Arc<dyn ColumnRepository>,
+    board_member_repository: Arc<dyn BoardMemberRepository>,
+    unit_of_work_factory: Arc<dyn UnitOfWorkFactory>,
+}
+
+impl CommentService {
+    pub fn new(
+        comment_repository: Arc<dyn CommentRepository>,
+        task_repository: Arc<dyn TaskRepository>,
+        column_repository: Arc<dyn ColumnRepository>,
+        board_member_repository: Arc<dyn BoardMemberRepository>,
+        unit_of_work_factory: Arc<dyn UnitOfWorkFactory>,
+    ) -> Self {
+        Self {
+            comment_repository,
+            task_repository,
+            column_repository,
+            board_member_repository,
+            unit_of_work_factory,
+        }
+    }
+
+    pub async fn create_comment(
+        &self,
+        dto: CreateCommentDto,
+        user_id: Uuid,
+    ) -> Result<CommentDto, ApplicationError> {
+        dto.validate()?;
+
+        let board_id = self.find_board_id(dto.task_id).await?;
+
+        self.ensure_member(board_id, user_id).await?;
+
+        if let Some(parent_id) = dto.parent_id {
+            let parent = self
+                .comment_repository
+                .find_by_id(parent_id)
+                .await?
+                .ok_or_else(|| ApplicationError::NotFound {
+                    message: "Parent comment with the given ID not found".to_string(),
+                })?;
+
+            if parent.task_id != dto.task_id {
+                return Err(ApplicationError::BadRequest {
+                    message: "Parent comment belongs to a different task".to_string(),
+                });
+            }
+        }
+
+        let comment = Comment::new(
+            Uuid::now_v7(),
+            dto.task_id,
+            dto.parent_id,
+            user_id,
+            dto.content,
+        );
+
+        let unit_of_work = self.unit_of_work_factory.begin().await?;
+
+        let saved_comment = unit_of_work.comment_repository().create(comment).await?;
+
+        unit_of_work
+            .outbox_repository()
+            .create(OutboxMessage::new(
+                Uuid::now_v7(),
+                board_id,
+                BoardEvent::CommentAdded(CommentAddedEvent {
+                    comment_id: saved_comment.id,
+                    task_id: saved_comment.task_id,
+                    parent_id: saved_comment.parent_id,
+                    author_id: saved_comment.author_id,
+                    content: saved_comment.content.clone(),
+                    timestamp: saved_comment.created_at,
+                }),
+            ))
+            .await?;
+
+        unit_of_work.commit().await?;
+
+        Ok(CommentDto::from_domain(saved_comment))
+    }
+
+    pub async fn get_comment_by_id(
+        &self,
+        comment_id: Uuid,
+        user_id: Uuid,
+    ) -> Result<CommentDto, ApplicationError> {
+        let comment = self.find_comment(comment_id).await?;
+        let board_id = self.find_board_id(comment.task_id).await?;
+
+        self.ensure_member(board_id, user_id).await?;
+
+        Ok(CommentDto::from_domain(comment))
+    }
+
+    pub async fn get_task_comments(
+        &self,
+        task_id: Uuid,
+        user_id: Uuid,
+    ) -> Result<Vec<CommentDto>, ApplicationError> {
+        let board_id = self.find_board_id(task_id).await?;
+
+        self.ensure_member(board_id, user_id).await?;
+
+        let comments = self.comment_repository.find_by_task_id(task_id).await?;
+
+        Ok(comments.into_iter().map(CommentDto::from_domain).collect())
+    }
+
+    pub async fn get_comment_history(
+        &self,
+        comment_id: Uuid,
+        user_id: Uuid,
+    ) -> Result<Vec<CommentRevisionDto>, ApplicationError> {
+        let comment = self.find_comment(comment_id).await?;
+        let board_id = self.find_board_id(comment.task_id).await?;
+
+        self.ensure_member(board_id, user_id).await?;
+
+        let revisions = self.comment_repository.find_revisions(comment_id).await?;
+
+        Ok(revisions
+            .into_iter()
+            .map(CommentRevisionDto::from_domain)
+            .collect())
+    }
+
+    pub async fn update_comment(
+        &self,
+        dto: UpdateCommentDto,
+        comment_id: Uuid,
+        user_id: Uuid,
+    ) -> Result<CommentDto, ApplicationError> {
+        dto.validate()?;
+
+        let mut comment = self.find_comment(comment_id).await?;
+        let board_id = self.find_board_id(comment.task_id).await?;
+
+        self.ensure_author_or_moderator(&comment, board_id, user_id)
+            .await?;
+
+        let revision =
+            CommentRevision::new(Uuid::now_v7(), comment.id, comment.content.clone(), user_id);
+
+        comment.content = dto.content;
+        comment.updated_at = Utc::now().fixed_offset();
+
+        let unit_of_work = self.unit_of_work_factory.begin().await?;
+
+        let updated_comment = unit_of_work
+            .comment_repository()
+            .update(comment, revision)
+            .await?;
+
+        unit_of_work
+            .outbox_repository()
+            .create(OutboxMessage::new(
+                Uuid::now_v7(),
+                board_id,
+                BoardEvent::CommentEdited(CommentEditedEvent {
+                    comment_id,
+                    task_id: updated_comment.task_id,
+                    content: updated_comment.content.clone(),
+                    edited_by: user_id,
+                    timestamp: updated_comment.updated_at,
+                }),
+            ))
+            .await?;
+
+        unit_of_work.commit().await?;
+
+        Ok(CommentDto::from_domain(updated_comment))
+    }
+
+    pub async fn delete_comment(
+        &self,
+        comment_id: Uuid,
+        user_id: Uuid,
+    ) -> Result<u64, ApplicationError> {
+        let comment = self.find_comment(comment_id).await?;
+        let board_id = self.find_board_id(comment.task_id).await?;
+
+        self.ensure_author_or_moderator(&comment, board_id, user_id)
+            .await?;
+
+        let unit_of_work = self.unit_of_work_factory.begin().await?;
+
+        let deleted_comment = unit_of_work.comment_repository().delete(comment_id).await?;
+
+        unit_of_work
+            .outbox_repository()
+            .create(OutboxMessage::new(
+                Uuid::now_v7(),
+                board_id,
+                BoardEvent::CommentDeleted(CommentDeletedEvent {
+                    comment_id,
+                    task_id: comment.task_id,
+                    deleted_by: user_id,
+                    timestamp: Utc::now().fixed_offset(),
+                }),
+            ))
+            .await?;
+
+        unit_of_work.commit().await?;
+
+        Ok(deleted_comment)
+    }
+
+    async fn find_comment(&self, comment_id: Uuid) -> Result<Comment, ApplicationError> {
+        self.comment_repository
+            .find_by_id(comment_id)
+            .await?
+            .ok_or_else(|| ApplicationError::NotFound {
+                message: "Comment with the given ID not found".to_string(),
+            })
+    }
+
+    async fn find_board_id(&self, task_id: Uuid) -> Result<Uuid, ApplicationError> {
+        let task = self
+            .task_repository
+            .find_by_id(task_id)
+            .await?
+            .ok_or_else(|| ApplicationError::NotFound {
+                message: "Task with the given ID not found".to_string(),
+            })?;
+
+        let column = self
+            .column_repository
+            .find_by_id(task.column_id)
+            .await?
+            .ok_or_else(|| ApplicationError::NotFound {
+                message: "Column with the given ID not found".to_string(),
+            })?;
+
+        Ok(column.board_id)
+    }
+
+    async fn ensure_member(&self, board_id: Uuid, user_id: Uuid) -> Result<(), ApplicationError> {
+        if !self
+            .board_member_repository
+            .check_permissions(
+                board_id,
+                user_id,
+                vec![
+                    BoardMemberRoleEnum::Owner,
+                    BoardMemberRoleEnum::Moderator,
+                    BoardMemberRoleEnum::Member,
+                ],
+            )
+            .await?
+        {
+            return Err(ApplicationError::Forbidden {
+                message: "You don't have access to this board".to_string(),
+            });
+        }
+
+        Ok(())
+    }
+
+    async fn ensure_author_or_moderator(
+        &self,
+        comment: &Comment,
+        board_id: Uuid,
+        user_id: Uuid,
+    ) -> Result<(), ApplicationError> {
+        let allowed_roles = if comment.author_id == user_id {
+            vec![
+                BoardMemberRoleEnum::Owner,
+                BoardMemberRoleEnum::Moderator,
+                BoardMemberRoleEnum::Member,
+            ]
+        } else {
+            vec![BoardMemberRoleEnum::Owner, BoardMemberRoleEnum::Moderator]
+        };
+
+        if !self
+            .board_member_repository
+            .check_permissions(board_id, user_id, allowed_roles)
+            .await?
+        {
+            return Err(ApplicationError::Forbidden {
+                message: "You don't have permission to perform this action".to_string(),
+            });
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,330 @@
+use crate::{
+    application::dto::{CreateCustomFieldDto, CustomFieldDto, UpdateCustomFieldDto},
+    domain::{
+        events::{
+            BoardEvent, CustomFieldCreatedEvent, CustomFieldDeletedEvent, CustomFieldUpdatedEvent,
+        },
+        repositories::{
+            BoardMemberRepository, CustomField, CustomFieldCondition, CustomFieldFilter,
+            CustomFieldRepository, OutboxMessage, TaskFilter, UnitOfWork, UnitOfWorkFactory,
+        },
+    },
+    shared::error::ApplicationError,
+};
+use chrono::Utc;
+use entity::{BoardMemberRoleEnum, CustomFieldTypeEnum};
+use serde_json::Value;
+use std::sync::Arc;
+use uuid::Uuid;
+use validator::Validate;
+
+pub struct CustomFieldService {
+    custom_field_repository: Arc<dyn CustomFieldRepository>,
+    board_member_repository: Arc<dyn BoardMemberRepository>,
+    unit_of_work_factory: Arc<dyn UnitOfWorkFactory>,
+}
+
+impl CustomFieldService {
+    pub fn new(
+        custom_field_repository: Arc<dyn CustomFieldRepository>,
+        board_member_repository: Arc<dyn BoardMemberRepository>,
+        unit_of_work_factory: Arc<dyn UnitOfWorkFactory>,
+    ) -> Self {
+        Self {
+            custom_field_repository,
+            board_member_repository,
+            unit_of_work_factory,
+        }
+    }
+
+    pub async fn create_custom_field(
+        &self,
+        dto: CreateCustomFieldDto,
+        user_id: Uuid,
+    ) -> Result<CustomFieldDto, ApplicationError> {
+        dto.validate()?;
+
+        self.ensure_owner(dto.board_id, user_id).await?;
+
+        let name = Self::normalize_name(&dto.name)?;
+        let mut field = CustomField::new(
+            Uuid::now_v7(),
+            dto.board_id,
+            name,
+            dto.field_type,
+            Self::normalize_options(dto.options.unwrap_or_default()),
+        );
+        Self::validate_options(&field)?;
+
+        let unit_of_work = self.unit_of_work_factory.begin().await?;
+
+        Self::ensure_unique_name(unit_of_work.as_ref(), field.board_id, &field.name, None).await?;
+
+        field = unit_of_work.custom_field_repository().create(field).await?;
+
+        unit_of_work
+            .outbox_repository()
+            .create(OutboxMessage::new(
+                Uuid::now_v7(),
+                field.board_id,
+                BoardEvent::CustomFieldCreated(CustomFieldCreatedEvent {
+                    field_id: field.id,
+                    name: field.name.clone(),
+                    field_type: field.field_type,
+                    options: field.options.clone(),
+                    created_by: user_id,
+                    timestamp: field.created_at,
+                }),
+            ))
+            .await?;
+
+        unit_of_work.commit().await?;
+
+        Ok(CustomFieldDto::from_domain(field))
+    }
+
+    pub async fn get_board_custom_fields(
+        &self,
+        board_id: Uuid,
+        user_id: Uuid,
+    ) -> Result<Vec<CustomFieldDto>, ApplicationError> {
+        if self
+            .board_member_repository
+            .find_by_board_and_user_id(board_id, user_id)
+            .await?
+            .is_none()
+        {
+            return Err(ApplicationError::Forbidden {
+                message: "You don't have access to this board".to_string(),
+            });
+        }
+
+        let fields = self
+            .custom_field_repository
+            .find_by_board_id(board_id)
+            .await?;
+
+        Ok(fields
+            .into_iter()
+            .map(CustomFieldDto::from_domain)
+            .collect())
+    }
+
+    pub async fn update_custom_field(
+        &self,
+        field_id: Uuid,
+        dto: UpdateCustomFieldDto,
+        user_id: Uuid,
+    ) -> Result<CustomFieldDto, ApplicationError> {
+        dto.validate()?;
+
+        let mut field = self.find_custom_field(field_id).await?;
+
+        self.ensure_owner(field.board_id, user_id).await?;
+
+        let unit_of_work = self.unit_of_work_factory.begin().await?;
+
+        if let Some(name) = dto.name {
+            let name = Self::normalize_name(&name)?;
+            Self::ensure_unique_name(unit_of_work.as_ref(), field.board_id, &name, Some(field_id))
+                .await?;
+            field.name = name;
+        }
+
+        if let Some(options) = dto.options {
+            let options = Self::normalize_options(options);
+
+            for removed in field
+                .options
+                .iter()
+                .filter(|option| !options.contains(option))
+            {
+                Self::ensure_option_unused(unit_of_work.as_ref(), &field, removed).await?;
+            }
+
+            field.options = options;
+            Self::validate_options(&field)?;
+        }
+
+        field.updated_at = Utc::now().fixed_offset();
+
+        let updated_field = unit_of_work.custom_field_repository().update(field).await?;
+
+        unit_of_work
+            .outbox_repository()
+            .create(OutboxMessage::new(
+                Uuid::now_v7(),
+                updated_field.board_id,
+                BoardEvent::CustomFieldUpdated(CustomFieldUpdatedEvent {
+                    field_id,
+                    name: updated_field.name.clone(),
+                    options: updated_field.options.clone(),
+                    updated_by: user_id,
+                    timestamp: updated_field.updated_at,
+                }),
+            ))
+            .await?;
+
+        unit_of_work.commit().await?;
+
+        Ok(CustomFieldDto::from_domain(updated_field))
+    }
+
+    pub async fn delete_custom_field(
+        &self,
+        field_id: Uuid,
+        user_id: Uuid,
+    ) -> Result<u64, ApplicationError> {
+        let field = self.find_custom_field(field_id).await?;
+
+        self.ensure_owner(field.board_id, user_id).await?;
+
+        let unit_of_work = self.unit_of_work_factory.begin().await?;
+
+        // Read before the delete, which removes the field's values from its tasks
+        let task_ids = unit_of_work
+            .custom_field_repository()
+            .find_task_ids(field_id)
+            .await?;
+
+        let deleted_field = unit_of_work
+            .custom_field_repository()
+            .delete(field_id)
+            .await?;
+
+        unit_of_work
+            .outbox_repository()
+            .create(OutboxMessage::new(
+                Uuid::now_v7(),
+                field.board_id,
+                BoardEvent::CustomFieldDeleted(CustomFieldDeletedEvent {
+                    field_id,
+                    task_ids,
+                    deleted_by: user_id,
+                    timestamp: Utc::now().fixed_offset(),
+                }),
+            ))
+            .await?;
+
+        unit_of_work.commit().await?;
+
+        Ok(deleted_field)
+    }
+
+    async fn find_custom_field(&self, field_id: Uuid) -> Result<CustomField, ApplicationError> {
+        self.custom_field_repository
+            .find_by_id(field_id)
+            .await?
+            .ok_or_else(|| ApplicationError::NotFound {
+                message: "Custom field with the given ID not found".to_string(),
+            })
+    }
+
+    fn normalize_name(name: &str) -> Result<String, ApplicationError> {
+        let name = name.trim();
+        if name.is_empty() {
+            return Err(ApplicationError::BadRequest {
+                message: "Custom field name cannot be blank".to_string(),
+            });
+        }
+
+        Ok(name.to_string())
+    }
+
+    /// Trims the options and drops repeated ones, keeping their order
+    fn normalize_options(options: Vec<String>) -> Vec<String> {
+        let mut normalized: Vec<String> = Vec::with_capacity(options.len());
+        for option in options {
+            let option = option.trim();
+            if !normalized.iter().any(|existing| existing == option) {
+                normalized.push(option.to_string());
+            }
+        }
+
+        normalized
+    }
+
+    fn validate_options(field: &CustomField) -> Result<(), ApplicationError> {
+        match (field.has_options(), field.options.is_empty()) {
+            (true, true) => Err(ApplicationError::BadRequest {
+                message: "Select fields need at least one option".to_string(),
+            }),
+            (false, false) => Err(ApplicationError::BadRequest {
+                message: "Only select fields can have options".to_string(),
+            }),
+            _ => Ok(()),
+        }
+    }
+
+    /// Field names are unique per board regardless of case
+    async fn ensure_unique_name(
+        unit_of_work: &dyn UnitOfWork,
+        board_id: Uuid,
+        name: &str,
+        field_id: Option<Uuid>,
+    ) -> Result<(), ApplicationError> {
+        let existing = unit_of_work
+            .custom_field_repository()
+            .find_by_board_and_name(board_id, name)
+            .await?;
+
+        match existing {
+            Some(existing) if Some(existing.id) != field_id => Err(ApplicationError::Conflict {
+                message: format!(
+                    "A custom field named '{}' already exists on this board",
+                    existing.name
+                ),
+            }),
+            _ => Ok(()),
+        }
+    }
+
+    async fn ensure_option_unused(
+        unit_of_work: &dyn UnitOfWork,
+        field: &CustomField,
+        option: &str,
+    ) -> Result<(), ApplicationError> {
+        let value = match field.field_type {
+            CustomFieldTypeEnum::MultiSelect => Value::Array(vec![Value::from(option)]),
+            _ => Value::from(option),
+        };
+
+        let holders = unit_of_work
+            .task_repository()
+            .search(
+                field.board_id,
+                TaskFilter {
+                    custom_fields: vec![CustomFieldFilter {
+                        field_id: field.id,
+                        condition: CustomFieldCondition::Contains(value),
+                    }],
+                    include_archived: true,
+                    limit: 1,
+                    ..Default::default()
+                },
+            )
+            .await?;
+
+        if !holders.is_empty() {
+            return Err(ApplicationError::Conflict {
+                message: format!("Option '{}' is still selected on some tasks", option),
+            });
+        }
+
+        Ok(())
+    }
+
+    async fn ensure_owner(&self, board_id: Uuid, user_id: Uuid) -> Result<(), ApplicationError> {
+        if !self
+            .board_member_repository
+            .check_permissions(board_id, user_id, vec![BoardMemberRoleEnum::Owner])
+            .await?
+        {
+            return Err(ApplicationError::Forbidden {
+                message: "You don't have permission to perform this action".to_string(),
+            });
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,241 @@
+use crate::{
+    application::dto::{CreateLabelDto, LabelDto, UpdateLabelDto},
+    domain::{
+        events::{BoardEvent, LabelCreatedEvent, LabelDeletedEvent, LabelUpdatedEvent},
+        repositories::{
+            BoardMemberRepository, Label, LabelRepository, OutboxMessage, UnitOfWork,
+            UnitOfWorkFactory,
+        },
+    },
+    shared::error::ApplicationError,
+};
+use chrono::Utc;
+use std::sync::Arc;
+use uuid::Uuid;
+use validator::Validate;
+
+const DEFAULT_LABEL_COLOR: &str = "#94a3b8";
+
+pub struct LabelService {
+    label_repository: Arc<dyn LabelRepository>,
+    board_member_repository: Arc<dyn BoardMemberRepository>,
+    unit_of_work_factory: Arc<dyn UnitOfWorkFactory>,
+}
+
+impl LabelService {
+    pub fn new(
+        label_repository: Arc<dyn LabelRepository>,
+        board_member_repository: Arc<dyn BoardMemberRepository>,
+        unit_of_work_factory: Arc<dyn UnitOfWorkFactory>,
+    ) -> Self {
+        Self {
+            label_repository,
+            board_member_repository,
+            unit_of_work_factory,
+        }
+    }
+
+    pub async fn create_label(
+        &self,
+        dto: CreateLabelDto,
+        user_id: Uuid,
+    ) -> Result<LabelDto, ApplicationError> {
+        dto.validate()?;
+
+        self.ensure_member(dto.board_id, user_id).await?;
+
+        let name = Self::normalize_name(&dto.name)?;
+
+        let unit_of_work = self.unit_of_work_factory.begin().await?;
+
+        Self::ensure_unique_name(unit_of_work.as_ref(), dto.board_id, &name, None).await?;
+
+        let label = unit_of_work
+            .label_repository()
+            .create(Label::new(
+                Uuid::now_v7(),
+                dto.board_id,
+                name,
+                dto.color
+                    .unwrap_or_else(|| DEFAULT_LABEL_COLOR.to_string())
+                    .to_lowercase(),
+            ))
+            .await?;
+
+        unit_of_work
+            .outbox_repository()
+            .create(OutboxMessage::new(
+                Uuid::now_v7(),
+                label.board_id,
+                BoardEvent::LabelCreated(LabelCreatedEvent {
+                    label_id: label.id,
+                    name: label.name.clone(),
+                    color: label.color.clone(),
+                    created_by: user_id,
+                    timestamp: label.created_at,
+                }),
+            ))
+            .await?;
+
+        unit_of_work.commit().await?;
+
+        Ok(LabelDto::from_domain(label))
+    }
+
+    pub async fn get_board_labels(
+        &self,
+        board_id: Uuid,
+        user_id: Uuid,
+    ) -> Result<Vec<LabelDto>, ApplicationError> {
+        self.ensure_member(board_id, user_id).await?;
+
+        let labels = self.label_repository.find_by_board_id(board_id).await?;
+
+        Ok(labels.into_iter().map(LabelDto::from_domain).collect())
+    }
+
+    pub async fn update_label(
+        &self,
+        label_id: Uuid,
+        dto: UpdateLabelDto,
+        user_id: Uuid,
+    ) -> Result<LabelDto, ApplicationError> {
+        dto.validate()?;
+
+        let mut label = self.find_label(label_id).await?;
+
+        self.ensure_member(label.board_id, user_id).await?;
+
+        let unit_of_work = self.unit_of_work_factory.begin().await?;
+
+        if let Some(name) = dto.name {
+            let name = Self::normalize_name(&name)?;
+            Self::ensure_unique_name(unit_of_work.as_ref(), label.board_id, &name, Some(label_id))
+                .await?;
+            label.name = name;
+        }
+
+        if let Some(color) = dto.color {
+            label.color = color.to_lowercase();
+        }
+
+        label.updated_at = Utc::now().fixed_offset();
+
+        let updated_label = unit_of_work.label_repository().update(label).await?;
+
+        unit_of_work
+            .outbox_repository()
+            .create(OutboxMessage::new(
+                Uuid::now_v7(),
+                updated_label.board_id,
+                BoardEvent::LabelUpdated(LabelUpdatedEvent {
+                    label_id,
+                    name: updated_label.name.clone(),
+                    color: updated_label.color.clone(),
+                    updated_by: user_id,
+                    timestamp: updated_label.updated_at,
+                }),
+            ))
+            .await?;
+
+        unit_of_work.commit().await?;
+
+        Ok(LabelDto::from_domain(updated_label))
+    }
+
+    pub async fn delete_label(
+        &self,
+        label_id: Uuid,
+        user_id: Uuid,
+    ) -> Result<u64, ApplicationError> {
+        let label = self.find_label(label_id).await?;
+
+        self.ensure_member(label.board_id, user_id).await?;
+
+        let unit_of_work = self.unit_of_work_factory.begin().await?;
+
+        // Read before the delete, which removes the label from its tasks
+        let task_ids = unit_of_work
+            .label_repository()
+            .find_task_ids(label_id)
+            .await?;
+
+        let deleted_label = unit_of_work.label_repository().delete(label_id).await?;
+
+        unit_of_work
+            .outbox_repository()
+            .create(OutboxMessage::new(
+                Uuid::now_v7(),
+                label.board_id,
+                BoardEvent::LabelDeleted(LabelDeletedEvent {
+                    label_id,
+                    task_ids,
+                    deleted_by: user_id,
+                    timestamp: Utc::now().fixed_offset(),
+                }),
+            ))
+            .await?;
+
+        unit_of_work.commit().await?;
+
+        Ok(deleted_label)
+    }
+
+    async fn find_label(&self, label_id: Uuid) -> Result<Label, ApplicationError> {
+        self.label_repository
+            .find_by_id(label_id)
+            .await?
+            .ok_or_else(|| ApplicationError::NotFound {
+                message: "Label with the given ID not found".to_string(),
+            })
+    }
+
+    fn normalize_name(name: &str) -> Result<String, ApplicationError> {
+        let name = name.trim();
+        if name.is_empty() {
+            return Err(ApplicationError::BadRequest {
+                message: "Label name cannot be blank".to_string(),
+            });
+        }
+
+        Ok(name.to_string())
+    }
+
+    /// Label names are unique per board regardless of case
+    async fn ensure_unique_name(
+        unit_of_work: &dyn UnitOfWork,
+        board_id: Uuid,
+        name: &str,
+        label_id: Option<Uuid>,
+    ) -> Result<(), ApplicationError> {
+        let existing = unit_of_work
+            .label_repository()
+            .find_by_board_and_name(board_id, name)
+            .await?;
+
+        match existing {
+            Some(existing) if Some(existing.id) != label_id => Err(ApplicationError::Conflict {
+                message: format!(
+                    "A label named '{}' already exists on this board",
+                    existing.name
+                ),
+            }),
+            _ => Ok(()),
+        }
+    }
+
+    async fn ensure_member(&self, board_id: Uuid, user_id: Uuid) -> Result<(), ApplicationError> {
+        if self
+            .board_member_repository
+            .find_by_board_and_user_id(board_id, user_id)
+            .await?
+            .is_none()
+        {
+            return Err(ApplicationError::Forbidden {
+                message: "You don't have access to this board".to_string(),
+            });
+        }
+
+        Ok(())
+    }
+}
@@ -1,13 +1,31 @@
+pub mod attachment_service;
 pub mod auth_service;
+pub mod board_activity_service;
+pub mod board_analytics_service;
 pub mod board_service;
+pub mod board_sync_service;
+pub mod checklist_service;
 pub mod column_service;
+pub mod comment_service;
+pub mod custom_field_service;
+pub mod label_service;
 pub mod task_service;
+pub mod time_entry_service;
 pub mod user_service;
 pub mod websocket_service;
 
+pub use attachment_service::AttachmentService;
 pub use auth_service::AuthService;
+pub use board_activity_service::BoardActivityService;
+pub use board_analytics_service::BoardAnalyticsService;
 pub use board_service::BoardService;
+pub use board_sync_service::BoardSyncService;
+pub use checklist_service::ChecklistService;
 pub use column_service::ColumnService;
+pub use comment_service::CommentService;
+pub use custom_field_service::CustomFieldService;
+pub use label_service::LabelService;
 pub use task_service::TaskService;
+pub use time_entry_service::TimeEntryService;
 pub use user_service::UserService;
 pub use websocket_service::WebSocketService;
@@ -1,38 +1,81 @@
 use crate::{
-    application::dto::{CreateTaskDto, TaskDto, UpdateTaskDto},
+    application::dto::{
+        BulkTaskDto, BulkTaskOperationDto, BulkTaskResultDto, CreateTaskDto, CreateTaskRelationDto,
+        CustomFieldOperatorDto, DuplicateTaskDto, TaskDto, TaskRelationDto, TaskSearchDto,
+        TaskSearchPageDto, UpdateTaskDto,
+    },
     domain::{
         events::{
-            BoardEvent, SharedEventBus, TaskCreatedEvent, TaskDeletedEvent, TaskMovedEvent,
-            TaskUpdatedEvent,
+            BoardEvent, BulkTaskOperation, EntityPosition, LabelCreatedEvent,
+            PositionsRebalancedEvent, TaskArchivedEvent, TaskAssignedEvent, TaskCompletedEvent,
+            TaskCreatedEvent, TaskDeletedEvent, TaskMovedEvent, TaskRelationCreatedEvent,
+            TaskRelationDeletedEvent, TaskRemovedEvent, TaskReopenedEvent, TaskRestoredEvent,
+            TaskUnassignedEvent, TaskUpdatedEvent, TasksBulkUpdatedEvent, WipLimitExceededEvent,
+        },
+        repositories::{
+            Attachment, AttachmentRepository, BoardMemberRepository, Checklist, ChecklistItem,
+            Column, ColumnRepository, Comment, CustomField, CustomFieldCondition,
+            CustomFieldFilter, CustomFieldRepository, Label, LabelRepository, OutboxMessage, Task,
+            TaskFilter, TaskRelation, TaskRepository, UnitOfWork, UnitOfWorkFactory,
+        },
+        services::StorageService,
+    },
+    shared::{
+        error::ApplicationError,
+        utils::{
+            FractionalIndexGenerator, constants::ATTACHMENT_BOARD_QUOTA,
+            fractional_indexing::MAX_POSITION_LENGTH,
         },
-        repositories::{BoardMemberRepository, ColumnRepository, Task, TaskRepository},
     },
-    shared::{error::ApplicationError, utils::FractionalIndexGenerator},
 };
-use chrono::Utc;
-use std::sync::Arc;
+use bytes::BytesMut;
+use chrono::{DateTime, FixedOffset, NaiveDate, Utc};
+use entity::{BoardMemberRoleEnum, CustomFieldTypeEnum, TaskRelationTypeEnum};
+use futures_util::TryStreamExt;
+use serde_json::Value;
+use std::{
+    collections::{HashMap, HashSet, VecDeque},
+    sync::Arc,
+};
+use tracing::warn;
 use uuid::Uuid;
 use validator::Validate;
 
+const DEFAULT_SEARCH_PAGE_SIZE: u64 = 50;
+const MAX_CUSTOM_FIELD_TEXT_LENGTH: usize = 1000;
+
 pub struct TaskService {
     task_repository: Arc<dyn TaskRepository>,
     column_repository: Arc<dyn ColumnRepository>,
     board_member_repository: Arc<dyn BoardMemberRepository>,
-    event_bus: SharedEventBus,
+    attachment_repository: Arc<dyn AttachmentRepository>,
+    label_repository: Arc<dyn LabelRepository>,
+    custom_field_repository: Arc<dyn CustomFieldRepository>,
+    storage_service: Arc<dyn StorageService>,
+    unit_of_work_factory: Arc<dyn UnitOfWorkFactory>,
 }
 
 impl TaskService {
+    #[allow(clippy::too_many_arguments)]
     pub fn new(
         task_repository: Arc<dyn TaskRepository>,
         column_repository: Arc<dyn ColumnRepository>,
         board_member_repository: Arc<dyn BoardMemberRepository>,
-        event_bus: SharedEventBus,
+        attachment_repository: Arc<dyn AttachmentRepository>,
+        label_repository: Arc<dyn LabelRepository>,
+        custom_field_repository: Arc<dyn CustomFieldRepository>,
+        storage_service: Arc<dyn StorageService>,
+        unit_of_work_factory: Arc<dyn UnitOfWorkFactory>,
     ) -> Self {
         Self {
             task_repository,
             column_repository,
             board_member_repository,
-            event_bus,
+            attachment_repository,
+            label_repository,
+            custom_field_repository,
+            storage_service,
+            unit_of_work_factory,
         }
     }
 
@@ -42,10 +85,15 @@ impl TaskService {
         user_id: Uuid,
     ) -> Result<TaskDto, ApplicationError> {
         dto.validate()?;
+        Self::validate_schedule(dto.start_at, dto.due_at)?;
 
-        let column = self
-            .column_repository
-            .find_by_id(dto.column_id)
+        // Locking the column serializes concurrent inserts, so the new position is generated
+        // from the column's current tasks
+        let unit_of_work = self.unit_of_work_factory.begin().await?;
+
+        let column = unit_of_work
+            .column_repository()
+            .find_by_id_for_update(dto.column_id)
             .await?
             .ok_or_else(|| ApplicationError::NotFound {
                 message: "Column with the given ID not found".to_string(),
@@ -62,16 +110,51 @@ impl TaskService {
             });
         }
 
-        let mut existing_tasks = self
-            .task_repository
-            .find_by_column_id(dto.column_id)
+        if column.archived_at.is_some() {
+            return Err(ApplicationError::BadRequest {
+                message: "Cannot add tasks to an archived column".to_string(),
+            });
+        }
+
+        let assignee_ids = self
+            .validate_assignees(column.board_id, dto.assignee_ids.unwrap_or_default())
+            .await?;
+        let label_ids = self
+            .validate_labels(column.board_id, dto.label_ids.unwrap_or_default())
+            .await?;
+        let mut custom_fields = HashMap::new();
+        self.validate_custom_fields(
+            column.board_id,
+            dto.custom_fields.unwrap_or_default(),
+            &mut custom_fields,
+        )
+        .await?;
+
+        let mut existing_tasks = unit_of_work
+            .task_repository()
+            .find_by_column_id(dto.column_id, true)
+            .await?;
+
+        let task_id = Uuid::now_v7();
+        let active_task_count = existing_tasks
+            .iter()
+            .filter(|t| t.archived_at.is_none())
+            .count();
+        let wip_limit_event = self
+            .check_wip_limit(
+                &column,
+                active_task_count,
+                &[task_id],
+                dto.override_wip_limit.unwrap_or(false),
+                user_id,
+            )
             .await?;
 
         existing_tasks.sort_by(|a, b| a.position.cmp(&b.position));
         let existing_positions: Vec<String> =
             existing_tasks.iter().map(|t| t.position.clone()).collect();
 
-        let position = if existing_positions.is_empty() {
+        let mut position = if existing_positions.is_empty() {
             FractionalIndexGenerator::first()
         } else {
             FractionalIndexGenerator::after(&existing_positions[existing_positions.len() - 1])
@@ -80,32 +163,78 @@ impl TaskService {
                 })?
         };
 
-        let task = Task::new(
-            Uuid::now_v7(),
+        let rebalanced_positions = if FractionalIndexGenerator::needs_rebalance(&position) {
+            let mut placed = [(task_id, position)];
+            let positions =
+                Self::rebalance_column_tasks(unit_of_work.as_ref(), dto.column_id, &mut placed)
+                    .await?;
+            let [(_, placed_position)] = placed;
+            position = placed_position;
+            Some(positions)
+        } else {
+            None
+        };
+
+        let mut task = Task::new(
+            task_id,
             dto.title,
             dto.description,
-            dto.tags,
+            label_ids,
             position,
             dto.column_id,
+            assignee_ids,
         );
+        if let Some(priority) = dto.priority {
+            task.priority = priority;
+        }
+        task.start_at = dto.start_at;
+        task.due_at = dto.due_at;
+        task.estimate_minutes = dto.estimate_minutes;
+        task.custom_fields = custom_fields;
+        let completion_event = Self::track_completion(&mut task, &column, user_id);
 
-        let saved_task = self.task_repository.create(task).await?;
+        let saved_task = unit_of_work.task_repository().create(task).await?;
 
-        self.event_bus
-            .publish(
+        unit_of_work
+            .outbox_repository()
+            .create(OutboxMessage::new(
+                Uuid::now_v7(),
                 column.board_id,
-                BoardEvent::TaskCreated(TaskCreatedEvent {
-                    task_id: saved_task.id,
-                    title: saved_task.title.clone(),
-                    description: saved_task.description.clone(),
-                    tags: saved_task.tags.clone(),
-                    position: saved_task.position.clone(),
-                    column_id: saved_task.column_id,
-                    created_by: user_id,
-                    timestamp: saved_task.created_at,
-                }),
-            )
-            .await;
+                Self::task_created_event(&saved_task, user_id, saved_task.created_at),
+            ))
+            .await?;
+
+        if let Some(event) = completion_event {
+            unit_of_work
+                .outbox_repository()
+                .create(OutboxMessage::new(Uuid::now_v7(), column.board_id, event))
+                .await?;
+        }
+
+        if let Some(event) = wip_limit_event {
+            unit_of_work
+                .outbox_repository()
+                .create(OutboxMessage::new(Uuid::now_v7(), column.board_id, event))
+                .await?;
+        }
+
+        if let Some(positions) = rebalanced_positions {
+            unit_of_work
+                .outbox_repository()
+                .create(OutboxMessage::new(
+                    Uuid::now_v7(),
+                    column.board_id,
+                    BoardEvent::PositionsRebalanced(PositionsRebalancedEvent {
+                        column_id: Some(saved_task.column_id),
+                        positions,
+                        rebalanced_by: user_id,
+                        timestamp: saved_task.created_at,
+                    }),
+                ))
+                .await?;
+        }
+
+        unit_of_work.commit().await?;
 
         Ok(TaskDto::from_domain(saved_task))
     }
@@ -148,6 +277,7 @@ impl TaskService {
     pub async fn get_column_tasks(
         &self,
         column_id: Uuid,
+        include_archived: bool,
         user_id: Uuid,
     ) -> Result<Vec<TaskDto>, ApplicationError> {
         let column = self
@@ -169,18 +299,102 @@ impl TaskService {
             });
         }
 
-        let mut tasks = self.task_repository.find_by_column_id(column_id).await?;
+        let mut tasks = self
+            .task_repository
+            .find_by_column_id(column_id, include_archived)
+            .await?;
 
         tasks.sort_by(|a, b| a.position.cmp(&b.position));
 
         Ok(tasks.into_iter().map(TaskDto::from_domain).collect())
     }
 
+    pub async fn search_tasks(
+        &self,
+        board_id: Uuid,
+        dto: TaskSearchDto,
+        user_id: Uuid,
+    ) -> Result<TaskSearchPageDto, ApplicationError> {
+        dto.validate()?;
+
+        if self
+            .board_member_repository
+            .find_by_board_and_user_id(board_id, user_id)
+            .await?
+            .is_none()
+        {
+            return Err(ApplicationError::Forbidden {
+                message: "You don't have access to this board".to_string(),
+            });
+        }
+
+        let filters = dto.custom_fields.unwrap_or_default();
+        let fields = self
+            .find_board_custom_fields(
+                board_id,
+                filters.iter().map(|filter| filter.field_id).collect(),
+            )
+            .await?;
+
+        let mut custom_fields = Vec::with_capacity(filters.len());
+        for filter in filters {
+            let field =
+                fields
+                    .get(&filter.field_id)
+                    .ok_or_else(|| ApplicationError::BadRequest {
+                        message: format!(
+                            "Custom field '{}' doesn't belong to this board",
+                            filter.field_id
+                        ),
+                    })?;
+
+            custom_fields.push(CustomFieldFilter {
+                field_id: field.id,
+                condition: Self::custom_field_condition(field, filter.operator, filter.value)?,
+            });
+        }
+
+        let limit = dto.limit.unwrap_or(DEFAULT_SEARCH_PAGE_SIZE);
+
+        // One extra row tells whether another page follows
+        let mut tasks = self
+            .task_repository
+            .search(
+                board_id,
+                TaskFilter {
+                    text: dto
+                        .query
+                        .map(|query| query.trim().to_string())
+                        .filter(|query| !query.is_empty()),
+                    label_ids: dto.label_ids.unwrap_or_default(),
+                    assignee_ids: dto.assignee_ids.unwrap_or_default(),
+                    custom_fields,
+                    include_archived: dto.include_archived.unwrap_or(false),
+                    cursor: dto.cursor,
+                    limit: limit + 1,
+                },
+            )
+            .await?;
+
+        let next_cursor = if tasks.len() as u64 > limit {
+            tasks.truncate(limit as usize);
+            tasks.last().map(|task| task.id)
+        } else {
+            None
+        };
+
+        Ok(TaskSearchPageDto {
+            items: tasks.into_iter().map(TaskDto::from_domain).collect(),
+            next_cursor,
+        })
+    }
+
     pub async fn update_task(
         &self,
         dto: UpdateTaskDto,
         task_id: Uuid,
         user_id: Uuid,
+        expected_version: Option<i32>,
     ) -> Result<TaskDto, ApplicationError> {
         dto.validate()?;
 
@@ -211,32 +425,107 @@ impl TaskService {
             });
         }
 
+        ApplicationError::ensure_version("The task", expected_version, task.version)?;
+
         if let Some(title) = dto.title {
             task.title = title;
         }
         if dto.description.is_some() {
             task.description = dto.description;
         }
-        if dto.tags.is_some() {
-            task.tags = dto.tags;
+        if let Some(label_ids) = dto.label_ids {
+            task.label_ids = self.validate_labels(column.board_id, label_ids).await?;
+        }
+        if let Some(custom_fields) = dto.custom_fields {
+            self.validate_custom_fields(column.board_id, custom_fields, &mut task.custom_fields)
+                .await?;
+        }
+        if let Some(priority) = dto.priority {
+            task.priority = priority;
+        }
+        if let Some(start_at) = dto.start_at {
+            task.start_at = start_at;
+        }
+        if let Some(due_at) = dto.due_at {
+            task.due_at = due_at;
+        }
+        if let Some(estimate_minutes) = dto.estimate_minutes {
+            task.estimate_minutes = estimate_minutes;
+        }
+        Self::validate_schedule(task.start_at, task.due_at)?;
+        let previous_assignee_ids = task.assignee_ids.clone();
+        if let Some(assignee_ids) = dto.assignee_ids {
+            task.assignee_ids = self
+                .validate_assignees(column.board_id, assignee_ids)
+                .await?;
         }
         task.updated_at = Utc::now().fixed_offset();
 
-        let updated_task = self.task_repository.update(task).await?;
+        let unit_of_work = self.unit_of_work_factory.begin().await?;
+
+        let updated_task = unit_of_work.task_repository().update(task).await?;
 
-        self.event_bus
-            .publish(
+        unit_of_work
+            .outbox_repository()
+            .create(OutboxMessage::new(
+                Uuid::now_v7(),
                 column.board_id,
                 BoardEvent::TaskUpdated(TaskUpdatedEvent {
                     task_id,
                     title: Some(updated_task.title.clone()),
                     description: updated_task.description.clone(),
-                    tags: updated_task.tags.clone(),
+                    label_ids: Some(updated_task.label_ids.clone()),
+                    custom_fields: Some(updated_task.custom_fields.clone()),
+                    priority: updated_task.priority.clone(),
+                    start_at: updated_task.start_at,
+                    due_at: updated_task.due_at,
+                    estimate_minutes: updated_task.estimate_minutes,
                     updated_by: user_id,
                     timestamp: updated_task.updated_at,
                 }),
-            )
-            .await;
+            ))
+            .await?;
+
+        for assignee_id in updated_task
+            .assignee_ids
+            .iter()
+            .filter(|id| !previous_assignee_ids.contains(id))
+        {
+            unit_of_work
+                .outbox_repository()
+                .create(OutboxMessage::new(
+                    Uuid::now_v7(),
+                    column.board_id,
+                    BoardEvent::TaskAssigned(TaskAssignedEvent {
+                        task_id,
+                        user_id: *assignee_id,
+                        assigned_by: user_id,
+                        timestamp: updated_task.updated_at,
+                    }),
+                ))
+                .await?;
+        }
+
+        for assignee_id in previous_assignee_ids
+            .iter()
+            .filter(|id| !updated_task.assignee_ids.contains(id))
+        {
+            unit_of_work
+                .outbox_repository()
+                .create(OutboxMessage::new(
+                    Uuid::now_v7(),
+                    column.board_id,
+                    BoardEvent::TaskUnassigned(TaskUnassignedEvent {
+                        task_id,
+                        user_id: *assignee_id,
+                        unassigned_by: user_id,
+                        timestamp: updated_task.updated_at,
+                    }),
+                ))
+                .await?;
+        }
+
+        unit_of_work.commit().await?;
 
         Ok(TaskDto::from_domain(updated_task))
     }
@@ -247,39 +536,41 @@ impl TaskService {
         task_id: Uuid,
         column_id: Uuid,
         user_id: Uuid,
+        expected_version: Option<i32>,
+        override_wip_limit: bool,
     ) -> Result<TaskDto, ApplicationError> {
-        let task = self
-            .task_repository
-            .find_by_id(task_id)
+        // Locking the task and the target column serializes concurrent moves, so positions
+        // are generated from the column's current tasks
+        let unit_of_work = self.unit_of_work_factory.begin().await?;
+
+        let task = unit_of_work
+            .task_repository()
+            .find_by_id_for_update(task_id)
             .await?
             .ok_or_else(|| ApplicationError::NotFound {
                 message: "Task with the given ID not found".to_string(),
             })?;
 
-        let old_column = self
-            .column_repository
+        let old_column = unit_of_work
+            .column_repository()
             .find_by_id(task.column_id)
             .await?
             .ok_or_else(|| ApplicationError::NotFound {
                 message: "Source column with the given ID not found".to_string(),
             })?;
 
-        let new_column = self
-            .column_repository
-            .find_by_id(column_id)
+        let new_column = unit_of_work
+            .column_repository()
+            .find_by_id_for_update(column_id)
             .await?
             .ok_or_else(|| ApplicationError::NotFound {
                 message: "Target column with the given ID not found".to_string(),
             })?;
 
-        if old_column.board_id != new_column.board_id {
-            return Err(ApplicationError::BadRequest {
-                message: "Cannot move task between columns of different boards".to_string(),
-            });
-        }
+        let crosses_boards = old_column.board_id != new_column.board_id;
 
-        if self
-            .board_member_repository
+        if unit_of_work
+            .board_member_repository()
             .find_by_board_and_user_id(old_column.board_id, user_id)
             .await?
             .is_none()
@@ -289,7 +580,40 @@ impl TaskService {
             });
         }
 
-        let mut target_column_tasks = self.task_repository.find_by_column_id(column_id).await?;
+        if crosses_boards
+            && unit_of_work
+                .board_member_repository()
+                .find_by_board_and_user_id(new_column.board_id, user_id)
+                .await?
+                .is_none()
+        {
+            return Err(ApplicationError::Forbidden {
+                message: "You don't have access to the target board".to_string(),
+            });
+        }
+
+        ApplicationError::ensure_version("The task", expected_version, task.version)?;
+
+        if task.archived_at.is_some() {
+            return Err(ApplicationError::BadRequest {
+                message: "Archived tasks cannot be moved".to_string(),
+            });
+        }
+
+        if new_column.archived_at.is_some() {
+            return Err(ApplicationError::BadRequest {
+                message: "Cannot move tasks to an archived column".to_string(),
+            });
+        }
+
+        if new_column.is_done && task.completed_at.is_none() {
+            Self::ensure_no_open_blockers(unit_of_work.as_ref(), &[&task]).await?;
+        }
+
+        let mut target_column_tasks = unit_of_work
+            .task_repository()
+            .find_by_column_id(column_id, false)
+            .await?;
 
         target_column_tasks.sort_by(|a, b| a.position.cmp(&b.position));
 
@@ -308,12 +632,36 @@ impl TaskService {
             });
         }
 
-        let new_position =
+        let wip_limit_event = if task.column_id != column_id {
+            self.check_wip_limit(
+                &new_column,
+                other_tasks.len(),
+                &[task_id],
+                override_wip_limit,
+                user_id,
+            )
+            .await?
+        } else {
+            None
+        };
+
+        let mut new_position =
             FractionalIndexGenerator::generate_for_position(&other_tasks, target_position)
                 .map_err(|err| ApplicationError::BadRequest {
                     message: format!("Failed to generate position: {}", err),
                 })?;
 
+        let rebalanced_positions = if FractionalIndexGenerator::needs_rebalance(&new_position) {
+            let mut placed = [(task_id, new_position)];
+            let positions =
+                Self::rebalance_column_tasks(unit_of_work.as_ref(), column_id, &mut placed).await?;
+            let [(_, placed_position)] = placed;
+            new_position = placed_position;
+            Some(positions)
+        } else {
+            None
+        };
+
         let old_position = if task.column_id == column_id {
             target_column_tasks
                 .iter()
@@ -327,42 +675,196 @@ impl TaskService {
         updated_task.column_id = column_id;
         updated_task.position = new_position;
         updated_task.updated_at = Utc::now().fixed_offset();
+        let completion_event = Self::track_completion(&mut updated_task, &new_column, user_id);
 
-        let saved_task = self.task_repository.update(updated_task).await?;
+        let mut removed_relations = Vec::new();
+        let mut created_label_events = Vec::new();
 
-        self.event_bus
-            .publish(
-                old_column.board_id,
-                BoardEvent::TaskMoved(TaskMovedEvent {
-                    task_id,
-                    old_column_id: task.column_id,
-                    new_column_id: column_id,
-                    old_position,
-                    new_position: target_position,
-                    moved_by: user_id,
-                    timestamp: saved_task.updated_at,
-                }),
+        if crosses_boards {
+            // Relations only link tasks of the same board
+            removed_relations = unit_of_work
+                .task_relation_repository()
+                .delete_by_task_id(task_id)
+                .await?;
+            updated_task.relations.clear();
+
+            // Assignees who aren't members of the target board lose the assignment
+            let target_member_ids: HashSet<Uuid> = unit_of_work
+                .board_member_repository()
+                .find_by_board_id(new_column.board_id)
+                .await?
+                .into_iter()
+                .map(|member| member.user_id)
+                .collect();
+            updated_task
+                .assignee_ids
+                .retain(|assignee_id| target_member_ids.contains(assignee_id));
+
+            // Labels are board-scoped, so they're carried over by name
+            let (label_ids, events) = Self::carry_labels(
+                unit_of_work.as_ref(),
+                &updated_task.label_ids,
+                new_column.board_id,
+                user_id,
             )
-            .await;
+            .await?;
+            updated_task.label_ids = label_ids;
+            created_label_events = events;
+
+            // Custom fields are board-scoped too, but there's nothing to map their values onto
+            updated_task.custom_fields.clear();
+
+            let attachments = unit_of_work
+                .attachment_repository()
+                .find_by_task_id(task_id)
+                .await?;
+
+            if !attachments.is_empty() {
+                let size: i64 = attachments.iter().map(|attachment| attachment.size).sum();
+                let used_size = unit_of_work
+                    .attachment_repository()
+                    .sum_size_by_board_id(new_column.board_id)
+                    .await?;
+
+                if (used_size + size) as u64 > *ATTACHMENT_BOARD_QUOTA {
+                    return Err(ApplicationError::PayloadTooLarge {
+                        message: format!(
+                            "Board attachment quota of {} bytes would be exceeded",
+                            *ATTACHMENT_BOARD_QUOTA
+                        ),
+                    });
+                }
+
+                unit_of_work
+                    .attachment_repository()
+                    .update_board_id_by_task_id(task_id, new_column.board_id)
+                    .await?;
+            }
+        }
+
+        let saved_task = unit_of_work.task_repository().update(updated_task).await?;
+
+        if crosses_boards {
+            unit_of_work
+                .outbox_repository()
+                .create(OutboxMessage::new(
+                    Uuid::now_v7(),
+                    old_column.board_id,
+                    BoardEvent::TaskRemoved(TaskRemovedEvent {
+                        task_id,
+                        column_id: task.column_id,
+                        removed_by: user_id,
+                        timestamp: saved_task.updated_at,
+                    }),
+                ))
+                .await?;
+
+            for relation in removed_relations {
+                unit_of_work
+                    .outbox_repository()
+                    .create(OutboxMessage::new(
+                        Uuid::now_v7(),
+                        old_column.board_id,
+                        Self::relation_deleted_event(relation, user_id, saved_task.updated_at),
+                    ))
+                    .await?;
+            }
+
+            for event in created_label_events {
+                unit_of_work
+                    .outbox_repository()
+                    .create(OutboxMessage::new(
+                        Uuid::now_v7(),
+                        new_column.board_id,
+                        event,
+                    ))
+                    .await?;
+            }
+
+            unit_of_work
+                .outbox_repository()
+                .create(OutboxMessage::new(
+                    Uuid::now_v7(),
+                    new_column.board_id,
+                    Self::task_created_event(&saved_task, user_id, saved_task.updated_at),
+                ))
+                .await?;
+        } else {
+            unit_of_work
+                .outbox_repository()
+                .create(OutboxMessage::new(
+                    Uuid::now_v7(),
+                    old_column.board_id,
+                    BoardEvent::TaskMoved(TaskMovedEvent {
+                        task_id,
+                        old_column_id: task.column_id,
+                        new_column_id: column_id,
+                        old_position,
+                        new_position: target_position,
+                        moved_by: user_id,
+                        timestamp: saved_task.updated_at,
+                    }),
+                ))
+                .await?;
+        }
+
+        if let Some(event) = completion_event {
+            unit_of_work
+                .outbox_repository()
+                .create(OutboxMessage::new(
+                    Uuid::now_v7(),
+                    new_column.board_id,
+                    event,
+                ))
+                .await?;
+        }
+
+        if let Some(event) = wip_limit_event {
+            unit_of_work
+                .outbox_repository()
+                .create(OutboxMessage::new(
+                    Uuid::now_v7(),
+                    new_column.board_id,
+                    event,
+                ))
+                .await?;
+        }
+
+        if let Some(positions) = rebalanced_positions {
+            unit_of_work
+                .outbox_repository()
+                .create(OutboxMessage::new(
+                    Uuid::now_v7(),
+                    new_column.board_id,
+                    BoardEvent::PositionsRebalanced(PositionsRebalancedEvent {
+                        column_id: Some(column_id),
+                        positions,
+                        rebalanced_by: user_id,
+                        timestamp: saved_task.updated_at,
+                    }),
+                ))
+                .await?;
+        }
+
+        unit_of_work.commit().await?;
 
         Ok(TaskDto::from_domain(saved_task))
     }
 
-    pub async fn delete_task(&self, task_id: Uuid, user_id: Uuid) -> Result<u64, ApplicationError> {
-        let task = self
-            .task_repository
-            .find_by_id(task_id)
-            .await?
-            .ok_or_else(|| ApplicationError::NotFound {
-                message: "Task with the given ID not found".to_string(),
-            })?;
+    pub async fn duplicate_task(
+        &self,
+        task_id: Uuid,
+        dto: DuplicateTaskDto,
+        user_id: Uuid,
+    ) -> Result<TaskDto, ApplicationError> {
+        let (source_task, source_board_id) = self.find_accessible_task(task_id, user_id).await?;
 
         let column = self
             .column_repository
-            .find_by_id(task.column_id)
+            .find_by_id(dto.column_id)
             .await?
             .ok_or_else(|| ApplicationError::NotFound {
-                message: "Column with the given ID not found".to_string(),
+                message: "Target column with the given ID not found".to_string(),
             })?;
 
         if self
@@ -372,23 +874,1699 @@ impl TaskService {
             .is_none()
         {
             return Err(ApplicationError::Forbidden {
-                message: "You don't have access to this board".to_string(),
+                message: "You don't have access to the target board".to_string(),
             });
         }
 
-        let deleted_column = self.task_repository.delete(task_id).await?;
+        if column.archived_at.is_some() {
+            return Err(ApplicationError::BadRequest {
+                message: "Cannot add tasks to an archived column".to_string(),
+            });
+        }
+
+        let source_attachments = if dto.include_attachments.unwrap_or(false) {
+            self.attachment_repository.find_by_task_id(task_id).await?
+        } else {
+            Vec::new()
+        };
+
+        if !source_attachments.is_empty() {
+            let size: i64 = source_attachments
+                .iter()
+                .map(|attachment| attachment.size)
+                .sum();
+            let used_size = self
+                .attachment_repository
+                .sum_size_by_board_id(column.board_id)
+                .await?;
+
+            if (used_size + size) as u64 > *ATTACHMENT_BOARD_QUOTA {
+                return Err(ApplicationError::PayloadTooLarge {
+                    message: format!(
+                        "Board attachment quota of {} bytes would be exceeded",
+                        *ATTACHMENT_BOARD_QUOTA
+                    ),
+                });
+            }
+        }
+
+        let duplicate_id = Uuid::now_v7();
 
-        self.event_bus
-            .publish(
+        // Blobs are copied up front and removed again if the copy isn't persisted
+        let mut attachments = Vec::with_capacity(source_attachments.len());
+        for source in source_attachments {
+            let attachment = Attachment::new(
+                Uuid::now_v7(),
+                duplicate_id,
                 column.board_id,
-                BoardEvent::TaskDeleted(TaskDeletedEvent {
-                    task_id,
-                    deleted_by: user_id,
-                    timestamp: Utc::now().fixed_offset(),
-                }),
+                source.uploaded_by,
+                source.file_name,
+                source.content_type,
+                source.size,
+            );
+
+            if let Err(err) = self
+                .copy_attachment_blob(&source.storage_key, &attachment)
+                .await
+            {
+                self.remove_attachment_blobs(
+                    attachments
+                        .iter()
+                        .map(|attachment: &Attachment| attachment.storage_key.clone())
+                        .collect(),
+                )
+                .await;
+                return Err(err);
+            }
+
+            attachments.push(attachment);
+        }
+        let storage_keys: Vec<String> = attachments
+            .iter()
+            .map(|attachment| attachment.storage_key.clone())
+            .collect();
+
+        let result: Result<Task, ApplicationError> = async {
+            let unit_of_work = self.unit_of_work_factory.begin().await?;
+
+            unit_of_work
+                .column_repository()
+                .find_by_id_for_update(column.id)
+                .await?;
+
+            let mut column_tasks = unit_of_work
+                .task_repository()
+                .find_by_column_id(column.id, false)
+                .await?;
+
+            column_tasks.sort_by(|a, b| a.position.cmp(&b.position));
+            let existing_positions: Vec<String> =
+                column_tasks.iter().map(|t| t.position.clone()).collect();
+
+            let wip_limit_event = self
+                .check_wip_limit(
+                    &column,
+                    existing_positions.len(),
+                    &[duplicate_id],
+                    dto.override_wip_limit.unwrap_or(false),
+                    user_id,
+                )
+                .await?;
+
+            let target_position = dto.position.unwrap_or(existing_positions.len());
+            if target_position > existing_positions.len() {
+                return Err(ApplicationError::BadRequest {
+                    message: format!(
+                        "Target position is out of bounds (0 - {})",
+                        existing_positions.len()
+                    ),
+                });
+            }
+
+            let mut position = FractionalIndexGenerator::generate_for_position(
+                &existing_positions,
+                target_position,
             )
-            .await;
+            .map_err(|err| ApplicationError::BadRequest {
+                message: format!("Failed to generate position: {}", err),
+            })?;
 
-        Ok(deleted_column)
+            let rebalanced_positions = if FractionalIndexGenerator::needs_rebalance(&position) {
+                let mut placed = [(duplicate_id, position)];
+                let positions =
+                    Self::rebalance_column_tasks(unit_of_work.as_ref(), column.id, &mut placed)
+                        .await?;
+                let [(_, placed_position)] = placed;
+                position = placed_position;
+                Some(positions)
+            } else {
+                None
+            };
+
+            let (label_ids, created_label_events) = Self::carry_labels(
+                unit_of_work.as_ref(),
+                &source_task.label_ids,
+                column.board_id,
+                user_id,
+            )
+            .await?;
+
+            let mut task = Task::new(
+                duplicate_id,
+                source_task.title.clone(),
+                source_task.description.clone(),
+                label_ids,
+                position,
+                column.id,
+                Vec::new(),
+            );
+            if column.board_id == source_board_id {
+                task.custom_fields = source_task.custom_fields.clone();
+            }
+            let completion_event = Self::track_completion(&mut task, &column, user_id);
+
+            let mut saved_task = unit_of_work.task_repository().create(task).await?;
+
+            if dto.include_comments.unwrap_or(false) {
+                let mut comments = unit_of_work
+                    .comment_repository()
+                    .find_by_task_id(task_id)
+                    .await?;
+                comments.sort_by_key(|comment| comment.created_at);
+
+                // Replies are copied after their parents, so every parent is already mapped
+                let mut copied_ids: HashMap<Uuid, Uuid> = HashMap::new();
+                for comment in comments {
+                    let copy_id = Uuid::now_v7();
+                    copied_ids.insert(comment.id, copy_id);
+
+                    unit_of_work
+                        .comment_repository()
+                        .create(Comment {
+                            id: copy_id,
+                            task_id: duplicate_id,
+                            parent_id: comment
+                                .parent_id
+                                .and_then(|parent_id| copied_ids.get(&parent_id).copied()),
+                            ..comment
+                        })
+                        .await?;
+                }
+            }
+
+            // Copied items start out open again
+            if dto.include_checklists.unwrap_or(false) {
+                let mut items_by_checklist: HashMap<Uuid, Vec<ChecklistItem>> = HashMap::new();
+                for item in unit_of_work
+                    .checklist_repository()
+                    .find_items_by_task_id(task_id)
+                    .await?
+                {
+                    items_by_checklist
+                        .entry(item.checklist_id)
+                        .or_default()
+                        .push(item);
+                }
+
+                for checklist in unit_of_work
+                    .checklist_repository()
+                    .find_by_task_id(task_id)
+                    .await?
+                {
+                    let copy_id = Uuid::now_v7();
+
+                    unit_of_work
+                        .checklist_repository()
+                        .create(Checklist::new(
+                            copy_id,
+                            duplicate_id,
+                            checklist.title,
+                            checklist.position,
+                        ))
+                        .await?;
+
+                    for item in items_by_checklist.remove(&checklist.id).unwrap_or_default() {
+                        unit_of_work
+                            .checklist_repository()
+                            .create_item(ChecklistItem::new(
+                                Uuid::now_v7(),
+                                copy_id,
+                                item.content,
+                                item.position,
+                            ))
+                            .await?;
+                        saved_task.checklist_progress.total_items += 1;
+                    }
+                }
+            }
+
+            for attachment in &attachments {
+                unit_of_work
+                    .attachment_repository()
+                    .create(attachment.clone())
+                    .await?;
+            }
+
+            for event in created_label_events {
+                unit_of_work
+                    .outbox_repository()
+                    .create(OutboxMessage::new(Uuid::now_v7(), column.board_id, event))
+                    .await?;
+            }
+
+            unit_of_work
+                .outbox_repository()
+                .create(OutboxMessage::new(
+                    Uuid::now_v7(),
+                    column.board_id,
+                    Self::task_created_event(&saved_task, user_id, saved_task.created_at),
+                ))
+                .await?;
+
+            if let Some(event) = completion_event {
+                unit_of_work
+                    .outbox_repository()
+                    .create(OutboxMessage::new(Uuid::now_v7(), column.board_id, event))
+                    .await?;
+            }
+
+            if let Some(event) = wip_limit_event {
+                unit_of_work
+                    .outbox_repository()
+                    .create(OutboxMessage::new(Uuid::now_v7(), column.board_id, event))
+                    .await?;
+            }
+
+            if let Some(positions) = rebalanced_positions {
+                unit_of_work
+                    .outbox_repository()
+                    .create(OutboxMessage::new(
+                        Uuid::now_v7(),
+                        column.board_id,
+                        BoardEvent::PositionsRebalanced(PositionsRebalancedEvent {
+                            column_id: Some(column.id),
+                            positions,
+                            rebalanced_by: user_id,
+                            timestamp: saved_task.created_at,
+                        }),
+                    ))
+                    .await?;
+            }
+
+            unit_of_work.commit().await?;
+
+            Ok(saved_task)
+        }
+        .await;
+
+        match result {
+            Ok(saved_task) => Ok(TaskDto::from_domain(saved_task)),
+            Err(err) => {
+                self.remove_attachment_blobs(storage_keys).await;
+                Err(err)
+            }
+        }
+    }
+
+    pub async fn bulk_update_tasks(
+        &self,
+        dto: BulkTaskDto,
+        user_id: Uuid,
+    ) -> Result<BulkTaskResultDto, ApplicationError> {
+        dto.validate()?;
+
+        let mut unique_ids = HashSet::new();
+        let task_ids: Vec<Uuid> = dto
+            .task_ids
+            .into_iter()
+            .filter(|task_id| unique_ids.insert(*task_id))
+            .collect();
+
+        let unit_of_work = self.unit_of_work_factory.begin().await?;
+
+        let mut found_tasks: HashMap<Uuid, Task> = unit_of_work
+            .task_repository()
+            .find_by_ids(task_ids.clone())
+            .await?
+            .into_iter()
+            .map(|task| (task.id, task))
+            .collect();
+
+        let mut tasks = Vec::with_capacity(task_ids.len());
+        for task_id in &task_ids {
+            let task = found_tasks
+                .remove(task_id)
+                .ok_or_else(|| ApplicationError::NotFound {
+                    message: format!("Task '{}' not found", task_id),
+                })?;
+            tasks.push(task);
+        }
+
+        let mut column_boards: HashMap<Uuid, Uuid> = HashMap::new();
+        for task in &tasks {
+            if column_boards.contains_key(&task.column_id) {
+                continue;
+            }
+
+            let column = unit_of_work
+                .column_repository()
+                .find_by_id(task.column_id)
+                .await?
+                .ok_or_else(|| ApplicationError::NotFound {
+                    message: "Column with the given ID not found".to_string(),
+                })?;
+            column_boards.insert(column.id, column.board_id);
+        }
+
+        // Boards in the order their tasks were given, each checked once
+        let mut board_ids: Vec<Uuid> = Vec::new();
+        for task in &tasks {
+            let board_id = column_boards[&task.column_id];
+            if board_ids.contains(&board_id) {
+                continue;
+            }
+
+            if unit_of_work
+                .board_member_repository()
+                .find_by_board_and_user_id(board_id, user_id)
+                .await?
+                .is_none()
+            {
+                return Err(ApplicationError::Forbidden {
+                    message: "You don't have access to this board".to_string(),
+                });
+            }
+            board_ids.push(board_id);
+        }
+
+        let task_boards: HashMap<Uuid, Uuid> = tasks
+            .iter()
+            .map(|task| (task.id, column_boards[&task.column_id]))
+            .collect();
+
+        let now = Utc::now().fixed_offset();
+        let mut updated = Vec::new();
+        let mut deleted = Vec::new();
+        let mut storage_keys = Vec::new();
+        let mut rebalanced: Option<(Uuid, Uuid, Vec<EntityPosition>)> = None;
+        let mut wip_limit_event: Option<(Uuid, BoardEvent)> = None;
+        let mut completion_events: Vec<(Uuid, BoardEvent)> = Vec::new();
+
+        let operation = match dto.operation {
+            BulkTaskOperationDto::Move {
+                column_id,
+                override_wip_limit,
+            } => {
+                let column = unit_of_work
+                    .column_repository()
+                    .find_by_id_for_update(column_id)
+                    .await?
+                    .ok_or_else(|| ApplicationError::NotFound {
+                        message: "Target column with the given ID not found".to_string(),
+                    })?;
+
+                if board_ids
+                    .iter()
+                    .any(|board_id| *board_id != column.board_id)
+                {
+                    return Err(ApplicationError::BadRequest {
+                        message: "Tasks can only be moved in bulk within their own board"
+                            .to_string(),
+                    });
+                }
+
+                if column.archived_at.is_some() {
+                    return Err(ApplicationError::BadRequest {
+                        message: "Cannot move tasks to an archived column".to_string(),
+                    });
+                }
+
+                if tasks.iter().any(|task| task.archived_at.is_some()) {
+                    return Err(ApplicationError::BadRequest {
+                        message: "Archived tasks cannot be moved".to_string(),
+                    });
+                }
+
+                if column.is_done {
+                    let completing: Vec<&Task> = tasks
+                        .iter()
+                        .filter(|task| task.completed_at.is_none())
+                        .collect();
+                    Self::ensure_no_open_blockers(unit_of_work.as_ref(), &completing).await?;
+                }
+
+                let remaining_positions: Vec<String> = unit_of_work
+                    .task_repository()
+                    .find_by_column_id(column_id, false)
+                    .await?
+                    .into_iter()
+                    .filter(|t| !unique_ids.contains(&t.id))
+                    .map(|t| t.position)
+                    .collect();
+
+                // Tasks already in the column don't count towards the limit twice
+                let entering_ids: Vec<Uuid> = tasks
+                    .iter()
+                    .filter(|task| task.column_id != column_id)
+                    .map(|task| task.id)
+                    .collect();
+                let staying_count = tasks.len() - entering_ids.len();
+                wip_limit_event = self
+                    .check_wip_limit(
+                        &column,
+                        remaining_positions.len() + staying_count,
+                        &entering_ids,
+                        override_wip_limit.unwrap_or(false),
+                        user_id,
+                    )
+                    .await?
+                    .map(|event| (column.board_id, event));
+
+                let last_position = remaining_positions.into_iter().max();
+
+                let mut placed: Vec<(Uuid, String)> = Vec::with_capacity(tasks.len());
+                for task in &tasks {
+                    let position = match placed.last().map(|(_, position)| position) {
+                        Some(previous) => Some(previous.as_str()),
+                        None => last_position.as_deref(),
+                    }
+                    .map(FractionalIndexGenerator::after)
+                    .transpose()
+                    .map_err(|err| ApplicationError::BadRequest {
+                        message: format!("Failed to generate position: {}", err),
+                    })?
+                    .unwrap_or_else(FractionalIndexGenerator::first);
+                    placed.push((task.id, position));
+                }
+
+                if placed
+                    .iter()
+                    .any(|(_, position)| FractionalIndexGenerator::needs_rebalance(position))
+                {
+                    let positions =
+                        Self::rebalance_column_tasks(unit_of_work.as_ref(), column_id, &mut placed)
+                            .await?;
+                    rebalanced = Some((column.board_id, column_id, positions));
+                }
+
+                for (mut task, (_, position)) in tasks.into_iter().zip(&placed) {
+                    task.column_id = column_id;
+                    task.position = position.clone();
+                    task.updated_at = now;
+                    if let Some(event) = Self::track_completion(&mut task, &column, user_id) {
+                        completion_events.push((column.board_id, event));
+                    }
+                    updated.push(unit_of_work.task_repository().update(task).await?);
+                }
+
+                BulkTaskOperation::Move {
+                    column_id,
+                    positions: placed
+                        .into_iter()
+                        .map(|(id, position)| EntityPosition { id, position })
+                        .collect(),
+                }
+            }
+            BulkTaskOperationDto::Archive => {
+                // Tasks that are already archived are left untouched
+                for mut task in tasks {
+                    if task.archived_at.is_some() {
+                        continue;
+                    }
+
+                    task.archived_at = Some(now);
+                    task.updated_at = now;
+                    updated.push(unit_of_work.task_repository().update(task).await?);
+                }
+
+                BulkTaskOperation::Archive
+            }
+            BulkTaskOperationDto::Delete => {
+                for task in tasks {
+                    storage_keys.extend(
+                        unit_of_work
+                            .attachment_repository()
+                            .find_by_task_id(task.id)
+                            .await?
+                            .into_iter()
+                            .map(|attachment| attachment.storage_key),
+                    );
+                    unit_of_work.task_repository().delete(task.id).await?;
+                    deleted.push(task.id);
+                }
+
+                BulkTaskOperation::Delete
+            }
+            BulkTaskOperationDto::AddLabels { label_ids } => {
+                let labels = unit_of_work
+                    .label_repository()
+                    .find_by_ids(label_ids.clone())
+                    .await?;
+
+                for label_id in &label_ids {
+                    match labels.iter().find(|label| label.id == *label_id) {
+                        Some(label) if board_ids.contains(&label.board_id) => {}
+                        Some(_) => {
+                            return Err(ApplicationError::BadRequest {
+                                message: format!(
+                                    "Label '{}' doesn't belong to the boards of these tasks",
+                                    label_id
+                                ),
+                            });
+                        }
+                        None => {
+                            return Err(ApplicationError::NotFound {
+                                message: format!("Label '{}' not found", label_id),
+                            });
+                        }
+                    }
+                }
+
+                for mut task in tasks {
+                    for label in &labels {
+                        if label.board_id == task_boards[&task.id]
+                            && !task.label_ids.contains(&label.id)
+                        {
+                            task.label_ids.push(label.id);
+                        }
+                    }
+
+                    task.updated_at = now;
+                    updated.push(unit_of_work.task_repository().update(task).await?);
+                }
+
+                BulkTaskOperation::AddLabels { label_ids }
+            }
+            BulkTaskOperationDto::RemoveLabels { label_ids } => {
+                for mut task in tasks {
+                    task.label_ids
+                        .retain(|label_id| !label_ids.contains(label_id));
+                    task.updated_at = now;
+                    updated.push(unit_of_work.task_repository().update(task).await?);
+                }
+
+                BulkTaskOperation::RemoveLabels { label_ids }
+            }
+            BulkTaskOperationDto::Assign { user_ids } => {
+                let mut assignable: HashMap<Uuid, Vec<Uuid>> = HashMap::new();
+                for board_id in &board_ids {
+                    assignable.insert(
+                        *board_id,
+                        self.validate_assignees(*board_id, user_ids.clone()).await?,
+                    );
+                }
+
+                for mut task in tasks {
+                    for assignee_id in &assignable[&task_boards[&task.id]] {
+                        if !task.assignee_ids.contains(assignee_id) {
+                            task.assignee_ids.push(*assignee_id);
+                        }
+                    }
+
+                    task.updated_at = now;
+                    updated.push(unit_of_work.task_repository().update(task).await?);
+                }
+
+                BulkTaskOperation::Assign { user_ids }
+            }
+        };
+
+        // One event per board, covering every task of that board the operation changed
+        for board_id in &board_ids {
+            let changed_ids: Vec<Uuid> = updated
+                .iter()
+                .map(|task: &Task| task.id)
+                .chain(deleted.iter().copied())
+                .filter(|task_id| task_boards[task_id] == *board_id)
+                .collect();
+
+            if changed_ids.is_empty() {
+                continue;
+            }
+
+            unit_of_work
+                .outbox_repository()
+                .create(OutboxMessage::new(
+                    Uuid::now_v7(),
+                    *board_id,
+                    BoardEvent::TasksBulkUpdated(TasksBulkUpdatedEvent {
+                        task_ids: changed_ids,
+                        operation: operation.clone(),
+                        updated_by: user_id,
+                        timestamp: now,
+                    }),
+                ))
+                .await?;
+        }
+
+        // Completions are announced per task, like single moves, so analytics sees each of them
+        for (board_id, event) in wip_limit_event.into_iter().chain(completion_events) {
+            unit_of_work
+                .outbox_repository()
+                .create(OutboxMessage::new(Uuid::now_v7(), board_id, event))
+                .await?;
+        }
+
+        if let Some((board_id, column_id, positions)) = rebalanced {
+            unit_of_work
+                .outbox_repository()
+                .create(OutboxMessage::new(
+                    Uuid::now_v7(),
+                    board_id,
+                    BoardEvent::PositionsRebalanced(PositionsRebalancedEvent {
+                        column_id: Some(column_id),
+                        positions,
+                        rebalanced_by: user_id,
+                        timestamp: now,
+                    }),
+                ))
+                .await?;
+        }
+
+        unit_of_work.commit().await?;
+
+        self.remove_attachment_blobs(storage_keys).await;
+
+        Ok(BulkTaskResultDto {
+            updated: updated.into_iter().map(TaskDto::from_domain).collect(),
+            deleted,
+        })
+    }
+
+    pub async fn archive_task(
+        &self,
+        task_id: Uuid,
+        user_id: Uuid,
+    ) -> Result<TaskDto, ApplicationError> {
+        let (mut task, board_id) = self.find_accessible_task(task_id, user_id).await?;
+
+        if task.archived_at.is_some() {
+            return Err(ApplicationError::Conflict {
+                message: "Task is already archived".to_string(),
+            });
+        }
+
+        let now = Utc::now().fixed_offset();
+        task.archived_at = Some(now);
+        task.updated_at = now;
+
+        let unit_of_work = self.unit_of_work_factory.begin().await?;
+
+        let archived_task = unit_of_work.task_repository().update(task).await?;
+
+        unit_of_work
+            .outbox_repository()
+            .create(OutboxMessage::new(
+                Uuid::now_v7(),
+                board_id,
+                BoardEvent::TaskArchived(TaskArchivedEvent {
+                    task_id,
+                    column_id: archived_task.column_id,
+                    archived_by: user_id,
+                    timestamp: now,
+                }),
+            ))
+            .await?;
+
+        unit_of_work.commit().await?;
+
+        Ok(TaskDto::from_domain(archived_task))
+    }
+
+    pub async fn unarchive_task(
+        &self,
+        task_id: Uuid,
+        user_id: Uuid,
+    ) -> Result<TaskDto, ApplicationError> {
+        let (mut task, board_id) = self.find_accessible_task(task_id, user_id).await?;
+
+        if task.archived_at.is_none() {
+            return Err(ApplicationError::Conflict {
+                message: "Task is not archived".to_string(),
+            });
+        }
+
+        let now = Utc::now().fixed_offset();
+        task.archived_at = None;
+        task.updated_at = now;
+
+        let unit_of_work = self.unit_of_work_factory.begin().await?;
+
+        let restored_task = unit_of_work.task_repository().update(task).await?;
+
+        unit_of_work
+            .outbox_repository()
+            .create(OutboxMessage::new(
+                Uuid::now_v7(),
+                board_id,
+                BoardEvent::TaskRestored(TaskRestoredEvent {
+                    task_id,
+                    column_id: restored_task.column_id,
+                    restored_by: user_id,
+                    timestamp: now,
+                }),
+            ))
+            .await?;
+
+        unit_of_work.commit().await?;
+
+        Ok(TaskDto::from_domain(restored_task))
+    }
+
+    pub async fn delete_task(&self, task_id: Uuid, user_id: Uuid) -> Result<u64, ApplicationError> {
+        let task = self
+            .task_repository
+            .find_by_id(task_id)
+            .await?
+            .ok_or_else(|| ApplicationError::NotFound {
+                message: "Task with the given ID not found".to_string(),
+            })?;
+
+        let column = self
+            .column_repository
+            .find_by_id(task.column_id)
+            .await?
+            .ok_or_else(|| ApplicationError::NotFound {
+                message: "Column with the given ID not found".to_string(),
+            })?;
+
+        if self
+            .board_member_repository
+            .find_by_board_and_user_id(column.board_id, user_id)
+            .await?
+            .is_none()
+        {
+            return Err(ApplicationError::Forbidden {
+                message: "You don't have access to this board".to_string(),
+            });
+        }
+
+        let storage_keys = self
+            .attachment_repository
+            .find_by_task_id(task_id)
+            .await?
+            .into_iter()
+            .map(|attachment| attachment.storage_key)
+            .collect();
+
+        let unit_of_work = self.unit_of_work_factory.begin().await?;
+
+        let deleted_column = unit_of_work.task_repository().delete(task_id).await?;
+
+        unit_of_work
+            .outbox_repository()
+            .create(OutboxMessage::new(
+                Uuid::now_v7(),
+                column.board_id,
+                BoardEvent::TaskDeleted(TaskDeletedEvent {
+                    task_id,
+                    deleted_by: user_id,
+                    timestamp: Utc::now().fixed_offset(),
+                }),
+            ))
+            .await?;
+
+        unit_of_work.commit().await?;
+
+        self.remove_attachment_blobs(storage_keys).await;
+
+        Ok(deleted_column)
+    }
+
+    pub async fn create_task_relation(
+        &self,
+        task_id: Uuid,
+        dto: CreateTaskRelationDto,
+        user_id: Uuid,
+    ) -> Result<TaskRelationDto, ApplicationError> {
+        let related_task_id = dto.related_task_id;
+        if related_task_id == task_id {
+            return Err(ApplicationError::BadRequest {
+                message: "A task cannot be related to itself".to_string(),
+            });
+        }
+
+        // Both tasks are locked in a fixed order, so concurrent links between the same tasks
+        // can't slip past the duplicate and cycle checks
+        let unit_of_work = self.unit_of_work_factory.begin().await?;
+
+        let mut tasks = HashMap::new();
+        for id in [task_id.min(related_task_id), task_id.max(related_task_id)] {
+            let task = unit_of_work
+                .task_repository()
+                .find_by_id_for_update(id)
+                .await?
+                .ok_or_else(|| ApplicationError::NotFound {
+                    message: format!("Task '{}' not found", id),
+                })?;
+            tasks.insert(id, task);
+        }
+
+        let task = &tasks[&task_id];
+        let related_task = &tasks[&related_task_id];
+
+        let column = unit_of_work
+            .column_repository()
+            .find_by_id(task.column_id)
+            .await?
+            .ok_or_else(|| ApplicationError::NotFound {
+                message: "Column with the given ID not found".to_string(),
+            })?;
+
+        if unit_of_work
+            .board_member_repository()
+            .find_by_board_and_user_id(column.board_id, user_id)
+            .await?
+            .is_none()
+        {
+            return Err(ApplicationError::Forbidden {
+                message: "You don't have access to this board".to_string(),
+            });
+        }
+
+        let related_column = unit_of_work
+            .column_repository()
+            .find_by_id(related_task.column_id)
+            .await?
+            .ok_or_else(|| ApplicationError::NotFound {
+                message: "Column with the given ID not found".to_string(),
+            })?;
+
+        if related_column.board_id != column.board_id {
+            return Err(ApplicationError::BadRequest {
+                message: "Only tasks on the same board can be related".to_string(),
+            });
+        }
+
+        let (source_task_id, target_task_id, relation_type) =
+            dto.relation_type.to_directed(task_id, related_task_id);
+
+        if task
+            .relations
+            .iter()
+            .any(|relation| relation.links(source_task_id, target_task_id, relation_type))
+        {
+            return Err(ApplicationError::Conflict {
+                message: "The tasks are already related this way".to_string(),
+            });
+        }
+
+        if relation_type == TaskRelationTypeEnum::Blocks {
+            let blocking_relations = unit_of_work
+                .task_relation_repository()
+                .find_by_board_id(column.board_id, TaskRelationTypeEnum::Blocks)
+                .await?;
+
+            if Self::blocks_transitively(&blocking_relations, target_task_id, source_task_id) {
+                return Err(ApplicationError::Conflict {
+                    message: "The relation would create a cycle of blocking tasks".to_string(),
+                });
+            }
+        }
+
+        let relation = unit_of_work
+            .task_relation_repository()
+            .create(TaskRelation::new(
+                Uuid::now_v7(),
+                source_task_id,
+                target_task_id,
+                relation_type,
+                user_id,
+            ))
+            .await?;
+
+        unit_of_work
+            .outbox_repository()
+            .create(OutboxMessage::new(
+                Uuid::now_v7(),
+                column.board_id,
+                BoardEvent::TaskRelationCreated(TaskRelationCreatedEvent {
+                    relation_id: relation.id,
+                    source_task_id,
+                    target_task_id,
+                    relation_type,
+                    created_by: user_id,
+                    timestamp: relation.created_at,
+                }),
+            ))
+            .await?;
+
+        unit_of_work.commit().await?;
+
+        Ok(TaskRelationDto::from_domain(relation, task_id))
+    }
+
+    pub async fn delete_task_relation(
+        &self,
+        task_id: Uuid,
+        relation_id: Uuid,
+        user_id: Uuid,
+    ) -> Result<u64, ApplicationError> {
+        let (task, board_id) = self.find_accessible_task(task_id, user_id).await?;
+
+        let relation = task
+            .relations
+            .into_iter()
+            .find(|relation| relation.id == relation_id)
+            .ok_or_else(|| ApplicationError::NotFound {
+                message: "Relation with the given ID not found".to_string(),
+            })?;
+
+        let unit_of_work = self.unit_of_work_factory.begin().await?;
+
+        let deleted_relation = unit_of_work
+            .task_relation_repository()
+            .delete(relation_id)
+            .await?;
+
+        if deleted_relation > 0 {
+            unit_of_work
+                .outbox_repository()
+                .create(OutboxMessage::new(
+                    Uuid::now_v7(),
+                    board_id,
+                    Self::relation_deleted_event(relation, user_id, Utc::now().fixed_offset()),
+                ))
+                .await?;
+        }
+
+        unit_of_work.commit().await?;
+
+        Ok(deleted_relation)
+    }
+
+    /// Rejects adding `added_task_ids` to a column beyond its WIP limit, unless an owner or
+    /// moderator explicitly overrides it. Returns the event announcing the override.
+    async fn check_wip_limit(
+        &self,
+        column: &Column,
+        active_task_count: usize,
+        added_task_ids: &[Uuid],
+        override_wip_limit: bool,
+        user_id: Uuid,
+    ) -> Result<Option<BoardEvent>, ApplicationError> {
+        let Some(wip_limit) = column.wip_limit else {
+            return Ok(None);
+        };
+
+        let task_count = active_task_count + added_task_ids.len();
+        if added_task_ids.is_empty() || task_count <= wip_limit as usize {
+            return Ok(None);
+        }
+
+        if !override_wip_limit {
+            return Err(ApplicationError::Conflict {
+                message: format!(
+                    "Column '{}' has reached its WIP limit of {} tasks",
+                    column.name, wip_limit
+                ),
+            });
+        }
+
+        if !self
+            .board_member_repository
+            .check_permissions(
+                column.board_id,
+                user_id,
+                vec![BoardMemberRoleEnum::Owner, BoardMemberRoleEnum::Moderator],
+            )
+            .await?
+        {
+            return Err(ApplicationError::Forbidden {
+                message: "Only board owners and moderators can exceed a WIP limit".to_string(),
+            });
+        }
+
+        Ok(Some(BoardEvent::WipLimitExceeded(WipLimitExceededEvent {
+            column_id: column.id,
+            task_ids: added_task_ids.to_vec(),
+            wip_limit,
+            task_count,
+            overridden_by: user_id,
+            timestamp: Utc::now().fixed_offset(),
+        })))
+    }
+
+    /// Marks the task as completed when it enters a done column and clears the completion
+    /// when it leaves one. Returns the event announcing either change.
+    fn track_completion(task: &mut Task, column: &Column, changed_by: Uuid) -> Option<BoardEvent> {
+        match (column.is_done, task.completed_at) {
+            (true, None) => {
+                task.completed_at = Some(task.updated_at);
+
+                Some(BoardEvent::TaskCompleted(TaskCompletedEvent {
+                    task_id: task.id,
+                    column_id: column.id,
+                    completed_by: changed_by,
+                    timestamp: task.updated_at,
+                }))
+            }
+            (false, Some(_)) => {
+                task.completed_at = None;
+
+                Some(BoardEvent::TaskReopened(TaskReopenedEvent {
+                    task_id: task.id,
+                    column_id: column.id,
+                    reopened_by: changed_by,
+                    timestamp: task.updated_at,
+                }))
+            }
+            _ => None,
+        }
+    }
+
+    fn task_created_event(
+        task: &Task,
+        created_by: Uuid,
+        timestamp: DateTime<FixedOffset>,
+    ) -> BoardEvent {
+        BoardEvent::TaskCreated(TaskCreatedEvent {
+            task_id: task.id,
+            title: task.title.clone(),
+            description: task.description.clone(),
+            label_ids: task.label_ids.clone(),
+            custom_fields: task.custom_fields.clone(),
+            position: task.position.clone(),
+            column_id: task.column_id,
+            assignee_ids: task.assignee_ids.clone(),
+            priority: task.priority.clone(),
+            start_at: task.start_at,
+            due_at: task.due_at,
+            estimate_minutes: task.estimate_minutes,
+            created_at: Some(task.created_at),
+            created_by,
+            timestamp,
+        })
+    }
+
+    /// Rejects completing the given tasks while any task blocking them is still open.
+    /// Blockers that are completed along with them don't count.
+    async fn ensure_no_open_blockers(
+        unit_of_work: &dyn UnitOfWork,
+        tasks: &[&Task],
+    ) -> Result<(), ApplicationError> {
+        let completing_ids: HashSet<Uuid> = tasks.iter().map(|task| task.id).collect();
+
+        let blocker_ids: HashSet<Uuid> = tasks
+            .iter()
+            .flat_map(|task| {
+                task.relations.iter().filter(|relation| {
+                    relation.relation_type == TaskRelationTypeEnum::Blocks
+                        && relation.target_task_id == task.id
+                })
+            })
+            .map(|relation| relation.source_task_id)
+            .filter(|blocker_id| !completing_ids.contains(blocker_id))
+            .collect();
+
+        if blocker_ids.is_empty() {
+            return Ok(());
+        }
+
+        // Archived blockers have been abandoned rather than left open
+        let open_blockers: HashSet<Uuid> = unit_of_work
+            .task_repository()
+            .find_by_ids(blocker_ids.into_iter().collect())
+            .await?
+            .into_iter()
+            .filter(|blocker| blocker.completed_at.is_none() && blocker.archived_at.is_none())
+            .map(|blocker| blocker.id)
+            .collect();
+
+        for task in tasks {
+            let open_count = task
+                .relations
+                .iter()
+                .filter(|relation| {
+                    relation.relation_type == TaskRelationTypeEnum::Blocks
+                        && relation.target_task_id == task.id
+                        && open_blockers.contains(&relation.source_task_id)
+                })
+                .count();
+
+            if open_count > 0 {
+                return Err(ApplicationError::Conflict {
+                    message: format!(
+                        "Task '{}' is still blocked by {} open task(s)",
+                        task.title, open_count
+                    ),
+                });
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Whether `from_task_id` blocks `to_task_id` directly or through a chain of blockers
+    fn blocks_transitively(
+        relations: &[TaskRelation],
+        from_task_id: Uuid,
+        to_task_id: Uuid,
+    ) -> bool {
+        let mut blocked: HashMap<Uuid, Vec<Uuid>> = HashMap::new();
+        for relation in relations {
+            blocked
+                .entry(relation.source_task_id)
+                .or_default()
+                .push(relation.target_task_id);
+        }
+
+        let mut visited = HashSet::from([from_task_id]);
+        let mut queue = VecDeque::from([from_task_id]);
+        while let Some(task_id) = queue.pop_front() {
+            if task_id == to_task_id {
+                return true;
+            }
+
+            for next_id in blocked.get(&task_id).into_iter().flatten() {
+                if visited.insert(*next_id) {
+                    queue.push_back(*next_id);
+                }
+            }
+        }
+
+        false
+    }
+
+    fn relation_deleted_event(
+        relation: TaskRelation,
+        deleted_by: Uuid,
+        timestamp: DateTime<FixedOffset>,
+    ) -> BoardEvent {
+        BoardEvent::TaskRelationDeleted(TaskRelationDeletedEvent {
+            relation_id: relation.id,
+            source_task_id: relation.source_task_id,
+            target_task_id: relation.target_task_id,
+            relation_type: relation.relation_type,
+            deleted_by,
+            timestamp,
+        })
+    }
+
+    async fn find_accessible_task(
+        &self,
+        task_id: Uuid,
+        user_id: Uuid,
+    ) -> Result<(Task, Uuid), ApplicationError> {
+        let task = self
+            .task_repository
+            .find_by_id(task_id)
+            .await?
+            .ok_or_else(|| ApplicationError::NotFound {
+                message: "Task with the given ID not found".to_string(),
+            })?;
+
+        let column = self
+            .column_repository
+            .find_by_id(task.column_id)
+            .await?
+            .ok_or_else(|| ApplicationError::NotFound {
+                message: "Column with the given ID not found".to_string(),
+            })?;
+
+        if self
+            .board_member_repository
+            .find_by_board_and_user_id(column.board_id, user_id)
+            .await?
+            .is_none()
+        {
+            return Err(ApplicationError::Forbidden {
+                message: "You don't have access to this board".to_string(),
+            });
+        }
+
+        Ok((task, column.board_id))
+    }
+
+    /// Gives every task of the column a fresh, short key once a generated key grows too long.
+    /// `placed` holds the tasks being created or moved, ordered by their generated keys; those
+    /// keys are replaced with the rebalanced ones for the caller to persist, all other tasks
+    /// are updated here.
+    async fn rebalance_column_tasks(
+        unit_of_work: &dyn UnitOfWork,
+        column_id: Uuid,
+        placed: &mut [(Uuid, String)],
+    ) -> Result<Vec<EntityPosition>, ApplicationError> {
+        let placed_ids: HashSet<Uuid> = placed.iter().map(|(id, _)| *id).collect();
+
+        let mut ordered_tasks: Vec<(Uuid, String)> = unit_of_work
+            .task_repository()
+            .find_by_column_id(column_id, true)
+            .await?
+            .into_iter()
+            .filter(|t| !placed_ids.contains(&t.id))
+            .map(|t| (t.id, t.position))
+            .collect();
+        ordered_tasks.extend(placed.iter().cloned());
+        ordered_tasks.sort_by(|a, b| a.1.cmp(&b.1));
+
+        let keys = FractionalIndexGenerator::sequence(ordered_tasks.len()).map_err(|err| {
+            ApplicationError::InternalError {
+                message: format!("Failed to rebalance task positions: {}", err),
+            }
+        })?;
+        let positions: Vec<EntityPosition> = ordered_tasks
+            .into_iter()
+            .zip(keys)
+            .map(|((id, _), position)| EntityPosition { id, position })
+            .collect();
+
+        if positions
+            .iter()
+            .any(|p| p.position.len() > MAX_POSITION_LENGTH)
+        {
+            return Err(ApplicationError::BadRequest {
+                message: "The column holds too many tasks to rebalance their positions".to_string(),
+            });
+        }
+
+        let mut other_positions = Vec::with_capacity(positions.len());
+        for p in &positions {
+            match placed.iter_mut().find(|(id, _)| *id == p.id) {
+                Some((_, key)) => *key = p.position.clone(),
+                None => other_positions.push((p.id, p.position.clone())),
+            }
+        }
+
+        unit_of_work
+            .task_repository()
+            .update_positions(other_positions)
+            .await?;
+
+        Ok(positions)
+    }
+
+    fn validate_schedule(
+        start_at: Option<DateTime<FixedOffset>>,
+        due_at: Option<DateTime<FixedOffset>>,
+    ) -> Result<(), ApplicationError> {
+        if let (Some(start_at), Some(due_at)) = (start_at, due_at)
+            && start_at >= due_at
+        {
+            return Err(ApplicationError::BadRequest {
+                message: "Task start date must be before its due date".to_string(),
+            });
+        }
+
+        Ok(())
+    }
+
+    async fn validate_assignees(
+        &self,
+        board_id: Uuid,
+        assignee_ids: Vec<Uuid>,
+    ) -> Result<Vec<Uuid>, ApplicationError> {
+        if assignee_ids.is_empty() {
+            return Ok(assignee_ids);
+        }
+
+        let member_ids: HashSet<Uuid> = self
+            .board_member_repository
+            .find_by_board_id(board_id)
+            .await?
+            .into_iter()
+            .map(|m| m.user_id)
+            .collect();
+
+        let mut unique_ids = HashSet::new();
+        let mut validated_ids = Vec::new();
+
+        for assignee_id in assignee_ids {
+            if !member_ids.contains(&assignee_id) {
+                return Err(ApplicationError::BadRequest {
+                    message: format!("User '{}' is not a member of this board", assignee_id),
+                });
+            }
+
+            if unique_ids.insert(assignee_id) {
+                validated_ids.push(assignee_id);
+            }
+        }
+
+        Ok(validated_ids)
+    }
+
+    async fn validate_labels(
+        &self,
+        board_id: Uuid,
+        label_ids: Vec<Uuid>,
+    ) -> Result<Vec<Uuid>, ApplicationError> {
+        if label_ids.is_empty() {
+            return Ok(label_ids);
+        }
+
+        let board_label_ids: HashSet<Uuid> = self
+            .label_repository
+            .find_by_ids(label_ids.clone())
+            .await?
+            .into_iter()
+            .filter(|label| label.board_id == board_id)
+            .map(|label| label.id)
+            .collect();
+
+        let mut unique_ids = HashSet::new();
+        let mut validated_ids = Vec::new();
+
+        for label_id in label_ids {
+            if !board_label_ids.contains(&label_id) {
+                return Err(ApplicationError::BadRequest {
+                    message: format!("Label '{}' doesn't belong to this board", label_id),
+                });
+            }
+
+            if unique_ids.insert(label_id) {
+                validated_ids.push(label_id);
+            }
+        }
+
+        Ok(validated_ids)
+    }
+
+    async fn find_board_custom_fields(
+        &self,
+        board_id: Uuid,
+        field_ids: Vec<Uuid>,
+    ) -> Result<HashMap<Uuid, CustomField>, ApplicationError> {
+        if field_ids.is_empty() {
+            return Ok(HashMap::new());
+        }
+
+        Ok(self
+            .custom_field_repository
+            .find_by_ids(field_ids)
+            .await?
+            .into_iter()
+            .filter(|field| field.board_id == board_id)
+            .map(|field| (field.id, field))
+            .collect())
+    }
+
+    /// Applies the given values onto `custom_fields`. A `null` value, blank text and an empty
+    /// multi-select clear the field; every other value is checked against the field's type.
+    async fn validate_custom_fields(
+        &self,
+        board_id: Uuid,
+        values: HashMap<Uuid, Value>,
+        custom_fields: &mut HashMap<Uuid, Value>,
+    ) -> Result<(), ApplicationError> {
+        let fields = self
+            .find_board_custom_fields(board_id, values.keys().copied().collect())
+            .await?;
+
+        let member_ids: HashSet<Uuid> = if fields
+            .values()
+            .any(|field| field.field_type == CustomFieldTypeEnum::User)
+        {
+            self.board_member_repository
+                .find_by_board_id(board_id)
+                .await?
+                .into_iter()
+                .map(|m| m.user_id)
+                .collect()
+        } else {
+            HashSet::new()
+        };
+
+        for (field_id, value) in values {
+            let field = fields
+                .get(&field_id)
+                .ok_or_else(|| ApplicationError::BadRequest {
+                    message: format!("Custom field '{}' doesn't belong to this board", field_id),
+                })?;
+
+            match Self::parse_custom_field_value(field, value)? {
+                Some(value) => {
+                    if field.field_type == CustomFieldTypeEnum::User
+                        && !value
+                            .as_str()
+                            .and_then(|id| Uuid::parse_str(id).ok())
+                            .is_some_and(|id| member_ids.contains(&id))
+                    {
+                        return Err(ApplicationError::BadRequest {
+                            message: format!(
+                                "Custom field '{}' only accepts members of this board",
+                                field.name
+                            ),
+                        });
+                    }
+
+                    custom_fields.insert(field_id, value);
+                }
+                None => {
+                    custom_fields.remove(&field_id);
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Checks a value against the field's type and returns it in its stored form, or `None`
+    /// when the value clears the field
+    fn parse_custom_field_value(
+        field: &CustomField,
+        value: Value,
+    ) -> Result<Option<Value>, ApplicationError> {
+        let invalid = || ApplicationError::BadRequest {
+            message: format!("Invalid value for custom field '{}'", field.name),
+        };
+
+        let parsed = match field.field_type {
+            _ if value.is_null() => None,
+            CustomFieldTypeEnum::Text => {
+                let text = value.as_str().ok_or_else(invalid)?.trim();
+                if text.chars().count() > MAX_CUSTOM_FIELD_TEXT_LENGTH {
+                    return Err(ApplicationError::BadRequest {
+                        message: format!(
+                            "Custom field '{}' can hold up to {} characters",
+                            field.name, MAX_CUSTOM_FIELD_TEXT_LENGTH
+                        ),
+                    });
+                }
+
+                (!text.is_empty()).then(|| Value::from(text))
+            }
+            CustomFieldTypeEnum::Number => {
+                if !value.is_number() {
+                    return Err(invalid());
+                }
+
+                Some(value)
+            }
+            CustomFieldTypeEnum::Date => {
+                let date = value
+                    .as_str()
+                    .and_then(|date| NaiveDate::parse_from_str(date, "%Y-%m-%d").ok())
+                    .ok_or_else(invalid)?;
+
+                Some(Value::from(date.to_string()))
+            }
+            CustomFieldTypeEnum::SingleSelect => {
+                let option = value.as_str().ok_or_else(invalid)?;
+                if !field.options.iter().any(|existing| existing == option) {
+                    return Err(invalid());
+                }
+
+                Some(value)
+            }
+            CustomFieldTypeEnum::MultiSelect => {
+                let mut selected: Vec<Value> = Vec::new();
+                for option in value.as_array().ok_or_else(invalid)? {
+                    if !option
+                        .as_str()
+                        .is_some_and(|option| field.options.iter().any(|o| o == option))
+                    {
+                        return Err(invalid());
+                    }
+
+                    if !selected.contains(option) {
+                        selected.push(option.clone());
+                    }
+                }
+
+                (!selected.is_empty()).then_some(Value::Array(selected))
+            }
+            CustomFieldTypeEnum::User => {
+                let user_id = value
+                    .as_str()
+                    .and_then(|id| Uuid::parse_str(id).ok())
+                    .ok_or_else(invalid)?;
+
+                Some(Value::from(user_id.to_string()))
+            }
+        };
+
+        Ok(parsed)
+    }
+
+    fn custom_field_condition(
+        field: &CustomField,
+        operator: CustomFieldOperatorDto,
+        value: Option<Value>,
+    ) -> Result<CustomFieldCondition, ApplicationError> {
+        let unsupported = || ApplicationError::BadRequest {
+            message: format!(
+                "Custom field '{}' can't be filtered with this operator",
+                field.name
+            ),
+        };
+        let required = || ApplicationError::BadRequest {
+            message: format!(
+                "A valid value is required to filter by custom field '{}'",
+                field.name
+            ),
+        };
+
+        let condition = match operator {
+            CustomFieldOperatorDto::IsSet => CustomFieldCondition::IsSet(true),
+            CustomFieldOperatorDto::IsNotSet => CustomFieldCondition::IsSet(false),
+            // Multi-select values are matched by any one of their options
+            CustomFieldOperatorDto::Equals
+                if field.field_type == CustomFieldTypeEnum::MultiSelect =>
+            {
+                let value = Self::parse_custom_field_value(
+                    field,
+                    Value::Array(vec![value.ok_or_else(required)?]),
+                )?
+                .ok_or_else(required)?;
+
+                CustomFieldCondition::Contains(value)
+            }
+            CustomFieldOperatorDto::Equals => {
+                let value = Self::parse_custom_field_value(field, value.ok_or_else(required)?)?
+                    .ok_or_else(required)?;
+
+                CustomFieldCondition::Contains(value)
+            }
+            CustomFieldOperatorDto::Contains => {
+                if field.field_type != CustomFieldTypeEnum::Text {
+                    return Err(unsupported());
+                }
+
+                let value = Self::parse_custom_field_value(field, value.ok_or_else(required)?)?
+                    .ok_or_else(required)?;
+
+                CustomFieldCondition::TextContains(value.as_str().unwrap_or_default().to_string())
+            }
+            CustomFieldOperatorDto::Gte | CustomFieldOperatorDto::Lte => {
+                let is_min = operator == CustomFieldOperatorDto::Gte;
+
+                match field.field_type {
+                    CustomFieldTypeEnum::Number => {
+                        let bound = value.as_ref().and_then(Value::as_f64);
+                        if bound.is_none() {
+                            return Err(required());
+                        }
+
+                        CustomFieldCondition::NumberRange {
+                            min: bound.filter(|_| is_min),
+                            max: bound.filter(|_| !is_min),
+                        }
+                    }
+                    CustomFieldTypeEnum::Date => {
+                        let bound = value
+                            .as_ref()
+                            .and_then(Value::as_str)
+                            .and_then(|date| NaiveDate::parse_from_str(date, "%Y-%m-%d").ok());
+                        if bound.is_none() {
+                            return Err(required());
+                        }
+
+                        CustomFieldCondition::DateRange {
+                            min: bound.filter(|_| is_min),
+                            max: bound.filter(|_| !is_min),
+                        }
+                    }
+                    _ => return Err(unsupported()),
+                }
+            }
+        };
+
+        Ok(condition)
+    }
+
+    /// Maps labels onto the given board by name, creating the ones the board doesn't have yet
+    /// with the same colour. Returns the mapped IDs and the events announcing created labels.
+    async fn carry_labels(
+        unit_of_work: &dyn UnitOfWork,
+        label_ids: &[Uuid],
+        board_id: Uuid,
+        user_id: Uuid,
+    ) -> Result<(Vec<Uuid>, Vec<BoardEvent>), ApplicationError> {
+        let mut labels: HashMap<Uuid, Label> = unit_of_work
+            .label_repository()
+            .find_by_ids(label_ids.to_vec())
+            .await?
+            .into_iter()
+            .map(|label| (label.id, label))
+            .collect();
+
+        let mut carried_ids = Vec::with_capacity(label_ids.len());
+        let mut events = Vec::new();
+
+        for label_id in label_ids {
+            let Some(label) = labels.remove(label_id) else {
+                continue;
+            };
+
+            if label.board_id == board_id {
+                carried_ids.push(label.id);
+                continue;
+            }
+
+            let existing = unit_of_work
+                .label_repository()
+                .find_by_board_and_name(board_id, &label.name)
+                .await?;
+
+            let carried = match existing {
+                Some(existing) => existing,
+                None => {
+                    let created = unit_of_work
+                        .label_repository()
+                        .create(Label::new(
+                            Uuid::now_v7(),
+                            board_id,
+                            label.name,
+                            label.color,
+                        ))
+                        .await?;
+
+                    events.push(BoardEvent::LabelCreated(LabelCreatedEvent {
+                        label_id: created.id,
+                        name: created.name.clone(),
+                        color: created.color.clone(),
+                        created_by: user_id,
+                        timestamp: created.created_at,
+                    }));
+                    created
+                }
+            };
+
+            if !carried_ids.contains(&carried.id) {
+                carried_ids.push(carried.id);
+            }
+        }
+
+        Ok((carried_ids, events))
+    }
+
+    async fn copy_attachment_blob(
+        &self,
+        source_key: &str,
+        attachment: &Attachment,
+    ) -> Result<(), ApplicationError> {
+        let data = self
+            .storage_service
+            .get_object(source_key)
+            .await
+            .map_err(|err| ApplicationError::InternalError { message: err })?
+            .try_fold(BytesMut::new(), |mut data, chunk| async move {
+                data.extend_from_slice(&chunk);
+                Ok(data)
+            })
+            .await
+            .map_err(|err| ApplicationError::InternalError {
+                message: format!("Failed to read attachment '{}': {}", source_key, err),
+            })?;
+
+        self.storage_service
+            .put_object(
+                &attachment.storage_key,
+                &attachment.content_type,
+                data.freeze(),
+            )
+            .await
+            .map_err(|err| ApplicationError::InternalError { message: err })
+    }
+
+    async fn remove_attachment_blobs(&self, storage_keys: Vec<String>) {
+        if storage_keys.is_empty() {
+            return;
+        }
+
+        if let Err(err) = self.storage_service.delete_objects(&storage_keys).await {
+            warn!("Failed to remove attachment blobs: {}", err);
+        }
     }
 }
@@ -0,0 +1,577 @@
+use crate::{
+    application::dto::{
+        CreateTimeEntryDto, DailyTimeDto, StartTimerDto, TaskTimeDto, TimeEntryDto, TimeReportDto,
+        TimeReportQueryDto, UpdateTimeEntryDto, UserTimeDto,
+    },
+    domain::{
+        events::{
+            BoardEvent, TimeEntryDeletedEvent, TimeEntryLoggedEvent, TimeEntryUpdatedEvent,
+            TimerStartedEvent, TimerStoppedEvent,
+        },
+        repositories::{
+            BoardMemberRepository, ColumnRepository, OutboxMessage, Task, TaskRepository,
+            TimeEntry, TimeEntryFilter, TimeEntryRepository, UnitOfWorkFactory,
+        },
+    },
+    shared::error::ApplicationError,
+};
+use chrono::{DateTime, Days, Duration, FixedOffset, NaiveDate, Utc};
+use entity::BoardMemberRoleEnum;
+use std::{cmp::Reverse, collections::HashMap, sync::Arc};
+use uuid::Uuid;
+use validator::Validate;
+
+const DEFAULT_RANGE_DAYS: u64 = 30;
+const MAX_RANGE_DAYS: i64 = 366;
+const MAX_ENTRY_HOURS: i64 = 24;
+
+pub struct TimeEntryService {
+    time_entry_repository: Arc<dyn TimeEntryRepository>,
+    task_repository: Arc<dyn TaskRepository>,
+    column_repository: Arc<dyn ColumnRepository>,
+    board_member_repository: Arc<dyn BoardMemberRepository>,
+    unit_of_work_factory: Arc<dyn UnitOfWorkFactory>,
+}
+
+impl TimeEntryService {
+    pub fn new(
+        time_entry_repository: Arc<dyn TimeEntryRepository>,
+        task_repository: Arc<dyn TaskRepository>,
+        column_repository: Arc<dyn ColumnRepository>,
+        board_member_repository: Arc<dyn BoardMemberRepository>,
+        unit_of_work_factory: Arc<dyn UnitOfWorkFactory>,
+    ) -> Self {
+        Self {
+            time_entry_repository,
+            task_repository,
+            column_repository,
+            board_member_repository,
+            unit_of_work_factory,
+        }
+    }
+
+    pub async fn log_time_entry(
+        &self,
+        dto: CreateTimeEntryDto,
+        user_id: Uuid,
+    ) -> Result<TimeEntryDto, ApplicationError> {
+        dto.validate()?;
+
+        let (task, board_id) = self.find_open_task(dto.task_id, user_id).await?;
+        let ended_at = Self::resolve_end(dto.started_at, dto.ended_at, dto.duration_minutes)?;
+
+        let unit_of_work = self.unit_of_work_factory.begin().await?;
+
+        let entry = unit_of_work
+            .time_entry_repository()
+            .create(TimeEntry::new(
+                Uuid::now_v7(),
+                task.id,
+                user_id,
+                dto.started_at,
+                Some(ended_at),
+                Self::normalize_note(dto.note),
+            ))
+            .await?;
+
+        unit_of_work
+            .outbox_repository()
+            .create(OutboxMessage::new(
+                Uuid::now_v7(),
+                board_id,
+                BoardEvent::TimeEntryLogged(TimeEntryLoggedEvent {
+                    entry_id: entry.id,
+                    task_id: entry.task_id,
+                    user_id,
+                    started_at: entry.started_at,
+                    ended_at,
+                    note: entry.note.clone(),
+                    timestamp: entry.created_at,
+                }),
+            ))
+            .await?;
+
+        unit_of_work.commit().await?;
+
+        Ok(TimeEntryDto::from_domain(entry))
+    }
+
+    pub async fn start_timer(
+        &self,
+        task_id: Uuid,
+        dto: StartTimerDto,
+        user_id: Uuid,
+    ) -> Result<TimeEntryDto, ApplicationError> {
+        dto.validate()?;
+
+        let (task, board_id) = self.find_open_task(task_id, user_id).await?;
+
+        let unit_of_work = self.unit_of_work_factory.begin().await?;
+
+        if unit_of_work
+            .time_entry_repository()
+            .find_running_by_user_id(user_id)
+            .await?
+            .is_some()
+        {
+            return Err(ApplicationError::Conflict {
+                message: "You already have a running timer".to_string(),
+            });
+        }
+
+        let entry = unit_of_work
+            .time_entry_repository()
+            .create(TimeEntry::new(
+                Uuid::now_v7(),
+                task.id,
+                user_id,
+                Utc::now().fixed_offset(),
+                None,
+                Self::normalize_note(dto.note),
+            ))
+            .await?;
+
+        unit_of_work
+            .outbox_repository()
+            .create(OutboxMessage::new(
+                Uuid::now_v7(),
+                board_id,
+                BoardEvent::TimerStarted(TimerStartedEvent {
+                    entry_id: entry.id,
+                    task_id: entry.task_id,
+                    user_id,
+                    started_at: entry.started_at,
+                    note: entry.note.clone(),
+                    timestamp: entry.created_at,
+                }),
+            ))
+            .await?;
+
+        unit_of_work.commit().await?;
+
+        Ok(TimeEntryDto::from_domain(entry))
+    }
+
+    pub async fn stop_timer(&self, user_id: Uuid) -> Result<TimeEntryDto, ApplicationError> {
+        let mut entry = self
+            .time_entry_repository
+            .find_running_by_user_id(user_id)
+            .await?
+            .ok_or_else(|| ApplicationError::NotFound {
+                message: "You don't have a running timer".to_string(),
+            })?;
+
+        // Users can stop their timer even after leaving the board
+        let (_, board_id) = self.find_task(entry.task_id).await?;
+
+        let now = Utc::now().fixed_offset();
+        entry.ended_at = Some(now);
+        entry.updated_at = now;
+
+        let unit_of_work = self.unit_of_work_factory.begin().await?;
+
+        let stopped_entry = unit_of_work.time_entry_repository().update(entry).await?;
+
+        unit_of_work
+            .outbox_repository()
+            .create(OutboxMessage::new(
+                Uuid::now_v7(),
+                board_id,
+                BoardEvent::TimerStopped(TimerStoppedEvent {
+                    entry_id: stopped_entry.id,
+                    task_id: stopped_entry.task_id,
+                    user_id,
+                    started_at: stopped_entry.started_at,
+                    ended_at: now,
+                    timestamp: now,
+                }),
+            ))
+            .await?;
+
+        unit_of_work.commit().await?;
+
+        Ok(TimeEntryDto::from_domain(stopped_entry))
+    }
+
+    pub async fn get_running_timers(
+        &self,
+        board_id: Uuid,
+        user_id: Uuid,
+    ) -> Result<Vec<TimeEntryDto>, ApplicationError> {
+        self.ensure_member(board_id, user_id).await?;
+
+        let entries = self
+            .time_entry_repository
+            .find_running_by_board_id(board_id)
+            .await?;
+
+        Ok(entries.into_iter().map(TimeEntryDto::from_domain).collect())
+    }
+
+    pub async fn get_task_time_entries(
+        &self,
+        task_id: Uuid,
+        user_id: Uuid,
+    ) -> Result<Vec<TimeEntryDto>, ApplicationError> {
+        let (_, board_id) = self.find_task(task_id).await?;
+
+        self.ensure_member(board_id, user_id).await?;
+
+        let entries = self.time_entry_repository.find_by_task_id(task_id).await?;
+
+        Ok(entries.into_iter().map(TimeEntryDto::from_domain).collect())
+    }
+
+    pub async fn update_time_entry(
+        &self,
+        entry_id: Uuid,
+        dto: UpdateTimeEntryDto,
+        user_id: Uuid,
+    ) -> Result<TimeEntryDto, ApplicationError> {
+        dto.validate()?;
+
+        let mut entry = self.find_time_entry(entry_id).await?;
+        let (_, board_id) = self.find_task(entry.task_id).await?;
+
+        self.ensure_author_or_moderator(&entry, board_id, user_id)
+            .await?;
+
+        if let Some(started_at) = dto.started_at {
+            entry.started_at = started_at;
+        }
+
+        if entry.is_running() {
+            if dto.ended_at.is_some() || dto.duration_minutes.is_some() {
+                return Err(ApplicationError::BadRequest {
+                    message: "A running timer can only be ended by stopping it".to_string(),
+                });
+            }
+            if entry.started_at > Utc::now() {
+                return Err(ApplicationError::BadRequest {
+                    message: "A timer can't start in the future".to_string(),
+                });
+            }
+        } else {
+            // Keeping the current end still re-checks it against a moved start
+            let (ended_at, duration_minutes) = match (dto.ended_at, dto.duration_minutes) {
+                (None, None) => (entry.ended_at, None),
+                given => given,
+            };
+            entry.ended_at = Some(Self::resolve_end(
+                entry.started_at,
+                ended_at,
+                duration_minutes,
+            )?);
+        }
+
+        if dto.note.is_some() {
+            entry.note = Self::normalize_note(dto.note);
+        }
+        entry.updated_at = Utc::now().fixed_offset();
+
+        let unit_of_work = self.unit_of_work_factory.begin().await?;
+
+        let updated_entry = unit_of_work.time_entry_repository().update(entry).await?;
+
+        unit_of_work
+            .outbox_repository()
+            .create(OutboxMessage::new(
+                Uuid::now_v7(),
+                board_id,
+                BoardEvent::TimeEntryUpdated(TimeEntryUpdatedEvent {
+                    entry_id,
+                    task_id: updated_entry.task_id,
+                    started_at: updated_entry.started_at,
+                    ended_at: updated_entry.ended_at,
+                    note: updated_entry.note.clone(),
+                    updated_by: user_id,
+                    timestamp: updated_entry.updated_at,
+                }),
+            ))
+            .await?;
+
+        unit_of_work.commit().await?;
+
+        Ok(TimeEntryDto::from_domain(updated_entry))
+    }
+
+    pub async fn delete_time_entry(
+        &self,
+        entry_id: Uuid,
+        user_id: Uuid,
+    ) -> Result<u64, ApplicationError> {
+        let entry = self.find_time_entry(entry_id).await?;
+        let (_, board_id) = self.find_task(entry.task_id).await?;
+
+        self.ensure_author_or_moderator(&entry, board_id, user_id)
+            .await?;
+
+        let unit_of_work = self.unit_of_work_factory.begin().await?;
+
+        let deleted_entry = unit_of_work
+            .time_entry_repository()
+            .delete(entry_id)
+            .await?;
+
+        unit_of_work
+            .outbox_repository()
+            .create(OutboxMessage::new(
+                Uuid::now_v7(),
+                board_id,
+                BoardEvent::TimeEntryDeleted(TimeEntryDeletedEvent {
+                    entry_id,
+                    task_id: entry.task_id,
+                    deleted_by: user_id,
+                    timestamp: Utc::now().fixed_offset(),
+                }),
+            ))
+            .await?;
+
+        unit_of_work.commit().await?;
+
+        Ok(deleted_entry)
+    }
+
+    pub async fn get_time_report(
+        &self,
+        board_id: Uuid,
+        query: TimeReportQueryDto,
+        user_id: Uuid,
+    ) -> Result<TimeReportDto, ApplicationError> {
+        let to = query.to.unwrap_or_else(|| Utc::now().date_naive());
+        let from = match query.from {
+            Some(from) => from,
+            None => to
+                .checked_sub_days(Days::new(DEFAULT_RANGE_DAYS - 1))
+                .unwrap_or(to),
+        };
+
+        if from > to {
+            return Err(ApplicationError::BadRequest {
+                message: "Start of the date range must be before its end".to_string(),
+            });
+        }
+        if (to - from).num_days() >= MAX_RANGE_DAYS {
+            return Err(ApplicationError::BadRequest {
+                message: format!("Date range can't span more than {} days", MAX_RANGE_DAYS),
+            });
+        }
+
+        self.ensure_member(board_id, user_id).await?;
+
+        let summaries = self
+            .time_entry_repository
+            .summarize_by_board_id(
+                board_id,
+                TimeEntryFilter {
+                    user_id: query.user_id,
+                    from: Self::start_of_day(from),
+                    to: Self::start_of_day(to + Days::new(1)),
+                },
+            )
+            .await?;
+
+        let mut user_totals: HashMap<Uuid, i64> = HashMap::new();
+        let mut task_totals: HashMap<Uuid, i64> = HashMap::new();
+        let mut day_totals: HashMap<NaiveDate, i64> = HashMap::new();
+        for summary in &summaries {
+            *user_totals.entry(summary.user_id).or_default() += summary.total_seconds;
+            *task_totals.entry(summary.task_id).or_default() += summary.total_seconds;
+            *day_totals.entry(summary.date).or_default() += summary.total_seconds;
+        }
+
+        let tasks: HashMap<Uuid, Task> = self
+            .task_repository
+            .find_by_ids(task_totals.keys().copied().collect())
+            .await?
+            .into_iter()
+            .map(|task| (task.id, task))
+            .collect();
+
+        let mut users: Vec<UserTimeDto> = user_totals
+            .into_iter()
+            .map(|(user_id, total_seconds)| UserTimeDto {
+                user_id,
+                total_seconds,
+            })
+            .collect();
+        users.sort_by_key(|user| Reverse(user.total_seconds));
+
+        let mut task_times: Vec<TaskTimeDto> = task_totals
+            .into_iter()
+            .filter_map(|(task_id, total_seconds)| {
+                let task = tasks.get(&task_id)?;
+                Some(TaskTimeDto {
+                    task_id,
+                    title: task.title.clone(),
+                    estimate_minutes: task.estimate_minutes,
+                    total_seconds,
+                })
+            })
+            .collect();
+        task_times.sort_by_key(|task| Reverse(task.total_seconds));
+
+        let days = from
+            .iter_days()
+            .take_while(|date| *date <= to)
+            .map(|date| DailyTimeDto {
+                date,
+                total_seconds: day_totals.get(&date).copied().unwrap_or(0),
+            })
+            .collect();
+
+        Ok(TimeReportDto {
+            board_id,
+            user_id: query.user_id,
+            from,
+            to,
+            total_seconds: summaries.iter().map(|summary| summary.total_seconds).sum(),
+            users,
+            tasks: task_times,
+            days,
+        })
+    }
+
+    async fn find_time_entry(&self, entry_id: Uuid) -> Result<TimeEntry, ApplicationError> {
+        self.time_entry_repository
+            .find_by_id(entry_id)
+            .await?
+            .ok_or_else(|| ApplicationError::NotFound {
+                message: "Time entry with the given ID not found".to_string(),
+            })
+    }
+
+    async fn find_task(&self, task_id: Uuid) -> Result<(Task, Uuid), ApplicationError> {
+        let task = self
+            .task_repository
+            .find_by_id(task_id)
+            .await?
+            .ok_or_else(|| ApplicationError::NotFound {
+                message: "Task with the given ID not found".to_string(),
+            })?;
+
+        let column = self
+            .column_repository
+            .find_by_id(task.column_id)
+            .await?
+            .ok_or_else(|| ApplicationError::NotFound {
+                message: "Column with the given ID not found".to_string(),
+            })?;
+
+        Ok((task, column.board_id))
+    }
+
+    /// Time can only be tracked on tasks that haven't been archived
+    async fn find_open_task(
+        &self,
+        task_id: Uuid,
+        user_id: Uuid,
+    ) -> Result<(Task, Uuid), ApplicationError> {
+        let (task, board_id) = self.find_task(task_id).await?;
+
+        self.ensure_member(board_id, user_id).await?;
+
+        if task.archived_at.is_some() {
+            return Err(ApplicationError::BadRequest {
+                message: "Time can't be tracked on archived tasks".to_string(),
+            });
+        }
+
+        Ok((task, board_id))
+    }
+
+    /// Resolves the end of an entry from either its end or its duration
+    fn resolve_end(
+        started_at: DateTime<FixedOffset>,
+        ended_at: Option<DateTime<FixedOffset>>,
+        duration_minutes: Option<i64>,
+    ) -> Result<DateTime<FixedOffset>, ApplicationError> {
+        let ended_at = match (ended_at, duration_minutes) {
+            (Some(ended_at), None) => ended_at,
+            (None, Some(duration_minutes)) => started_at + Duration::minutes(duration_minutes),
+            (Some(_), Some(_)) => {
+                return Err(ApplicationError::BadRequest {
+                    message: "Give either the end or the duration of the entry, not both"
+                        .to_string(),
+                });
+            }
+            (None, None) => {
+                return Err(ApplicationError::BadRequest {
+                    message: "Either the end or the duration of the entry must be given"
+                        .to_string(),
+                });
+            }
+        };
+
+        if ended_at <= started_at {
+            return Err(ApplicationError::BadRequest {
+                message: "Time entry must end after it starts".to_string(),
+            });
+        }
+        if ended_at > Utc::now() {
+            return Err(ApplicationError::BadRequest {
+                message: "Time entries can't end in the future".to_string(),
+            });
+        }
+        if ended_at - started_at > Duration::hours(MAX_ENTRY_HOURS) {
+            return Err(ApplicationError::BadRequest {
+                message: format!("Time entry can't span more than {} hours", MAX_ENTRY_HOURS),
+            });
+        }
+
+        Ok(ended_at)
+    }
+
+    fn normalize_note(note: Option<String>) -> Option<String> {
+        note.map(|note| note.trim().to_string())
+            .filter(|note| !note.is_empty())
+    }
+
+    fn start_of_day(date: NaiveDate) -> DateTime<FixedOffset> {
+        date.and_time(Default::default()).and_utc().fixed_offset()
+    }
+
+    async fn ensure_member(&self, board_id: Uuid, user_id: Uuid) -> Result<(), ApplicationError> {
+        if self
+            .board_member_repository
+            .find_by_board_and_user_id(board_id, user_id)
+            .await?
+            .is_none()
+        {
+            return Err(ApplicationError::Forbidden {
+                message: "You don't have access to this board".to_string(),
+            });
+        }
+
+        Ok(())
+    }
+
+    async fn ensure_author_or_moderator(
+        &self,
+        entry: &TimeEntry,
+        board_id: Uuid,
+        user_id: Uuid,
+    ) -> Result<(), ApplicationError> {
+        let allowed_roles = if entry.user_id == user_id {
+            vec![
+                BoardMemberRoleEnum::Owner,
+                BoardMemberRoleEnum::Moderator,
+                BoardMemberRoleEnum::Member,
+            ]
+        } else {
+            vec![BoardMemberRoleEnum::Owner, BoardMemberRoleEnum::Moderator]
+        };
+
+        if !self
+            .board_member_repository
+            .check_permissions(board_id, user_id, allowed_roles)
+            .await?
+        {
+            return Err(ApplicationError::Forbidden {
+                message: "You don't have permission to perform this action".to_string(),
+            });
+        }
+
+        Ok(())
+    }
+}
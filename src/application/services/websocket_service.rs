@@ -1,12 +1,14 @@
 use crate::{
+    application::dto::{ResyncRequiredDto, WebSocketControlMessage},
     domain::{
-        events::{BoardEvent, SharedEventBus},
-        repositories::BoardMemberRepository,
+        events::{BoardEventEnvelope, SharedEventBus},
+        repositories::{BoardActivityRepository, BoardMemberRepository},
     },
-    shared::error::ApplicationError,
+    shared::{error::ApplicationError, utils::constants::WEBSOCKET_MAX_REPLAY_EVENTS},
 };
 use actix_ws::{Message, MessageStream, Session};
 use futures_util::StreamExt;
+use serde::Serialize;
 use std::sync::Arc;
 use tokio::sync::broadcast;
 use tracing::{info, warn};
@@ -15,16 +17,19 @@ use uuid::Uuid;
 pub struct WebSocketService {
     event_bus: SharedEventBus,
     board_member_repository: Arc<dyn BoardMemberRepository>,
+    board_activity_repository: Arc<dyn BoardActivityRepository>,
 }
 
 impl WebSocketService {
     pub fn new(
         event_bus: SharedEventBus,
         board_member_repository: Arc<dyn BoardMemberRepository>,
+        board_activity_repository: Arc<dyn BoardActivityRepository>,
     ) -> Self {
         Self {
             event_bus,
             board_member_repository,
+            board_activity_repository,
         }
     }
 
@@ -48,7 +53,10 @@ impl WebSocketService {
         Ok(())
     }
 
-    pub async fn subscribe_to_board(&self, board_id: Uuid) -> broadcast::Receiver<BoardEvent> {
+    pub async fn subscribe_to_board(
+        &self,
+        board_id: Uuid,
+    ) -> broadcast::Receiver<BoardEventEnvelope> {
         self.event_bus.subscribe(board_id).await
     }
 
@@ -56,26 +64,63 @@ impl WebSocketService {
         &self,
         board_id: Uuid,
         user_id: Uuid,
+        since: Option<i64>,
         mut session: Session,
         mut msg_stream: MessageStream,
     ) {
         info!("User '{}' connected to board '{}'", user_id, board_id);
 
+        // Subscribe before reading the store so that nothing published in between is lost
         let mut rx = self.subscribe_to_board(board_id).await;
 
-        loop {
+        let mut last_sequence = match since {
+            Some(since) => self.replay_events(board_id, since, &mut session).await,
+            None => self
+                .board_activity_repository
+                .find_latest_sequence(board_id)
+                .await
+                .inspect_err(|err| {
+                    warn!(
+                        "Failed to read latest sequence of board '{}': {}",
+                        board_id, err
+                    )
+                })
+                .ok(),
+        };
+
+        while let Some(sequence) = last_sequence {
             tokio::select! {
-                event = rx.recv() => {
-                    match event {
-                        Ok(event) => {
-                            if let Ok(json) = serde_json::to_string(&event)
-                                && session.text(json).await.is_err() {
-                                break;
+                envelope = rx.recv() => {
+                    match envelope {
+                        Ok(envelope) => {
+                            // Already delivered during replay or published before the connection
+                            if envelope.sequence <= sequence {
+                                continue;
+                            }
+
+                            // A notification went missing on the way, so the store fills the gap
+                            // up to and including this event
+                            if envelope.sequence > sequence + 1 {
+                                warn!(
+                                    "Missed events between sequence '{}' and '{}', replaying them",
+                                    sequence, envelope.sequence
+                                );
+
+                                last_sequence = self.replay_events(board_id, sequence, &mut session).await;
+                                continue;
                             }
 
+                            last_sequence = Self::send_message(&mut session, &envelope)
+                                .await
+                                .then_some(envelope.sequence);
                         }
                         Err(broadcast::error::RecvError::Lagged(n)) => {
-                            warn!("Client lagged '{}' messages", n);
+                            warn!(
+                                "Client lagged '{}' messages, replaying from sequence '{}'",
+                                n, sequence
+                            );
+
+                            last_sequence = self.replay_events(board_id, sequence, &mut session).await;
                         }
                         Err(_) => break,
                     }
@@ -83,10 +128,8 @@ impl WebSocketService {
 
                 msg = msg_stream.next() => {
                     match msg {
-                        Some(Ok(Message::Ping(bytes))) => {
-                            if session.pong(&bytes).await.is_err() {
-                                break;
-                            }
+                        Some(Ok(Message::Ping(bytes))) if session.pong(&bytes).await.is_err() => {
+                            break;
                         }
                         Some(Ok(Message::Close(_))) | None => break,
                         _ => {}
@@ -101,4 +144,89 @@ impl WebSocketService {
 
         self.event_bus.cleanup_board(board_id).await;
     }
+
+    /// Sends every persisted event after `since` to the client, or a `resyncRequired` message
+    /// when the gap is too large to replay. Returns the last sequence the client is up to date
+    /// with, or `None` if the connection should be closed.
+    async fn replay_events(
+        &self,
+        board_id: Uuid,
+        since: i64,
+        session: &mut Session,
+    ) -> Option<i64> {
+        let max_events = *WEBSOCKET_MAX_REPLAY_EVENTS;
+
+        let activities = self
+            .board_activity_repository
+            .find_since_sequence(board_id, since, max_events + 1)
+            .await
+            .inspect_err(|err| {
+                warn!(
+                    "Failed to load missed events of board '{}': {}",
+                    board_id, err
+                )
+            })
+            .ok()?;
+
+        if activities.len() as u64 > max_events {
+            let latest_sequence = self
+                .board_activity_repository
+                .find_latest_sequence(board_id)
+                .await
+                .inspect_err(|err| {
+                    warn!(
+                        "Failed to read latest sequence of board '{}': {}",
+                        board_id, err
+                    )
+                })
+                .ok()?;
+
+            info!(
+                "Too many missed events on board '{}', requesting resync at sequence '{}'",
+                board_id, latest_sequence
+            );
+
+            let message = WebSocketControlMessage::ResyncRequired(ResyncRequiredDto {
+                board_id,
+                latest_sequence,
+            });
+
+            return Self::send_message(session, &message)
+                .await
+                .then_some(latest_sequence);
+        }
+
+        let mut last_sequence = since;
+
+        for activity in activities {
+            last_sequence = activity.sequence;
+
+            let envelope = match activity.to_envelope() {
+                Ok(envelope) => envelope,
+                Err(err) => {
+                    warn!(
+                        "Skipping unreadable event '{}' of board '{}': {}",
+                        activity.id, board_id, err
+                    );
+                    continue;
+                }
+            };
+
+            if !Self::send_message(session, &envelope).await {
+                return None;
+            }
+        }
+
+        Some(last_sequence)
+    }
+
+    async fn send_message<T: Serialize>(session: &mut Session, message: &T) -> bool {
+        match serde_json::to_string(message) {
+            Ok(json) => session.text(json).await.is_ok(),
+            Err(err) => {
+                warn!("Failed to serialize WebSocket message: {}", err);
+                true
+            }
+        }
+    }
 }
@@ -1,6 +1,8 @@
 use chrono::{DateTime, FixedOffset};
-use entity::BoardMemberRoleEnum;
+use entity::{BoardMemberRoleEnum, CustomFieldTypeEnum, TaskPriorityEnum, TaskRelationTypeEnum};
 use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::collections::HashMap;
 use uuid::Uuid;
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -9,6 +11,8 @@ pub enum BoardEvent {
     BoardCreated(BoardCreatedEvent),
     BoardUpdated(BoardUpdatedEvent),
     BoardDeleted(BoardDeletedEvent),
+    BoardArchived(BoardArchivedEvent),
+    BoardRestored(BoardRestoredEvent),
     MemberAdded(MemberAddedEvent),
     MemberRoleChanged(MemberRoleChangedEvent),
     MemberRemoved(MemberRemovedEvent),
@@ -16,10 +20,171 @@ pub enum BoardEvent {
     ColumnUpdated(ColumnUpdatedEvent),
     ColumnMoved(ColumnMovedEvent),
     ColumnDeleted(ColumnDeletedEvent),
+    ColumnArchived(ColumnArchivedEvent),
+    ColumnRestored(ColumnRestoredEvent),
     TaskCreated(TaskCreatedEvent),
     TaskUpdated(TaskUpdatedEvent),
     TaskMoved(TaskMovedEvent),
     TaskDeleted(TaskDeletedEvent),
+    TaskRemoved(TaskRemovedEvent),
+    TaskArchived(TaskArchivedEvent),
+    TaskRestored(TaskRestoredEvent),
+    TaskCompleted(TaskCompletedEvent),
+    TaskReopened(TaskReopenedEvent),
+    TaskAssigned(TaskAssignedEvent),
+    TaskUnassigned(TaskUnassignedEvent),
+    TaskRelationCreated(TaskRelationCreatedEvent),
+    TaskRelationDeleted(TaskRelationDeletedEvent),
+    LabelCreated(LabelCreatedEvent),
+    LabelUpdated(LabelUpdatedEvent),
+    LabelDeleted(LabelDeletedEvent),
+    CustomFieldCreated(CustomFieldCreatedEvent),
+    CustomFieldUpdated(CustomFieldUpdatedEvent),
+    CustomFieldDeleted(CustomFieldDeletedEvent),
+    TasksBulkUpdated(TasksBulkUpdatedEvent),
+    PositionsRebalanced(PositionsRebalancedEvent),
+    WipLimitExceeded(WipLimitExceededEvent),
+    ChecklistCreated(ChecklistCreatedEvent),
+    ChecklistUpdated(ChecklistUpdatedEvent),
+    ChecklistMoved(ChecklistMovedEvent),
+    ChecklistDeleted(ChecklistDeletedEvent),
+    ChecklistItemCreated(ChecklistItemCreatedEvent),
+    ChecklistItemUpdated(ChecklistItemUpdatedEvent),
+    ChecklistItemToggled(ChecklistItemToggledEvent),
+    ChecklistItemMoved(ChecklistItemMovedEvent),
+    ChecklistItemDeleted(ChecklistItemDeletedEvent),
+    CommentAdded(CommentAddedEvent),
+    CommentEdited(CommentEditedEvent),
+    CommentDeleted(CommentDeletedEvent),
+    AttachmentAdded(AttachmentAddedEvent),
+    AttachmentDeleted(AttachmentDeletedEvent),
+    TimerStarted(TimerStartedEvent),
+    TimerStopped(TimerStoppedEvent),
+    TimeEntryLogged(TimeEntryLoggedEvent),
+    TimeEntryUpdated(TimeEntryUpdatedEvent),
+    TimeEntryDeleted(TimeEntryDeletedEvent),
+}
+
+impl BoardEvent {
+    pub fn actor_id(&self) -> Uuid {
+        match self {
+            BoardEvent::BoardCreated(event) => event.owner_id,
+            BoardEvent::BoardUpdated(event) => event.updated_by,
+            BoardEvent::BoardDeleted(event) => event.deleted_by,
+            BoardEvent::BoardArchived(event) => event.archived_by,
+            BoardEvent::BoardRestored(event) => event.restored_by,
+            BoardEvent::MemberAdded(event) => event.added_by,
+            BoardEvent::MemberRoleChanged(event) => event.changed_by,
+            BoardEvent::MemberRemoved(event) => event.removed_by,
+            BoardEvent::ColumnCreated(event) => event.created_by,
+            BoardEvent::ColumnUpdated(event) => event.updated_by,
+            BoardEvent::ColumnMoved(event) => event.moved_by,
+            BoardEvent::ColumnDeleted(event) => event.deleted_by,
+            BoardEvent::ColumnArchived(event) => event.archived_by,
+            BoardEvent::ColumnRestored(event) => event.restored_by,
+            BoardEvent::TaskCreated(event) => event.created_by,
+            BoardEvent::TaskUpdated(event) => event.updated_by,
+            BoardEvent::TaskMoved(event) => event.moved_by,
+            BoardEvent::TaskDeleted(event) => event.deleted_by,
+            BoardEvent::TaskRemoved(event) => event.removed_by,
+            BoardEvent::TaskArchived(event) => event.archived_by,
+            BoardEvent::TaskRestored(event) => event.restored_by,
+            BoardEvent::TaskCompleted(event) => event.completed_by,
+            BoardEvent::TaskReopened(event) => event.reopened_by,
+            BoardEvent::TaskAssigned(event) => event.assigned_by,
+            BoardEvent::TaskUnassigned(event) => event.unassigned_by,
+            BoardEvent::TaskRelationCreated(event) => event.created_by,
+            BoardEvent::TaskRelationDeleted(event) => event.deleted_by,
+            BoardEvent::LabelCreated(event) => event.created_by,
+            BoardEvent::LabelUpdated(event) => event.updated_by,
+            BoardEvent::LabelDeleted(event) => event.deleted_by,
+            BoardEvent::CustomFieldCreated(event) => event.created_by,
+            BoardEvent::CustomFieldUpdated(event) => event.updated_by,
+            BoardEvent::CustomFieldDeleted(event) => event.deleted_by,
+            BoardEvent::TasksBulkUpdated(event) => event.updated_by,
+            BoardEvent::PositionsRebalanced(event) => event.rebalanced_by,
+            BoardEvent::WipLimitExceeded(event) => event.overridden_by,
+            BoardEvent::ChecklistCreated(event) => event.created_by,
+            BoardEvent::ChecklistUpdated(event) => event.updated_by,
+            BoardEvent::ChecklistMoved(event) => event.moved_by,
+            BoardEvent::ChecklistDeleted(event) => event.deleted_by,
+            BoardEvent::ChecklistItemCreated(event) => event.created_by,
+            BoardEvent::ChecklistItemUpdated(event) => event.updated_by,
+            BoardEvent::ChecklistItemToggled(event) => event.toggled_by,
+            BoardEvent::ChecklistItemMoved(event) => event.moved_by,
+            BoardEvent::ChecklistItemDeleted(event) => event.deleted_by,
+            BoardEvent::CommentAdded(event) => event.author_id,
+            BoardEvent::CommentEdited(event) => event.edited_by,
+            BoardEvent::CommentDeleted(event) => event.deleted_by,
+            BoardEvent::AttachmentAdded(event) => event.uploaded_by,
+            BoardEvent::AttachmentDeleted(event) => event.deleted_by,
+            BoardEvent::TimerStarted(event) => event.user_id,
+            BoardEvent::TimerStopped(event) => event.user_id,
+            BoardEvent::TimeEntryLogged(event) => event.user_id,
+            BoardEvent::TimeEntryUpdated(event) => event.updated_by,
+            BoardEvent::TimeEntryDeleted(event) => event.deleted_by,
+        }
+    }
+
+    pub fn timestamp(&self) -> DateTime<FixedOffset> {
+        match self {
+            BoardEvent::BoardCreated(event) => event.timestamp,
+            BoardEvent::BoardUpdated(event) => event.timestamp,
+            BoardEvent::BoardDeleted(event) => event.timestamp,
+            BoardEvent::BoardArchived(event) => event.timestamp,
+            BoardEvent::BoardRestored(event) => event.timestamp,
+            BoardEvent::MemberAdded(event) => event.timestamp,
+            BoardEvent::MemberRoleChanged(event) => event.timestamp,
+            BoardEvent::MemberRemoved(event) => event.timestamp,
+            BoardEvent::ColumnCreated(event) => event.timestamp,
+            BoardEvent::ColumnUpdated(event) => event.timestamp,
+            BoardEvent::ColumnMoved(event) => event.timestamp,
+            BoardEvent::ColumnDeleted(event) => event.timestamp,
+            BoardEvent::ColumnArchived(event) => event.timestamp,
+            BoardEvent::ColumnRestored(event) => event.timestamp,
+            BoardEvent::TaskCreated(event) => event.timestamp,
+            BoardEvent::TaskUpdated(event) => event.timestamp,
+            BoardEvent::TaskMoved(event) => event.timestamp,
+            BoardEvent::TaskDeleted(event) => event.timestamp,
+            BoardEvent::TaskRemoved(event) => event.timestamp,
+            BoardEvent::TaskArchived(event) => event.timestamp,
+            BoardEvent::TaskRestored(event) => event.timestamp,
+            BoardEvent::TaskCompleted(event) => event.timestamp,
+            BoardEvent::TaskReopened(event) => event.timestamp,
+            BoardEvent::TaskAssigned(event) => event.timestamp,
+            BoardEvent::TaskUnassigned(event) => event.timestamp,
+            BoardEvent::TaskRelationCreated(event) => event.timestamp,
+            BoardEvent::TaskRelationDeleted(event) => event.timestamp,
+            BoardEvent::LabelCreated(event) => event.timestamp,
+            BoardEvent::LabelUpdated(event) => event.timestamp,
+            BoardEvent::LabelDeleted(event) => event.timestamp,
+            BoardEvent::CustomFieldCreated(event) => event.timestamp,
+            BoardEvent::CustomFieldUpdated(event) => event.timestamp,
+            BoardEvent::CustomFieldDeleted(event) => event.timestamp,
+            BoardEvent::TasksBulkUpdated(event) => event.timestamp,
+            BoardEvent::PositionsRebalanced(event) => event.timestamp,
+            BoardEvent::WipLimitExceeded(event) => event.timestamp,
+            BoardEvent::ChecklistCreated(event) => event.timestamp,
+            BoardEvent::ChecklistUpdated(event) => event.timestamp,
+            BoardEvent::ChecklistMoved(event) => event.timestamp,
+            BoardEvent::ChecklistDeleted(event) => event.timestamp,
+            BoardEvent::ChecklistItemCreated(event) => event.timestamp,
+            BoardEvent::ChecklistItemUpdated(event) => event.timestamp,
+            BoardEvent::ChecklistItemToggled(event) => event.timestamp,
+            BoardEvent::ChecklistItemMoved(event) => event.timestamp,
+            BoardEvent::ChecklistItemDeleted(event) => event.timestamp,
+            BoardEvent::CommentAdded(event) => event.timestamp,
+            BoardEvent::CommentEdited(event) => event.timestamp,
+            BoardEvent::CommentDeleted(event) => event.timestamp,
+            BoardEvent::AttachmentAdded(event) => event.timestamp,
+            BoardEvent::AttachmentDeleted(event) => event.timestamp,
+            BoardEvent::TimerStarted(event) => event.timestamp,
+            BoardEvent::TimerStopped(event) => event.timestamp,
+            BoardEvent::TimeEntryLogged(event) => event.timestamp,
+            BoardEvent::TimeEntryUpdated(event) => event.timestamp,
+            BoardEvent::TimeEntryDeleted(event) => event.timestamp,
+        }
+    }
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -50,6 +215,22 @@ pub struct BoardDeletedEvent {
     pub timestamp: DateTime<FixedOffset>,
 }
 
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BoardArchivedEvent {
+    pub board_id: Uuid,
+    pub archived_by: Uuid,
+    pub timestamp: DateTime<FixedOffset>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BoardRestoredEvent {
+    pub board_id: Uuid,
+    pub restored_by: Uuid,
+    pub timestamp: DateTime<FixedOffset>,
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct MemberAddedEvent {
@@ -86,6 +267,8 @@ pub struct ColumnCreatedEvent {
     pub name: String,
     pub position: String,
     pub board_id: Uuid,
+    pub wip_limit: Option<i32>,
+    pub is_done: bool,
     pub created_by: Uuid,
     pub timestamp: DateTime<FixedOffset>,
 }
@@ -95,6 +278,8 @@ pub struct ColumnCreatedEvent {
 pub struct ColumnUpdatedEvent {
     pub column_id: Uuid,
     pub name: Option<String>,
+    pub wip_limit: Option<i32>,
+    pub is_done: bool,
     pub updated_by: Uuid,
     pub timestamp: DateTime<FixedOffset>,
 }
@@ -117,15 +302,45 @@ pub struct ColumnDeletedEvent {
     pub timestamp: DateTime<FixedOffset>,
 }
 
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ColumnArchivedEvent {
+    pub column_id: Uuid,
+    pub archived_by: Uuid,
+    pub timestamp: DateTime<FixedOffset>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ColumnRestoredEvent {
+    pub column_id: Uuid,
+    pub restored_by: Uuid,
+    pub timestamp: DateTime<FixedOffset>,
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct TaskCreatedEvent {
     pub task_id: Uuid,
     pub title: String,
     pub description: Option<String>,
-    pub tags: Option<Vec<String>>,
+    /// Missing from events recorded before tags were replaced by labels
+    #[serde(default)]
+    pub label_ids: Vec<Uuid>,
+    /// Missing from events recorded before custom fields were introduced
+    #[serde(default)]
+    pub custom_fields: HashMap<Uuid, Value>,
     pub position: String,
     pub column_id: Uuid,
+    pub assignee_ids: Vec<Uuid>,
+    pub priority: TaskPriorityEnum,
+    pub start_at: Option<DateTime<FixedOffset>>,
+    pub due_at: Option<DateTime<FixedOffset>>,
+    pub estimate_minutes: Option<i32>,
+    /// When the task was first created, which precedes `timestamp` for tasks moved over from
+    /// another board. Missing from events recorded before cross-board moves kept it.
+    #[serde(default)]
+    pub created_at: Option<DateTime<FixedOffset>>,
     pub created_by: Uuid,
     pub timestamp: DateTime<FixedOffset>,
 }
@@ -136,7 +351,12 @@ pub struct TaskUpdatedEvent {
     pub task_id: Uuid,
     pub title: Option<String>,
     pub description: Option<String>,
-    pub tags: Option<Vec<String>>,
+    pub label_ids: Option<Vec<Uuid>>,
+    pub custom_fields: Option<HashMap<Uuid, Value>>,
+    pub priority: TaskPriorityEnum,
+    pub start_at: Option<DateTime<FixedOffset>>,
+    pub due_at: Option<DateTime<FixedOffset>>,
+    pub estimate_minutes: Option<i32>,
     pub updated_by: Uuid,
     pub timestamp: DateTime<FixedOffset>,
 }
@@ -160,3 +380,447 @@ pub struct TaskDeletedEvent {
     pub deleted_by: Uuid,
     pub timestamp: DateTime<FixedOffset>,
 }
+
+/// The task was moved to another board and is no longer part of this one
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TaskRemovedEvent {
+    pub task_id: Uuid,
+    pub column_id: Uuid,
+    pub removed_by: Uuid,
+    pub timestamp: DateTime<FixedOffset>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TaskArchivedEvent {
+    pub task_id: Uuid,
+    pub column_id: Uuid,
+    pub archived_by: Uuid,
+    pub timestamp: DateTime<FixedOffset>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TaskRestoredEvent {
+    pub task_id: Uuid,
+    pub column_id: Uuid,
+    pub restored_by: Uuid,
+    pub timestamp: DateTime<FixedOffset>,
+}
+
+/// The task entered a done column
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TaskCompletedEvent {
+    pub task_id: Uuid,
+    pub column_id: Uuid,
+    pub completed_by: Uuid,
+    pub timestamp: DateTime<FixedOffset>,
+}
+
+/// The task left the done columns
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TaskReopenedEvent {
+    pub task_id: Uuid,
+    pub column_id: Uuid,
+    pub reopened_by: Uuid,
+    pub timestamp: DateTime<FixedOffset>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TaskAssignedEvent {
+    pub task_id: Uuid,
+    pub user_id: Uuid,
+    pub assigned_by: Uuid,
+    pub timestamp: DateTime<FixedOffset>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TaskUnassignedEvent {
+    pub task_id: Uuid,
+    pub user_id: Uuid,
+    pub unassigned_by: Uuid,
+    pub timestamp: DateTime<FixedOffset>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TaskRelationCreatedEvent {
+    pub relation_id: Uuid,
+    pub source_task_id: Uuid,
+    pub target_task_id: Uuid,
+    pub relation_type: TaskRelationTypeEnum,
+    pub created_by: Uuid,
+    pub timestamp: DateTime<FixedOffset>,
+}
+
+/// Also emitted for every relation a task loses when it moves to another board
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TaskRelationDeletedEvent {
+    pub relation_id: Uuid,
+    pub source_task_id: Uuid,
+    pub target_task_id: Uuid,
+    pub relation_type: TaskRelationTypeEnum,
+    pub deleted_by: Uuid,
+    pub timestamp: DateTime<FixedOffset>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct LabelCreatedEvent {
+    pub label_id: Uuid,
+    pub name: String,
+    pub color: String,
+    pub created_by: Uuid,
+    pub timestamp: DateTime<FixedOffset>,
+}
+
+/// Cards reference labels by ID, so a rename or new colour applies to all of them at once
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct LabelUpdatedEvent {
+    pub label_id: Uuid,
+    pub name: String,
+    pub color: String,
+    pub updated_by: Uuid,
+    pub timestamp: DateTime<FixedOffset>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct LabelDeletedEvent {
+    pub label_id: Uuid,
+    /// Tasks the label was removed from
+    pub task_ids: Vec<Uuid>,
+    pub deleted_by: Uuid,
+    pub timestamp: DateTime<FixedOffset>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CustomFieldCreatedEvent {
+    pub field_id: Uuid,
+    pub name: String,
+    pub field_type: CustomFieldTypeEnum,
+    pub options: Vec<String>,
+    pub created_by: Uuid,
+    pub timestamp: DateTime<FixedOffset>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CustomFieldUpdatedEvent {
+    pub field_id: Uuid,
+    pub name: String,
+    pub options: Vec<String>,
+    pub updated_by: Uuid,
+    pub timestamp: DateTime<FixedOffset>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CustomFieldDeletedEvent {
+    pub field_id: Uuid,
+    /// Tasks the field's value was removed from
+    pub task_ids: Vec<Uuid>,
+    pub deleted_by: Uuid,
+    pub timestamp: DateTime<FixedOffset>,
+}
+
+/// A single operation applied to several tasks of the board at once
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TasksBulkUpdatedEvent {
+    pub task_ids: Vec<Uuid>,
+    pub operation: BulkTaskOperation,
+    pub updated_by: Uuid,
+    pub timestamp: DateTime<FixedOffset>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(
+    rename_all = "camelCase",
+    rename_all_fields = "camelCase",
+    tag = "type"
+)]
+pub enum BulkTaskOperation {
+    Move {
+        column_id: Uuid,
+        positions: Vec<EntityPosition>,
+    },
+    Archive,
+    Delete,
+    /// Only found in events recorded before tags were replaced by labels
+    AddTags {
+        tags: Vec<String>,
+    },
+    /// Only found in events recorded before tags were replaced by labels
+    RemoveTags {
+        tags: Vec<String>,
+    },
+    AddLabels {
+        label_ids: Vec<Uuid>,
+    },
+    RemoveLabels {
+        label_ids: Vec<Uuid>,
+    },
+    Assign {
+        user_ids: Vec<Uuid>,
+    },
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PositionsRebalancedEvent {
+    /// Set when the tasks of this column were rebalanced, `None` when the board's columns were
+    pub column_id: Option<Uuid>,
+    pub positions: Vec<EntityPosition>,
+    pub rebalanced_by: Uuid,
+    pub timestamp: DateTime<FixedOffset>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct EntityPosition {
+    pub id: Uuid,
+    pub position: String,
+}
+
+/// An owner or moderator added tasks to a column beyond its WIP limit
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct WipLimitExceededEvent {
+    pub column_id: Uuid,
+    pub task_ids: Vec<Uuid>,
+    pub wip_limit: i32,
+    pub task_count: usize,
+    pub overridden_by: Uuid,
+    pub timestamp: DateTime<FixedOffset>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ChecklistCreatedEvent {
+    pub checklist_id: Uuid,
+    pub task_id: Uuid,
+    pub title: String,
+    pub position: String,
+    /// New keys of the task's other checklists when the insert required a rebalance
+    pub rebalanced_positions: Vec<EntityPosition>,
+    pub created_by: Uuid,
+    pub timestamp: DateTime<FixedOffset>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ChecklistUpdatedEvent {
+    pub checklist_id: Uuid,
+    pub task_id: Uuid,
+    pub title: String,
+    pub updated_by: Uuid,
+    pub timestamp: DateTime<FixedOffset>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ChecklistMovedEvent {
+    pub checklist_id: Uuid,
+    pub task_id: Uuid,
+    pub old_position: usize,
+    pub new_position: usize,
+    pub position: String,
+    pub rebalanced_positions: Vec<EntityPosition>,
+    pub moved_by: Uuid,
+    pub timestamp: DateTime<FixedOffset>,
+}
+
+/// Carries the task's checklist progress after the change
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ChecklistDeletedEvent {
+    pub checklist_id: Uuid,
+    pub task_id: Uuid,
+    pub completed_items: u64,
+    pub total_items: u64,
+    pub deleted_by: Uuid,
+    pub timestamp: DateTime<FixedOffset>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ChecklistItemCreatedEvent {
+    pub item_id: Uuid,
+    pub checklist_id: Uuid,
+    pub task_id: Uuid,
+    pub content: String,
+    pub position: String,
+    /// New keys of the checklist's other items when the insert required a rebalance
+    pub rebalanced_positions: Vec<EntityPosition>,
+    pub completed_items: u64,
+    pub total_items: u64,
+    pub created_by: Uuid,
+    pub timestamp: DateTime<FixedOffset>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ChecklistItemUpdatedEvent {
+    pub item_id: Uuid,
+    pub checklist_id: Uuid,
+    pub task_id: Uuid,
+    pub content: String,
+    pub updated_by: Uuid,
+    pub timestamp: DateTime<FixedOffset>,
+}
+
+/// Carries the task's checklist progress after the change, so progress bars can update
+/// without refetching the task
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ChecklistItemToggledEvent {
+    pub item_id: Uuid,
+    pub checklist_id: Uuid,
+    pub task_id: Uuid,
+    pub is_completed: bool,
+    pub completed_items: u64,
+    pub total_items: u64,
+    pub toggled_by: Uuid,
+    pub timestamp: DateTime<FixedOffset>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ChecklistItemMovedEvent {
+    pub item_id: Uuid,
+    pub checklist_id: Uuid,
+    pub task_id: Uuid,
+    pub old_position: usize,
+    pub new_position: usize,
+    pub position: String,
+    pub rebalanced_positions: Vec<EntityPosition>,
+    pub moved_by: Uuid,
+    pub timestamp: DateTime<FixedOffset>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ChecklistItemDeletedEvent {
+    pub item_id: Uuid,
+    pub checklist_id: Uuid,
+    pub task_id: Uuid,
+    pub completed_items: u64,
+    pub total_items: u64,
+    pub deleted_by: Uuid,
+    pub timestamp: DateTime<FixedOffset>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CommentAddedEvent {
+    pub comment_id: Uuid,
+    pub task_id: Uuid,
+    pub parent_id: Option<Uuid>,
+    pub author_id: Uuid,
+    pub content: String,
+    pub timestamp: DateTime<FixedOffset>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CommentEditedEvent {
+    pub comment_id: Uuid,
+    pub task_id: Uuid,
+    pub content: String,
+    pub edited_by: Uuid,
+    pub timestamp: DateTime<FixedOffset>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CommentDeletedEvent {
+    pub comment_id: Uuid,
+    pub task_id: Uuid,
+    pub deleted_by: Uuid,
+    pub timestamp: DateTime<FixedOffset>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AttachmentAddedEvent {
+    pub attachment_id: Uuid,
+    pub task_id: Uuid,
+    pub file_name: String,
+    pub content_type: String,
+    pub size: i64,
+    pub uploaded_by: Uuid,
+    pub timestamp: DateTime<FixedOffset>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AttachmentDeletedEvent {
+    pub attachment_id: Uuid,
+    pub task_id: Uuid,
+    pub deleted_by: Uuid,
+    pub timestamp: DateTime<FixedOffset>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TimerStartedEvent {
+    pub entry_id: Uuid,
+    pub task_id: Uuid,
+    pub user_id: Uuid,
+    pub started_at: DateTime<FixedOffset>,
+    pub note: Option<String>,
+    pub timestamp: DateTime<FixedOffset>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TimerStoppedEvent {
+    pub entry_id: Uuid,
+    pub task_id: Uuid,
+    pub user_id: Uuid,
+    pub started_at: DateTime<FixedOffset>,
+    pub ended_at: DateTime<FixedOffset>,
+    pub timestamp: DateTime<FixedOffset>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TimeEntryLoggedEvent {
+    pub entry_id: Uuid,
+    pub task_id: Uuid,
+    pub user_id: Uuid,
+    pub started_at: DateTime<FixedOffset>,
+    pub ended_at: DateTime<FixedOffset>,
+    pub note: Option<String>,
+    pub timestamp: DateTime<FixedOffset>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TimeEntryUpdatedEvent {
+    pub entry_id: Uuid,
+    pub task_id: Uuid,
+    pub started_at: DateTime<FixedOffset>,
+    pub ended_at: Option<DateTime<FixedOffset>>,
+    pub note: Option<String>,
+    pub updated_by: Uuid,
+    pub timestamp: DateTime<FixedOffset>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TimeEntryDeletedEvent {
+    pub entry_id: Uuid,
+    pub task_id: Uuid,
+    pub deleted_by: Uuid,
+    pub timestamp: DateTime<FixedOffset>,
+}
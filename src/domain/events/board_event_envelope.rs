@@ -0,0 +1,24 @@
+use crate::domain::events::BoardEvent;
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BoardEventEnvelope {
+    pub id: Uuid,
+    pub board_id: Uuid,
+    pub sequence: i64,
+    #[serde(flatten)]
+    pub event: BoardEvent,
+}
+
+impl BoardEventEnvelope {
+    pub fn new(id: Uuid, board_id: Uuid, sequence: i64, event: BoardEvent) -> Self {
+        Self {
+            id,
+            board_id,
+            sequence,
+            event,
+        }
+    }
+}
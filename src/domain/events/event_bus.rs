@@ -1,4 +1,4 @@
-use crate::domain::events::BoardEvent;
+use crate::domain::events::{BoardEvent, BoardEventEnvelope};
 use async_trait::async_trait;
 use std::sync::Arc;
 use tokio::sync::broadcast;
@@ -6,8 +6,10 @@ use uuid::Uuid;
 
 #[async_trait]
 pub trait EventBus: Send + Sync {
-    async fn publish(&self, board_id: Uuid, event: BoardEvent);
-    async fn subscribe(&self, board_id: Uuid) -> broadcast::Receiver<BoardEvent>;
+    /// Records and broadcasts the event. Publishing again with the same `id` re-broadcasts
+    /// the already recorded event instead of recording a duplicate.
+    async fn publish(&self, id: Uuid, board_id: Uuid, event: BoardEvent) -> Result<(), String>;
+    async fn subscribe(&self, board_id: Uuid) -> broadcast::Receiver<BoardEventEnvelope>;
     async fn cleanup_board(&self, board_id: Uuid);
 }
 
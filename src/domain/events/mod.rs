@@ -1,5 +1,7 @@
 pub mod board_event;
+pub mod board_event_envelope;
 pub mod event_bus;
 
 pub use board_event::*;
+pub use board_event_envelope::BoardEventEnvelope;
 pub use event_bus::{EventBus, SharedEventBus};
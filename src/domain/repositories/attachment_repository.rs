@@ -0,0 +1,60 @@
+use crate::shared::error::ApplicationError;
+use async_trait::async_trait;
+use chrono::{DateTime, FixedOffset, Utc};
+use uuid::Uuid;
+
+#[derive(Debug, Clone)]
+pub struct Attachment {
+    pub id: Uuid,
+    pub task_id: Uuid,
+    pub board_id: Uuid,
+    pub uploaded_by: Uuid,
+    pub file_name: String,
+    pub content_type: String,
+    pub size: i64,
+    pub storage_key: String,
+    pub created_at: DateTime<FixedOffset>,
+}
+
+impl Attachment {
+    pub fn new(
+        id: Uuid,
+        task_id: Uuid,
+        board_id: Uuid,
+        uploaded_by: Uuid,
+        file_name: String,
+        content_type: String,
+        size: i64,
+    ) -> Self {
+        Self {
+            id,
+            task_id,
+            board_id,
+            uploaded_by,
+            file_name,
+            content_type,
+            size,
+            storage_key: format!("boards/{}/tasks/{}/{}", board_id, task_id, id),
+            created_at: Utc::now().fixed_offset(),
+        }
+    }
+}
+
+#[async_trait]
+pub trait AttachmentRepository: Send + Sync {
+    async fn create(&self, attachment: Attachment) -> Result<Attachment, ApplicationError>;
+    async fn find_by_id(&self, attachment_id: Uuid)
+    -> Result<Option<Attachment>, ApplicationError>;
+    async fn find_by_task_id(&self, task_id: Uuid) -> Result<Vec<Attachment>, ApplicationError>;
+    async fn find_by_column_id(&self, column_id: Uuid)
+    -> Result<Vec<Attachment>, ApplicationError>;
+    async fn find_by_board_id(&self, board_id: Uuid) -> Result<Vec<Attachment>, ApplicationError>;
+    async fn sum_size_by_board_id(&self, board_id: Uuid) -> Result<i64, ApplicationError>;
+    /// Reassigns the task's attachments to the board the task was moved to
+    async fn update_board_id_by_task_id(
+        &self,
+        task_id: Uuid,
+        board_id: Uuid,
+    ) -> Result<u64, ApplicationError>;
+    async fn delete(&self, attachment_id: Uuid) -> Result<u64, ApplicationError>;
+}
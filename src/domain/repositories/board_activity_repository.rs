@@ -0,0 +1,92 @@
+use crate::{
+    domain::events::{BoardEvent, BoardEventEnvelope},
+    shared::error::ApplicationError,
+};
+use async_trait::async_trait;
+use chrono::{DateTime, FixedOffset};
+use serde_json::{Value, json};
+use uuid::Uuid;
+
+#[derive(Debug, Clone)]
+pub struct BoardActivity {
+    pub id: Uuid,
+    pub board_id: Uuid,
+    pub actor_id: Option<Uuid>,
+    pub event_type: String,
+    pub payload: Value,
+    pub created_at: DateTime<FixedOffset>,
+    pub sequence: i64,
+}
+
+impl BoardActivity {
+    pub fn from_event(
+        id: Uuid,
+        board_id: Uuid,
+        event: &BoardEvent,
+    ) -> Result<Self, serde_json::Error> {
+        let mut value = serde_json::to_value(event)?;
+        let event_type = value["type"].as_str().unwrap_or_default().to_string();
+
+        Ok(Self {
+            id,
+            board_id,
+            actor_id: Some(event.actor_id()),
+            event_type,
+            payload: value["data"].take(),
+            created_at: event.timestamp(),
+            sequence: 0,
+        })
+    }
+
+    pub fn to_envelope(&self) -> Result<BoardEventEnvelope, serde_json::Error> {
+        let event = serde_json::from_value(json!({
+            "type": self.event_type,
+            "data": self.payload,
+        }))?;
+
+        Ok(BoardEventEnvelope::new(
+            self.id,
+            self.board_id,
+            self.sequence,
+            event,
+        ))
+    }
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct BoardActivityFilter {
+    pub actor_id: Option<Uuid>,
+    pub event_type: Option<String>,
+    pub from: Option<DateTime<FixedOffset>>,
+    pub to: Option<DateTime<FixedOffset>>,
+    pub cursor: Option<Uuid>,
+    pub limit: u64,
+}
+
+#[async_trait]
+pub trait BoardActivityRepository: Send + Sync {
+    async fn create(&self, activity: BoardActivity) -> Result<BoardActivity, ApplicationError>;
+    async fn find_by_id(&self, id: Uuid) -> Result<Option<BoardActivity>, ApplicationError>;
+    async fn find_by_board_id(
+        &self,
+        board_id: Uuid,
+        filter: BoardActivityFilter,
+    ) -> Result<Vec<BoardActivity>, ApplicationError>;
+    async fn find_since_sequence(
+        &self,
+        board_id: Uuid,
+        since: i64,
+        limit: u64,
+    ) -> Result<Vec<BoardActivity>, ApplicationError>;
+    /// Returns the task activities of the given types recorded between `from` and `until`,
+    /// preceded by what's needed to restore the state of every task at `from`: its latest
+    /// activity of each type and the first move out of the column it was created in. Oldest first.
+    async fn find_task_history(
+        &self,
+        board_id: Uuid,
+        event_types: Vec<String>,
+        from: DateTime<FixedOffset>,
+        until: DateTime<FixedOffset>,
+    ) -> Result<Vec<BoardActivity>, ApplicationError>;
+    async fn find_latest_sequence(&self, board_id: Uuid) -> Result<i64, ApplicationError>;
+}
@@ -37,11 +37,13 @@ pub trait BoardMemberRepository: Send + Sync {
         board_id: Uuid,
         user_id: Uuid,
     ) -> Result<Option<BoardMember>, ApplicationError>;
-    async fn get_role(
+    /// Locks the membership row until the surrounding transaction ends
+    async fn find_by_board_and_user_id_for_update(
         &self,
         board_id: Uuid,
         user_id: Uuid,
-    ) -> Result<Option<BoardMemberRoleEnum>, ApplicationError>;
+    ) -> Result<Option<BoardMember>, ApplicationError>;
+    async fn find_by_board_id(&self, board_id: Uuid) -> Result<Vec<BoardMember>, ApplicationError>;
     async fn check_permissions(
         &self,
         board_id: Uuid,
@@ -11,6 +11,8 @@ pub struct Board {
     pub owner_id: Uuid,
     pub created_at: DateTime<FixedOffset>,
     pub updated_at: DateTime<FixedOffset>,
+    pub archived_at: Option<DateTime<FixedOffset>>,
+    pub version: i32,
 }
 
 impl Board {
@@ -24,6 +26,8 @@ impl Board {
             owner_id,
             created_at: now,
             updated_at: now,
+            archived_at: None,
+            version: 1,
         }
     }
 }
@@ -36,7 +40,16 @@ pub trait BoardRepository: Send + Sync {
         board_id: Uuid,
         user_id: Uuid,
     ) -> Result<Option<Board>, ApplicationError>;
-    async fn find_by_membership(&self, user_id: Uuid) -> Result<Vec<Board>, ApplicationError>;
+    /// Locks the board row until the surrounding transaction ends
+    async fn find_by_id_for_update(
+        &self,
+        board_id: Uuid,
+    ) -> Result<Option<Board>, ApplicationError>;
+    async fn find_by_membership(
+        &self,
+        user_id: Uuid,
+        include_archived: bool,
+    ) -> Result<Vec<Board>, ApplicationError>;
     async fn update(&self, board: Board) -> Result<Board, ApplicationError>;
     async fn delete(&self, board_id: Uuid) -> Result<u64, ApplicationError>;
 }
@@ -0,0 +1,105 @@
+use crate::shared::error::ApplicationError;
+use async_trait::async_trait;
+use chrono::{DateTime, FixedOffset, Utc};
+use uuid::Uuid;
+
+#[derive(Debug, Clone)]
+pub struct Checklist {
+    pub id: Uuid,
+    pub task_id: Uuid,
+    pub title: String,
+    pub position: String,
+    pub created_at: DateTime<FixedOffset>,
+    pub updated_at: DateTime<FixedOffset>,
+}
+
+impl Checklist {
+    pub fn new(id: Uuid, task_id: Uuid, title: String, position: String) -> Self {
+        let now = Utc::now().fixed_offset();
+
+        Self {
+            id,
+            task_id,
+            title,
+            position,
+            created_at: now,
+            updated_at: now,
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct ChecklistItem {
+    pub id: Uuid,
+    pub checklist_id: Uuid,
+    pub content: String,
+    pub position: String,
+    pub is_completed: bool,
+    pub completed_by: Option<Uuid>,
+    pub completed_at: Option<DateTime<FixedOffset>>,
+    pub created_at: DateTime<FixedOffset>,
+    pub updated_at: DateTime<FixedOffset>,
+}
+
+impl ChecklistItem {
+    pub fn new(id: Uuid, checklist_id: Uuid, content: String, position: String) -> Self {
+        let now = Utc::now().fixed_offset();
+
+        Self {
+            id,
+            checklist_id,
+            content,
+            position,
+            is_completed: false,
+            completed_by: None,
+            completed_at: None,
+            created_at: now,
+            updated_at: now,
+        }
+    }
+}
+
+/// Completed and total checklist items across all checklists of a task
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct ChecklistProgress {
+    pub completed_items: u64,
+    pub total_items: u64,
+}
+
+#[async_trait]
+pub trait ChecklistRepository: Send + Sync {
+    async fn create(&self, checklist: Checklist) -> Result<Checklist, ApplicationError>;
+    async fn find_by_id(&self, checklist_id: Uuid) -> Result<Option<Checklist>, ApplicationError>;
+    /// Locks the checklist row until the surrounding transaction ends
+    async fn find_by_id_for_update(
+        &self,
+        checklist_id: Uuid,
+    ) -> Result<Option<Checklist>, ApplicationError>;
+    async fn find_by_task_id(&self, task_id: Uuid) -> Result<Vec<Checklist>, ApplicationError>;
+    async fn update(&self, checklist: Checklist) -> Result<Checklist, ApplicationError>;
+    async fn update_positions(
+        &self,
+        positions: Vec<(Uuid, String)>,
+    ) -> Result<(), ApplicationError>;
+    async fn delete(&self, checklist_id: Uuid) -> Result<u64, ApplicationError>;
+    async fn create_item(&self, item: ChecklistItem) -> Result<ChecklistItem, ApplicationError>;
+    async fn find_item_by_id(
+        &self,
+        item_id: Uuid,
+    ) -> Result<Option<ChecklistItem>, ApplicationError>;
+    async fn find_items_by_checklist_id(
+        &self,
+        checklist_id: Uuid,
+    ) -> Result<Vec<ChecklistItem>, ApplicationError>;
+    async fn find_items_by_task_id(
+        &self,
+        task_id: Uuid,
+    ) -> Result<Vec<ChecklistItem>, ApplicationError>;
+    async fn update_item(&self, item: ChecklistItem) -> Result<ChecklistItem, ApplicationError>;
+    async fn update_item_positions(
+        &self,
+        positions: Vec<(Uuid, String)>,
+    ) -> Result<(), ApplicationError>;
+    async fn delete_item(&self, item_id: Uuid) -> Result<u64, ApplicationError>;
+    async fn find_progress(&self, task_id: Uuid) -> Result<ChecklistProgress, ApplicationError>;
+}
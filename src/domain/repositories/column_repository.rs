@@ -11,6 +11,10 @@ pub struct Column {
     pub board_id: Uuid,
     pub created_at: DateTime<FixedOffset>,
     pub updated_at: DateTime<FixedOffset>,
+    pub archived_at: Option<DateTime<FixedOffset>>,
+    pub version: i32,
+    pub wip_limit: Option<i32>,
+    pub is_done: bool,
 }
 
 impl Column {
@@ -24,6 +28,10 @@ impl Column {
             board_id,
             created_at: now,
             updated_at: now,
+            archived_at: None,
+            version: 1,
+            wip_limit: None,
+            is_done: false,
         }
     }
 }
@@ -32,7 +40,21 @@ impl Column {
 pub trait ColumnRepository: Send + Sync {
     async fn create(&self, column: Column) -> Result<Column, ApplicationError>;
     async fn find_by_id(&self, column_id: Uuid) -> Result<Option<Column>, ApplicationError>;
-    async fn find_by_board_id(&self, board_id: Uuid) -> Result<Vec<Column>, ApplicationError>;
+    /// Locks the column row until the surrounding transaction ends
+    async fn find_by_id_for_update(
+        &self,
+        column_id: Uuid,
+    ) -> Result<Option<Column>, ApplicationError>;
+    async fn find_by_board_id(
+        &self,
+        board_id: Uuid,
+        include_archived: bool,
+    ) -> Result<Vec<Column>, ApplicationError>;
     async fn update(&self, column: Column) -> Result<Column, ApplicationError>;
+    /// Rewrites the given positions and bumps the version of every affected row
+    async fn update_positions(
+        &self,
+        positions: Vec<(Uuid, String)>,
+    ) -> Result<(), ApplicationError>;
     async fn delete(&self, column_id: Uuid) -> Result<u64, ApplicationError>;
 }
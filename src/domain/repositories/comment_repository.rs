@@ -0,0 +1,75 @@
+use crate::shared::error::ApplicationError;
+use async_trait::async_trait;
+use chrono::{DateTime, FixedOffset, Utc};
+use uuid::Uuid;
+
+#[derive(Debug, Clone)]
+pub struct Comment {
+    pub id: Uuid,
+    pub task_id: Uuid,
+    pub parent_id: Option<Uuid>,
+    pub author_id: Uuid,
+    pub content: String,
+    pub created_at: DateTime<FixedOffset>,
+    pub updated_at: DateTime<FixedOffset>,
+}
+
+impl Comment {
+    pub fn new(
+        id: Uuid,
+        task_id: Uuid,
+        parent_id: Option<Uuid>,
+        author_id: Uuid,
+        content: String,
+    ) -> Self {
+        let now = Utc::now().fixed_offset();
+
+        Self {
+            id,
+            task_id,
+            parent_id,
+            author_id,
+            content,
+            created_at: now,
+            updated_at: now,
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct CommentRevision {
+    pub id: Uuid,
+    pub comment_id: Uuid,
+    pub content: String,
+    pub edited_by: Uuid,
+    pub created_at: DateTime<FixedOffset>,
+}
+
+impl CommentRevision {
+    pub fn new(id: Uuid, comment_id: Uuid, content: String, edited_by: Uuid) -> Self {
+        Self {
+            id,
+            comment_id,
+            content,
+            edited_by,
+            created_at: Utc::now().fixed_offset(),
+        }
+    }
+}
+
+#[async_trait]
+pub trait CommentRepository: Send + Sync {
+    async fn create(&self, comment: Comment) -> Result<Comment, ApplicationError>;
+    async fn find_by_id(&self, comment_id: Uuid) -> Result<Option<Comment>, ApplicationError>;
+    async fn find_by_task_id(&self, task_id: Uuid) -> Result<Vec<Comment>, ApplicationError>;
+    async fn update(
+        &self,
+        comment: Comment,
+        revision: CommentRevision,
+    ) -> Result<Comment, ApplicationError>;
+    async fn find_revisions(
+        &self,
+        comment_id: Uuid,
+    ) -> Result<Vec<CommentRevision>, ApplicationError>;
+    async fn delete(&self, comment_id: Uuid) -> Result<u64, ApplicationError>;
+}
@@ -0,0 +1,66 @@
+use crate::shared::error::ApplicationError;
+use async_trait::async_trait;
+use chrono::{DateTime, FixedOffset, Utc};
+use entity::CustomFieldTypeEnum;
+use uuid::Uuid;
+
+#[derive(Debug, Clone)]
+pub struct CustomField {
+    pub id: Uuid,
+    pub board_id: Uuid,
+    pub name: String,
+    pub field_type: CustomFieldTypeEnum,
+    /// Choices of single- and multi-select fields, empty for every other type
+    pub options: Vec<String>,
+    pub created_at: DateTime<FixedOffset>,
+    pub updated_at: DateTime<FixedOffset>,
+}
+
+impl CustomField {
+    pub fn new(
+        id: Uuid,
+        board_id: Uuid,
+        name: String,
+        field_type: CustomFieldTypeEnum,
+        options: Vec<String>,
+    ) -> Self {
+        let now = Utc::now().fixed_offset();
+
+        Self {
+            id,
+            board_id,
+            name,
+            field_type,
+            options,
+            created_at: now,
+            updated_at: now,
+        }
+    }
+
+    pub fn has_options(&self) -> bool {
+        matches!(
+            self.field_type,
+            CustomFieldTypeEnum::SingleSelect | CustomFieldTypeEnum::MultiSelect
+        )
+    }
+}
+
+#[async_trait]
+pub trait CustomFieldRepository: Send + Sync {
+    async fn create(&self, field: CustomField) -> Result<CustomField, ApplicationError>;
+    async fn find_by_id(&self, field_id: Uuid) -> Result<Option<CustomField>, ApplicationError>;
+    async fn find_by_ids(&self, field_ids: Vec<Uuid>)
+    -> Result<Vec<CustomField>, ApplicationError>;
+    async fn find_by_board_id(&self, board_id: Uuid) -> Result<Vec<CustomField>, ApplicationError>;
+    /// Matches the name case-insensitively, as field names are unique per board in that way
+    async fn find_by_board_and_name(
+        &self,
+        board_id: Uuid,
+        name: &str,
+    ) -> Result<Option<CustomField>, ApplicationError>;
+    /// Returns the tasks holding a value for the field
+    async fn find_task_ids(&self, field_id: Uuid) -> Result<Vec<Uuid>, ApplicationError>;
+    async fn update(&self, field: CustomField) -> Result<CustomField, ApplicationError>;
+    /// Also removes the field's values from every task
+    async fn delete(&self, field_id: Uuid) -> Result<u64, ApplicationError>;
+}
@@ -0,0 +1,46 @@
+use crate::shared::error::ApplicationError;
+use async_trait::async_trait;
+use chrono::{DateTime, FixedOffset, Utc};
+use uuid::Uuid;
+
+#[derive(Debug, Clone)]
+pub struct Label {
+    pub id: Uuid,
+    pub board_id: Uuid,
+    pub name: String,
+    pub color: String,
+    pub created_at: DateTime<FixedOffset>,
+    pub updated_at: DateTime<FixedOffset>,
+}
+
+impl Label {
+    pub fn new(id: Uuid, board_id: Uuid, name: String, color: String) -> Self {
+        let now = Utc::now().fixed_offset();
+
+        Self {
+            id,
+            board_id,
+            name,
+            color,
+            created_at: now,
+            updated_at: now,
+        }
+    }
+}
+
+#[async_trait]
+pub trait LabelRepository: Send + Sync {
+    async fn create(&self, label: Label) -> Result<Label, ApplicationError>;
+    async fn find_by_id(&self, label_id: Uuid) -> Result<Option<Label>, ApplicationError>;
+    async fn find_by_ids(&self, label_ids: Vec<Uuid>) -> Result<Vec<Label>, ApplicationError>;
+    async fn find_by_board_id(&self, board_id: Uuid) -> Result<Vec<Label>, ApplicationError>;
+    /// Matches the name case-insensitively, as label names are unique per board in that way
+    async fn find_by_board_and_name(
+        &self,
+        board_id: Uuid,
+        name: &str,
+    ) -> Result<Option<Label>, ApplicationError>;
+    async fn find_task_ids(&self, label_id: Uuid) -> Result<Vec<Uuid>, ApplicationError>;
+    async fn update(&self, label: Label) -> Result<Label, ApplicationError>;
+    async fn delete(&self, label_id: Uuid) -> Result<u64, ApplicationError>;
+}
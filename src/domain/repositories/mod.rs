@@ -1,11 +1,35 @@
+pub mod attachment_repository;
+pub mod board_activity_repository;
 pub mod board_member_repository;
 pub mod board_repository;
+pub mod checklist_repository;
 pub mod column_repository;
+pub mod comment_repository;
+pub mod custom_field_repository;
+pub mod label_repository;
+pub mod outbox_repository;
+pub mod task_relation_repository;
 pub mod task_repository;
+pub mod time_entry_repository;
+pub mod unit_of_work;
 pub mod user_repository;
 
+pub use attachment_repository::{Attachment, AttachmentRepository};
+pub use board_activity_repository::{BoardActivity, BoardActivityFilter, BoardActivityRepository};
 pub use board_member_repository::{BoardMember, BoardMemberRepository};
 pub use board_repository::{Board, BoardRepository};
+pub use checklist_repository::{Checklist, ChecklistItem, ChecklistProgress, ChecklistRepository};
 pub use column_repository::{Column, ColumnRepository};
-pub use task_repository::{Task, TaskRepository};
+pub use comment_repository::{Comment, CommentRepository, CommentRevision};
+pub use custom_field_repository::{CustomField, CustomFieldRepository};
+pub use label_repository::{Label, LabelRepository};
+pub use outbox_repository::{OutboxMessage, OutboxRepository};
+pub use task_relation_repository::{TaskRelation, TaskRelationRepository};
+pub use task_repository::{
+    CustomFieldCondition, CustomFieldFilter, Task, TaskFilter, TaskRepository,
+};
+pub use time_entry_repository::{
+    TimeEntry, TimeEntryFilter, TimeEntryRepository, TimeEntrySummary,
+};
+pub use unit_of_work::{UnitOfWork, UnitOfWorkFactory};
 pub use user_repository::{User, UserRepository};
@@ -0,0 +1,52 @@
+use crate::{domain::events::BoardEvent, shared::error::ApplicationError};
+use async_trait::async_trait;
+use chrono::{DateTime, FixedOffset, Utc};
+use uuid::Uuid;
+
+#[derive(Debug, Clone)]
+pub struct OutboxMessage {
+    pub id: Uuid,
+    pub board_id: Uuid,
+    pub event: BoardEvent,
+    pub attempts: i32,
+    pub last_error: Option<String>,
+    pub available_at: DateTime<FixedOffset>,
+    pub created_at: DateTime<FixedOffset>,
+}
+
+impl OutboxMessage {
+    pub fn new(id: Uuid, board_id: Uuid, event: BoardEvent) -> Self {
+        let now = Utc::now().fixed_offset();
+
+        Self {
+            id,
+            board_id,
+            event,
+            attempts: 0,
+            last_error: None,
+            available_at: now,
+            created_at: now,
+        }
+    }
+}
+
+#[async_trait]
+pub trait OutboxRepository: Send + Sync {
+    async fn create(&self, message: OutboxMessage) -> Result<OutboxMessage, ApplicationError>;
+    /// Leases the oldest message of up to `limit` boards until `lease_until`, skipping rows
+    /// already leased by another dispatcher. A board's later messages are only handed out once
+    /// the ones before them are gone, so a board's events are published in order even while
+    /// an earlier one waits for a retry. Messages are returned in the order they were written.
+    async fn claim_available(
+        &self,
+        limit: u64,
+        lease_until: DateTime<FixedOffset>,
+    ) -> Result<Vec<OutboxMessage>, ApplicationError>;
+    async fn record_failure(
+        &self,
+        id: Uuid,
+        error: String,
+        retry_at: DateTime<FixedOffset>,
+    ) -> Result<(), ApplicationError>;
+    async fn delete(&self, id: Uuid) -> Result<u64, ApplicationError>;
+}
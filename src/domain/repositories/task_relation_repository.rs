@@ -0,0 +1,67 @@
+use crate::shared::error::ApplicationError;
+use async_trait::async_trait;
+use chrono::{DateTime, FixedOffset, Utc};
+use entity::TaskRelationTypeEnum;
+use uuid::Uuid;
+
+/// Directed link between two tasks of the same board. `Blocks` and `Duplicates` read from the
+/// source to the target task, `RelatesTo` applies both ways.
+#[derive(Debug, Clone)]
+pub struct TaskRelation {
+    pub id: Uuid,
+    pub source_task_id: Uuid,
+    pub target_task_id: Uuid,
+    pub relation_type: TaskRelationTypeEnum,
+    pub created_by: Option<Uuid>,
+    pub created_at: DateTime<FixedOffset>,
+}
+
+impl TaskRelation {
+    pub fn new(
+        id: Uuid,
+        source_task_id: Uuid,
+        target_task_id: Uuid,
+        relation_type: TaskRelationTypeEnum,
+        created_by: Uuid,
+    ) -> Self {
+        Self {
+            id,
+            source_task_id,
+            target_task_id,
+            relation_type,
+            created_by: Some(created_by),
+            created_at: Utc::now().fixed_offset(),
+        }
+    }
+
+    /// Whether the relation links the same two tasks with the same meaning
+    pub fn links(
+        &self,
+        source_task_id: Uuid,
+        target_task_id: Uuid,
+        relation_type: TaskRelationTypeEnum,
+    ) -> bool {
+        if self.relation_type != relation_type {
+            return false;
+        }
+
+        (self.source_task_id == source_task_id && self.target_task_id == target_task_id)
+            || (relation_type == TaskRelationTypeEnum::RelatesTo
+                && self.source_task_id == target_task_id
+                && self.target_task_id == source_task_id)
+    }
+}
+
+#[async_trait]
+pub trait TaskRelationRepository: Send + Sync {
+    async fn create(&self, relation: TaskRelation) -> Result<TaskRelation, ApplicationError>;
+    async fn find_by_board_id(
+        &self,
+        board_id: Uuid,
+        relation_type: TaskRelationTypeEnum,
+    ) -> Result<Vec<TaskRelation>, ApplicationError>;
+    async fn delete(&self, relation_id: Uuid) -> Result<u64, ApplicationError>;
+    /// Removes every relation the task takes part in and returns the removed relations
+    async fn delete_by_task_id(&self, task_id: Uuid)
+    -> Result<Vec<TaskRelation>, ApplicationError>;
+}
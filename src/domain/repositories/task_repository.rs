@@ -1,6 +1,12 @@
-use crate::shared::error::ApplicationError;
+use crate::{
+    domain::repositories::{ChecklistProgress, TaskRelation},
+    shared::error::ApplicationError,
+};
 use async_trait::async_trait;
-use chrono::{DateTime, FixedOffset, Utc};
+use chrono::{DateTime, FixedOffset, NaiveDate, Utc};
+use entity::TaskPriorityEnum;
+use serde_json::Value;
+use std::collections::HashMap;
 use uuid::Uuid;
 
 #[derive(Debug, Clone)]
@@ -8,11 +14,25 @@ pub struct Task {
     pub id: Uuid,
     pub title: String,
     pub description: Option<String>,
-    pub tags: Option<Vec<String>>,
+    pub label_ids: Vec<Uuid>,
     pub position: String,
     pub column_id: Uuid,
+    pub assignee_ids: Vec<Uuid>,
+    pub priority: TaskPriorityEnum,
+    pub start_at: Option<DateTime<FixedOffset>>,
+    pub due_at: Option<DateTime<FixedOffset>>,
+    /// Expected effort, compared against the time logged on the task
+    pub estimate_minutes: Option<i32>,
     pub created_at: DateTime<FixedOffset>,
     pub updated_at: DateTime<FixedOffset>,
+    pub archived_at: Option<DateTime<FixedOffset>>,
+    pub version: i32,
+    pub completed_at: Option<DateTime<FixedOffset>>,
+    pub checklist_progress: ChecklistProgress,
+    /// Relations the task takes part in, as either source or target
+    pub relations: Vec<TaskRelation>,
+    /// Values of the board's custom fields, keyed by field ID
+    pub custom_fields: HashMap<Uuid, Value>,
 }
 
 impl Task {
@@ -20,9 +40,10 @@ impl Task {
         id: Uuid,
         title: String,
         description: Option<String>,
-        tags: Option<Vec<String>>,
+        label_ids: Vec<Uuid>,
         position: String,
         column_id: Uuid,
+        assignee_ids: Vec<Uuid>,
     ) -> Self {
         let now = Utc::now().fixed_offset();
 
@@ -30,20 +51,98 @@ impl Task {
             id,
             title,
             description,
-            tags,
+            label_ids,
             position,
             column_id,
+            assignee_ids,
+            priority: TaskPriorityEnum::Medium,
+            start_at: None,
+            due_at: None,
+            estimate_minutes: None,
             created_at: now,
             updated_at: now,
+            archived_at: None,
+            version: 1,
+            completed_at: None,
+            checklist_progress: ChecklistProgress::default(),
+            relations: Vec::new(),
+            custom_fields: HashMap::new(),
         }
     }
 }
 
+#[derive(Debug, Clone)]
+pub enum CustomFieldCondition {
+    /// The stored value contains the given one: equality for single values, membership for
+    /// multi-select fields
+    Contains(Value),
+    /// Case-insensitive substring of a text value
+    TextContains(String),
+    /// Inclusive bounds of a number value
+    NumberRange {
+        min: Option<f64>,
+        max: Option<f64>,
+    },
+    /// Inclusive bounds of a date value
+    DateRange {
+        min: Option<NaiveDate>,
+        max: Option<NaiveDate>,
+    },
+    IsSet(bool),
+}
+
+#[derive(Debug, Clone)]
+pub struct CustomFieldFilter {
+    pub field_id: Uuid,
+    pub condition: CustomFieldCondition,
+}
+
+/// Every given criterion must match; labels and assignees match when the task has any of them
+#[derive(Debug, Clone, Default)]
+pub struct TaskFilter {
+    /// Case-insensitive substring of the title or description
+    pub text: Option<String>,
+    pub label_ids: Vec<Uuid>,
+    pub assignee_ids: Vec<Uuid>,
+    pub custom_fields: Vec<CustomFieldFilter>,
+    pub include_archived: bool,
+    pub cursor: Option<Uuid>,
+    pub limit: u64,
+}
+
 #[async_trait]
 pub trait TaskRepository: Send + Sync {
     async fn create(&self, task: Task) -> Result<Task, ApplicationError>;
     async fn find_by_id(&self, task_id: Uuid) -> Result<Option<Task>, ApplicationError>;
-    async fn find_by_column_id(&self, column_id: Uuid) -> Result<Vec<Task>, ApplicationError>;
+    /// Locks the task row until the surrounding transaction ends
+    async fn find_by_id_for_update(&self, task_id: Uuid) -> Result<Option<Task>, ApplicationError>;
+    async fn find_by_column_id(
+        &self,
+        column_id: Uuid,
+        include_archived: bool,
+    ) -> Result<Vec<Task>, ApplicationError>;
+    async fn find_by_ids(&self, task_ids: Vec<Uuid>) -> Result<Vec<Task>, ApplicationError>;
+    async fn find_by_board_id(
+        &self,
+        board_id: Uuid,
+        include_archived: bool,
+    ) -> Result<Vec<Task>, ApplicationError>;
+    /// Returns the matching tasks of the board, newest first
+    async fn search(
+        &self,
+        board_id: Uuid,
+        filter: TaskFilter,
+    ) -> Result<Vec<Task>, ApplicationError>;
     async fn update(&self, task: Task) -> Result<Task, ApplicationError>;
+    /// Rewrites the given positions and bumps the version of every affected row
+    async fn update_positions(
+        &self,
+        positions: Vec<(Uuid, String)>,
+    ) -> Result<(), ApplicationError>;
     async fn delete(&self, task_id: Uuid) -> Result<u64, ApplicationError>;
+    async fn remove_assignee_from_board(
+        &self,
+        board_id: Uuid,
+        user_id: Uuid,
+    ) -> Result<Vec<Uuid>, ApplicationError>;
 }
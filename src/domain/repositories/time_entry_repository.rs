@@ -0,0 +1,91 @@
+use crate::shared::error::ApplicationError;
+use async_trait::async_trait;
+use chrono::{DateTime, FixedOffset, NaiveDate, Utc};
+use uuid::Uuid;
+
+#[derive(Debug, Clone)]
+pub struct TimeEntry {
+    pub id: Uuid,
+    pub task_id: Uuid,
+    pub user_id: Uuid,
+    pub started_at: DateTime<FixedOffset>,
+    /// Unset while the entry is a running timer
+    pub ended_at: Option<DateTime<FixedOffset>>,
+    pub note: Option<String>,
+    pub created_at: DateTime<FixedOffset>,
+    pub updated_at: DateTime<FixedOffset>,
+}
+
+impl TimeEntry {
+    pub fn new(
+        id: Uuid,
+        task_id: Uuid,
+        user_id: Uuid,
+        started_at: DateTime<FixedOffset>,
+        ended_at: Option<DateTime<FixedOffset>>,
+        note: Option<String>,
+    ) -> Self {
+        let now = Utc::now().fixed_offset();
+
+        Self {
+            id,
+            task_id,
+            user_id,
+            started_at,
+            ended_at,
+            note,
+            created_at: now,
+            updated_at: now,
+        }
+    }
+
+    pub fn is_running(&self) -> bool {
+        self.ended_at.is_none()
+    }
+
+    pub fn duration_seconds(&self) -> Option<i64> {
+        self.ended_at
+            .map(|ended_at| (ended_at - self.started_at).num_seconds())
+    }
+}
+
+/// Time logged by one user on one task during one UTC day
+#[derive(Debug, Clone)]
+pub struct TimeEntrySummary {
+    pub task_id: Uuid,
+    pub user_id: Uuid,
+    pub date: NaiveDate,
+    pub total_seconds: i64,
+}
+
+/// Selects stopped entries by the time they were started; `to` is exclusive
+#[derive(Debug, Clone)]
+pub struct TimeEntryFilter {
+    pub user_id: Option<Uuid>,
+    pub from: DateTime<FixedOffset>,
+    pub to: DateTime<FixedOffset>,
+}
+
+#[async_trait]
+pub trait TimeEntryRepository: Send + Sync {
+    /// Fails with a conflict when the entry would be a second running timer of its user
+    async fn create(&self, entry: TimeEntry) -> Result<TimeEntry, ApplicationError>;
+    async fn find_by_id(&self, entry_id: Uuid) -> Result<Option<TimeEntry>, ApplicationError>;
+    async fn find_by_task_id(&self, task_id: Uuid) -> Result<Vec<TimeEntry>, ApplicationError>;
+    async fn find_running_by_user_id(
+        &self,
+        user_id: Uuid,
+    ) -> Result<Option<TimeEntry>, ApplicationError>;
+    async fn find_running_by_board_id(
+        &self,
+        board_id: Uuid,
+    ) -> Result<Vec<TimeEntry>, ApplicationError>;
+    /// Sums the stopped entries on the board's tasks per task, user and day
+    async fn summarize_by_board_id(
+        &self,
+        board_id: Uuid,
+        filter: TimeEntryFilter,
+    ) -> Result<Vec<TimeEntrySummary>, ApplicationError>;
+    async fn update(&self, entry: TimeEntry) -> Result<TimeEntry, ApplicationError>;
+    async fn delete(&self, entry_id: Uuid) -> Result<u64, ApplicationError>;
+}
@@ -0,0 +1,33 @@
+use crate::{
+    domain::repositories::{
+        AttachmentRepository, BoardMemberRepository, BoardRepository, ChecklistRepository,
+        ColumnRepository, CommentRepository, CustomFieldRepository, LabelRepository,
+        OutboxRepository, TaskRelationRepository, TaskRepository, TimeEntryRepository,
+    },
+    shared::error::ApplicationError,
+};
+use async_trait::async_trait;
+
+/// Repositories bound to a single database transaction. Nothing is persisted until
+/// `commit` is called; dropping the unit of work rolls the transaction back.
+#[async_trait]
+pub trait UnitOfWork: Send + Sync {
+    fn board_repository(&self) -> &dyn BoardRepository;
+    fn board_member_repository(&self) -> &dyn BoardMemberRepository;
+    fn column_repository(&self) -> &dyn ColumnRepository;
+    fn task_repository(&self) -> &dyn TaskRepository;
+    fn task_relation_repository(&self) -> &dyn TaskRelationRepository;
+    fn comment_repository(&self) -> &dyn CommentRepository;
+    fn checklist_repository(&self) -> &dyn ChecklistRepository;
+    fn label_repository(&self) -> &dyn LabelRepository;
+    fn custom_field_repository(&self) -> &dyn CustomFieldRepository;
+    fn attachment_repository(&self) -> &dyn AttachmentRepository;
+    fn time_entry_repository(&self) -> &dyn TimeEntryRepository;
+    fn outbox_repository(&self) -> &dyn OutboxRepository;
+    async fn commit(self: Box<Self>) -> Result<(), ApplicationError>;
+}
+
+#[async_trait]
+pub trait UnitOfWorkFactory: Send + Sync {
+    async fn begin(&self) -> Result<Box<dyn UnitOfWork>, ApplicationError>;
+}
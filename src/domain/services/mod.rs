@@ -1,5 +1,7 @@
 pub mod email_service;
+pub mod storage_service;
 pub mod token_service;
 
 pub use email_service::{EmailService, EmailTemplate};
+pub use storage_service::{StorageService, StorageStream};
 pub use token_service::TokenService;
@@ -0,0 +1,14 @@
+use async_trait::async_trait;
+use bytes::Bytes;
+use futures_util::stream::BoxStream;
+use std::io;
+
+pub type StorageStream = BoxStream<'static, Result<Bytes, io::Error>>;
+
+#[async_trait]
+pub trait StorageService: Send + Sync {
+    async fn put_object(&self, key: &str, content_type: &str, data: Bytes) -> Result<(), String>;
+    async fn get_object(&self, key: &str) -> Result<StorageStream, String>;
+    async fn delete_object(&self, key: &str) -> Result<(), String>;
+    async fn delete_objects(&self, keys: &[String]) -> Result<(), String>;
+}
@@ -0,0 +1,53 @@
+use crate::domain::{
+    events::{BoardEvent, BoardEventEnvelope},
+    repositories::{BoardActivity, BoardActivityRepository},
+};
+use std::sync::Arc;
+use uuid::Uuid;
+
+pub struct ActivityRecorder {
+    board_activity_repository: Arc<dyn BoardActivityRepository>,
+}
+
+impl ActivityRecorder {
+    pub fn new(board_activity_repository: Arc<dyn BoardActivityRepository>) -> Self {
+        Self {
+            board_activity_repository,
+        }
+    }
+
+    /// Persists the event and wraps it in an envelope carrying the assigned sequence number.
+    /// An event that was already recorded under the same id keeps its original sequence.
+    pub async fn record(
+        &self,
+        id: Uuid,
+        board_id: Uuid,
+        event: BoardEvent,
+    ) -> Result<BoardEventEnvelope, String> {
+        let existing = self
+            .board_activity_repository
+            .find_by_id(id)
+            .await
+            .map_err(|err| format!("Failed to look up activity '{}': {}", id, err))?;
+
+        let activity = match existing {
+            Some(activity) => activity,
+            None => {
+                let activity = BoardActivity::from_event(id, board_id, &event)
+                    .map_err(|err| format!("Failed to serialize event '{}': {}", id, err))?;
+
+                self.board_activity_repository
+                    .create(activity)
+                    .await
+                    .map_err(|err| format!("Failed to record activity '{}': {}", id, err))?
+            }
+        };
+
+        Ok(BoardEventEnvelope::new(
+            activity.id,
+            board_id,
+            activity.sequence,
+            event,
+        ))
+    }
+}
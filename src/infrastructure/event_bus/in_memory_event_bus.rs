@@ -1,4 +1,10 @@
-use crate::domain::events::{BoardEvent, EventBus};
+use crate::{
+    domain::{
+        events::{BoardEvent, BoardEventEnvelope, EventBus},
+        repositories::BoardActivityRepository,
+    },
+    infrastructure::event_bus::ActivityRecorder,
+};
 use async_trait::async_trait;
 use std::{collections::HashMap, sync::Arc};
 use tokio::sync::{RwLock, broadcast};
@@ -8,17 +14,19 @@ use uuid::Uuid;
 const CHANNEL_CAPACITY: usize = 100;
 
 pub struct InMemoryEventBus {
-    channels: Arc<RwLock<HashMap<Uuid, broadcast::Sender<BoardEvent>>>>,
+    channels: Arc<RwLock<HashMap<Uuid, broadcast::Sender<BoardEventEnvelope>>>>,
+    activity_recorder: ActivityRecorder,
 }
 
 impl InMemoryEventBus {
-    pub fn new() -> Self {
+    pub fn new(board_activity_repository: Arc<dyn BoardActivityRepository>) -> Self {
         Self {
             channels: Arc::new(RwLock::new(HashMap::new())),
+            activity_recorder: ActivityRecorder::new(board_activity_repository),
         }
     }
 
-    async fn get_or_create_channel(&self, board_id: Uuid) -> broadcast::Sender<BoardEvent> {
+    async fn get_or_create_channel(&self, board_id: Uuid) -> broadcast::Sender<BoardEventEnvelope> {
         let mut channels = self.channels.write().await;
 
         channels
@@ -46,15 +54,11 @@ impl InMemoryEventBus {
     }
 }
 
-impl Default for InMemoryEventBus {
-    fn default() -> Self {
-        Self::new()
-    }
-}
-
 #[async_trait]
 impl EventBus for InMemoryEventBus {
-    async fn publish(&self, board_id: Uuid, event: BoardEvent) {
+    async fn publish(&self, id: Uuid, board_id: Uuid, event: BoardEvent) -> Result<(), String> {
+        let envelope = self.activity_recorder.record(id, board_id, event).await?;
+
         let sender = self.get_or_create_channel(board_id).await;
 
         if sender.receiver_count() == 0 {
@@ -63,19 +67,22 @@ impl EventBus for InMemoryEventBus {
                 board_id
             );
 
-            return;
+            return Ok(());
         }
 
-        match sender.send(event.clone()) {
+        // Sending only fails when the last subscriber has just left, so there is nothing to retry
+        match sender.send(envelope.clone()) {
             Ok(count) => info!(
                 "Published event to board '{}', {} subscribers notified: {:?}",
-                board_id, count, event
+                board_id, count, envelope
             ),
             Err(err) => warn!("Failed to publish event to board '{}': {}", board_id, err),
         }
+
+        Ok(())
     }
 
-    async fn subscribe(&self, board_id: Uuid) -> broadcast::Receiver<BoardEvent> {
+    async fn subscribe(&self, board_id: Uuid) -> broadcast::Receiver<BoardEventEnvelope> {
         let sender = self.get_or_create_channel(board_id).await;
 
         sender.subscribe()
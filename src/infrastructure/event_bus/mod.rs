@@ -1,3 +1,11 @@
+pub mod activity_recorder;
 pub mod in_memory_event_bus;
+pub mod outbox_dispatcher;
+pub mod postgres_event_bus;
+pub mod redis_event_bus;
 
+pub use activity_recorder::ActivityRecorder;
 pub use in_memory_event_bus::InMemoryEventBus;
+pub use outbox_dispatcher::OutboxDispatcher;
+pub use postgres_event_bus::PostgresEventBus;
+pub use redis_event_bus::RedisEventBus;
@@ -0,0 +1,118 @@
+use crate::domain::{events::SharedEventBus, repositories::OutboxRepository};
+use chrono::{Duration as ChronoDuration, Utc};
+use std::{sync::Arc, time::Duration};
+use tokio::{sync::Notify, task::JoinHandle};
+use tracing::{info, warn};
+
+const BATCH_SIZE: u64 = 100;
+const POLL_INTERVAL: Duration = Duration::from_secs(1);
+const LEASE_SECONDS: i64 = 30;
+const MAX_RETRY_DELAY_SECONDS: i64 = 300;
+
+/// Publishes events written to the outbox by committed transactions. Delivery is
+/// at-least-once: a message is only deleted after the event bus accepted it, and the
+/// outbox id is reused as the event id so that redeliveries can be recognized.
+pub struct OutboxDispatcher {
+    outbox_repository: Arc<dyn OutboxRepository>,
+    event_bus: SharedEventBus,
+    outbox_notify: Arc<Notify>,
+}
+
+impl OutboxDispatcher {
+    pub fn new(
+        outbox_repository: Arc<dyn OutboxRepository>,
+        event_bus: SharedEventBus,
+        outbox_notify: Arc<Notify>,
+    ) -> Self {
+        Self {
+            outbox_repository,
+            event_bus,
+            outbox_notify,
+        }
+    }
+
+    pub fn start(self) -> JoinHandle<()> {
+        tokio::spawn(async move {
+            info!("Outbox dispatcher started");
+
+            loop {
+                // Only the oldest message of each board is claimed at a time, so keep going
+                // while there's anything left to dispatch
+                let claimed = self.dispatch_batch().await;
+
+                if claimed == 0 {
+                    tokio::select! {
+                        _ = self.outbox_notify.notified() => {}
+                        _ = tokio::time::sleep(POLL_INTERVAL) => {}
+                    }
+                }
+            }
+        })
+    }
+
+    async fn dispatch_batch(&self) -> usize {
+        let now = Utc::now().fixed_offset();
+
+        let messages = match self
+            .outbox_repository
+            .claim_available(BATCH_SIZE, now + ChronoDuration::seconds(LEASE_SECONDS))
+            .await
+        {
+            Ok(messages) => messages,
+            Err(err) => {
+                warn!("Failed to claim outbox messages: {}", err);
+                return 0;
+            }
+        };
+
+        let claimed = messages.len();
+
+        for message in messages {
+            match self
+                .event_bus
+                .publish(message.id, message.board_id, message.event)
+                .await
+            {
+                Ok(()) => {
+                    if let Err(err) = self.outbox_repository.delete(message.id).await {
+                        warn!(
+                            "Failed to remove dispatched outbox message '{}', it will be redelivered: {}",
+                            message.id, err
+                        );
+                    }
+                }
+                Err(err) => {
+                    // The board's later messages stay queued behind this one until it's retried
+                    let delay = 2_i64
+                        .saturating_pow(message.attempts.clamp(0, 16) as u32)
+                        .min(MAX_RETRY_DELAY_SECONDS);
+
+                    warn!(
+                        "Failed to dispatch outbox message '{}' (attempt {}), retrying in {}s: {}",
+                        message.id,
+                        message.attempts + 1,
+                        delay,
+                        err
+                    );
+
+                    if let Err(err) = self
+                        .outbox_repository
+                        .record_failure(
+                            message.id,
+                            err,
+                            Utc::now().fixed_offset() + ChronoDuration::seconds(delay),
+                        )
+                        .await
+                    {
+                        warn!(
+                            "Failed to record outbox failure of message '{}': {}",
+                            message.id, err
+                        );
+                    }
+                }
+            }
+        }
+
+        claimed
+    }
+}
@@ -0,0 +1,256 @@
+use crate::{
+    domain::{
+        events::{BoardEvent, BoardEventEnvelope, EventBus},
+        repositories::BoardActivityRepository,
+    },
+    infrastructure::event_bus::ActivityRecorder,
+};
+use async_trait::async_trait;
+use sea_orm::{
+    ConnectionTrait, DatabaseConnection, DbBackend, Statement,
+    sqlx::{Error as SqlxError, postgres::PgListener},
+};
+use serde::{Deserialize, Serialize};
+use std::{collections::HashMap, sync::Arc, time::Duration};
+use tokio::sync::{RwLock, broadcast, mpsc, oneshot};
+use tracing::{info, warn};
+use uuid::Uuid;
+
+const CHANNEL_CAPACITY: usize = 100;
+const CHANNEL_PREFIX: &str = "board_events_";
+const RECONNECT_DELAY: Duration = Duration::from_secs(1);
+// Postgres rejects NOTIFY payloads of 8000 bytes or more
+const MAX_NOTIFY_PAYLOAD_SIZE: usize = 7999;
+
+type Channels = Arc<RwLock<HashMap<Uuid, broadcast::Sender<BoardEventEnvelope>>>>;
+
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase", tag = "type", content = "data")]
+enum Notification {
+    Event(Box<BoardEventEnvelope>),
+    /// Sent instead of the event when it does not fit into a notification payload
+    ActivityReference(Uuid),
+}
+
+enum ListenerCommand {
+    /// Acknowledged once the connection listens, so no event published afterwards is missed
+    Listen(Uuid, oneshot::Sender<()>),
+    Unlisten(Uuid),
+}
+
+/// Fans board events out across instances through Postgres `NOTIFY` on one channel per board.
+/// A dedicated `LISTEN` connection only listens to boards with local subscribers.
+pub struct PostgresEventBus {
+    database: DatabaseConnection,
+    channels: Channels,
+    commands: mpsc::UnboundedSender<ListenerCommand>,
+    activity_recorder: ActivityRecorder,
+}
+
+impl PostgresEventBus {
+    pub async fn new(
+        database: DatabaseConnection,
+        board_activity_repository: Arc<dyn BoardActivityRepository>,
+    ) -> Result<Self, SqlxError> {
+        let channels: Channels = Arc::new(RwLock::new(HashMap::new()));
+        let listener = PgListener::connect_with(database.get_postgres_connection_pool()).await?;
+        let (commands, command_rx) = mpsc::unbounded_channel();
+
+        tokio::spawn(Self::listen(
+            listener,
+            command_rx,
+            channels.clone(),
+            board_activity_repository.clone(),
+        ));
+
+        Ok(Self {
+            database,
+            channels,
+            commands,
+            activity_recorder: ActivityRecorder::new(board_activity_repository),
+        })
+    }
+
+    fn channel_name(board_id: Uuid) -> String {
+        format!("{}{}", CHANNEL_PREFIX, board_id)
+    }
+
+    async fn listen(
+        mut listener: PgListener,
+        mut command_rx: mpsc::UnboundedReceiver<ListenerCommand>,
+        channels: Channels,
+        board_activity_repository: Arc<dyn BoardActivityRepository>,
+    ) {
+        loop {
+            tokio::select! {
+                command = command_rx.recv() => {
+                    let result = match command {
+                        Some(ListenerCommand::Listen(board_id, ack)) => {
+                            let result = listener.listen(&Self::channel_name(board_id)).await;
+                            let _ = ack.send(());
+                            result
+                        }
+                        Some(ListenerCommand::Unlisten(board_id)) => {
+                            listener.unlisten(&Self::channel_name(board_id)).await
+                        }
+                        // The event bus was dropped
+                        None => break,
+                    };
+
+                    if let Err(err) = result {
+                        warn!("Failed to update Postgres event bus channels: {}", err);
+                    }
+                }
+
+                notification = listener.try_recv() => {
+                    match notification {
+                        Ok(Some(notification)) => {
+                            Self::dispatch(
+                                &channels,
+                                board_activity_repository.as_ref(),
+                                notification.payload(),
+                            )
+                            .await;
+                        }
+                        // The listener reconnects and listens to its channels again on the next call
+                        Ok(None) => warn!("Lost Postgres event bus connection, reconnecting"),
+                        Err(err) => {
+                            warn!("Failed to receive Postgres notification: {}", err);
+                            tokio::time::sleep(RECONNECT_DELAY).await;
+                        }
+                    }
+                }
+            }
+        }
+    }
+
+    async fn dispatch(
+        channels: &Channels,
+        board_activity_repository: &dyn BoardActivityRepository,
+        payload: &str,
+    ) {
+        let envelope = match serde_json::from_str::<Notification>(payload) {
+            Ok(Notification::Event(envelope)) => *envelope,
+            Ok(Notification::ActivityReference(activity_id)) => {
+                match board_activity_repository.find_by_id(activity_id).await {
+                    Ok(Some(activity)) => match activity.to_envelope() {
+                        Ok(envelope) => envelope,
+                        Err(err) => {
+                            warn!("Failed to read activity '{}': {}", activity_id, err);
+                            return;
+                        }
+                    },
+                    Ok(None) => {
+                        warn!("Notified activity '{}' not found", activity_id);
+                        return;
+                    }
+                    Err(err) => {
+                        warn!("Failed to load activity '{}': {}", activity_id, err);
+                        return;
+                    }
+                }
+            }
+            Err(err) => {
+                warn!("Failed to deserialize Postgres notification: {}", err);
+                return;
+            }
+        };
+
+        let channels = channels.read().await;
+
+        if let Some(sender) = channels.get(&envelope.board_id) {
+            // Sending only fails when the last local subscriber has just left
+            let _ = sender.send(envelope);
+        }
+    }
+
+    async fn cleanup_if_empty(&self, board_id: Uuid) {
+        let mut channels = self.channels.write().await;
+
+        if let Some(sender) = channels.get(&board_id)
+            && sender.receiver_count() == 0
+        {
+            info!(
+                "Cleaning up empty broadcast channel for board '{}'",
+                board_id
+            );
+
+            channels.remove(&board_id);
+
+            let _ = self.commands.send(ListenerCommand::Unlisten(board_id));
+        }
+    }
+}
+
+#[async_trait]
+impl EventBus for PostgresEventBus {
+    async fn publish(&self, id: Uuid, board_id: Uuid, event: BoardEvent) -> Result<(), String> {
+        let envelope = self.activity_recorder.record(id, board_id, event).await?;
+
+        let activity_id = envelope.id;
+
+        let payload = match serde_json::to_string(&Notification::Event(Box::new(envelope))) {
+            Ok(payload) if payload.len() <= MAX_NOTIFY_PAYLOAD_SIZE => payload,
+            Ok(_) => serde_json::to_string(&Notification::ActivityReference(activity_id)).map_err(
+                |err| {
+                    format!(
+                        "Failed to serialize activity reference for board '{}': {}",
+                        board_id, err
+                    )
+                },
+            )?,
+            Err(err) => {
+                return Err(format!(
+                    "Failed to serialize event for board '{}': {}",
+                    board_id, err
+                ));
+            }
+        };
+
+        self.database
+            .execute(Statement::from_sql_and_values(
+                DbBackend::Postgres,
+                "SELECT pg_notify($1, $2)",
+                [Self::channel_name(board_id).into(), payload.into()],
+            ))
+            .await
+            .map_err(|err| format!("Failed to publish event to board '{}': {}", board_id, err))?;
+
+        info!("Published event '{}' to board '{}'", activity_id, board_id);
+
+        Ok(())
+    }
+
+    async fn subscribe(&self, board_id: Uuid) -> broadcast::Receiver<BoardEventEnvelope> {
+        let mut channels = self.channels.write().await;
+
+        if let Some(sender) = channels.get(&board_id) {
+            return sender.subscribe();
+        }
+
+        info!("Creating new broadcast channel for board '{}'", board_id);
+
+        let (sender, receiver) = broadcast::channel(CHANNEL_CAPACITY);
+
+        let (ack, ack_rx) = oneshot::channel();
+        let listening = self
+            .commands
+            .send(ListenerCommand::Listen(board_id, ack))
+            .is_ok();
+
+        channels.insert(board_id, sender);
+
+        // The listener needs the channels lock to dispatch, so it is released before waiting
+        drop(channels);
+
+        if listening {
+            let _ = ack_rx.await;
+        }
+
+        receiver
+    }
+
+    async fn cleanup_board(&self, board_id: Uuid) {
+        self.cleanup_if_empty(board_id).await;
+    }
+}
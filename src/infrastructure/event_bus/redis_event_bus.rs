@@ -0,0 +1,229 @@
+use crate::{
+    domain::{
+        events::{BoardEvent, BoardEventEnvelope, EventBus},
+        repositories::BoardActivityRepository,
+    },
+    infrastructure::event_bus::ActivityRecorder,
+};
+use async_trait::async_trait;
+use futures_util::StreamExt;
+use redis::{
+    AsyncCommands, Client as RedisClient, Msg, RedisError, RedisResult,
+    aio::{ConnectionManager, PubSubSink, PubSubStream},
+};
+use std::{collections::HashMap, sync::Arc, time::Duration};
+use tokio::sync::{Mutex, RwLock, broadcast};
+use tracing::{info, warn};
+use uuid::Uuid;
+
+const CHANNEL_CAPACITY: usize = 100;
+const CHANNEL_PREFIX: &str = "board_events:";
+const RECONNECT_DELAY: Duration = Duration::from_secs(1);
+
+type Channels = Arc<RwLock<HashMap<Uuid, broadcast::Sender<BoardEventEnvelope>>>>;
+
+/// Fans board events out across instances through one Redis pub/sub channel per board.
+/// Every instance only subscribes to the channels of boards it has local subscribers for.
+pub struct RedisEventBus {
+    /// Shared by all publishes; reconnects on its own when the connection drops
+    connection: ConnectionManager,
+    channels: Channels,
+    sink: Arc<Mutex<PubSubSink>>,
+    activity_recorder: ActivityRecorder,
+}
+
+impl RedisEventBus {
+    pub async fn new(
+        redis_client: RedisClient,
+        board_activity_repository: Arc<dyn BoardActivityRepository>,
+    ) -> Result<Self, RedisError> {
+        let connection = ConnectionManager::new(redis_client.clone()).await?;
+        let channels: Channels = Arc::new(RwLock::new(HashMap::new()));
+        let (sink, stream) = redis_client.get_async_pubsub().await?.split();
+        let sink = Arc::new(Mutex::new(sink));
+
+        tokio::spawn(Self::listen(
+            redis_client,
+            channels.clone(),
+            sink.clone(),
+            stream,
+        ));
+
+        Ok(Self {
+            connection,
+            channels,
+            sink,
+            activity_recorder: ActivityRecorder::new(board_activity_repository),
+        })
+    }
+
+    fn channel_name(board_id: Uuid) -> String {
+        format!("{}{}", CHANNEL_PREFIX, board_id)
+    }
+
+    async fn listen(
+        redis_client: RedisClient,
+        channels: Channels,
+        sink: Arc<Mutex<PubSubSink>>,
+        mut stream: PubSubStream,
+    ) {
+        loop {
+            while let Some(msg) = stream.next().await {
+                Self::dispatch(&channels, msg).await;
+            }
+
+            warn!("Lost Redis event bus subscription, reconnecting");
+
+            stream = loop {
+                tokio::time::sleep(RECONNECT_DELAY).await;
+
+                match Self::resubscribe(&redis_client, &channels, &sink).await {
+                    Ok(stream) => break stream,
+                    Err(err) => warn!("Failed to reconnect Redis event bus: {}", err),
+                }
+            };
+
+            info!("Successfully reconnected Redis event bus");
+        }
+    }
+
+    async fn resubscribe(
+        redis_client: &RedisClient,
+        channels: &Channels,
+        sink: &Mutex<PubSubSink>,
+    ) -> RedisResult<PubSubStream> {
+        // Same lock order as subscribe and cleanup_board: channels first, then the sink
+        let channels = channels.read().await;
+        let mut sink = sink.lock().await;
+
+        let (mut new_sink, stream) = redis_client.get_async_pubsub().await?.split();
+
+        for board_id in channels.keys() {
+            new_sink.subscribe(Self::channel_name(*board_id)).await?;
+        }
+
+        *sink = new_sink;
+
+        Ok(stream)
+    }
+
+    async fn dispatch(channels: &Channels, msg: Msg) {
+        let envelope = match msg.get_payload::<String>().map(|payload| {
+            serde_json::from_str::<BoardEventEnvelope>(&payload).map_err(|err| err.to_string())
+        }) {
+            Ok(Ok(envelope)) => envelope,
+            Ok(Err(err)) => {
+                warn!(
+                    "Failed to deserialize event from channel '{}': {}",
+                    msg.get_channel_name(),
+                    err
+                );
+                return;
+            }
+            Err(err) => {
+                warn!(
+                    "Failed to read message from channel '{}': {}",
+                    msg.get_channel_name(),
+                    err
+                );
+                return;
+            }
+        };
+
+        let channels = channels.read().await;
+
+        if let Some(sender) = channels.get(&envelope.board_id) {
+            // Sending only fails when the last local subscriber has just left
+            let _ = sender.send(envelope);
+        }
+    }
+
+    async fn cleanup_if_empty(&self, board_id: Uuid) {
+        let mut channels = self.channels.write().await;
+
+        if let Some(sender) = channels.get(&board_id)
+            && sender.receiver_count() == 0
+        {
+            info!(
+                "Cleaning up empty broadcast channel for board '{}'",
+                board_id
+            );
+
+            channels.remove(&board_id);
+
+            if let Err(err) = self
+                .sink
+                .lock()
+                .await
+                .unsubscribe(Self::channel_name(board_id))
+                .await
+            {
+                warn!(
+                    "Failed to unsubscribe from Redis channel of board '{}': {}",
+                    board_id, err
+                );
+            }
+        }
+    }
+}
+
+#[async_trait]
+impl EventBus for RedisEventBus {
+    async fn publish(&self, id: Uuid, board_id: Uuid, event: BoardEvent) -> Result<(), String> {
+        let envelope = self.activity_recorder.record(id, board_id, event).await?;
+
+        let payload = serde_json::to_string(&envelope).map_err(|err| {
+            format!(
+                "Failed to serialize event for board '{}': {}",
+                board_id, err
+            )
+        })?;
+
+        let count: usize = self
+            .connection
+            .clone()
+            .publish(Self::channel_name(board_id), payload)
+            .await
+            .map_err(|err| format!("Failed to publish event to board '{}': {}", board_id, err))?;
+
+        info!(
+            "Published event to board '{}', {} instances notified: {:?}",
+            board_id, count, envelope
+        );
+
+        Ok(())
+    }
+
+    async fn subscribe(&self, board_id: Uuid) -> broadcast::Receiver<BoardEventEnvelope> {
+        let mut channels = self.channels.write().await;
+
+        if let Some(sender) = channels.get(&board_id) {
+            return sender.subscribe();
+        }
+
+        info!("Creating new broadcast channel for board '{}'", board_id);
+
+        let (sender, receiver) = broadcast::channel(CHANNEL_CAPACITY);
+
+        if let Err(err) = self
+            .sink
+            .lock()
+            .await
+            .subscribe(Self::channel_name(board_id))
+            .await
+        {
+            warn!(
+                "Failed to subscribe to Redis channel of board '{}': {}",
+                board_id, err
+            );
+        }
+
+        channels.insert(board_id, sender);
+
+        receiver
+    }
+
+    async fn cleanup_board(&self, board_id: Uuid) {
+        self.cleanup_if_empty(board_id).await;
+    }
+}
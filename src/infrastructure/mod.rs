@@ -2,3 +2,4 @@ pub mod cache;
 pub mod email;
 pub mod event_bus;
 pub mod persistence;
+pub mod storage;
@@ -0,0 +1,159 @@
+use crate::{
+    domain::repositories::{Attachment, AttachmentRepository},
+    shared::error::ApplicationError,
+};
+use async_trait::async_trait;
+use entity::{
+    AttachmentActiveModel, AttachmentColumn, AttachmentEntity, AttachmentModel, AttachmentRelation,
+    TaskColumn,
+};
+use sea_orm::{
+    ActiveValue::Set,
+    ColumnTrait, ConnectionTrait, DatabaseConnection, EntityTrait, JoinType, QueryFilter,
+    QueryOrder, QuerySelect, RelationTrait,
+    sea_query::{Alias, Expr, Func},
+};
+use uuid::Uuid;
+
+pub struct SeaOrmAttachmentRepository<C = DatabaseConnection> {
+    db: C,
+}
+
+impl<C: ConnectionTrait> SeaOrmAttachmentRepository<C> {
+    pub fn new(db: C) -> Self {
+        Self { db }
+    }
+
+    fn to_domain(model: AttachmentModel) -> Attachment {
+        Attachment {
+            id: model.id,
+            task_id: model.task_id,
+            board_id: model.board_id,
+            uploaded_by: model.uploaded_by,
+            file_name: model.file_name,
+            content_type: model.content_type,
+            size: model.size,
+            storage_key: model.storage_key,
+            created_at: model.created_at,
+        }
+    }
+
+    fn to_active_model(attachment: Attachment) -> AttachmentActiveModel {
+        AttachmentActiveModel {
+            id: Set(attachment.id),
+            task_id: Set(attachment.task_id),
+            board_id: Set(attachment.board_id),
+            uploaded_by: Set(attachment.uploaded_by),
+            file_name: Set(attachment.file_name),
+            content_type: Set(attachment.content_type),
+            size: Set(attachment.size),
+            storage_key: Set(attachment.storage_key),
+            created_at: Set(attachment.created_at),
+        }
+    }
+}
+
+#[async_trait]
+impl<C: ConnectionTrait + Send> AttachmentRepository for SeaOrmAttachmentRepository<C> {
+    async fn create(&self, attachment: Attachment) -> Result<Attachment, ApplicationError> {
+        let active_model = Self::to_active_model(attachment);
+
+        let result = AttachmentEntity::insert(active_model)
+            .exec_with_returning(&self.db)
+            .await
+            .map_err(ApplicationError::DatabaseError)?;
+
+        Ok(Self::to_domain(result))
+    }
+
+    async fn find_by_id(
+        &self,
+        attachment_id: Uuid,
+    ) -> Result<Option<Attachment>, ApplicationError> {
+        let result = AttachmentEntity::find_by_id(attachment_id)
+            .one(&self.db)
+            .await
+            .map_err(ApplicationError::DatabaseError)?;
+
+        Ok(result.map(Self::to_domain))
+    }
+
+    async fn find_by_task_id(&self, task_id: Uuid) -> Result<Vec<Attachment>, ApplicationError> {
+        let result = AttachmentEntity::find()
+            .filter(AttachmentColumn::TaskId.eq(task_id))
+            .order_by_asc(AttachmentColumn::CreatedAt)
+            .all(&self.db)
+            .await
+            .map_err(ApplicationError::DatabaseError)?;
+
+        Ok(result.into_iter().map(Self::to_domain).collect())
+    }
+
+    async fn find_by_column_id(
+        &self,
+        column_id: Uuid,
+    ) -> Result<Vec<Attachment>, ApplicationError> {
+        let result = AttachmentEntity::find()
+            .join(JoinType::InnerJoin, AttachmentRelation::Task.def())
+            .filter(TaskColumn::ColumnId.eq(column_id))
+            .all(&self.db)
+            .await
+            .map_err(ApplicationError::DatabaseError)?;
+
+        Ok(result.into_iter().map(Self::to_domain).collect())
+    }
+
+    async fn find_by_board_id(&self, board_id: Uuid) -> Result<Vec<Attachment>, ApplicationError> {
+        let result = AttachmentEntity::find()
+            .filter(AttachmentColumn::BoardId.eq(board_id))
+            .all(&self.db)
+            .await
+            .map_err(ApplicationError::DatabaseError)?;
+
+        Ok(result.into_iter().map(Self::to_domain).collect())
+    }
+
+    async fn sum_size_by_board_id(&self, board_id: Uuid) -> Result<i64, ApplicationError> {
+        let result = AttachmentEntity::find()
+            .select_only()
+            .column_as(
+                Expr::expr(Func::coalesce([
+                    Expr::col(AttachmentColumn::Size).sum(),
+                    Expr::val(0).into(),
+                ]))
+                .cast_as(Alias::new("bigint")),
+                "total_size",
+            )
+            .filter(AttachmentColumn::BoardId.eq(board_id))
+            .into_tuple::<i64>()
+            .one(&self.db)
+            .await
+            .map_err(ApplicationError::DatabaseError)?;
+
+        Ok(result.unwrap_or(0))
+    }
+
+    async fn update_board_id_by_task_id(
+        &self,
+        task_id: Uuid,
+        board_id: Uuid,
+    ) -> Result<u64, ApplicationError> {
+        let result = AttachmentEntity::update_many()
+            .col_expr(AttachmentColumn::BoardId, Expr::value(board_id))
+            .filter(AttachmentColumn::TaskId.eq(task_id))
+            .exec(&self.db)
+            .await
+            .map_err(ApplicationError::DatabaseError)?;
+
+        Ok(result.rows_affected)
+    }
+
+    async fn delete(&self, attachment_id: Uuid) -> Result<u64, ApplicationError> {
+        let result = AttachmentEntity::delete_by_id(attachment_id)
+            .exec(&self.db)
+            .await
+            .map_err(ApplicationError::DatabaseError)?;
+
+        Ok(result.rows_affected)
+    }
+}
@@ -0,0 +1,255 @@
+use crate::{
+    domain::repositories::{BoardActivity, BoardActivityFilter, BoardActivityRepository},
+    shared::error::ApplicationError,
+};
+use async_trait::async_trait;
+use chrono::{DateTime, FixedOffset};
+use entity::{
+    BoardActivityActiveModel, BoardActivityColumn, BoardActivityEntity, BoardActivityModel,
+    BoardEventSequenceActiveModel, BoardEventSequenceColumn, BoardEventSequenceEntity,
+};
+use sea_orm::{
+    ActiveValue::Set,
+    ColumnTrait, DatabaseConnection, DbBackend, EntityTrait, QueryFilter, QueryOrder, QuerySelect,
+    Statement, TransactionTrait, Value,
+    sea_query::{Expr, OnConflict},
+};
+use uuid::Uuid;
+
+pub struct SeaOrmBoardActivityRepository {
+    db: DatabaseConnection,
+}
+
+impl SeaOrmBoardActivityRepository {
+    pub fn new(db: DatabaseConnection) -> Self {
+        Self { db }
+    }
+
+    fn to_domain(model: BoardActivityModel) -> BoardActivity {
+        BoardActivity {
+            id: model.id,
+            board_id: model.board_id,
+            actor_id: model.actor_id,
+            event_type: model.event_type,
+            payload: model.payload,
+            created_at: model.created_at,
+            sequence: model.sequence,
+        }
+    }
+
+    fn to_active_model(activity: BoardActivity) -> BoardActivityActiveModel {
+        BoardActivityActiveModel {
+            id: Set(activity.id),
+            board_id: Set(activity.board_id),
+            actor_id: Set(activity.actor_id),
+            event_type: Set(activity.event_type),
+            payload: Set(activity.payload),
+            created_at: Set(activity.created_at),
+            sequence: Set(activity.sequence),
+        }
+    }
+}
+
+#[async_trait]
+impl BoardActivityRepository for SeaOrmBoardActivityRepository {
+    async fn create(&self, activity: BoardActivity) -> Result<BoardActivity, ApplicationError> {
+        let txn = self
+            .db
+            .begin()
+            .await
+            .map_err(ApplicationError::DatabaseError)?;
+
+        // The counter row lock serializes concurrent publishers of the same board
+        let counter = BoardEventSequenceEntity::insert(BoardEventSequenceActiveModel {
+            board_id: Set(activity.board_id),
+            last_sequence: Set(1),
+        })
+        .on_conflict(
+            OnConflict::column(BoardEventSequenceColumn::BoardId)
+                .value(
+                    BoardEventSequenceColumn::LastSequence,
+                    Expr::col((
+                        BoardEventSequenceEntity,
+                        BoardEventSequenceColumn::LastSequence,
+                    ))
+                    .add(1),
+                )
+                .to_owned(),
+        )
+        .exec_with_returning(&txn)
+        .await
+        .map_err(ApplicationError::DatabaseError)?;
+
+        let active_model = Self::to_active_model(BoardActivity {
+            sequence: counter.last_sequence,
+            ..activity
+        });
+
+        let result = BoardActivityEntity::insert(active_model)
+            .exec_with_returning(&txn)
+            .await
+            .map_err(ApplicationError::DatabaseError)?;
+
+        txn.commit()
+            .await
+            .map_err(ApplicationError::DatabaseError)?;
+
+        Ok(Self::to_domain(result))
+    }
+
+    async fn find_by_id(&self, id: Uuid) -> Result<Option<BoardActivity>, ApplicationError> {
+        let result = BoardActivityEntity::find_by_id(id)
+            .one(&self.db)
+            .await
+            .map_err(ApplicationError::DatabaseError)?;
+
+        Ok(result.map(Self::to_domain))
+    }
+
+    async fn find_by_board_id(
+        &self,
+        board_id: Uuid,
+        filter: BoardActivityFilter,
+    ) -> Result<Vec<BoardActivity>, ApplicationError> {
+        let mut query =
+            BoardActivityEntity::find().filter(BoardActivityColumn::BoardId.eq(board_id));
+
+        if let Some(cursor) = filter.cursor {
+            query = query.filter(BoardActivityColumn::Id.lt(cursor));
+        }
+        if let Some(actor_id) = filter.actor_id {
+            query = query.filter(BoardActivityColumn::ActorId.eq(actor_id));
+        }
+        if let Some(event_type) = filter.event_type {
+            query = query.filter(BoardActivityColumn::EventType.eq(event_type));
+        }
+        if let Some(from) = filter.from {
+            query = query.filter(BoardActivityColumn::CreatedAt.gte(from));
+        }
+        if let Some(to) = filter.to {
+            query = query.filter(BoardActivityColumn::CreatedAt.lte(to));
+        }
+
+        let result = query
+            .order_by_desc(BoardActivityColumn::Id)
+            .limit(filter.limit)
+            .all(&self.db)
+            .await
+            .map_err(ApplicationError::DatabaseError)?;
+
+        Ok(result.into_iter().map(Self::to_domain).collect())
+    }
+
+    async fn find_since_sequence(
+        &self,
+        board_id: Uuid,
+        since: i64,
+        limit: u64,
+    ) -> Result<Vec<BoardActivity>, ApplicationError> {
+        let result = BoardActivityEntity::find()
+            .filter(BoardActivityColumn::BoardId.eq(board_id))
+            .filter(BoardActivityColumn::Sequence.gt(since))
+            .order_by_asc(BoardActivityColumn::Sequence)
+            .limit(limit)
+            .all(&self.db)
+            .await
+            .map_err(ApplicationError::DatabaseError)?;
+
+        Ok(result.into_iter().map(Self::to_domain).collect())
+    }
+
+    async fn find_task_history(
+        &self,
+        board_id: Uuid,
+        event_types: Vec<String>,
+        from: DateTime<FixedOffset>,
+        until: DateTime<FixedOffset>,
+    ) -> Result<Vec<BoardActivity>, ApplicationError> {
+        let event_type_params = (0..event_types.len())
+            .map(|i| format!("${}", i + 4))
+            .collect::<Vec<_>>()
+            .join(", ");
+
+        // Earlier activities are reduced in the database: bulk updates are expanded to one row
+        // per task, and only the latest row of each task, type and bulk operation is kept
+        // together with the first move to a column other than the one the task was created in
+        let sql = format!(
+            r#"
+            WITH earlier AS (
+                SELECT activity.id, activity.event_type, activity.sequence, task.task_id,
+                    activity.payload ->> 'columnId' AS column_id,
+                    COALESCE(
+                        activity.payload ->> 'newColumnId',
+                        activity.payload -> 'operation' ->> 'columnId'
+                    ) AS target_column_id,
+                    activity.payload -> 'operation' ->> 'type' AS operation
+                FROM board_activity activity
+                CROSS JOIN LATERAL (
+                    SELECT activity.payload ->> 'taskId' AS task_id
+                    WHERE activity.payload ->> 'taskId' IS NOT NULL
+                    UNION ALL
+                    SELECT jsonb_array_elements_text(activity.payload -> 'taskIds')
+                ) task
+                WHERE activity.board_id = $1
+                    AND activity.created_at < $2
+                    AND activity.event_type IN ({event_types})
+            ),
+            created AS (
+                SELECT DISTINCT ON (task_id) task_id, sequence, column_id
+                FROM earlier
+                WHERE event_type = 'taskCreated'
+                ORDER BY task_id, sequence DESC
+            ),
+            latest AS (
+                SELECT DISTINCT ON (task_id, event_type, operation) id
+                FROM earlier
+                ORDER BY task_id, event_type, operation, sequence DESC
+            ),
+            started AS (
+                SELECT DISTINCT ON (earlier.task_id) earlier.id
+                FROM earlier
+                JOIN created ON created.task_id = earlier.task_id
+                WHERE earlier.sequence > created.sequence
+                    AND earlier.target_column_id <> created.column_id
+                ORDER BY earlier.task_id, earlier.sequence
+            )
+            SELECT * FROM board_activity
+            WHERE id IN (SELECT id FROM latest UNION SELECT id FROM started)
+                OR (
+                    board_id = $1
+                    AND created_at >= $2
+                    AND created_at < $3
+                    AND event_type IN ({event_types})
+                )
+            ORDER BY sequence
+            "#,
+            event_types = event_type_params
+        );
+
+        let values: Vec<Value> = [board_id.into(), from.into(), until.into()]
+            .into_iter()
+            .chain(event_types.into_iter().map(Value::from))
+            .collect();
+
+        let result = BoardActivityEntity::find()
+            .from_raw_sql(Statement::from_sql_and_values(
+                DbBackend::Postgres,
+                sql,
+                values,
+            ))
+            .all(&self.db)
+            .await
+            .map_err(ApplicationError::DatabaseError)?;
+
+        Ok(result.into_iter().map(Self::to_domain).collect())
+    }
+
+    async fn find_latest_sequence(&self, board_id: Uuid) -> Result<i64, ApplicationError> {
+        let result = BoardEventSequenceEntity::find_by_id(board_id)
+            .one(&self.db)
+            .await
+            .map_err(ApplicationError::DatabaseError)?;
+
+        Ok(result.map_or(0, |counter| counter.last_sequence))
+    }
+}
@@ -9,17 +9,17 @@ use entity::{
 };
 use sea_orm::{
     ActiveValue::Set, ColumnTrait, ConnectionTrait, DatabaseConnection, DbErr, EntityTrait,
-    FromQueryResult, QueryFilter,
+    FromQueryResult, QueryFilter, QuerySelect,
 };
 use sea_query::{Alias, Expr, ExprTrait, Query};
 use uuid::Uuid;
 
-pub struct SeaOrmBoardMemberRepository {
-    db: DatabaseConnection,
+pub struct SeaOrmBoardMemberRepository<C = DatabaseConnection> {
+    db: C,
 }
 
-impl SeaOrmBoardMemberRepository {
-    pub fn new(db: DatabaseConnection) -> Self {
+impl<C: ConnectionTrait> SeaOrmBoardMemberRepository<C> {
+    pub fn new(db: C) -> Self {
         Self { db }
     }
 
@@ -47,7 +47,7 @@ impl SeaOrmBoardMemberRepository {
 }
 
 #[async_trait]
-impl BoardMemberRepository for SeaOrmBoardMemberRepository {
+impl<C: ConnectionTrait + Send> BoardMemberRepository for SeaOrmBoardMemberRepository<C> {
     async fn create(&self, board_member: BoardMember) -> Result<BoardMember, ApplicationError> {
         let active_model = Self::to_active_model(board_member);
 
@@ -74,19 +74,30 @@ impl BoardMemberRepository for SeaOrmBoardMemberRepository {
         Ok(result.map(Self::to_domain))
     }
 
-    async fn get_role(
+    async fn find_by_board_and_user_id_for_update(
         &self,
         board_id: Uuid,
         user_id: Uuid,
-    ) -> Result<Option<BoardMemberRoleEnum>, ApplicationError> {
+    ) -> Result<Option<BoardMember>, ApplicationError> {
         let result = BoardMemberEntity::find()
             .filter(BoardMemberColumn::BoardId.eq(board_id))
             .filter(BoardMemberColumn::UserId.eq(user_id))
+            .lock_exclusive()
             .one(&self.db)
             .await
             .map_err(ApplicationError::DatabaseError)?;
 
-        Ok(result.map(|m| m.role))
+        Ok(result.map(Self::to_domain))
+    }
+
+    async fn find_by_board_id(&self, board_id: Uuid) -> Result<Vec<BoardMember>, ApplicationError> {
+        let result = BoardMemberEntity::find()
+            .filter(BoardMemberColumn::BoardId.eq(board_id))
+            .all(&self.db)
+            .await
+            .map_err(ApplicationError::DatabaseError)?;
+
+        Ok(result.into_iter().map(Self::to_domain).collect())
     }
 
     async fn check_permissions(
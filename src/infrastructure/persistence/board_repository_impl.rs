@@ -3,19 +3,21 @@ use crate::{
     shared::error::ApplicationError,
 };
 use async_trait::async_trait;
-use entity::{BoardActiveModel, BoardEntity, BoardMemberColumn, BoardModel, BoardRelation};
+use entity::{
+    BoardActiveModel, BoardColumn, BoardEntity, BoardMemberColumn, BoardModel, BoardRelation,
+};
 use sea_orm::{
-    ActiveValue::Set, ColumnTrait, DatabaseConnection, EntityTrait, JoinType, QueryFilter,
-    QuerySelect, RelationTrait,
+    ActiveValue::Set, ColumnTrait, ConnectionTrait, DatabaseConnection, DbErr, EntityTrait,
+    JoinType, QueryFilter, QuerySelect, RelationTrait,
 };
 use uuid::Uuid;
 
-pub struct SeaOrmBoardRepository {
-    db: DatabaseConnection,
+pub struct SeaOrmBoardRepository<C = DatabaseConnection> {
+    db: C,
 }
 
-impl SeaOrmBoardRepository {
-    pub fn new(db: DatabaseConnection) -> Self {
+impl<C: ConnectionTrait> SeaOrmBoardRepository<C> {
+    pub fn new(db: C) -> Self {
         Self { db }
     }
 
@@ -27,6 +29,8 @@ impl SeaOrmBoardRepository {
             owner_id: model.owner_id,
             created_at: model.created_at,
             updated_at: model.updated_at,
+            archived_at: model.archived_at,
+            version: model.version,
         }
     }
 
@@ -38,12 +42,27 @@ impl SeaOrmBoardRepository {
             owner_id: Set(board.owner_id),
             created_at: Set(board.created_at),
             updated_at: Set(board.updated_at),
+            archived_at: Set(board.archived_at),
+            version: Set(board.version),
+        }
+    }
+
+    async fn version_conflict(&self, board_id: Uuid) -> ApplicationError {
+        match BoardEntity::find_by_id(board_id).one(&self.db).await {
+            Ok(Some(model)) => ApplicationError::PreconditionFailed {
+                message: "The board has been modified since it was retrieved".to_string(),
+                current_version: model.version,
+            },
+            Ok(None) => ApplicationError::NotFound {
+                message: "Board with the given ID not found".to_string(),
+            },
+            Err(err) => ApplicationError::DatabaseError(err),
         }
     }
 }
 
 #[async_trait]
-impl BoardRepository for SeaOrmBoardRepository {
+impl<C: ConnectionTrait + Send> BoardRepository for SeaOrmBoardRepository<C> {
     async fn create(&self, board: Board) -> Result<Board, ApplicationError> {
         let active_model = Self::to_active_model(board);
 
@@ -70,10 +89,33 @@ impl BoardRepository for SeaOrmBoardRepository {
         Ok(result.map(Self::to_domain))
     }
 
-    async fn find_by_membership(&self, user_id: Uuid) -> Result<Vec<Board>, ApplicationError> {
-        let result = BoardEntity::find()
+    async fn find_by_id_for_update(
+        &self,
+        board_id: Uuid,
+    ) -> Result<Option<Board>, ApplicationError> {
+        let result = BoardEntity::find_by_id(board_id)
+            .lock_exclusive()
+            .one(&self.db)
+            .await
+            .map_err(ApplicationError::DatabaseError)?;
+
+        Ok(result.map(Self::to_domain))
+    }
+
+    async fn find_by_membership(
+        &self,
+        user_id: Uuid,
+        include_archived: bool,
+    ) -> Result<Vec<Board>, ApplicationError> {
+        let mut query = BoardEntity::find()
             .join(JoinType::InnerJoin, BoardRelation::BoardMember.def())
-            .filter(BoardMemberColumn::UserId.eq(user_id))
+            .filter(BoardMemberColumn::UserId.eq(user_id));
+
+        if !include_archived {
+            query = query.filter(BoardColumn::ArchivedAt.is_null());
+        }
+
+        let result = query
             .all(&self.db)
             .await
             .map_err(ApplicationError::DatabaseError)?;
@@ -82,12 +124,21 @@ impl BoardRepository for SeaOrmBoardRepository {
     }
 
     async fn update(&self, board: Board) -> Result<Board, ApplicationError> {
-        let active_model = Self::to_active_model(board);
+        let (board_id, expected_version) = (board.id, board.version);
+
+        let mut active_model = Self::to_active_model(board);
+        active_model.version = Set(expected_version + 1);
 
-        let result = BoardEntity::update(active_model)
+        // Only applies when nobody else has updated the board since it was read
+        let result = match BoardEntity::update(active_model)
+            .filter(BoardColumn::Version.eq(expected_version))
             .exec(&self.db)
             .await
-            .map_err(ApplicationError::DatabaseError)?;
+        {
+            Ok(result) => result,
+            Err(DbErr::RecordNotUpdated) => return Err(self.version_conflict(board_id).await),
+            Err(err) => return Err(ApplicationError::DatabaseError(err)),
+        };
 
         Ok(Self::to_domain(result))
     }
@@ -0,0 +1,263 @@
+use crate::{
+    domain::repositories::{Checklist, ChecklistItem, ChecklistProgress, ChecklistRepository},
+    shared::error::ApplicationError,
+};
+use async_trait::async_trait;
+use entity::{
+    ChecklistActiveModel, ChecklistColumn, ChecklistEntity, ChecklistItemActiveModel,
+    ChecklistItemColumn, ChecklistItemEntity, ChecklistItemModel, ChecklistItemRelation,
+    ChecklistModel,
+};
+use sea_orm::{
+    ActiveValue::Set, ColumnTrait, ConnectionTrait, DatabaseConnection, EntityTrait, JoinType,
+    QueryFilter, QueryOrder, QuerySelect, RelationTrait, sea_query::Expr,
+};
+use uuid::Uuid;
+
+pub struct SeaOrmChecklistRepository<C = DatabaseConnection> {
+    db: C,
+}
+
+impl<C: ConnectionTrait> SeaOrmChecklistRepository<C> {
+    pub fn new(db: C) -> Self {
+        Self { db }
+    }
+
+    fn to_domain(model: ChecklistModel) -> Checklist {
+        Checklist {
+            id: model.id,
+            task_id: model.task_id,
+            title: model.title,
+            position: model.position,
+            created_at: model.created_at,
+            updated_at: model.updated_at,
+        }
+    }
+
+    fn to_active_model(checklist: Checklist) -> ChecklistActiveModel {
+        ChecklistActiveModel {
+            id: Set(checklist.id),
+            task_id: Set(checklist.task_id),
+            title: Set(checklist.title),
+            position: Set(checklist.position),
+            created_at: Set(checklist.created_at),
+            updated_at: Set(checklist.updated_at),
+        }
+    }
+
+    fn item_to_domain(model: ChecklistItemModel) -> ChecklistItem {
+        ChecklistItem {
+            id: model.id,
+            checklist_id: model.checklist_id,
+            content: model.content,
+            position: model.position,
+            is_completed: model.is_completed,
+            completed_by: model.completed_by,
+            completed_at: model.completed_at,
+            created_at: model.created_at,
+            updated_at: model.updated_at,
+        }
+    }
+
+    fn item_to_active_model(item: ChecklistItem) -> ChecklistItemActiveModel {
+        ChecklistItemActiveModel {
+            id: Set(item.id),
+            checklist_id: Set(item.checklist_id),
+            content: Set(item.content),
+            position: Set(item.position),
+            is_completed: Set(item.is_completed),
+            completed_by: Set(item.completed_by),
+            completed_at: Set(item.completed_at),
+            created_at: Set(item.created_at),
+            updated_at: Set(item.updated_at),
+        }
+    }
+}
+
+#[async_trait]
+impl<C: ConnectionTrait + Send> ChecklistRepository for SeaOrmChecklistRepository<C> {
+    async fn create(&self, checklist: Checklist) -> Result<Checklist, ApplicationError> {
+        let active_model = Self::to_active_model(checklist);
+
+        let result = ChecklistEntity::insert(active_model)
+            .exec_with_returning(&self.db)
+            .await
+            .map_err(ApplicationError::DatabaseError)?;
+
+        Ok(Self::to_domain(result))
+    }
+
+    async fn find_by_id(&self, checklist_id: Uuid) -> Result<Option<Checklist>, ApplicationError> {
+        let result = ChecklistEntity::find_by_id(checklist_id)
+            .one(&self.db)
+            .await
+            .map_err(ApplicationError::DatabaseError)?;
+
+        Ok(result.map(Self::to_domain))
+    }
+
+    async fn find_by_id_for_update(
+        &self,
+        checklist_id: Uuid,
+    ) -> Result<Option<Checklist>, ApplicationError> {
+        let result = ChecklistEntity::find_by_id(checklist_id)
+            .lock_exclusive()
+            .one(&self.db)
+            .await
+            .map_err(ApplicationError::DatabaseError)?;
+
+        Ok(result.map(Self::to_domain))
+    }
+
+    async fn find_by_task_id(&self, task_id: Uuid) -> Result<Vec<Checklist>, ApplicationError> {
+        let result = ChecklistEntity::find()
+            .filter(ChecklistColumn::TaskId.eq(task_id))
+            .order_by_asc(ChecklistColumn::Position)
+            .all(&self.db)
+            .await
+            .map_err(ApplicationError::DatabaseError)?;
+
+        Ok(result.into_iter().map(Self::to_domain).collect())
+    }
+
+    async fn update(&self, checklist: Checklist) -> Result<Checklist, ApplicationError> {
+        let active_model = Self::to_active_model(checklist);
+
+        let result = ChecklistEntity::update(active_model)
+            .exec(&self.db)
+            .await
+            .map_err(ApplicationError::DatabaseError)?;
+
+        Ok(Self::to_domain(result))
+    }
+
+    async fn update_positions(
+        &self,
+        positions: Vec<(Uuid, String)>,
+    ) -> Result<(), ApplicationError> {
+        for (id, position) in positions {
+            ChecklistEntity::update_many()
+                .col_expr(ChecklistColumn::Position, Expr::value(position))
+                .filter(ChecklistColumn::Id.eq(id))
+                .exec(&self.db)
+                .await
+                .map_err(ApplicationError::DatabaseError)?;
+        }
+
+        Ok(())
+    }
+
+    async fn delete(&self, checklist_id: Uuid) -> Result<u64, ApplicationError> {
+        let result = ChecklistEntity::delete_by_id(checklist_id)
+            .exec(&self.db)
+            .await
+            .map_err(ApplicationError::DatabaseError)?;
+
+        Ok(result.rows_affected)
+    }
+
+    async fn create_item(&self, item: ChecklistItem) -> Result<ChecklistItem, ApplicationError> {
+        let active_model = Self::item_to_active_model(item);
+
+        let result = ChecklistItemEntity::insert(active_model)
+            .exec_with_returning(&self.db)
+            .await
+            .map_err(ApplicationError::DatabaseError)?;
+
+        Ok(Self::item_to_domain(result))
+    }
+
+    async fn find_item_by_id(
+        &self,
+        item_id: Uuid,
+    ) -> Result<Option<ChecklistItem>, ApplicationError> {
+        let result = ChecklistItemEntity::find_by_id(item_id)
+            .one(&self.db)
+            .await
+            .map_err(ApplicationError::DatabaseError)?;
+
+        Ok(result.map(Self::item_to_domain))
+    }
+
+    async fn find_items_by_checklist_id(
+        &self,
+        checklist_id: Uuid,
+    ) -> Result<Vec<ChecklistItem>, ApplicationError> {
+        let result = ChecklistItemEntity::find()
+            .filter(ChecklistItemColumn::ChecklistId.eq(checklist_id))
+            .order_by_asc(ChecklistItemColumn::Position)
+            .all(&self.db)
+            .await
+            .map_err(ApplicationError::DatabaseError)?;
+
+        Ok(result.into_iter().map(Self::item_to_domain).collect())
+    }
+
+    async fn find_items_by_task_id(
+        &self,
+        task_id: Uuid,
+    ) -> Result<Vec<ChecklistItem>, ApplicationError> {
+        let result = ChecklistItemEntity::find()
+            .join(JoinType::InnerJoin, ChecklistItemRelation::Checklist.def())
+            .filter(ChecklistColumn::TaskId.eq(task_id))
+            .order_by_asc(ChecklistItemColumn::Position)
+            .all(&self.db)
+            .await
+            .map_err(ApplicationError::DatabaseError)?;
+
+        Ok(result.into_iter().map(Self::item_to_domain).collect())
+    }
+
+    async fn update_item(&self, item: ChecklistItem) -> Result<ChecklistItem, ApplicationError> {
+        let active_model = Self::item_to_active_model(item);
+
+        let result = ChecklistItemEntity::update(active_model)
+            .exec(&self.db)
+            .await
+            .map_err(ApplicationError::DatabaseError)?;
+
+        Ok(Self::item_to_domain(result))
+    }
+
+    async fn update_item_positions(
+        &self,
+        positions: Vec<(Uuid, String)>,
+    ) -> Result<(), ApplicationError> {
+        for (id, position) in positions {
+            ChecklistItemEntity::update_many()
+                .col_expr(ChecklistItemColumn::Position, Expr::value(position))
+                .filter(ChecklistItemColumn::Id.eq(id))
+                .exec(&self.db)
+                .await
+                .map_err(ApplicationError::DatabaseError)?;
+        }
+
+        Ok(())
+    }
+
+    async fn delete_item(&self, item_id: Uuid) -> Result<u64, ApplicationError> {
+        let result = ChecklistItemEntity::delete_by_id(item_id)
+            .exec(&self.db)
+            .await
+            .map_err(ApplicationError::DatabaseError)?;
+
+        Ok(result.rows_affected)
+    }
+
+    async fn find_progress(&self, task_id: Uuid) -> Result<ChecklistProgress, ApplicationError> {
+        let items: Vec<bool> = ChecklistItemEntity::find()
+            .select_only()
+            .column(ChecklistItemColumn::IsCompleted)
+            .join(JoinType::InnerJoin, ChecklistItemRelation::Checklist.def())
+            .filter(ChecklistColumn::TaskId.eq(task_id))
+            .into_tuple()
+            .all(&self.db)
+            .await
+            .map_err(ApplicationError::DatabaseError)?;
+
+        Ok(ChecklistProgress {
+            completed_items: items.iter().filter(|is_completed| **is_completed).count() as u64,
+            total_items: items.len() as u64,
+        })
+    }
+}
@@ -5,16 +5,17 @@ use crate::{
 use async_trait::async_trait;
 use entity::{ColumnActiveModel, ColumnColumn, ColumnEntity, ColumnModel};
 use sea_orm::{
-    ActiveValue::Set, ColumnTrait, DatabaseConnection, EntityTrait, QueryFilter, QueryOrder,
+    ActiveValue::Set, ColumnTrait, ConnectionTrait, DatabaseConnection, DbErr, EntityTrait,
+    QueryFilter, QueryOrder, QuerySelect, sea_query::Expr,
 };
 use uuid::Uuid;
 
-pub struct SeaOrmColumnRepository {
-    db: DatabaseConnection,
+pub struct SeaOrmColumnRepository<C = DatabaseConnection> {
+    db: C,
 }
 
-impl SeaOrmColumnRepository {
-    pub fn new(db: DatabaseConnection) -> Self {
+impl<C: ConnectionTrait> SeaOrmColumnRepository<C> {
+    pub fn new(db: C) -> Self {
         Self { db }
     }
 
@@ -26,6 +27,10 @@ impl SeaOrmColumnRepository {
             board_id: model.board_id,
             created_at: model.created_at,
             updated_at: model.updated_at,
+            archived_at: model.archived_at,
+            version: model.version,
+            wip_limit: model.wip_limit,
+            is_done: model.is_done,
         }
     }
 
@@ -37,12 +42,29 @@ impl SeaOrmColumnRepository {
             board_id: Set(column.board_id),
             created_at: Set(column.created_at),
             updated_at: Set(column.updated_at),
+            archived_at: Set(column.archived_at),
+            version: Set(column.version),
+            wip_limit: Set(column.wip_limit),
+            is_done: Set(column.is_done),
+        }
+    }
+
+    async fn version_conflict(&self, column_id: Uuid) -> ApplicationError {
+        match ColumnEntity::find_by_id(column_id).one(&self.db).await {
+            Ok(Some(model)) => ApplicationError::PreconditionFailed {
+                message: "The column has been modified since it was retrieved".to_string(),
+                current_version: model.version,
+            },
+            Ok(None) => ApplicationError::NotFound {
+                message: "Column with the given ID not found".to_string(),
+            },
+            Err(err) => ApplicationError::DatabaseError(err),
         }
     }
 }
 
 #[async_trait]
-impl ColumnRepository for SeaOrmColumnRepository {
+impl<C: ConnectionTrait + Send> ColumnRepository for SeaOrmColumnRepository<C> {
     async fn create(&self, column: Column) -> Result<Column, ApplicationError> {
         let active_model = Self::to_active_model(column);
 
@@ -63,9 +85,31 @@ impl ColumnRepository for SeaOrmColumnRepository {
         Ok(result.map(Self::to_domain))
     }
 
-    async fn find_by_board_id(&self, board_id: Uuid) -> Result<Vec<Column>, ApplicationError> {
-        let result = ColumnEntity::find()
-            .filter(ColumnColumn::BoardId.eq(board_id))
+    async fn find_by_id_for_update(
+        &self,
+        column_id: Uuid,
+    ) -> Result<Option<Column>, ApplicationError> {
+        let result = ColumnEntity::find_by_id(column_id)
+            .lock_exclusive()
+            .one(&self.db)
+            .await
+            .map_err(ApplicationError::DatabaseError)?;
+
+        Ok(result.map(Self::to_domain))
+    }
+
+    async fn find_by_board_id(
+        &self,
+        board_id: Uuid,
+        include_archived: bool,
+    ) -> Result<Vec<Column>, ApplicationError> {
+        let mut query = ColumnEntity::find().filter(ColumnColumn::BoardId.eq(board_id));
+
+        if !include_archived {
+            query = query.filter(ColumnColumn::ArchivedAt.is_null());
+        }
+
+        let result = query
             .order_by_asc(ColumnColumn::Position)
             .all(&self.db)
             .await
@@ -75,16 +119,45 @@ impl ColumnRepository for SeaOrmColumnRepository {
     }
 
     async fn update(&self, column: Column) -> Result<Column, ApplicationError> {
-        let active_model = Self::to_active_model(column);
+        let (column_id, expected_version) = (column.id, column.version);
+
+        let mut active_model = Self::to_active_model(column);
+        active_model.version = Set(expected_version + 1);
 
-        let result = ColumnEntity::update(active_model)
+        // Only applies when nobody else has updated the column since it was read
+        let result = match ColumnEntity::update(active_model)
+            .filter(ColumnColumn::Version.eq(expected_version))
             .exec(&self.db)
             .await
-            .map_err(ApplicationError::DatabaseError)?;
+        {
+            Ok(result) => result,
+            Err(DbErr::RecordNotUpdated) => return Err(self.version_conflict(column_id).await),
+            Err(err) => return Err(ApplicationError::DatabaseError(err)),
+        };
 
         Ok(Self::to_domain(result))
     }
 
+    async fn update_positions(
+        &self,
+        positions: Vec<(Uuid, String)>,
+    ) -> Result<(), ApplicationError> {
+        for (id, position) in positions {
+            ColumnEntity::update_many()
+                .col_expr(ColumnColumn::Position, Expr::value(position))
+                .col_expr(
+                    ColumnColumn::Version,
+                    Expr::col(ColumnColumn::Version).add(1),
+                )
+                .filter(ColumnColumn::Id.eq(id))
+                .exec(&self.db)
+                .await
+                .map_err(ApplicationError::DatabaseError)?;
+        }
+
+        Ok(())
+    }
+
     async fn delete(&self, column_id: Uuid) -> Result<u64, ApplicationError> {
         let result = ColumnEntity::delete_by_id(column_id)
             .exec(&self.db)
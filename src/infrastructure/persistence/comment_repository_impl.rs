@@ -0,0 +1,145 @@
+use crate::{
+    domain::repositories::{Comment, CommentRepository, CommentRevision},
+    shared::error::ApplicationError,
+};
+use async_trait::async_trait;
+use entity::{
+    CommentActiveModel, CommentColumn, CommentEntity, CommentModel, CommentRevisionActiveModel,
+    CommentRevisionColumn, CommentRevisionEntity, CommentRevisionModel,
+};
+use sea_orm::{
+    ActiveValue::Set, ColumnTrait, ConnectionTrait, DatabaseConnection, EntityTrait, QueryFilter,
+    QueryOrder,
+};
+use uuid::Uuid;
+
+pub struct SeaOrmCommentRepository<C = DatabaseConnection> {
+    db: C,
+}
+
+impl<C: ConnectionTrait> SeaOrmCommentRepository<C> {
+    pub fn new(db: C) -> Self {
+        Self { db }
+    }
+
+    fn to_domain(model: CommentModel) -> Comment {
+        Comment {
+            id: model.id,
+            task_id: model.task_id,
+            parent_id: model.parent_id,
+            author_id: model.author_id,
+            content: model.content,
+            created_at: model.created_at,
+            updated_at: model.updated_at,
+        }
+    }
+
+    fn to_active_model(comment: Comment) -> CommentActiveModel {
+        CommentActiveModel {
+            id: Set(comment.id),
+            task_id: Set(comment.task_id),
+            parent_id: Set(comment.parent_id),
+            author_id: Set(comment.author_id),
+            content: Set(comment.content),
+            created_at: Set(comment.created_at),
+            updated_at: Set(comment.updated_at),
+        }
+    }
+
+    fn revision_to_domain(model: CommentRevisionModel) -> CommentRevision {
+        CommentRevision {
+            id: model.id,
+            comment_id: model.comment_id,
+            content: model.content,
+            edited_by: model.edited_by,
+            created_at: model.created_at,
+        }
+    }
+
+    fn revision_to_active_model(revision: CommentRevision) -> CommentRevisionActiveModel {
+        CommentRevisionActiveModel {
+            id: Set(revision.id),
+            comment_id: Set(revision.comment_id),
+            content: Set(revision.content),
+            edited_by: Set(revision.edited_by),
+            created_at: Set(revision.created_at),
+        }
+    }
+}
+
+#[async_trait]
+impl<C: ConnectionTrait + Send> CommentRepository for SeaOrmCommentRepository<C> {
+    async fn create(&self, comment: Comment) -> Result<Comment, ApplicationError> {
+        let active_model = Self::to_active_model(comment);
+
+        let result = CommentEntity::insert(active_model)
+            .exec_with_returning(&self.db)
+            .await
+            .map_err(ApplicationError::DatabaseError)?;
+
+        Ok(Self::to_domain(result))
+    }
+
+    async fn find_by_id(&self, comment_id: Uuid) -> Result<Option<Comment>, ApplicationError> {
+        let result = CommentEntity::find_by_id(comment_id)
+            .one(&self.db)
+            .await
+            .map_err(ApplicationError::DatabaseError)?;
+
+        Ok(result.map(Self::to_domain))
+    }
+
+    async fn find_by_task_id(&self, task_id: Uuid) -> Result<Vec<Comment>, ApplicationError> {
+        let result = CommentEntity::find()
+            .filter(CommentColumn::TaskId.eq(task_id))
+            .order_by_asc(CommentColumn::CreatedAt)
+            .all(&self.db)
+            .await
+            .map_err(ApplicationError::DatabaseError)?;
+
+        Ok(result.into_iter().map(Self::to_domain).collect())
+    }
+
+    async fn update(
+        &self,
+        comment: Comment,
+        revision: CommentRevision,
+    ) -> Result<Comment, ApplicationError> {
+        CommentRevisionEntity::insert(Self::revision_to_active_model(revision))
+            .exec(&self.db)
+            .await
+            .map_err(ApplicationError::DatabaseError)?;
+
+        let active_model = Self::to_active_model(comment);
+
+        let result = CommentEntity::update(active_model)
+            .exec(&self.db)
+            .await
+            .map_err(ApplicationError::DatabaseError)?;
+
+        Ok(Self::to_domain(result))
+    }
+
+    async fn find_revisions(
+        &self,
+        comment_id: Uuid,
+    ) -> Result<Vec<CommentRevision>, ApplicationError> {
+        let result = CommentRevisionEntity::find()
+            .filter(CommentRevisionColumn::CommentId.eq(comment_id))
+            .order_by_asc(CommentRevisionColumn::CreatedAt)
+            .all(&self.db)
+            .await
+            .map_err(ApplicationError::DatabaseError)?;
+
+        Ok(result.into_iter().map(Self::revision_to_domain).collect())
+    }
+
+    async fn delete(&self, comment_id: Uuid) -> Result<u64, ApplicationError> {
+        let result = CommentEntity::delete_by_id(comment_id)
+            .exec(&self.db)
+            .await
+            .map_err(ApplicationError::DatabaseError)?;
+
+        Ok(result.rows_affected)
+    }
+}
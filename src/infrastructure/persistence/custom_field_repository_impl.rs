@@ -0,0 +1,166 @@
+use crate::{
+    domain::repositories::{CustomField, CustomFieldRepository},
+    shared::error::ApplicationError,
+};
+use async_trait::async_trait;
+use entity::{
+    CustomFieldActiveModel, CustomFieldColumn, CustomFieldEntity, CustomFieldModel, TaskColumn,
+    TaskEntity,
+};
+use sea_orm::{
+    ActiveValue::Set,
+    ColumnTrait, ConnectionTrait, DatabaseConnection, EntityTrait, QueryFilter, QueryOrder,
+    QuerySelect,
+    sea_query::{BinOper, Expr, Func, extension::postgres::PgExpr},
+};
+use uuid::Uuid;
+
+pub struct SeaOrmCustomFieldRepository<C = DatabaseConnection> {
+    db: C,
+}
+
+impl<C: ConnectionTrait> SeaOrmCustomFieldRepository<C> {
+    pub fn new(db: C) -> Self {
+        Self { db }
+    }
+
+    fn to_domain(model: CustomFieldModel) -> CustomField {
+        CustomField {
+            id: model.id,
+            board_id: model.board_id,
+            name: model.name,
+            field_type: model.field_type,
+            options: model.options,
+            created_at: model.created_at,
+            updated_at: model.updated_at,
+        }
+    }
+
+    fn to_active_model(field: CustomField) -> CustomFieldActiveModel {
+        CustomFieldActiveModel {
+            id: Set(field.id),
+            board_id: Set(field.board_id),
+            name: Set(field.name),
+            field_type: Set(field.field_type),
+            options: Set(field.options),
+            created_at: Set(field.created_at),
+            updated_at: Set(field.updated_at),
+        }
+    }
+}
+
+#[async_trait]
+impl<C: ConnectionTrait + Send> CustomFieldRepository for SeaOrmCustomFieldRepository<C> {
+    async fn create(&self, field: CustomField) -> Result<CustomField, ApplicationError> {
+        let active_model = Self::to_active_model(field);
+
+        let result = CustomFieldEntity::insert(active_model)
+            .exec_with_returning(&self.db)
+            .await
+            .map_err(ApplicationError::DatabaseError)?;
+
+        Ok(Self::to_domain(result))
+    }
+
+    async fn find_by_id(&self, field_id: Uuid) -> Result<Option<CustomField>, ApplicationError> {
+        let result = CustomFieldEntity::find_by_id(field_id)
+            .one(&self.db)
+            .await
+            .map_err(ApplicationError::DatabaseError)?;
+
+        Ok(result.map(Self::to_domain))
+    }
+
+    async fn find_by_ids(
+        &self,
+        field_ids: Vec<Uuid>,
+    ) -> Result<Vec<CustomField>, ApplicationError> {
+        let result = CustomFieldEntity::find()
+            .filter(CustomFieldColumn::Id.is_in(field_ids))
+            .order_by_asc(CustomFieldColumn::CreatedAt)
+            .all(&self.db)
+            .await
+            .map_err(ApplicationError::DatabaseError)?;
+
+        Ok(result.into_iter().map(Self::to_domain).collect())
+    }
+
+    async fn find_by_board_id(&self, board_id: Uuid) -> Result<Vec<CustomField>, ApplicationError> {
+        let result = CustomFieldEntity::find()
+            .filter(CustomFieldColumn::BoardId.eq(board_id))
+            .order_by_asc(CustomFieldColumn::CreatedAt)
+            .all(&self.db)
+            .await
+            .map_err(ApplicationError::DatabaseError)?;
+
+        Ok(result.into_iter().map(Self::to_domain).collect())
+    }
+
+    async fn find_by_board_and_name(
+        &self,
+        board_id: Uuid,
+        name: &str,
+    ) -> Result<Option<CustomField>, ApplicationError> {
+        let result = CustomFieldEntity::find()
+            .filter(CustomFieldColumn::BoardId.eq(board_id))
+            .filter(
+                Expr::expr(Func::lower(Expr::col(CustomFieldColumn::Name))).eq(name.to_lowercase()),
+            )
+            .one(&self.db)
+            .await
+            .map_err(ApplicationError::DatabaseError)?;
+
+        Ok(result.map(Self::to_domain))
+    }
+
+    async fn find_task_ids(&self, field_id: Uuid) -> Result<Vec<Uuid>, ApplicationError> {
+        TaskEntity::find()
+            .select_only()
+            .column(TaskColumn::Id)
+            .filter(
+                Expr::expr(
+                    Expr::col(TaskColumn::CustomFields).get_json_field(field_id.to_string()),
+                )
+                .is_not_null(),
+            )
+            .order_by_asc(TaskColumn::CreatedAt)
+            .into_tuple()
+            .all(&self.db)
+            .await
+            .map_err(ApplicationError::DatabaseError)
+    }
+
+    async fn update(&self, field: CustomField) -> Result<CustomField, ApplicationError> {
+        let active_model = Self::to_active_model(field);
+
+        let result = CustomFieldEntity::update(active_model)
+            .exec(&self.db)
+            .await
+            .map_err(ApplicationError::DatabaseError)?;
+
+        Ok(Self::to_domain(result))
+    }
+
+    async fn delete(&self, field_id: Uuid) -> Result<u64, ApplicationError> {
+        let key = field_id.to_string();
+
+        TaskEntity::update_many()
+            .col_expr(
+                TaskColumn::CustomFields,
+                Expr::col(TaskColumn::CustomFields).binary(BinOper::Sub, Expr::val(key.clone())),
+            )
+            .filter(
+                Expr::expr(Expr::col(TaskColumn::CustomFields).get_json_field(key)).is_not_null(),
+            )
+            .exec(&self.db)
+            .await
+            .map_err(ApplicationError::DatabaseError)?;
+
+        let result = CustomFieldEntity::delete_by_id(field_id)
+            .exec(&self.db)
+            .await
+            .map_err(ApplicationError::DatabaseError)?;
+
+        Ok(result.rows_affected)
+    }
+}
@@ -0,0 +1,139 @@
+use crate::{
+    domain::repositories::{Label, LabelRepository},
+    shared::error::ApplicationError,
+};
+use async_trait::async_trait;
+use entity::{
+    LabelActiveModel, LabelColumn, LabelEntity, LabelModel, TaskLabelColumn, TaskLabelEntity,
+};
+use sea_orm::{
+    ActiveValue::Set,
+    ColumnTrait, ConnectionTrait, DatabaseConnection, EntityTrait, QueryFilter, QueryOrder,
+    QuerySelect,
+    sea_query::{Expr, Func},
+};
+use uuid::Uuid;
+
+pub struct SeaOrmLabelRepository<C = DatabaseConnection> {
+    db: C,
+}
+
+impl<C: ConnectionTrait> SeaOrmLabelRepository<C> {
+    pub fn new(db: C) -> Self {
+        Self { db }
+    }
+
+    fn to_domain(model: LabelModel) -> Label {
+        Label {
+            id: model.id,
+            board_id: model.board_id,
+            name: model.name,
+            color: model.color,
+            created_at: model.created_at,
+            updated_at: model.updated_at,
+        }
+    }
+
+    fn to_active_model(label: Label) -> LabelActiveModel {
+        LabelActiveModel {
+            id: Set(label.id),
+            board_id: Set(label.board_id),
+            name: Set(label.name),
+            color: Set(label.color),
+            created_at: Set(label.created_at),
+            updated_at: Set(label.updated_at),
+        }
+    }
+}
+
+#[async_trait]
+impl<C: ConnectionTrait + Send> LabelRepository for SeaOrmLabelRepository<C> {
+    async fn create(&self, label: Label) -> Result<Label, ApplicationError> {
+        let active_model = Self::to_active_model(label);
+
+        let result = LabelEntity::insert(active_model)
+            .exec_with_returning(&self.db)
+            .await
+            .map_err(ApplicationError::DatabaseError)?;
+
+        Ok(Self::to_domain(result))
+    }
+
+    async fn find_by_id(&self, label_id: Uuid) -> Result<Option<Label>, ApplicationError> {
+        let result = LabelEntity::find_by_id(label_id)
+            .one(&self.db)
+            .await
+            .map_err(ApplicationError::DatabaseError)?;
+
+        Ok(result.map(Self::to_domain))
+    }
+
+    async fn find_by_ids(&self, label_ids: Vec<Uuid>) -> Result<Vec<Label>, ApplicationError> {
+        let result = LabelEntity::find()
+            .filter(LabelColumn::Id.is_in(label_ids))
+            .order_by_asc(LabelColumn::Name)
+            .all(&self.db)
+            .await
+            .map_err(ApplicationError::DatabaseError)?;
+
+        Ok(result.into_iter().map(Self::to_domain).collect())
+    }
+
+    async fn find_by_board_id(&self, board_id: Uuid) -> Result<Vec<Label>, ApplicationError> {
+        let result = LabelEntity::find()
+            .filter(LabelColumn::BoardId.eq(board_id))
+            .order_by_asc(LabelColumn::Name)
+            .all(&self.db)
+            .await
+            .map_err(ApplicationError::DatabaseError)?;
+
+        Ok(result.into_iter().map(Self::to_domain).collect())
+    }
+
+    async fn find_by_board_and_name(
+        &self,
+        board_id: Uuid,
+        name: &str,
+    ) -> Result<Option<Label>, ApplicationError> {
+        let result = LabelEntity::find()
+            .filter(LabelColumn::BoardId.eq(board_id))
+            .filter(Expr::expr(Func::lower(Expr::col(LabelColumn::Name))).eq(name.to_lowercase()))
+            .one(&self.db)
+            .await
+            .map_err(ApplicationError::DatabaseError)?;
+
+        Ok(result.map(Self::to_domain))
+    }
+
+    async fn find_task_ids(&self, label_id: Uuid) -> Result<Vec<Uuid>, ApplicationError> {
+        TaskLabelEntity::find()
+            .select_only()
+            .column(TaskLabelColumn::TaskId)
+            .filter(TaskLabelColumn::LabelId.eq(label_id))
+            .order_by_asc(TaskLabelColumn::CreatedAt)
+            .into_tuple()
+            .all(&self.db)
+            .await
+            .map_err(ApplicationError::DatabaseError)
+    }
+
+    async fn update(&self, label: Label) -> Result<Label, ApplicationError> {
+        let active_model = Self::to_active_model(label);
+
+        let result = LabelEntity::update(active_model)
+            .exec(&self.db)
+            .await
+            .map_err(ApplicationError::DatabaseError)?;
+
+        Ok(Self::to_domain(result))
+    }
+
+    async fn delete(&self, label_id: Uuid) -> Result<u64, ApplicationError> {
+        let result = LabelEntity::delete_by_id(label_id)
+            .exec(&self.db)
+            .await
+            .map_err(ApplicationError::DatabaseError)?;
+
+        Ok(result.rows_affected)
+    }
+}
@@ -1,12 +1,32 @@
+pub mod attachment_repository_impl;
+pub mod board_activity_repository_impl;
 pub mod board_member_repository_impl;
 pub mod board_repository_impl;
+pub mod checklist_repository_impl;
 pub mod column_repository_impl;
+pub mod comment_repository_impl;
+pub mod custom_field_repository_impl;
 pub mod database;
+pub mod label_repository_impl;
+pub mod outbox_repository_impl;
+pub mod task_relation_repository_impl;
 pub mod task_repository_impl;
+pub mod time_entry_repository_impl;
+pub mod unit_of_work_impl;
 pub mod user_repository_impl;
 
+pub use attachment_repository_impl::SeaOrmAttachmentRepository;
+pub use board_activity_repository_impl::SeaOrmBoardActivityRepository;
 pub use board_member_repository_impl::SeaOrmBoardMemberRepository;
 pub use board_repository_impl::SeaOrmBoardRepository;
+pub use checklist_repository_impl::SeaOrmChecklistRepository;
 pub use column_repository_impl::SeaOrmColumnRepository;
+pub use comment_repository_impl::SeaOrmCommentRepository;
+pub use custom_field_repository_impl::SeaOrmCustomFieldRepository;
+pub use label_repository_impl::SeaOrmLabelRepository;
+pub use outbox_repository_impl::SeaOrmOutboxRepository;
+pub use task_relation_repository_impl::SeaOrmTaskRelationRepository;
 pub use task_repository_impl::SeaOrmTaskRepository;
+pub use time_entry_repository_impl::SeaOrmTimeEntryRepository;
+pub use unit_of_work_impl::SeaOrmUnitOfWorkFactory;
 pub use user_repository_impl::SeaOrmUserRepository;
@@ -0,0 +1,134 @@
+use crate::{
+    domain::repositories::{OutboxMessage, OutboxRepository},
+    shared::error::ApplicationError,
+};
+use async_trait::async_trait;
+use chrono::{DateTime, FixedOffset, Utc};
+use entity::{EventOutboxActiveModel, EventOutboxColumn, EventOutboxEntity, EventOutboxModel};
+use sea_orm::{
+    ActiveValue::Set, ColumnTrait, ConnectionTrait, DatabaseConnection, DbErr, EntityTrait,
+    QueryFilter,
+};
+use sea_query::{Expr, LockBehavior, LockType, Order, Query};
+use uuid::Uuid;
+
+pub struct SeaOrmOutboxRepository<C = DatabaseConnection> {
+    db: C,
+}
+
+impl<C: ConnectionTrait> SeaOrmOutboxRepository<C> {
+    pub fn new(db: C) -> Self {
+        Self { db }
+    }
+
+    fn to_domain(model: EventOutboxModel) -> Result<OutboxMessage, ApplicationError> {
+        let event = serde_json::from_value(model.payload)
+            .map_err(|err| ApplicationError::DatabaseError(DbErr::Json(err.to_string())))?;
+
+        Ok(OutboxMessage {
+            id: model.id,
+            board_id: model.board_id,
+            event,
+            attempts: model.attempts,
+            last_error: model.last_error,
+            available_at: model.available_at,
+            created_at: model.created_at,
+        })
+    }
+
+    fn to_active_model(message: OutboxMessage) -> Result<EventOutboxActiveModel, ApplicationError> {
+        let payload = serde_json::to_value(&message.event)
+            .map_err(|err| ApplicationError::DatabaseError(DbErr::Json(err.to_string())))?;
+        let event_type = payload["type"].as_str().unwrap_or_default().to_string();
+
+        Ok(EventOutboxActiveModel {
+            id: Set(message.id),
+            board_id: Set(message.board_id),
+            event_type: Set(event_type),
+            payload: Set(payload),
+            attempts: Set(message.attempts),
+            last_error: Set(message.last_error),
+            available_at: Set(message.available_at),
+            created_at: Set(message.created_at),
+        })
+    }
+}
+
+#[async_trait]
+impl<C: ConnectionTrait + Send> OutboxRepository for SeaOrmOutboxRepository<C> {
+    async fn create(&self, message: OutboxMessage) -> Result<OutboxMessage, ApplicationError> {
+        let active_model = Self::to_active_model(message)?;
+
+        let result = EventOutboxEntity::insert(active_model)
+            .exec_with_returning(&self.db)
+            .await
+            .map_err(ApplicationError::DatabaseError)?;
+
+        Self::to_domain(result)
+    }
+
+    async fn claim_available(
+        &self,
+        limit: u64,
+        lease_until: DateTime<FixedOffset>,
+    ) -> Result<Vec<OutboxMessage>, ApplicationError> {
+        let board_heads = Query::select()
+            .distinct_on([EventOutboxColumn::BoardId])
+            .column(EventOutboxColumn::Id)
+            .from(EventOutboxEntity)
+            .order_by(EventOutboxColumn::BoardId, Order::Asc)
+            .order_by(EventOutboxColumn::Id, Order::Asc)
+            .to_owned();
+
+        let available = Query::select()
+            .column(EventOutboxColumn::Id)
+            .from(EventOutboxEntity)
+            .and_where(Expr::col(EventOutboxColumn::Id).in_subquery(board_heads))
+            .and_where(Expr::col(EventOutboxColumn::AvailableAt).lte(Utc::now().fixed_offset()))
+            .order_by(EventOutboxColumn::Id, Order::Asc)
+            .limit(limit)
+            .lock_with_behavior(LockType::Update, LockBehavior::SkipLocked)
+            .to_owned();
+
+        let mut result = EventOutboxEntity::update_many()
+            .col_expr(EventOutboxColumn::AvailableAt, Expr::value(lease_until))
+            .filter(EventOutboxColumn::Id.in_subquery(available))
+            .exec_with_returning(&self.db)
+            .await
+            .map_err(ApplicationError::DatabaseError)?;
+
+        result.sort_by_key(|model| model.id);
+
+        result.into_iter().map(Self::to_domain).collect()
+    }
+
+    async fn record_failure(
+        &self,
+        id: Uuid,
+        error: String,
+        retry_at: DateTime<FixedOffset>,
+    ) -> Result<(), ApplicationError> {
+        EventOutboxEntity::update_many()
+            .col_expr(
+                EventOutboxColumn::Attempts,
+                Expr::col(EventOutboxColumn::Attempts).add(1),
+            )
+            .col_expr(EventOutboxColumn::LastError, Expr::value(error))
+            .col_expr(EventOutboxColumn::AvailableAt, Expr::value(retry_at))
+            .filter(EventOutboxColumn::Id.eq(id))
+            .exec(&self.db)
+            .await
+            .map_err(ApplicationError::DatabaseError)?;
+
+        Ok(())
+    }
+
+    async fn delete(&self, id: Uuid) -> Result<u64, ApplicationError> {
+        let result = EventOutboxEntity::delete_by_id(id)
+            .exec(&self.db)
+            .await
+            .map_err(ApplicationError::DatabaseError)?;
+
+        Ok(result.rows_affected)
+    }
+}
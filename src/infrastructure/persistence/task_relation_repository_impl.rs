@@ -0,0 +1,116 @@
+use crate::{
+    domain::repositories::{TaskRelation, TaskRelationRepository},
+    shared::error::ApplicationError,
+};
+use async_trait::async_trait;
+use entity::{
+    ColumnColumn, TaskRelation as TaskEntityRelation, TaskRelationActiveModel, TaskRelationColumn,
+    TaskRelationEntity, TaskRelationModel, TaskRelationRelation, TaskRelationTypeEnum,
+};
+use sea_orm::{
+    ActiveValue::Set, ColumnTrait, Condition, ConnectionTrait, DatabaseConnection, EntityTrait,
+    JoinType, QueryFilter, QueryOrder, QuerySelect, RelationTrait,
+};
+use uuid::Uuid;
+
+pub struct SeaOrmTaskRelationRepository<C = DatabaseConnection> {
+    db: C,
+}
+
+impl<C: ConnectionTrait> SeaOrmTaskRelationRepository<C> {
+    pub fn new(db: C) -> Self {
+        Self { db }
+    }
+
+    fn to_domain(model: TaskRelationModel) -> TaskRelation {
+        TaskRelation {
+            id: model.id,
+            source_task_id: model.source_task_id,
+            target_task_id: model.target_task_id,
+            relation_type: model.relation_type,
+            created_by: model.created_by,
+            created_at: model.created_at,
+        }
+    }
+
+    fn to_active_model(relation: TaskRelation) -> TaskRelationActiveModel {
+        TaskRelationActiveModel {
+            id: Set(relation.id),
+            source_task_id: Set(relation.source_task_id),
+            target_task_id: Set(relation.target_task_id),
+            relation_type: Set(relation.relation_type),
+            created_by: Set(relation.created_by),
+            created_at: Set(relation.created_at),
+        }
+    }
+}
+
+#[async_trait]
+impl<C: ConnectionTrait + Send> TaskRelationRepository for SeaOrmTaskRelationRepository<C> {
+    async fn create(&self, relation: TaskRelation) -> Result<TaskRelation, ApplicationError> {
+        let active_model = Self::to_active_model(relation);
+
+        let result = TaskRelationEntity::insert(active_model)
+            .exec_with_returning(&self.db)
+            .await
+            .map_err(ApplicationError::DatabaseError)?;
+
+        Ok(Self::to_domain(result))
+    }
+
+    async fn find_by_board_id(
+        &self,
+        board_id: Uuid,
+        relation_type: TaskRelationTypeEnum,
+    ) -> Result<Vec<TaskRelation>, ApplicationError> {
+        // Both tasks of a relation always share the board, so joining the source is enough
+        let result = TaskRelationEntity::find()
+            .join(JoinType::InnerJoin, TaskRelationRelation::SourceTask.def())
+            .join(JoinType::InnerJoin, TaskEntityRelation::Column.def())
+            .filter(ColumnColumn::BoardId.eq(board_id))
+            .filter(TaskRelationColumn::RelationType.eq(relation_type))
+            .order_by_asc(TaskRelationColumn::CreatedAt)
+            .all(&self.db)
+            .await
+            .map_err(ApplicationError::DatabaseError)?;
+
+        Ok(result.into_iter().map(Self::to_domain).collect())
+    }
+
+    async fn delete(&self, relation_id: Uuid) -> Result<u64, ApplicationError> {
+        let result = TaskRelationEntity::delete_by_id(relation_id)
+            .exec(&self.db)
+            .await
+            .map_err(ApplicationError::DatabaseError)?;
+
+        Ok(result.rows_affected)
+    }
+
+    async fn delete_by_task_id(
+        &self,
+        task_id: Uuid,
+    ) -> Result<Vec<TaskRelation>, ApplicationError> {
+        let condition = Condition::any()
+            .add(TaskRelationColumn::SourceTaskId.eq(task_id))
+            .add(TaskRelationColumn::TargetTaskId.eq(task_id));
+
+        let relations = TaskRelationEntity::find()
+            .filter(condition.clone())
+            .order_by_asc(TaskRelationColumn::CreatedAt)
+            .all(&self.db)
+            .await
+            .map_err(ApplicationError::DatabaseError)?;
+
+        if relations.is_empty() {
+            return Ok(Vec::new());
+        }
+
+        TaskRelationEntity::delete_many()
+            .filter(condition)
+            .exec(&self.db)
+            .await
+            .map_err(ApplicationError::DatabaseError)?;
+
+        Ok(relations.into_iter().map(Self::to_domain).collect())
+    }
+}
@@ -1,31 +1,73 @@
 use crate::{
-    domain::repositories::{Task, TaskRepository},
+    domain::repositories::{
+        ChecklistProgress, CustomFieldCondition, Task, TaskFilter, TaskRelation, TaskRepository,
+    },
     shared::error::ApplicationError,
 };
 use async_trait::async_trait;
-use entity::{TaskActiveModel, TaskColumn, TaskEntity, TaskModel};
-use sea_orm::{ActiveValue::Set, ColumnTrait, DatabaseConnection, EntityTrait, QueryFilter};
+use chrono::Utc;
+use entity::{
+    ChecklistColumn, ChecklistItemColumn, ChecklistItemEntity, ChecklistItemRelation, ColumnColumn,
+    TaskActiveModel, TaskAssigneeActiveModel, TaskAssigneeColumn, TaskAssigneeEntity,
+    TaskAssigneeRelation, TaskColumn, TaskEntity, TaskLabelActiveModel, TaskLabelColumn,
+    TaskLabelEntity, TaskModel, TaskRelation as TaskEntityRelation, TaskRelationColumn,
+    TaskRelationEntity,
+};
+use sea_orm::{
+    ActiveValue::Set,
+    ColumnTrait, Condition, ConnectionTrait, DatabaseConnection, DbErr, EntityTrait, JoinType,
+    QueryFilter, QueryOrder, QuerySelect, RelationTrait,
+    sea_query::{
+        Alias, Expr, Func, Query,
+        extension::postgres::{PgBinOper, PgExpr},
+    },
+};
+use serde_json::{Map, Value};
+use std::collections::HashMap;
 use uuid::Uuid;
 
-pub struct SeaOrmTaskRepository {
-    db: DatabaseConnection,
+pub struct SeaOrmTaskRepository<C = DatabaseConnection> {
+    db: C,
 }
 
-impl SeaOrmTaskRepository {
-    pub fn new(db: DatabaseConnection) -> Self {
+impl<C: ConnectionTrait> SeaOrmTaskRepository<C> {
+    pub fn new(db: C) -> Self {
         Self { db }
     }
 
-    fn to_domain(model: TaskModel) -> Task {
+    fn to_domain(
+        model: TaskModel,
+        assignee_ids: Vec<Uuid>,
+        label_ids: Vec<Uuid>,
+        checklist_progress: ChecklistProgress,
+        relations: Vec<TaskRelation>,
+    ) -> Task {
         Task {
             id: model.id,
             title: model.title,
             description: model.description,
-            tags: model.tags,
+            label_ids,
             position: model.position,
             column_id: model.column_id,
+            assignee_ids,
+            priority: model.priority,
+            start_at: model.start_at,
+            due_at: model.due_at,
+            estimate_minutes: model.estimate_minutes,
             created_at: model.created_at,
             updated_at: model.updated_at,
+            archived_at: model.archived_at,
+            version: model.version,
+            completed_at: model.completed_at,
+            checklist_progress,
+            relations,
+            custom_fields: match model.custom_fields {
+                Value::Object(values) => values
+                    .into_iter()
+                    .filter_map(|(key, value)| Some((key.parse().ok()?, value)))
+                    .collect(),
+                _ => HashMap::new(),
+            },
         }
     }
 
@@ -34,18 +76,258 @@ impl SeaOrmTaskRepository {
             id: Set(task.id),
             title: Set(task.title),
             description: Set(task.description),
-            tags: Set(task.tags),
             position: Set(task.position),
             column_id: Set(task.column_id),
             created_at: Set(task.created_at),
             updated_at: Set(task.updated_at),
+            priority: Set(task.priority),
+            start_at: Set(task.start_at),
+            due_at: Set(task.due_at),
+            archived_at: Set(task.archived_at),
+            version: Set(task.version),
+            completed_at: Set(task.completed_at),
+            custom_fields: Set(Value::Object(
+                task.custom_fields
+                    .into_iter()
+                    .map(|(field_id, value)| (field_id.to_string(), value))
+                    .collect::<Map<String, Value>>(),
+            )),
+            estimate_minutes: Set(task.estimate_minutes),
+        }
+    }
+
+    /// Escapes the wildcards of a LIKE pattern and matches the text anywhere
+    fn like_pattern(text: &str) -> String {
+        let escaped = text
+            .to_lowercase()
+            .replace('\\', "\\\\")
+            .replace('%', "\\%")
+            .replace('_', "\\_");
+
+        format!("%{}%", escaped)
+    }
+
+    /// Loads the assignees, labels, checklist progress and relations of the given task rows
+    async fn with_details(&self, models: Vec<TaskModel>) -> Result<Vec<Task>, ApplicationError> {
+        let task_ids: Vec<Uuid> = models.iter().map(|model| model.id).collect();
+        let mut assignees = self.find_assignee_ids(task_ids.clone()).await?;
+        let mut labels = self.find_label_ids(task_ids.clone()).await?;
+        let mut progress = self.find_checklist_progress(task_ids.clone()).await?;
+        let mut relations = self.find_relations(task_ids).await?;
+
+        Ok(models
+            .into_iter()
+            .map(|model| {
+                let assignee_ids = assignees.remove(&model.id).unwrap_or_default();
+                let label_ids = labels.remove(&model.id).unwrap_or_default();
+                let checklist_progress = progress.remove(&model.id).unwrap_or_default();
+                let task_relations = relations.remove(&model.id).unwrap_or_default();
+                Self::to_domain(
+                    model,
+                    assignee_ids,
+                    label_ids,
+                    checklist_progress,
+                    task_relations,
+                )
+            })
+            .collect())
+    }
+
+    async fn find_assignee_ids(
+        &self,
+        task_ids: Vec<Uuid>,
+    ) -> Result<HashMap<Uuid, Vec<Uuid>>, ApplicationError> {
+        let assignees = TaskAssigneeEntity::find()
+            .filter(TaskAssigneeColumn::TaskId.is_in(task_ids))
+            .order_by_asc(TaskAssigneeColumn::CreatedAt)
+            .all(&self.db)
+            .await
+            .map_err(ApplicationError::DatabaseError)?;
+
+        let mut result: HashMap<Uuid, Vec<Uuid>> = HashMap::new();
+        for assignee in assignees {
+            result
+                .entry(assignee.task_id)
+                .or_default()
+                .push(assignee.user_id);
+        }
+
+        Ok(result)
+    }
+
+    async fn find_label_ids(
+        &self,
+        task_ids: Vec<Uuid>,
+    ) -> Result<HashMap<Uuid, Vec<Uuid>>, ApplicationError> {
+        let task_labels = TaskLabelEntity::find()
+            .filter(TaskLabelColumn::TaskId.is_in(task_ids))
+            .order_by_asc(TaskLabelColumn::CreatedAt)
+            .all(&self.db)
+            .await
+            .map_err(ApplicationError::DatabaseError)?;
+
+        let mut result: HashMap<Uuid, Vec<Uuid>> = HashMap::new();
+        for task_label in task_labels {
+            result
+                .entry(task_label.task_id)
+                .or_default()
+                .push(task_label.label_id);
+        }
+
+        Ok(result)
+    }
+
+    async fn find_checklist_progress(
+        &self,
+        task_ids: Vec<Uuid>,
+    ) -> Result<HashMap<Uuid, ChecklistProgress>, ApplicationError> {
+        let items: Vec<(Uuid, bool)> = ChecklistItemEntity::find()
+            .select_only()
+            .column(ChecklistColumn::TaskId)
+            .column(ChecklistItemColumn::IsCompleted)
+            .join(JoinType::InnerJoin, ChecklistItemRelation::Checklist.def())
+            .filter(ChecklistColumn::TaskId.is_in(task_ids))
+            .into_tuple()
+            .all(&self.db)
+            .await
+            .map_err(ApplicationError::DatabaseError)?;
+
+        let mut result: HashMap<Uuid, ChecklistProgress> = HashMap::new();
+        for (task_id, is_completed) in items {
+            let progress = result.entry(task_id).or_default();
+            progress.total_items += 1;
+            if is_completed {
+                progress.completed_items += 1;
+            }
+        }
+
+        Ok(result)
+    }
+
+    async fn find_relations(
+        &self,
+        task_ids: Vec<Uuid>,
+    ) -> Result<HashMap<Uuid, Vec<TaskRelation>>, ApplicationError> {
+        let relations = TaskRelationEntity::find()
+            .filter(
+                Condition::any()
+                    .add(TaskRelationColumn::SourceTaskId.is_in(task_ids.clone()))
+                    .add(TaskRelationColumn::TargetTaskId.is_in(task_ids)),
+            )
+            .order_by_asc(TaskRelationColumn::CreatedAt)
+            .all(&self.db)
+            .await
+            .map_err(ApplicationError::DatabaseError)?;
+
+        let mut result: HashMap<Uuid, Vec<TaskRelation>> = HashMap::new();
+        for model in relations {
+            let relation = TaskRelation {
+                id: model.id,
+                source_task_id: model.source_task_id,
+                target_task_id: model.target_task_id,
+                relation_type: model.relation_type,
+                created_by: model.created_by,
+                created_at: model.created_at,
+            };
+
+            result
+                .entry(relation.target_task_id)
+                .or_default()
+                .push(relation.clone());
+            result
+                .entry(relation.source_task_id)
+                .or_default()
+                .push(relation);
+        }
+
+        Ok(result)
+    }
+
+    async fn replace_assignees(
+        &self,
+        task_id: Uuid,
+        assignee_ids: &[Uuid],
+    ) -> Result<(), ApplicationError> {
+        TaskAssigneeEntity::delete_many()
+            .filter(TaskAssigneeColumn::TaskId.eq(task_id))
+            .exec(&self.db)
+            .await
+            .map_err(ApplicationError::DatabaseError)?;
+
+        if assignee_ids.is_empty() {
+            return Ok(());
+        }
+
+        let now = Utc::now().fixed_offset();
+        let active_models = assignee_ids.iter().map(|user_id| TaskAssigneeActiveModel {
+            id: Set(Uuid::now_v7()),
+            task_id: Set(task_id),
+            user_id: Set(*user_id),
+            created_at: Set(now),
+        });
+
+        TaskAssigneeEntity::insert_many(active_models)
+            .exec(&self.db)
+            .await
+            .map_err(ApplicationError::DatabaseError)?;
+
+        Ok(())
+    }
+
+    async fn replace_labels(
+        &self,
+        task_id: Uuid,
+        label_ids: &[Uuid],
+    ) -> Result<(), ApplicationError> {
+        TaskLabelEntity::delete_many()
+            .filter(TaskLabelColumn::TaskId.eq(task_id))
+            .exec(&self.db)
+            .await
+            .map_err(ApplicationError::DatabaseError)?;
+
+        if label_ids.is_empty() {
+            return Ok(());
+        }
+
+        let now = Utc::now().fixed_offset();
+        let active_models = label_ids.iter().map(|label_id| TaskLabelActiveModel {
+            id: Set(Uuid::now_v7()),
+            task_id: Set(task_id),
+            label_id: Set(*label_id),
+            created_at: Set(now),
+        });
+
+        TaskLabelEntity::insert_many(active_models)
+            .exec(&self.db)
+            .await
+            .map_err(ApplicationError::DatabaseError)?;
+
+        Ok(())
+    }
+
+    async fn version_conflict(&self, task_id: Uuid) -> ApplicationError {
+        match TaskEntity::find_by_id(task_id).one(&self.db).await {
+            Ok(Some(model)) => ApplicationError::PreconditionFailed {
+                message: "The task has been modified since it was retrieved".to_string(),
+                current_version: model.version,
+            },
+            Ok(None) => ApplicationError::NotFound {
+                message: "Task with the given ID not found".to_string(),
+            },
+            Err(err) => ApplicationError::DatabaseError(err),
         }
     }
 }
 
 #[async_trait]
-impl TaskRepository for SeaOrmTaskRepository {
+impl<C: ConnectionTrait + Send> TaskRepository for SeaOrmTaskRepository<C> {
     async fn create(&self, task: Task) -> Result<Task, ApplicationError> {
+        let (assignee_ids, label_ids, checklist_progress, relations) = (
+            task.assignee_ids.clone(),
+            task.label_ids.clone(),
+            task.checklist_progress,
+            task.relations.clone(),
+        );
         let active_model = Self::to_active_model(task);
 
         let result = TaskEntity::insert(active_model)
@@ -53,7 +335,16 @@ impl TaskRepository for SeaOrmTaskRepository {
             .await
             .map_err(ApplicationError::DatabaseError)?;
 
-        Ok(Self::to_domain(result))
+        self.replace_assignees(result.id, &assignee_ids).await?;
+        self.replace_labels(result.id, &label_ids).await?;
+
+        Ok(Self::to_domain(
+            result,
+            assignee_ids,
+            label_ids,
+            checklist_progress,
+            relations,
+        ))
     }
 
     async fn find_by_id(&self, task_id: Uuid) -> Result<Option<Task>, ApplicationError> {
@@ -62,28 +353,229 @@ impl TaskRepository for SeaOrmTaskRepository {
             .await
             .map_err(ApplicationError::DatabaseError)?;
 
-        Ok(result.map(Self::to_domain))
+        Ok(self.with_details(result.into_iter().collect()).await?.pop())
     }
 
-    async fn find_by_column_id(&self, column_id: Uuid) -> Result<Vec<Task>, ApplicationError> {
+    async fn find_by_id_for_update(&self, task_id: Uuid) -> Result<Option<Task>, ApplicationError> {
+        let result = TaskEntity::find_by_id(task_id)
+            .lock_exclusive()
+            .one(&self.db)
+            .await
+            .map_err(ApplicationError::DatabaseError)?;
+
+        Ok(self.with_details(result.into_iter().collect()).await?.pop())
+    }
+
+    async fn find_by_column_id(
+        &self,
+        column_id: Uuid,
+        include_archived: bool,
+    ) -> Result<Vec<Task>, ApplicationError> {
+        let mut query = TaskEntity::find().filter(TaskColumn::ColumnId.eq(column_id));
+
+        if !include_archived {
+            query = query.filter(TaskColumn::ArchivedAt.is_null());
+        }
+
+        let result = query
+            .all(&self.db)
+            .await
+            .map_err(ApplicationError::DatabaseError)?;
+
+        self.with_details(result).await
+    }
+
+    async fn find_by_ids(&self, task_ids: Vec<Uuid>) -> Result<Vec<Task>, ApplicationError> {
         let result = TaskEntity::find()
-            .filter(TaskColumn::ColumnId.eq(column_id))
+            .filter(TaskColumn::Id.is_in(task_ids))
+            .all(&self.db)
+            .await
+            .map_err(ApplicationError::DatabaseError)?;
+
+        self.with_details(result).await
+    }
+
+    async fn find_by_board_id(
+        &self,
+        board_id: Uuid,
+        include_archived: bool,
+    ) -> Result<Vec<Task>, ApplicationError> {
+        let mut query = TaskEntity::find()
+            .join(JoinType::InnerJoin, TaskEntityRelation::Column.def())
+            .filter(ColumnColumn::BoardId.eq(board_id));
+
+        if !include_archived {
+            query = query.filter(TaskColumn::ArchivedAt.is_null());
+        }
+
+        let result = query
+            .order_by_asc(TaskColumn::Position)
+            .all(&self.db)
+            .await
+            .map_err(ApplicationError::DatabaseError)?;
+
+        self.with_details(result).await
+    }
+
+    async fn search(
+        &self,
+        board_id: Uuid,
+        filter: TaskFilter,
+    ) -> Result<Vec<Task>, ApplicationError> {
+        let mut query = TaskEntity::find()
+            .join(JoinType::InnerJoin, TaskEntityRelation::Column.def())
+            .filter(ColumnColumn::BoardId.eq(board_id));
+
+        if !filter.include_archived {
+            query = query.filter(TaskColumn::ArchivedAt.is_null());
+        }
+        if let Some(cursor) = filter.cursor {
+            query = query.filter(TaskColumn::Id.lt(cursor));
+        }
+        if let Some(text) = filter.text {
+            let pattern = Self::like_pattern(&text);
+            query = query.filter(
+                Condition::any()
+                    .add(Expr::expr(Func::lower(Expr::col(TaskColumn::Title))).like(&pattern))
+                    .add(
+                        Expr::expr(Func::lower(Expr::col(TaskColumn::Description))).like(&pattern),
+                    ),
+            );
+        }
+        if !filter.label_ids.is_empty() {
+            query = query.filter(
+                TaskColumn::Id.in_subquery(
+                    Query::select()
+                        .column(TaskLabelColumn::TaskId)
+                        .from(TaskLabelEntity)
+                        .and_where(TaskLabelColumn::LabelId.is_in(filter.label_ids))
+                        .to_owned(),
+                ),
+            );
+        }
+        if !filter.assignee_ids.is_empty() {
+            query = query.filter(
+                TaskColumn::Id.in_subquery(
+                    Query::select()
+                        .column(TaskAssigneeColumn::TaskId)
+                        .from(TaskAssigneeEntity)
+                        .and_where(TaskAssigneeColumn::UserId.is_in(filter.assignee_ids))
+                        .to_owned(),
+                ),
+            );
+        }
+
+        for custom_field in filter.custom_fields {
+            let key = custom_field.field_id.to_string();
+            let values = Expr::col((TaskEntity, TaskColumn::CustomFields));
+
+            query = match custom_field.condition {
+                CustomFieldCondition::Contains(value) => {
+                    let mut contained = Map::new();
+                    contained.insert(key, value);
+                    query.filter(
+                        values.binary(PgBinOper::Contains, Expr::val(Value::Object(contained))),
+                    )
+                }
+                CustomFieldCondition::TextContains(text) => query.filter(
+                    Expr::expr(Func::lower(values.cast_json_field(key)))
+                        .like(Self::like_pattern(&text)),
+                ),
+                CustomFieldCondition::NumberRange { min, max } => {
+                    let number =
+                        Expr::expr(values.cast_json_field(key)).cast_as(Alias::new("float8"));
+                    let mut condition = Condition::all();
+                    if let Some(min) = min {
+                        condition = condition.add(Expr::expr(number.clone()).gte(min));
+                    }
+                    if let Some(max) = max {
+                        condition = condition.add(Expr::expr(number).lte(max));
+                    }
+                    query.filter(condition)
+                }
+                // Dates are stored as `YYYY-MM-DD`, which orders like the dates themselves
+                CustomFieldCondition::DateRange { min, max } => {
+                    let date = values.cast_json_field(key);
+                    let mut condition = Condition::all();
+                    if let Some(min) = min {
+                        condition = condition.add(Expr::expr(date.clone()).gte(min.to_string()));
+                    }
+                    if let Some(max) = max {
+                        condition = condition.add(Expr::expr(date).lte(max.to_string()));
+                    }
+                    query.filter(condition)
+                }
+                CustomFieldCondition::IsSet(is_set) => {
+                    let value = Expr::expr(values.get_json_field(key));
+                    query.filter(if is_set {
+                        value.is_not_null()
+                    } else {
+                        value.is_null()
+                    })
+                }
+            };
+        }
+
+        let result = query
+            .order_by_desc(TaskColumn::Id)
+            .limit(filter.limit)
             .all(&self.db)
             .await
             .map_err(ApplicationError::DatabaseError)?;
 
-        Ok(result.into_iter().map(Self::to_domain).collect())
+        self.with_details(result).await
     }
 
     async fn update(&self, task: Task) -> Result<Task, ApplicationError> {
-        let active_model = Self::to_active_model(task);
+        let (assignee_ids, label_ids, checklist_progress, relations) = (
+            task.assignee_ids.clone(),
+            task.label_ids.clone(),
+            task.checklist_progress,
+            task.relations.clone(),
+        );
+        let (task_id, expected_version) = (task.id, task.version);
+
+        let mut active_model = Self::to_active_model(task);
+        active_model.version = Set(expected_version + 1);
 
-        let result = TaskEntity::update(active_model)
+        // Only applies when nobody else has updated the task since it was read
+        let result = match TaskEntity::update(active_model)
+            .filter(TaskColumn::Version.eq(expected_version))
             .exec(&self.db)
             .await
-            .map_err(ApplicationError::DatabaseError)?;
+        {
+            Ok(result) => result,
+            Err(DbErr::RecordNotUpdated) => return Err(self.version_conflict(task_id).await),
+            Err(err) => return Err(ApplicationError::DatabaseError(err)),
+        };
+
+        self.replace_assignees(result.id, &assignee_ids).await?;
+        self.replace_labels(result.id, &label_ids).await?;
 
-        Ok(Self::to_domain(result))
+        Ok(Self::to_domain(
+            result,
+            assignee_ids,
+            label_ids,
+            checklist_progress,
+            relations,
+        ))
+    }
+
+    async fn update_positions(
+        &self,
+        positions: Vec<(Uuid, String)>,
+    ) -> Result<(), ApplicationError> {
+        for (id, position) in positions {
+            TaskEntity::update_many()
+                .col_expr(TaskColumn::Position, Expr::value(position))
+                .col_expr(TaskColumn::Version, Expr::col(TaskColumn::Version).add(1))
+                .filter(TaskColumn::Id.eq(id))
+                .exec(&self.db)
+                .await
+                .map_err(ApplicationError::DatabaseError)?;
+        }
+
+        Ok(())
     }
 
     async fn delete(&self, task_id: Uuid) -> Result<u64, ApplicationError> {
@@ -94,4 +586,31 @@ impl TaskRepository for SeaOrmTaskRepository {
 
         Ok(result.rows_affected)
     }
+
+    async fn remove_assignee_from_board(
+        &self,
+        board_id: Uuid,
+        user_id: Uuid,
+    ) -> Result<Vec<Uuid>, ApplicationError> {
+        let assignments = TaskAssigneeEntity::find()
+            .join(JoinType::InnerJoin, TaskAssigneeRelation::Task.def())
+            .join(JoinType::InnerJoin, TaskEntityRelation::Column.def())
+            .filter(TaskAssigneeColumn::UserId.eq(user_id))
+            .filter(ColumnColumn::BoardId.eq(board_id))
+            .all(&self.db)
+            .await
+            .map_err(ApplicationError::DatabaseError)?;
+
+        if assignments.is_empty() {
+            return Ok(Vec::new());
+        }
+
+        TaskAssigneeEntity::delete_many()
+            .filter(TaskAssigneeColumn::Id.is_in(assignments.iter().map(|a| a.id)))
+            .exec(&self.db)
+            .await
+            .map_err(ApplicationError::DatabaseError)?;
+
+        Ok(assignments.into_iter().map(|a| a.task_id).collect())
+    }
 }
@@ -0,0 +1,195 @@
+use crate::{
+    domain::repositories::{TimeEntry, TimeEntryFilter, TimeEntryRepository, TimeEntrySummary},
+    shared::error::ApplicationError,
+};
+use async_trait::async_trait;
+use chrono::NaiveDate;
+use entity::{
+    ColumnColumn, TaskRelation, TimeEntryActiveModel, TimeEntryColumn, TimeEntryEntity,
+    TimeEntryModel, TimeEntryRelation,
+};
+use sea_orm::{
+    ActiveValue::Set, ColumnTrait, ConnectionTrait, DatabaseConnection, DbErr, EntityTrait,
+    JoinType, QueryFilter, QueryOrder, QuerySelect, RelationTrait, SqlErr, sea_query::Expr,
+};
+use uuid::Uuid;
+
+pub struct SeaOrmTimeEntryRepository<C = DatabaseConnection> {
+    db: C,
+}
+
+impl<C: ConnectionTrait> SeaOrmTimeEntryRepository<C> {
+    pub fn new(db: C) -> Self {
+        Self { db }
+    }
+
+    fn to_domain(model: TimeEntryModel) -> TimeEntry {
+        TimeEntry {
+            id: model.id,
+            task_id: model.task_id,
+            user_id: model.user_id,
+            started_at: model.started_at,
+            ended_at: model.ended_at,
+            note: model.note,
+            created_at: model.created_at,
+            updated_at: model.updated_at,
+        }
+    }
+
+    fn to_active_model(entry: TimeEntry) -> TimeEntryActiveModel {
+        TimeEntryActiveModel {
+            id: Set(entry.id),
+            task_id: Set(entry.task_id),
+            user_id: Set(entry.user_id),
+            started_at: Set(entry.started_at),
+            ended_at: Set(entry.ended_at),
+            note: Set(entry.note),
+            created_at: Set(entry.created_at),
+            updated_at: Set(entry.updated_at),
+        }
+    }
+
+    /// The partial unique index on running entries settles concurrent timer starts
+    fn map_running_conflict(err: DbErr) -> ApplicationError {
+        match err.sql_err() {
+            Some(SqlErr::UniqueConstraintViolation(_)) => ApplicationError::Conflict {
+                message: "You already have a running timer".to_string(),
+            },
+            _ => ApplicationError::DatabaseError(err),
+        }
+    }
+}
+
+#[async_trait]
+impl<C: ConnectionTrait + Send> TimeEntryRepository for SeaOrmTimeEntryRepository<C> {
+    async fn create(&self, entry: TimeEntry) -> Result<TimeEntry, ApplicationError> {
+        let active_model = Self::to_active_model(entry);
+
+        let result = TimeEntryEntity::insert(active_model)
+            .exec_with_returning(&self.db)
+            .await
+            .map_err(Self::map_running_conflict)?;
+
+        Ok(Self::to_domain(result))
+    }
+
+    async fn find_by_id(&self, entry_id: Uuid) -> Result<Option<TimeEntry>, ApplicationError> {
+        let result = TimeEntryEntity::find_by_id(entry_id)
+            .one(&self.db)
+            .await
+            .map_err(ApplicationError::DatabaseError)?;
+
+        Ok(result.map(Self::to_domain))
+    }
+
+    async fn find_by_task_id(&self, task_id: Uuid) -> Result<Vec<TimeEntry>, ApplicationError> {
+        let result = TimeEntryEntity::find()
+            .filter(TimeEntryColumn::TaskId.eq(task_id))
+            .order_by_desc(TimeEntryColumn::StartedAt)
+            .all(&self.db)
+            .await
+            .map_err(ApplicationError::DatabaseError)?;
+
+        Ok(result.into_iter().map(Self::to_domain).collect())
+    }
+
+    async fn find_running_by_user_id(
+        &self,
+        user_id: Uuid,
+    ) -> Result<Option<TimeEntry>, ApplicationError> {
+        let result = TimeEntryEntity::find()
+            .filter(TimeEntryColumn::UserId.eq(user_id))
+            .filter(TimeEntryColumn::EndedAt.is_null())
+            .one(&self.db)
+            .await
+            .map_err(ApplicationError::DatabaseError)?;
+
+        Ok(result.map(Self::to_domain))
+    }
+
+    async fn find_running_by_board_id(
+        &self,
+        board_id: Uuid,
+    ) -> Result<Vec<TimeEntry>, ApplicationError> {
+        let result = TimeEntryEntity::find()
+            .join(JoinType::InnerJoin, TimeEntryRelation::Task.def())
+            .join(JoinType::InnerJoin, TaskRelation::Column.def())
+            .filter(ColumnColumn::BoardId.eq(board_id))
+            .filter(TimeEntryColumn::EndedAt.is_null())
+            .order_by_asc(TimeEntryColumn::StartedAt)
+            .all(&self.db)
+            .await
+            .map_err(ApplicationError::DatabaseError)?;
+
+        Ok(result.into_iter().map(Self::to_domain).collect())
+    }
+
+    async fn summarize_by_board_id(
+        &self,
+        board_id: Uuid,
+        filter: TimeEntryFilter,
+    ) -> Result<Vec<TimeEntrySummary>, ApplicationError> {
+        let date = Expr::cust("(time_entry.started_at AT TIME ZONE 'UTC')::date");
+
+        let mut query = TimeEntryEntity::find()
+            .select_only()
+            .column(TimeEntryColumn::TaskId)
+            .column(TimeEntryColumn::UserId)
+            .column_as(date.clone(), "date")
+            .column_as(
+                Expr::cust(
+                    "SUM(EXTRACT(EPOCH FROM time_entry.ended_at - time_entry.started_at))::bigint",
+                ),
+                "total_seconds",
+            )
+            .join(JoinType::InnerJoin, TimeEntryRelation::Task.def())
+            .join(JoinType::InnerJoin, TaskRelation::Column.def())
+            .filter(ColumnColumn::BoardId.eq(board_id))
+            .filter(TimeEntryColumn::EndedAt.is_not_null())
+            .filter(TimeEntryColumn::StartedAt.gte(filter.from))
+            .filter(TimeEntryColumn::StartedAt.lt(filter.to));
+
+        if let Some(user_id) = filter.user_id {
+            query = query.filter(TimeEntryColumn::UserId.eq(user_id));
+        }
+
+        let result = query
+            .group_by(TimeEntryColumn::TaskId)
+            .group_by(TimeEntryColumn::UserId)
+            .group_by(date)
+            .into_tuple::<(Uuid, Uuid, NaiveDate, i64)>()
+            .all(&self.db)
+            .await
+            .map_err(ApplicationError::DatabaseError)?;
+
+        Ok(result
+            .into_iter()
+            .map(|(task_id, user_id, date, total_seconds)| TimeEntrySummary {
+                task_id,
+                user_id,
+                date,
+                total_seconds,
+            })
+            .collect())
+    }
+
+    async fn update(&self, entry: TimeEntry) -> Result<TimeEntry, ApplicationError> {
+        let active_model = Self::to_active_model(entry);
+
+        let result = TimeEntryEntity::update(active_model)
+            .exec(&self.db)
+            .await
+            .map_err(ApplicationError::DatabaseError)?;
+
+        Ok(Self::to_domain(result))
+    }
+
+    async fn delete(&self, entry_id: Uuid) -> Result<u64, ApplicationError> {
+        let result = TimeEntryEntity::delete_by_id(entry_id)
+            .exec(&self.db)
+            .await
+            .map_err(ApplicationError::DatabaseError)?;
+
+        Ok(result.rows_affected)
+    }
+}
@@ -0,0 +1,190 @@
+use crate::{
+    domain::repositories::{
+        AttachmentRepository, BoardMemberRepository, BoardRepository, ChecklistRepository,
+        ColumnRepository, CommentRepository, CustomFieldRepository, LabelRepository,
+        OutboxRepository, TaskRelationRepository, TaskRepository, TimeEntryRepository, UnitOfWork,
+        UnitOfWorkFactory,
+    },
+    infrastructure::persistence::{
+        SeaOrmAttachmentRepository, SeaOrmBoardMemberRepository, SeaOrmBoardRepository,
+        SeaOrmChecklistRepository, SeaOrmColumnRepository, SeaOrmCommentRepository,
+        SeaOrmCustomFieldRepository, SeaOrmLabelRepository, SeaOrmOutboxRepository,
+        SeaOrmTaskRelationRepository, SeaOrmTaskRepository, SeaOrmTimeEntryRepository,
+    },
+    shared::error::ApplicationError,
+};
+use async_trait::async_trait;
+use sea_orm::{
+    ConnectionTrait, DatabaseConnection, DatabaseTransaction, DbBackend, DbErr, ExecResult,
+    QueryResult, Statement, TransactionTrait,
+};
+use std::sync::Arc;
+use tokio::sync::Notify;
+
+/// Shares one transaction between all repositories of a unit of work.
+#[derive(Clone)]
+pub struct SharedTransaction(Arc<DatabaseTransaction>);
+
+#[async_trait]
+impl ConnectionTrait for SharedTransaction {
+    fn get_database_backend(&self) -> DbBackend {
+        self.0.get_database_backend()
+    }
+
+    async fn execute(&self, stmt: Statement) -> Result<ExecResult, DbErr> {
+        self.0.execute(stmt).await
+    }
+
+    async fn execute_unprepared(&self, sql: &str) -> Result<ExecResult, DbErr> {
+        self.0.execute_unprepared(sql).await
+    }
+
+    async fn query_one(&self, stmt: Statement) -> Result<Option<QueryResult>, DbErr> {
+        self.0.query_one(stmt).await
+    }
+
+    async fn query_all(&self, stmt: Statement) -> Result<Vec<QueryResult>, DbErr> {
+        self.0.query_all(stmt).await
+    }
+}
+
+pub struct SeaOrmUnitOfWork {
+    transaction: SharedTransaction,
+    board_repository: SeaOrmBoardRepository<SharedTransaction>,
+    board_member_repository: SeaOrmBoardMemberRepository<SharedTransaction>,
+    column_repository: SeaOrmColumnRepository<SharedTransaction>,
+    task_repository: SeaOrmTaskRepository<SharedTransaction>,
+    task_relation_repository: SeaOrmTaskRelationRepository<SharedTransaction>,
+    comment_repository: SeaOrmCommentRepository<SharedTransaction>,
+    checklist_repository: SeaOrmChecklistRepository<SharedTransaction>,
+    label_repository: SeaOrmLabelRepository<SharedTransaction>,
+    custom_field_repository: SeaOrmCustomFieldRepository<SharedTransaction>,
+    attachment_repository: SeaOrmAttachmentRepository<SharedTransaction>,
+    time_entry_repository: SeaOrmTimeEntryRepository<SharedTransaction>,
+    outbox_repository: SeaOrmOutboxRepository<SharedTransaction>,
+    outbox_notify: Arc<Notify>,
+}
+
+impl SeaOrmUnitOfWork {
+    fn new(transaction: DatabaseTransaction, outbox_notify: Arc<Notify>) -> Self {
+        let transaction = SharedTransaction(Arc::new(transaction));
+
+        Self {
+            board_repository: SeaOrmBoardRepository::new(transaction.clone()),
+            board_member_repository: SeaOrmBoardMemberRepository::new(transaction.clone()),
+            column_repository: SeaOrmColumnRepository::new(transaction.clone()),
+            task_repository: SeaOrmTaskRepository::new(transaction.clone()),
+            task_relation_repository: SeaOrmTaskRelationRepository::new(transaction.clone()),
+            comment_repository: SeaOrmCommentRepository::new(transaction.clone()),
+            checklist_repository: SeaOrmChecklistRepository::new(transaction.clone()),
+            label_repository: SeaOrmLabelRepository::new(transaction.clone()),
+            custom_field_repository: SeaOrmCustomFieldRepository::new(transaction.clone()),
+            attachment_repository: SeaOrmAttachmentRepository::new(transaction.clone()),
+            time_entry_repository: SeaOrmTimeEntryRepository::new(transaction.clone()),
+            outbox_repository: SeaOrmOutboxRepository::new(transaction.clone()),
+            transaction,
+            outbox_notify,
+        }
+    }
+}
+
+#[async_trait]
+impl UnitOfWork for SeaOrmUnitOfWork {
+    fn board_repository(&self) -> &dyn BoardRepository {
+        &self.board_repository
+    }
+
+    fn board_member_repository(&self) -> &dyn BoardMemberRepository {
+        &self.board_member_repository
+    }
+
+    fn column_repository(&self) -> &dyn ColumnRepository {
+        &self.column_repository
+    }
+
+    fn task_repository(&self) -> &dyn TaskRepository {
+        &self.task_repository
+    }
+
+    fn task_relation_repository(&self) -> &dyn TaskRelationRepository {
+        &self.task_relation_repository
+    }
+
+    fn comment_repository(&self) -> &dyn CommentRepository {
+        &self.comment_repository
+    }
+
+    fn checklist_repository(&self) -> &dyn ChecklistRepository {
+        &self.checklist_repository
+    }
+
+    fn label_repository(&self) -> &dyn LabelRepository {
+        &self.label_repository
+    }
+
+    fn custom_field_repository(&self) -> &dyn CustomFieldRepository {
+        &self.custom_field_repository
+    }
+
+    fn attachment_repository(&self) -> &dyn AttachmentRepository {
+        &self.attachment_repository
+    }
+
+    fn time_entry_repository(&self) -> &dyn TimeEntryRepository {
+        &self.time_entry_repository
+    }
+
+    fn outbox_repository(&self) -> &dyn OutboxRepository {
+        &self.outbox_repository
+    }
+
+    async fn commit(self: Box<Self>) -> Result<(), ApplicationError> {
+        let SharedTransaction(transaction) = self.transaction.clone();
+        let outbox_notify = self.outbox_notify.clone();
+
+        // Release the repositories' handles so the transaction can be taken back
+        drop(self);
+
+        let transaction =
+            Arc::try_unwrap(transaction).map_err(|_| ApplicationError::InternalError {
+                message: "Transaction is still in use".to_string(),
+            })?;
+
+        transaction
+            .commit()
+            .await
+            .map_err(ApplicationError::DatabaseError)?;
+
+        // Wake the outbox dispatcher instead of waiting for its next poll
+        outbox_notify.notify_one();
+
+        Ok(())
+    }
+}
+
+pub struct SeaOrmUnitOfWorkFactory {
+    db: DatabaseConnection,
+    outbox_notify: Arc<Notify>,
+}
+
+impl SeaOrmUnitOfWorkFactory {
+    pub fn new(db: DatabaseConnection, outbox_notify: Arc<Notify>) -> Self {
+        Self { db, outbox_notify }
+    }
+}
+
+#[async_trait]
+impl UnitOfWorkFactory for SeaOrmUnitOfWorkFactory {
+    async fn begin(&self) -> Result<Box<dyn UnitOfWork>, ApplicationError> {
+        let transaction = self
+            .db
+            .begin()
+            .await
+            .map_err(ApplicationError::DatabaseError)?;
+
+        Ok(Box::new(SeaOrmUnitOfWork::new(
+            transaction,
+            self.outbox_notify.clone(),
+        )))
+    }
+}
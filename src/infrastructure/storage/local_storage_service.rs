@@ -0,0 +1,62 @@
+use crate::domain::services::{StorageService, StorageStream};
+use async_trait::async_trait;
+use bytes::Bytes;
+use futures_util::StreamExt;
+use std::{io::ErrorKind, path::PathBuf};
+use tokio::fs;
+use tokio_util::io::ReaderStream;
+
+pub struct LocalStorageService {
+    root: PathBuf,
+}
+
+impl LocalStorageService {
+    pub fn new(root: impl Into<PathBuf>) -> Self {
+        Self { root: root.into() }
+    }
+
+    fn resolve(&self, key: &str) -> PathBuf {
+        self.root.join(key)
+    }
+}
+
+#[async_trait]
+impl StorageService for LocalStorageService {
+    async fn put_object(&self, key: &str, _content_type: &str, data: Bytes) -> Result<(), String> {
+        let path = self.resolve(key);
+
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent)
+                .await
+                .map_err(|err| format!("Failed to create storage directory: {}", err))?;
+        }
+
+        fs::write(&path, &data)
+            .await
+            .map_err(|err| format!("Failed to write object '{}': {}", key, err))
+    }
+
+    async fn get_object(&self, key: &str) -> Result<StorageStream, String> {
+        let file = fs::File::open(self.resolve(key))
+            .await
+            .map_err(|err| format!("Failed to open object '{}': {}", key, err))?;
+
+        Ok(ReaderStream::new(file).boxed())
+    }
+
+    async fn delete_object(&self, key: &str) -> Result<(), String> {
+        match fs::remove_file(self.resolve(key)).await {
+            Ok(()) => Ok(()),
+            Err(err) if err.kind() == ErrorKind::NotFound => Ok(()),
+            Err(err) => Err(format!("Failed to delete object '{}': {}", key, err)),
+        }
+    }
+
+    async fn delete_objects(&self, keys: &[String]) -> Result<(), String> {
+        for key in keys {
+            self.delete_object(key).await?;
+        }
+
+        Ok(())
+    }
+}
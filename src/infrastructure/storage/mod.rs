@@ -0,0 +1,5 @@
+pub mod local_storage_service;
+pub mod s3_storage_service;
+
+pub use local_storage_service::LocalStorageService;
+pub use s3_storage_service::S3StorageService;
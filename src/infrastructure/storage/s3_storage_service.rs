@@ -0,0 +1,90 @@
+use crate::{
+    domain::services::{StorageService, StorageStream},
+    shared::utils::constants::{S3_ACCESS_KEY, S3_BUCKET, S3_ENDPOINT, S3_REGION, S3_SECRET_KEY},
+};
+use async_trait::async_trait;
+use bytes::Bytes;
+use futures_util::StreamExt;
+use s3::{Bucket, Region, creds::Credentials};
+use std::io;
+
+pub struct S3StorageService {
+    bucket: Box<Bucket>,
+}
+
+impl S3StorageService {
+    pub fn new() -> Result<Self, String> {
+        let credentials = Credentials::new(
+            Some(S3_ACCESS_KEY.as_str()),
+            Some(S3_SECRET_KEY.as_str()),
+            None,
+            None,
+            None,
+        )
+        .map_err(|err| format!("Failed to create S3 credentials: {}", err))?;
+
+        // A custom endpoint means an S3-compatible server such as MinIO, which expects path-style URLs
+        let bucket = match S3_ENDPOINT.as_ref() {
+            Some(endpoint) => {
+                let region = Region::Custom {
+                    region: S3_REGION.clone(),
+                    endpoint: endpoint.clone(),
+                };
+
+                Bucket::new(&S3_BUCKET, region, credentials)
+                    .map_err(|err| format!("Failed to create S3 bucket client: {}", err))?
+                    .with_path_style()
+            }
+            None => {
+                let region = S3_REGION
+                    .parse()
+                    .map_err(|err| format!("Invalid S3 region: {}", err))?;
+
+                Bucket::new(&S3_BUCKET, region, credentials)
+                    .map_err(|err| format!("Failed to create S3 bucket client: {}", err))?
+            }
+        };
+
+        Ok(S3StorageService { bucket })
+    }
+}
+
+#[async_trait]
+impl StorageService for S3StorageService {
+    async fn put_object(&self, key: &str, content_type: &str, data: Bytes) -> Result<(), String> {
+        self.bucket
+            .put_object_with_content_type(key, &data, content_type)
+            .await
+            .map(|_| ())
+            .map_err(|err| format!("Failed to upload object '{}': {}", key, err))
+    }
+
+    async fn get_object(&self, key: &str) -> Result<StorageStream, String> {
+        let response = self
+            .bucket
+            .get_object_stream(key)
+            .await
+            .map_err(|err| format!("Failed to download object '{}': {}", key, err))?;
+
+        Ok(response
+            .bytes
+            .map(|chunk| chunk.map_err(io::Error::other))
+            .boxed())
+    }
+
+    async fn delete_object(&self, key: &str) -> Result<(), String> {
+        self.bucket
+            .delete_object(key)
+            .await
+            .map(|_| ())
+            .map_err(|err| format!("Failed to delete object '{}': {}", key, err))
+    }
+
+    async fn delete_objects(&self, keys: &[String]) -> Result<(), String> {
+        for key in keys {
+            self.delete_object(key).await?;
+        }
+
+        Ok(())
+    }
+}
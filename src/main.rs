@@ -8,8 +8,8 @@ use crate::{
     presentation::http::configure_server,
     shared::{
         config::{
-            initialize_event_bus, initialize_infrastructure, initialize_repositories,
-            initialize_services,
+            initialize_event_bus, initialize_infrastructure, initialize_outbox_dispatcher,
+            initialize_repositories, initialize_services, initialize_storage,
         },
         utils::constants::{SERVER_ADDRESS, SERVER_PORT},
     },
@@ -33,26 +33,25 @@ async fn main() -> Result<()> {
         .await
         .expect("Failed to initialize infrastructure");
 
-    let (
-        user_repository,
-        board_repository,
-        board_member_repository,
-        column_repository,
-        task_repository,
-    ) = initialize_repositories(database);
-
-    let event_bus = initialize_event_bus();
-
-    let app_state = initialize_services(
-        user_repository,
-        board_repository,
-        board_member_repository,
-        column_repository,
-        task_repository,
-        redis_client,
-        event_bus,
+    let repositories = initialize_repositories(database.clone());
+
+    let event_bus = initialize_event_bus(
+        database,
+        redis_client.clone(),
+        repositories.board_activity_repository.clone(),
+    )
+    .await;
+
+    initialize_outbox_dispatcher(
+        repositories.outbox_repository.clone(),
+        event_bus.clone(),
+        repositories.outbox_notify.clone(),
     );
 
+    let storage_service = initialize_storage();
+
+    let app_state = initialize_services(repositories, redis_client, event_bus, storage_service);
+
     let server = configure_server(app_state, &SERVER_ADDRESS, *SERVER_PORT).await?;
 
     server.await
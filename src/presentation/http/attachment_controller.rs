@@ -0,0 +1,243 @@
+use crate::{
+    application::{
+        dto::{AttachmentDto, UploadAttachmentDto},
+        services::AttachmentService,
+    },
+    shared::{
+        error::{ApplicationError, ApplicationErrorSchema},
+        response::{ApiResponse, ApiResponseSchema},
+        utils::constants::ATTACHMENT_MAX_FILE_SIZE,
+    },
+};
+use actix_multipart::Multipart;
+use actix_web::{
+    HttpResponse, delete, get,
+    http::header::{ContentDisposition, DispositionParam, DispositionType},
+    post, web,
+};
+use bytes::BytesMut;
+use futures_util::TryStreamExt;
+use std::sync::Arc;
+use uuid::Uuid;
+
+pub fn configure(cfg: &mut web::ServiceConfig) {
+    cfg.service(
+        web::scope("/attachment")
+            .service(upload_attachment)
+            .service(get_task_attachments)
+            .service(download_attachment)
+            .service(delete_attachment),
+    );
+}
+
+#[utoipa::path(
+    post,
+    description = "***PROTECTED ENDPOINT***\n\nUploads a file as an attachment of a task. The file must be sent as the `file` field of a multipart form. Files larger than the configured size limit, or uploads exceeding the board's storage quota, are rejected. All board members can upload attachments.",
+    path = "/attachment/task/{taskId}",
+    params(
+        ("taskId" = Uuid, Path, description = "Unique identifier of the task")
+    ),
+    request_body(content = UploadAttachmentDto, content_type = "multipart/form-data"),
+    responses(
+        (status = 201, description = "Created - Attachment uploaded successfully", body = ApiResponseSchema<AttachmentDto>),
+        (status = 400, description = "Bad Request - Missing or empty file", body = ApplicationErrorSchema),
+        (status = 401, description = "Unauthorized - No active session or session has expired", body = ApplicationErrorSchema),
+        (status = 403, description = "Forbidden - User doesn't have access to this board", body = ApplicationErrorSchema),
+        (status = 404, description = "Not Found - Task with the given ID not found", body = ApplicationErrorSchema),
+        (status = 413, description = "Payload Too Large - File size limit or board quota exceeded", body = ApplicationErrorSchema),
+        (status = 500, description = "Internal Server Error - Failed to upload attachment", body = ApplicationErrorSchema)
+    ),
+    tag = "Attachment",
+    security(
+        ("session_cookie" = [])
+    )
+)]
+#[post("/task/{taskId}")]
+async fn upload_attachment(
+    attachment_service: web::Data<Arc<AttachmentService>>,
+    task_id: web::Path<Uuid>,
+    mut payload: Multipart,
+    user_id: web::ReqData<Uuid>,
+) -> Result<ApiResponse<AttachmentDto>, ApplicationError> {
+    let task_id = task_id.into_inner();
+    let user_id = user_id.into_inner();
+
+    while let Some(mut field) = payload.try_next().await.map_err(multipart_error)? {
+        if field.name() != Some("file") {
+            continue;
+        }
+
+        let file_name = field
+            .content_disposition()
+            .and_then(|disposition| disposition.get_filename())
+            .map(sanitize_file_name)
+            .filter(|file_name| !file_name.is_empty())
+            .ok_or_else(|| ApplicationError::BadRequest {
+                message: "Uploaded file must have a file name".to_string(),
+            })?;
+        let content_type = field
+            .content_type()
+            .map(|mime| mime.to_string())
+            .unwrap_or_else(|| "application/octet-stream".to_string());
+
+        let mut data = BytesMut::new();
+        while let Some(chunk) = field.try_next().await.map_err(multipart_error)? {
+            if (data.len() + chunk.len()) as u64 > *ATTACHMENT_MAX_FILE_SIZE {
+                return Err(ApplicationError::PayloadTooLarge {
+                    message: format!(
+                        "File size exceeds the limit of {} bytes",
+                        *ATTACHMENT_MAX_FILE_SIZE
+                    ),
+                });
+            }
+            data.extend_from_slice(&chunk);
+        }
+
+        let attachment = attachment_service
+            .upload_attachment(task_id, file_name, content_type, data.freeze(), user_id)
+            .await?;
+
+        return Ok(ApiResponse::Created {
+            message: "Attachment uploaded successfully".to_string(),
+            data: attachment,
+        });
+    }
+
+    Err(ApplicationError::BadRequest {
+        message: "Missing 'file' field in multipart form".to_string(),
+    })
+}
+
+#[utoipa::path(
+    get,
+    description = "***PROTECTED ENDPOINT***\n\nRetrieves the metadata of all attachments of a specific task, ordered by upload time. User must be a member of the board to access this endpoint.",
+    path = "/attachment/task/{taskId}",
+    params(
+        ("taskId" = Uuid, Path, description = "Unique identifier of the task")
+    ),
+    responses(
+        (status = 200, description = "OK - Attachments retrieved successfully", body = ApiResponseSchema<Vec<AttachmentDto>>),
+        (status = 401, description = "Unauthorized - No active session or session has expired", body = ApplicationErrorSchema),
+        (status = 403, description = "Forbidden - User doesn't have access to this board", body = ApplicationErrorSchema),
+        (status = 404, description = "Not Found - Task with the given ID not found", body = ApplicationErrorSchema),
+        (status = 500, description = "Internal Server Error - Failed to retrieve attachments", body = ApplicationErrorSchema)
+    ),
+    tag = "Attachment",
+    security(
+        ("session_cookie" = [])
+    )
+)]
+#[get("/task/{taskId}")]
+async fn get_task_attachments(
+    attachment_service: web::Data<Arc<AttachmentService>>,
+    task_id: web::Path<Uuid>,
+    user_id: web::ReqData<Uuid>,
+) -> Result<ApiResponse<Vec<AttachmentDto>>, ApplicationError> {
+    let task_id = task_id.into_inner();
+    let user_id = user_id.into_inner();
+    let attachments = attachment_service
+        .get_task_attachments(task_id, user_id)
+        .await?;
+
+    Ok(ApiResponse::Found {
+        message: "Attachments retrieved successfully".to_string(),
+        data: attachments,
+        page: None,
+        total_pages: None,
+    })
+}
+
+#[utoipa::path(
+    get,
+    description = "***PROTECTED ENDPOINT***\n\nStreams the content of an attachment with its original content type and file name. User must be a member of the board to access this endpoint.",
+    path = "/attachment/{attachmentId}/download",
+    params(
+        ("attachmentId" = Uuid, Path, description = "Unique identifier of the attachment")
+    ),
+    responses(
+        (status = 200, description = "OK - Attachment content", content_type = "application/octet-stream", body = Vec<u8>),
+        (status = 401, description = "Unauthorized - No active session or session has expired", body = ApplicationErrorSchema),
+        (status = 403, description = "Forbidden - User doesn't have access to this board", body = ApplicationErrorSchema),
+        (status = 404, description = "Not Found - Attachment with the given ID not found", body = ApplicationErrorSchema),
+        (status = 500, description = "Internal Server Error - Failed to download attachment", body = ApplicationErrorSchema)
+    ),
+    tag = "Attachment",
+    security(
+        ("session_cookie" = [])
+    )
+)]
+#[get("/{attachmentId}/download")]
+async fn download_attachment(
+    attachment_service: web::Data<Arc<AttachmentService>>,
+    attachment_id: web::Path<Uuid>,
+    user_id: web::ReqData<Uuid>,
+) -> Result<HttpResponse, ApplicationError> {
+    let attachment_id = attachment_id.into_inner();
+    let user_id = user_id.into_inner();
+    let (attachment, stream) = attachment_service
+        .download_attachment(attachment_id, user_id)
+        .await?;
+
+    Ok(HttpResponse::Ok()
+        .content_type(attachment.content_type)
+        .insert_header(ContentDisposition {
+            disposition: DispositionType::Attachment,
+            parameters: vec![DispositionParam::Filename(attachment.file_name)],
+        })
+        .no_chunking(attachment.size as u64)
+        .streaming(stream))
+}
+
+#[utoipa::path(
+    delete,
+    description = "***PROTECTED ENDPOINT***\n\nPermanently deletes an attachment and its stored file. Only the uploader, the board owner and moderators can delete attachments.",
+    path = "/attachment/{attachmentId}",
+    params(
+        ("attachmentId" = Uuid, Path, description = "Unique identifier of the attachment")
+    ),
+    responses(
+        (status = 200, description = "OK - Attachment deleted successfully", body = ApiResponseSchema<u64>),
+        (status = 401, description = "Unauthorized - No active session or session has expired", body = ApplicationErrorSchema),
+        (status = 403, description = "Forbidden - Only the uploader, board owner and moderator can delete this attachment", body = ApplicationErrorSchema),
+        (status = 404, description = "Not Found - Attachment with the given ID not found", body = ApplicationErrorSchema),
+        (status = 500, description = "Internal Server Error - Failed to delete attachment", body = ApplicationErrorSchema)
+    ),
+    tag = "Attachment",
+    security(
+        ("session_cookie" = [])
+    )
+)]
+#[delete("/{attachmentId}")]
+async fn delete_attachment(
+    attachment_service: web::Data<Arc<AttachmentService>>,
+    attachment_id: web::Path<Uuid>,
+    user_id: web::ReqData<Uuid>,
+) -> Result<ApiResponse<u64>, ApplicationError> {
+    let attachment_id = attachment_id.into_inner();
+    let user_id = user_id.into_inner();
+    let rows_affected = attachment_service
+        .delete_attachment(attachment_id, user_id)
+        .await?;
+
+    Ok(ApiResponse::Deleted {
+        message: "Attachment deleted successfully".to_string(),
+        rows_affected,
+    })
+}
+
+fn multipart_error(err: actix_multipart::MultipartError) -> ApplicationError {
+    ApplicationError::BadRequest {
+        message: format!("Invalid multipart payload: {}", err),
+    }
+}
+
+fn sanitize_file_name(file_name: &str) -> String {
+    file_name
+        .rsplit(['/', '\\'])
+        .next()
+        .unwrap_or_default()
+        .trim()
+        .chars()
+        .take(255)
+        .collect()
+}
@@ -1,17 +1,20 @@
 use crate::{
     application::{
         dto::{
-            AddBoardMemberDto, BoardDto, BoardMemberDto, CreateBoardDto, DeleteBoardMemberDto,
+            AddBoardMemberDto, ArchiveQueryDto, BoardActivityPageDto, BoardActivityQueryDto,
+            BoardAnalyticsDto, BoardAnalyticsQueryDto, BoardChangesPageDto, BoardChangesQueryDto,
+            BoardDto, BoardMemberDto, BoardSnapshotDto, CreateBoardDto, DeleteBoardMemberDto,
             UpdateBoardDto, UpdateBoardMemberRoleDto,
         },
-        services::BoardService,
+        services::{BoardActivityService, BoardAnalyticsService, BoardService, BoardSyncService},
     },
+    presentation::http::etag::{etag, expected_version},
     shared::{
         error::{ApplicationError, ApplicationErrorSchema},
         response::{ApiResponse, ApiResponseSchema},
     },
 };
-use actix_web::{delete, get, post, put, web};
+use actix_web::{CustomizeResponder, HttpRequest, Responder, delete, get, post, put, web};
 use std::sync::Arc;
 use uuid::Uuid;
 
@@ -23,8 +26,14 @@ pub fn configure(cfg: &mut web::ServiceConfig) {
             .service(add_new_board_member)
             .service(update_board_member_role)
             .service(remove_board_member)
+            .service(get_board_activity)
+            .service(get_board_analytics)
+            .service(get_board_snapshot)
+            .service(get_board_changes)
             .service(get_board)
             .service(update_board)
+            .service(archive_board)
+            .service(unarchive_board)
             .service(delete_board),
     );
 }
@@ -70,7 +79,7 @@ async fn create_board(
         ("boardId" = Uuid, Path, description = "Unique identifier of the board")
     ),
     responses(
-        (status = 200, description = "OK - Board data retrieved successfully", body = ApiResponseSchema<BoardDto>),
+        (status = 200, description = "OK - Board data retrieved successfully", body = ApiResponseSchema<BoardDto>, headers(("ETag" = String, description = "Current version of the board"))),
         (status = 401, description = "Unauthorized - No active session or session has expired", body = ApplicationErrorSchema),
         (status = 404, description = "Not found - Board with the given ID not found", body = ApplicationErrorSchema),
         (status = 500, description = "Internal server error - Failed to retrieve board", body = ApplicationErrorSchema)
@@ -85,23 +94,29 @@ async fn get_board(
     board_service: web::Data<Arc<BoardService>>,
     board_id: web::Path<Uuid>,
     user_id: web::ReqData<Uuid>,
-) -> Result<ApiResponse<BoardDto>, ApplicationError> {
+) -> Result<CustomizeResponder<ApiResponse<BoardDto>>, ApplicationError> {
     let board_id = board_id.into_inner();
     let user_id = user_id.into_inner();
     let board = board_service.get_board_by_id(board_id, user_id).await?;
+    let etag = etag(board.version);
 
     Ok(ApiResponse::Found {
         message: "Board data retrieved successfully".to_string(),
         data: board,
         page: None,
         total_pages: None,
-    })
+    }
+    .customize()
+    .insert_header(etag))
 }
 
 #[utoipa::path(
     get,
-    description = "***PROTECTED ENDPOINT***\n\nRetrieves a list of all boards where the authenticated user is a member.",
+    description = "***PROTECTED ENDPOINT***\n\nRetrieves a list of all boards where the authenticated user is a member. Archived boards are only included when requested.",
     path = "/board/",
+    params(
+        ("includeArchived" = Option<bool>, Query, description = "Whether archived boards should be included (default: false)")
+    ),
     responses(
         (status = 200, description = "OK - Boards retrieved successfully", body = ApiResponseSchema<Vec<BoardDto>>),
         (status = 401, description = "Unauthorized - No active session or session has expired", body = ApplicationErrorSchema),
@@ -115,10 +130,13 @@ async fn get_board(
 #[get("/")]
 async fn get_user_boards(
     board_service: web::Data<Arc<BoardService>>,
+    query: web::Query<ArchiveQueryDto>,
     user_id: web::ReqData<Uuid>,
 ) -> Result<ApiResponse<Vec<BoardDto>>, ApplicationError> {
     let user_id = user_id.into_inner();
-    let boards = board_service.get_boards_by_membership(user_id).await?;
+    let boards = board_service
+        .get_boards_by_membership(user_id, query.include_archived.unwrap_or(false))
+        .await?;
 
     Ok(ApiResponse::Found {
         message: "Boards retrieved successfully".to_string(),
@@ -128,20 +146,192 @@ async fn get_user_boards(
     })
 }
 
+#[utoipa::path(
+    get,
+    description = "***PROTECTED ENDPOINT***\n\nRetrieves the activity log of a board, newest first. Results are paginated with a cursor: pass the returned `nextCursor` to fetch the following page. Entries can be filtered by actor, event type and date range. User must be a member of the board to access this endpoint.",
+    path = "/board/{boardId}/activity",
+    params(
+        ("boardId" = Uuid, Path, description = "Unique identifier of the board"),
+        ("cursor" = Option<Uuid>, Query, description = "Cursor returned by the previous page"),
+        ("limit" = Option<u64>, Query, description = "Maximum number of entries to return (1 - 100, default: 50)"),
+        ("actorId" = Option<Uuid>, Query, description = "Only include entries caused by this user"),
+        ("eventType" = Option<String>, Query, description = "Only include entries of this event type (e.g. taskMoved)"),
+        ("from" = Option<String>, Query, description = "Only include entries at or after this RFC 3339 timestamp"),
+        ("to" = Option<String>, Query, description = "Only include entries at or before this RFC 3339 timestamp")
+    ),
+    responses(
+        (status = 200, description = "OK - Board activity retrieved successfully", body = ApiResponseSchema<BoardActivityPageDto>),
+        (status = 400, description = "Bad Request - Invalid query parameters", body = ApplicationErrorSchema),
+        (status = 401, description = "Unauthorized - No active session or session has expired", body = ApplicationErrorSchema),
+        (status = 403, description = "Forbidden - User doesn't have access to this board", body = ApplicationErrorSchema),
+        (status = 500, description = "Internal server error - Failed to retrieve board activity", body = ApplicationErrorSchema)
+    ),
+    tag = "Board",
+    security(
+        ("session_cookie" = [])
+    )
+)]
+#[get("/{boardId}/activity")]
+async fn get_board_activity(
+    board_activity_service: web::Data<Arc<BoardActivityService>>,
+    board_id: web::Path<Uuid>,
+    query: web::Query<BoardActivityQueryDto>,
+    user_id: web::ReqData<Uuid>,
+) -> Result<ApiResponse<BoardActivityPageDto>, ApplicationError> {
+    let board_id = board_id.into_inner();
+    let user_id = user_id.into_inner();
+    let activity = board_activity_service
+        .get_board_activity(board_id, query.into_inner(), user_id)
+        .await?;
+
+    Ok(ApiResponse::Found {
+        message: "Board activity retrieved successfully".to_string(),
+        data: activity,
+        page: None,
+        total_pages: None,
+    })
+}
+
+#[utoipa::path(
+    get,
+    description = "***PROTECTED ENDPOINT***\n\nRetrieves flow analytics of a board rebuilt from its activity log. Returns the lead time (creation to completion) and cycle time (first move out of the creation column to completion) of every task completed in the date range together with their percentiles, the number of tasks completed per week and the number of unarchived tasks in each column at the end of every day for a cumulative flow diagram. A task counts as completed from the moment it enters a done column until it leaves the done columns again. Days are counted in UTC and the range defaults to the last 30 days. User must be a member of the board to access this endpoint.",
+    path = "/board/{boardId}/analytics",
+    params(
+        ("boardId" = Uuid, Path, description = "Unique identifier of the board"),
+        ("from" = Option<String>, Query, description = "First day of the range (YYYY-MM-DD, default: 29 days before `to`)"),
+        ("to" = Option<String>, Query, description = "Last day of the range (YYYY-MM-DD, default: today); the range can't span more than 366 days")
+    ),
+    responses(
+        (status = 200, description = "OK - Board analytics retrieved successfully", body = ApiResponseSchema<BoardAnalyticsDto>),
+        (status = 400, description = "Bad Request - Invalid date range", body = ApplicationErrorSchema),
+        (status = 401, description = "Unauthorized - No active session or session has expired", body = ApplicationErrorSchema),
+        (status = 403, description = "Forbidden - User doesn't have access to this board", body = ApplicationErrorSchema),
+        (status = 500, description = "Internal server error - Failed to retrieve board analytics", body = ApplicationErrorSchema)
+    ),
+    tag = "Board",
+    security(
+        ("session_cookie" = [])
+    )
+)]
+#[get("/{boardId}/analytics")]
+async fn get_board_analytics(
+    board_analytics_service: web::Data<Arc<BoardAnalyticsService>>,
+    board_id: web::Path<Uuid>,
+    query: web::Query<BoardAnalyticsQueryDto>,
+    user_id: web::ReqData<Uuid>,
+) -> Result<ApiResponse<BoardAnalyticsDto>, ApplicationError> {
+    let board_id = board_id.into_inner();
+    let user_id = user_id.into_inner();
+    let analytics = board_analytics_service
+        .get_board_analytics(board_id, query.into_inner(), user_id)
+        .await?;
+
+    Ok(ApiResponse::Found {
+        message: "Board analytics retrieved successfully".to_string(),
+        data: analytics,
+        page: None,
+        total_pages: None,
+    })
+}
+
+#[utoipa::path(
+    get,
+    description = "***PROTECTED ENDPOINT***\n\nRetrieves a board together with its members, its columns and the tasks of every column, all ordered by position. The returned `sequence` can be passed as `since` when connecting to the board WebSocket, so no change made after the snapshot is missed. Archived columns and tasks are only included when requested. User must be a member of the board to access this endpoint.",
+    path = "/board/{boardId}/full",
+    params(
+        ("boardId" = Uuid, Path, description = "Unique identifier of the board"),
+        ("includeArchived" = Option<bool>, Query, description = "Whether archived columns and tasks should be included (default: false)")
+    ),
+    responses(
+        (status = 200, description = "OK - Board snapshot retrieved successfully", body = ApiResponseSchema<BoardSnapshotDto>),
+        (status = 401, description = "Unauthorized - No active session or session has expired", body = ApplicationErrorSchema),
+        (status = 404, description = "Not found - Board with the given ID not found", body = ApplicationErrorSchema),
+        (status = 500, description = "Internal server error - Failed to retrieve board snapshot", body = ApplicationErrorSchema)
+    ),
+    tag = "Board",
+    security(
+        ("session_cookie" = [])
+    )
+)]
+#[get("/{boardId}/full")]
+async fn get_board_snapshot(
+    board_sync_service: web::Data<Arc<BoardSyncService>>,
+    board_id: web::Path<Uuid>,
+    query: web::Query<ArchiveQueryDto>,
+    user_id: web::ReqData<Uuid>,
+) -> Result<ApiResponse<BoardSnapshotDto>, ApplicationError> {
+    let board_id = board_id.into_inner();
+    let user_id = user_id.into_inner();
+    let snapshot = board_sync_service
+        .get_board_snapshot(board_id, query.include_archived.unwrap_or(false), user_id)
+        .await?;
+
+    Ok(ApiResponse::Found {
+        message: "Board snapshot retrieved successfully".to_string(),
+        data: snapshot,
+        page: None,
+        total_pages: None,
+    })
+}
+
+#[utoipa::path(
+    get,
+    description = "***PROTECTED ENDPOINT***\n\nRetrieves what changed on a board after the given cursor, for clients that synchronize incrementally. Every changed member, column and task is returned once with its current state, or as a deletion, in the order of its last change. Pass the returned `nextCursor` to fetch the following changes while `hasMore` is true; it is an event sequence and can also be passed as `since` to the board WebSocket. User must be a member of the board to access this endpoint.",
+    path = "/board/{boardId}/changes",
+    params(
+        ("boardId" = Uuid, Path, description = "Unique identifier of the board"),
+        ("cursor" = Option<i64>, Query, description = "Cursor returned by the previous call or sequence of the board snapshot (default: 0, all changes)"),
+        ("limit" = Option<u64>, Query, description = "Maximum number of events to process (1 - 1000, default: 500)")
+    ),
+    responses(
+        (status = 200, description = "OK - Board changes retrieved successfully", body = ApiResponseSchema<BoardChangesPageDto>),
+        (status = 400, description = "Bad Request - Invalid query parameters", body = ApplicationErrorSchema),
+        (status = 401, description = "Unauthorized - No active session or session has expired", body = ApplicationErrorSchema),
+        (status = 404, description = "Not found - Board with the given ID not found", body = ApplicationErrorSchema),
+        (status = 500, description = "Internal server error - Failed to retrieve board changes", body = ApplicationErrorSchema)
+    ),
+    tag = "Board",
+    security(
+        ("session_cookie" = [])
+    )
+)]
+#[get("/{boardId}/changes")]
+async fn get_board_changes(
+    board_sync_service: web::Data<Arc<BoardSyncService>>,
+    board_id: web::Path<Uuid>,
+    query: web::Query<BoardChangesQueryDto>,
+    user_id: web::ReqData<Uuid>,
+) -> Result<ApiResponse<BoardChangesPageDto>, ApplicationError> {
+    let board_id = board_id.into_inner();
+    let user_id = user_id.into_inner();
+    let changes = board_sync_service
+        .get_board_changes(board_id, query.into_inner(), user_id)
+        .await?;
+
+    Ok(ApiResponse::Found {
+        message: "Board changes retrieved successfully".to_string(),
+        data: changes,
+        page: None,
+        total_pages: None,
+    })
+}
+
 #[utoipa::path(
     put,
     description = "***PROTECTED ENDPOINT***\n\nUpdates board information. Only the board owner and moderator can update board details.",
     path = "/board/{boardId}",
     params(
-        ("boardId" = Uuid, Path, description = "Unique identifier of the board")
+        ("boardId" = Uuid, Path, description = "Unique identifier of the board"),
+        ("If-Match" = Option<String>, Header, description = "ETag of the board the change is based on, the change is rejected when the board has been modified since")
     ),
     request_body = UpdateBoardDto,
     responses(
-        (status = 200, description = "OK - Board updated successfully", body = ApiResponseSchema<BoardDto>),
+        (status = 200, description = "OK - Board updated successfully", body = ApiResponseSchema<BoardDto>, headers(("ETag" = String, description = "Current version of the board"))),
         (status = 400, description = "Bad Request - Invalid input data", body = ApplicationErrorSchema),
         (status = 401, description = "Unauthorized - No active session or session has expired", body = ApplicationErrorSchema),
         (status = 403, description = "Forbidden - Only board owner and moderator can update board details", body = ApplicationErrorSchema),
         (status = 404, description = "Not found - Board with the given ID not found", body = ApplicationErrorSchema),
+        (status = 412, description = "Precondition Failed - Board has been modified since it was retrieved", body = ApplicationErrorSchema),
         (status = 500, description = "Internal server error - Failed to update board", body = ApplicationErrorSchema)
     ),
     tag = "Board",
@@ -155,17 +345,100 @@ async fn update_board(
     dto: web::Json<UpdateBoardDto>,
     board_id: web::Path<Uuid>,
     user_id: web::ReqData<Uuid>,
-) -> Result<ApiResponse<BoardDto>, ApplicationError> {
+    req: HttpRequest,
+) -> Result<CustomizeResponder<ApiResponse<BoardDto>>, ApplicationError> {
     let board_id = board_id.into_inner();
     let user_id = user_id.into_inner();
+    let expected_version = expected_version(&req)?;
     let board = board_service
-        .update_board(dto.into_inner(), board_id, user_id)
+        .update_board(dto.into_inner(), board_id, user_id, expected_version)
         .await?;
+    let etag = etag(board.version);
 
     Ok(ApiResponse::Updated {
         message: "Board updated successfully".to_string(),
         data: board,
-    })
+    }
+    .customize()
+    .insert_header(etag))
+}
+
+#[utoipa::path(
+    put,
+    description = "***PROTECTED ENDPOINT***\n\nArchives a board. Archived boards are hidden from listings unless explicitly requested and can be restored later. Only the board owner can archive or restore boards.",
+    path = "/board/{boardId}/archive",
+    params(
+        ("boardId" = Uuid, Path, description = "Unique identifier of the board")
+    ),
+    responses(
+        (status = 200, description = "OK - Board archived successfully", body = ApiResponseSchema<BoardDto>, headers(("ETag" = String, description = "Current version of the board"))),
+        (status = 401, description = "Unauthorized - No active session or session has expired", body = ApplicationErrorSchema),
+        (status = 403, description = "Forbidden - Only board owner can archive or restore boards", body = ApplicationErrorSchema),
+        (status = 404, description = "Not found - Board with the given ID not found", body = ApplicationErrorSchema),
+        (status = 409, description = "Conflict - Board is already archived", body = ApplicationErrorSchema),
+        (status = 500, description = "Internal server error - Failed to archive board", body = ApplicationErrorSchema)
+    ),
+    tag = "Board",
+    security(
+        ("session_cookie" = [])
+    )
+)]
+#[put("/{boardId}/archive")]
+async fn archive_board(
+    board_service: web::Data<Arc<BoardService>>,
+    board_id: web::Path<Uuid>,
+    user_id: web::ReqData<Uuid>,
+) -> Result<CustomizeResponder<ApiResponse<BoardDto>>, ApplicationError> {
+    let board_id = board_id.into_inner();
+    let user_id = user_id.into_inner();
+    let board = board_service.archive_board(board_id, user_id).await?;
+    let etag = etag(board.version);
+
+    Ok(ApiResponse::Updated {
+        message: "Board archived successfully".to_string(),
+        data: board,
+    }
+    .customize()
+    .insert_header(etag))
+}
+
+#[utoipa::path(
+    put,
+    description = "***PROTECTED ENDPOINT***\n\nRestores an archived board so that it appears in listings again. Only the board owner can archive or restore boards.",
+    path = "/board/{boardId}/unarchive",
+    params(
+        ("boardId" = Uuid, Path, description = "Unique identifier of the board")
+    ),
+    responses(
+        (status = 200, description = "OK - Board restored successfully", body = ApiResponseSchema<BoardDto>, headers(("ETag" = String, description = "Current version of the board"))),
+        (status = 401, description = "Unauthorized - No active session or session has expired", body = ApplicationErrorSchema),
+        (status = 403, description = "Forbidden - Only board owner can archive or restore boards", body = ApplicationErrorSchema),
+        (status = 404, description = "Not found - Board with the given ID not found", body = ApplicationErrorSchema),
+        (status = 409, description = "Conflict - Board is not archived", body = ApplicationErrorSchema),
+        (status = 500, description = "Internal server error - Failed to unarchive board", body = ApplicationErrorSchema)
+    ),
+    tag = "Board",
+    security(
+        ("session_cookie" = [])
+    )
+)]
+#[put("/{boardId}/unarchive")]
+async fn unarchive_board(
+    board_service: web::Data<Arc<BoardService>>,
+    board_id: web::Path<Uuid>,
+    user_id: web::ReqData<Uuid>,
+) -> Result<CustomizeResponder<ApiResponse<BoardDto>>, ApplicationError> {
+    let board_id = board_id.into_inner();
+    let user_id = user_id.into_inner();
+    let board = board_service.unarchive_board(board_id, user_id).await?;
+    let etag = etag(board.version);
+
+    Ok(ApiResponse::Updated {
+        message: "Board restored successfully".to_string(),
+        data: board,
+    }
+    .customize()
+    .insert_header(etag))
 }
 
 #[utoipa::path(
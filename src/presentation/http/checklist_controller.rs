@@ -0,0 +1,417 @@
+use crate::{
+    application::{
+        dto::{
+            ChecklistDto, ChecklistItemDto, CreateChecklistDto, CreateChecklistItemDto,
+            UpdateChecklistDto, UpdateChecklistItemDto,
+        },
+        services::ChecklistService,
+    },
+    shared::{
+        error::{ApplicationError, ApplicationErrorSchema},
+        response::{ApiResponse, ApiResponseSchema},
+    },
+};
+use actix_web::{delete, get, post, put, web};
+use std::sync::Arc;
+use uuid::Uuid;
+
+pub fn configure(cfg: &mut web::ServiceConfig) {
+    cfg.service(
+        web::scope("/checklist")
+            .service(create_checklist)
+            .service(get_task_checklists)
+            .service(update_checklist_item)
+            .service(move_checklist_item)
+            .service(delete_checklist_item)
+            .service(create_checklist_item)
+            .service(get_checklist)
+            .service(update_checklist)
+            .service(move_checklist)
+            .service(delete_checklist),
+    );
+}
+
+#[utoipa::path(
+    post,
+    description = "***PROTECTED ENDPOINT***\n\nAdds a checklist to a task. The checklist is placed at the given position (0-based) among the task's checklists, or last when no position is given. All board members can manage checklists.",
+    path = "/checklist/",
+    request_body = CreateChecklistDto,
+    responses(
+        (status = 201, description = "Created - Checklist created successfully", body = ApiResponseSchema<ChecklistDto>),
+        (status = 400, description = "Bad Request - Invalid input data", body = ApplicationErrorSchema),
+        (status = 401, description = "Unauthorized - No active session or session has expired", body = ApplicationErrorSchema),
+        (status = 403, description = "Forbidden - User doesn't have access to this board", body = ApplicationErrorSchema),
+        (status = 404, description = "Not Found - Task with the given ID not found", body = ApplicationErrorSchema),
+        (status = 500, description = "Internal Server Error - Failed to create checklist", body = ApplicationErrorSchema)
+    ),
+    tag = "Checklist",
+    security(
+        ("session_cookie" = [])
+    )
+)]
+#[post("/")]
+async fn create_checklist(
+    checklist_service: web::Data<Arc<ChecklistService>>,
+    dto: web::Json<CreateChecklistDto>,
+    user_id: web::ReqData<Uuid>,
+) -> Result<ApiResponse<ChecklistDto>, ApplicationError> {
+    let user_id = user_id.into_inner();
+    let checklist = checklist_service
+        .create_checklist(dto.into_inner(), user_id)
+        .await?;
+
+    Ok(ApiResponse::Created {
+        message: "Checklist created successfully".to_string(),
+        data: checklist,
+    })
+}
+
+#[utoipa::path(
+    get,
+    description = "***PROTECTED ENDPOINT***\n\nRetrieves all checklists of a specific task together with their items, both ordered by position. User must be a member of the board to access this endpoint.",
+    path = "/checklist/task/{taskId}",
+    params(
+        ("taskId" = Uuid, Path, description = "Unique identifier of the task")
+    ),
+    responses(
+        (status = 200, description = "OK - Checklists retrieved successfully", body = ApiResponseSchema<Vec<ChecklistDto>>),
+        (status = 401, description = "Unauthorized - No active session or session has expired", body = ApplicationErrorSchema),
+        (status = 403, description = "Forbidden - User doesn't have access to this board", body = ApplicationErrorSchema),
+        (status = 404, description = "Not Found - Task with the given ID not found", body = ApplicationErrorSchema),
+        (status = 500, description = "Internal Server Error - Failed to retrieve checklists", body = ApplicationErrorSchema)
+    ),
+    tag = "Checklist",
+    security(
+        ("session_cookie" = [])
+    )
+)]
+#[get("/task/{taskId}")]
+async fn get_task_checklists(
+    checklist_service: web::Data<Arc<ChecklistService>>,
+    task_id: web::Path<Uuid>,
+    user_id: web::ReqData<Uuid>,
+) -> Result<ApiResponse<Vec<ChecklistDto>>, ApplicationError> {
+    let task_id = task_id.into_inner();
+    let user_id = user_id.into_inner();
+    let checklists = checklist_service
+        .get_task_checklists(task_id, user_id)
+        .await?;
+
+    Ok(ApiResponse::Found {
+        message: "Checklists retrieved successfully".to_string(),
+        data: checklists,
+        page: None,
+        total_pages: None,
+    })
+}
+
+#[utoipa::path(
+    get,
+    description = "***PROTECTED ENDPOINT***\n\nRetrieves a specific checklist by its ID together with its items. User must be a member of the board to access this endpoint.",
+    path = "/checklist/{checklistId}",
+    params(
+        ("checklistId" = Uuid, Path, description = "Unique identifier of the checklist")
+    ),
+    responses(
+        (status = 200, description = "OK - Checklist data retrieved successfully", body = ApiResponseSchema<ChecklistDto>),
+        (status = 401, description = "Unauthorized - No active session or session has expired", body = ApplicationErrorSchema),
+        (status = 403, description = "Forbidden - User doesn't have access to this board", body = ApplicationErrorSchema),
+        (status = 404, description = "Not Found - Checklist with the given ID not found", body = ApplicationErrorSchema),
+        (status = 500, description = "Internal Server Error - Failed to retrieve checklist", body = ApplicationErrorSchema)
+    ),
+    tag = "Checklist",
+    security(
+        ("session_cookie" = [])
+    )
+)]
+#[get("/{checklistId}")]
+async fn get_checklist(
+    checklist_service: web::Data<Arc<ChecklistService>>,
+    checklist_id: web::Path<Uuid>,
+    user_id: web::ReqData<Uuid>,
+) -> Result<ApiResponse<ChecklistDto>, ApplicationError> {
+    let checklist_id = checklist_id.into_inner();
+    let user_id = user_id.into_inner();
+    let checklist = checklist_service
+        .get_checklist_by_id(checklist_id, user_id)
+        .await?;
+
+    Ok(ApiResponse::Found {
+        message: "Checklist data retrieved successfully".to_string(),
+        data: checklist,
+        page: None,
+        total_pages: None,
+    })
+}
+
+#[utoipa::path(
+    put,
+    description = "***PROTECTED ENDPOINT***\n\nRenames a checklist. All board members can manage checklists.",
+    path = "/checklist/{checklistId}",
+    params(
+        ("checklistId" = Uuid, Path, description = "Unique identifier of the checklist")
+    ),
+    request_body = UpdateChecklistDto,
+    responses(
+        (status = 200, description = "OK - Checklist updated successfully", body = ApiResponseSchema<ChecklistDto>),
+        (status = 400, description = "Bad Request - Invalid input data", body = ApplicationErrorSchema),
+        (status = 401, description = "Unauthorized - No active session or session has expired", body = ApplicationErrorSchema),
+        (status = 403, description = "Forbidden - User doesn't have access to this board", body = ApplicationErrorSchema),
+        (status = 404, description = "Not Found - Checklist with the given ID not found", body = ApplicationErrorSchema),
+        (status = 500, description = "Internal Server Error - Failed to update checklist", body = ApplicationErrorSchema)
+    ),
+    tag = "Checklist",
+    security(
+        ("session_cookie" = [])
+    )
+)]
+#[put("/{checklistId}")]
+async fn update_checklist(
+    checklist_service: web::Data<Arc<ChecklistService>>,
+    dto: web::Json<UpdateChecklistDto>,
+    checklist_id: web::Path<Uuid>,
+    user_id: web::ReqData<Uuid>,
+) -> Result<ApiResponse<ChecklistDto>, ApplicationError> {
+    let checklist_id = checklist_id.into_inner();
+    let user_id = user_id.into_inner();
+    let checklist = checklist_service
+        .update_checklist(dto.into_inner(), checklist_id, user_id)
+        .await?;
+
+    Ok(ApiResponse::Updated {
+        message: "Checklist updated successfully".to_string(),
+        data: checklist,
+    })
+}
+
+#[utoipa::path(
+    put,
+    description = "***PROTECTED ENDPOINT***\n\nMoves a checklist to a new position among the checklists of its task. Position is 0-indexed. All board members can manage checklists.",
+    path = "/checklist/{checklistId}/move/{position}",
+    params(
+        ("checklistId" = Uuid, Path, description = "Unique identifier of the checklist"),
+        ("position" = usize, Path, description = "New position index for the checklist (0-based)")
+    ),
+    responses(
+        (status = 200, description = "OK - Checklist moved successfully", body = ApiResponseSchema<ChecklistDto>),
+        (status = 400, description = "Bad Request - Target position is out of bounds", body = ApplicationErrorSchema),
+        (status = 401, description = "Unauthorized - No active session or session has expired", body = ApplicationErrorSchema),
+        (status = 403, description = "Forbidden - User doesn't have access to this board", body = ApplicationErrorSchema),
+        (status = 404, description = "Not Found - Checklist with the given ID not found", body = ApplicationErrorSchema),
+        (status = 500, description = "Internal Server Error - Failed to move checklist", body = ApplicationErrorSchema)
+    ),
+    tag = "Checklist",
+    security(
+        ("session_cookie" = [])
+    )
+)]
+#[put("/{checklistId}/move/{position}")]
+async fn move_checklist(
+    checklist_service: web::Data<Arc<ChecklistService>>,
+    path: web::Path<(Uuid, usize)>,
+    user_id: web::ReqData<Uuid>,
+) -> Result<ApiResponse<ChecklistDto>, ApplicationError> {
+    let (checklist_id, position) = path.into_inner();
+    let user_id = user_id.into_inner();
+    let checklist = checklist_service
+        .move_checklist(position, checklist_id, user_id)
+        .await?;
+
+    Ok(ApiResponse::Updated {
+        message: "Checklist moved successfully".to_string(),
+        data: checklist,
+    })
+}
+
+#[utoipa::path(
+    delete,
+    description = "***PROTECTED ENDPOINT***\n\nPermanently deletes a checklist together with all of its items. All board members can manage checklists.",
+    path = "/checklist/{checklistId}",
+    params(
+        ("checklistId" = Uuid, Path, description = "Unique identifier of the checklist")
+    ),
+    responses(
+        (status = 200, description = "OK - Checklist deleted successfully", body = ApiResponseSchema<u64>),
+        (status = 401, description = "Unauthorized - No active session or session has expired", body = ApplicationErrorSchema),
+        (status = 403, description = "Forbidden - User doesn't have access to this board", body = ApplicationErrorSchema),
+        (status = 404, description = "Not Found - Checklist with the given ID not found", body = ApplicationErrorSchema),
+        (status = 500, description = "Internal Server Error - Failed to delete checklist", body = ApplicationErrorSchema)
+    ),
+    tag = "Checklist",
+    security(
+        ("session_cookie" = [])
+    )
+)]
+#[delete("/{checklistId}")]
+async fn delete_checklist(
+    checklist_service: web::Data<Arc<ChecklistService>>,
+    checklist_id: web::Path<Uuid>,
+    user_id: web::ReqData<Uuid>,
+) -> Result<ApiResponse<u64>, ApplicationError> {
+    let checklist_id = checklist_id.into_inner();
+    let user_id = user_id.into_inner();
+    let rows_affected = checklist_service
+        .delete_checklist(checklist_id, user_id)
+        .await?;
+
+    Ok(ApiResponse::Deleted {
+        message: "Checklist deleted successfully".to_string(),
+        rows_affected,
+    })
+}
+
+#[utoipa::path(
+    post,
+    description = "***PROTECTED ENDPOINT***\n\nAdds an item to a checklist. The item is placed at the given position (0-based) within the checklist, or last when no position is given. All board members can manage checklists.",
+    path = "/checklist/{checklistId}/item",
+    params(
+        ("checklistId" = Uuid, Path, description = "Unique identifier of the checklist")
+    ),
+    request_body = CreateChecklistItemDto,
+    responses(
+        (status = 201, description = "Created - Checklist item created successfully", body = ApiResponseSchema<ChecklistItemDto>),
+        (status = 400, description = "Bad Request - Invalid input data", body = ApplicationErrorSchema),
+        (status = 401, description = "Unauthorized - No active session or session has expired", body = ApplicationErrorSchema),
+        (status = 403, description = "Forbidden - User doesn't have access to this board", body = ApplicationErrorSchema),
+        (status = 404, description = "Not Found - Checklist with the given ID not found", body = ApplicationErrorSchema),
+        (status = 500, description = "Internal Server Error - Failed to create checklist item", body = ApplicationErrorSchema)
+    ),
+    tag = "Checklist",
+    security(
+        ("session_cookie" = [])
+    )
+)]
+#[post("/{checklistId}/item")]
+async fn create_checklist_item(
+    checklist_service: web::Data<Arc<ChecklistService>>,
+    dto: web::Json<CreateChecklistItemDto>,
+    checklist_id: web::Path<Uuid>,
+    user_id: web::ReqData<Uuid>,
+) -> Result<ApiResponse<ChecklistItemDto>, ApplicationError> {
+    let checklist_id = checklist_id.into_inner();
+    let user_id = user_id.into_inner();
+    let item = checklist_service
+        .create_checklist_item(dto.into_inner(), checklist_id, user_id)
+        .await?;
+
+    Ok(ApiResponse::Created {
+        message: "Checklist item created successfully".to_string(),
+        data: item,
+    })
+}
+
+#[utoipa::path(
+    put,
+    description = "***PROTECTED ENDPOINT***\n\nEdits the content of a checklist item and/or marks it as completed or open again. All board members can manage checklists.",
+    path = "/checklist/item/{itemId}",
+    params(
+        ("itemId" = Uuid, Path, description = "Unique identifier of the checklist item")
+    ),
+    request_body = UpdateChecklistItemDto,
+    responses(
+        (status = 200, description = "OK - Checklist item updated successfully", body = ApiResponseSchema<ChecklistItemDto>),
+        (status = 400, description = "Bad Request - Invalid input data", body = ApplicationErrorSchema),
+        (status = 401, description = "Unauthorized - No active session or session has expired", body = ApplicationErrorSchema),
+        (status = 403, description = "Forbidden - User doesn't have access to this board", body = ApplicationErrorSchema),
+        (status = 404, description = "Not Found - Checklist item with the given ID not found", body = ApplicationErrorSchema),
+        (status = 500, description = "Internal Server Error - Failed to update checklist item", body = ApplicationErrorSchema)
+    ),
+    tag = "Checklist",
+    security(
+        ("session_cookie" = [])
+    )
+)]
+#[put("/item/{itemId}")]
+async fn update_checklist_item(
+    checklist_service: web::Data<Arc<ChecklistService>>,
+    dto: web::Json<UpdateChecklistItemDto>,
+    item_id: web::Path<Uuid>,
+    user_id: web::ReqData<Uuid>,
+) -> Result<ApiResponse<ChecklistItemDto>, ApplicationError> {
+    let item_id = item_id.into_inner();
+    let user_id = user_id.into_inner();
+    let item = checklist_service
+        .update_checklist_item(dto.into_inner(), item_id, user_id)
+        .await?;
+
+    Ok(ApiResponse::Updated {
+        message: "Checklist item updated successfully".to_string(),
+        data: item,
+    })
+}
+
+#[utoipa::path(
+    put,
+    description = "***PROTECTED ENDPOINT***\n\nMoves a checklist item to a new position within its checklist. Position is 0-indexed. All board members can manage checklists.",
+    path = "/checklist/item/{itemId}/move/{position}",
+    params(
+        ("itemId" = Uuid, Path, description = "Unique identifier of the checklist item"),
+        ("position" = usize, Path, description = "New position index for the item (0-based)")
+    ),
+    responses(
+        (status = 200, description = "OK - Checklist item moved successfully", body = ApiResponseSchema<ChecklistItemDto>),
+        (status = 400, description = "Bad Request - Target position is out of bounds", body = ApplicationErrorSchema),
+        (status = 401, description = "Unauthorized - No active session or session has expired", body = ApplicationErrorSchema),
+        (status = 403, description = "Forbidden - User doesn't have access to this board", body = ApplicationErrorSchema),
+        (status = 404, description = "Not Found - Checklist item with the given ID not found", body = ApplicationErrorSchema),
+        (status = 500, description = "Internal Server Error - Failed to move checklist item", body = ApplicationErrorSchema)
+    ),
+    tag = "Checklist",
+    security(
+        ("session_cookie" = [])
+    )
+)]
+#[put("/item/{itemId}/move/{position}")]
+async fn move_checklist_item(
+    checklist_service: web::Data<Arc<ChecklistService>>,
+    path: web::Path<(Uuid, usize)>,
+    user_id: web::ReqData<Uuid>,
+) -> Result<ApiResponse<ChecklistItemDto>, ApplicationError> {
+    let (item_id, position) = path.into_inner();
+    let user_id = user_id.into_inner();
+    let item = checklist_service
+        .move_checklist_item(position, item_id, user_id)
+        .await?;
+
+    Ok(ApiResponse::Updated {
+        message: "Checklist item moved successfully".to_string(),
+        data: item,
+    })
+}
+
+#[utoipa::path(
+    delete,
+    description = "***PROTECTED ENDPOINT***\n\nPermanently deletes a checklist item. All board members can manage checklists.",
+    path = "/checklist/item/{itemId}",
+    params(
+        ("itemId" = Uuid, Path, description = "Unique identifier of the checklist item")
+    ),
+    responses(
+        (status = 200, description = "OK - Checklist item deleted successfully", body = ApiResponseSchema<u64>),
+        (status = 401, description = "Unauthorized - No active session or session has expired", body = ApplicationErrorSchema),
+        (status = 403, description = "Forbidden - User doesn't have access to this board", body = ApplicationErrorSchema),
+        (status = 404, description = "Not Found - Checklist item with the given ID not found", body = ApplicationErrorSchema),
+        (status = 500, description = "Internal Server Error - Failed to delete checklist item", body = ApplicationErrorSchema)
+    ),
+    tag = "Checklist",
+    security(
+        ("session_cookie" = [])
+    )
+)]
+#[delete("/item/{itemId}")]
+async fn delete_checklist_item(
+    checklist_service: web::Data<Arc<ChecklistService>>,
+    item_id: web::Path<Uuid>,
+    user_id: web::ReqData<Uuid>,
+) -> Result<ApiResponse<u64>, ApplicationError> {
+    let item_id = item_id.into_inner();
+    let user_id = user_id.into_inner();
+    let rows_affected = checklist_service
+        .delete_checklist_item(item_id, user_id)
+        .await?;
+
+    Ok(ApiResponse::Deleted {
+        message: "Checklist item deleted successfully".to_string(),
+        rows_affected,
+    })
+}
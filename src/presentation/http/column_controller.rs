@@ -1,14 +1,15 @@
 use crate::{
     application::{
-        dto::{ColumnDto, CreateColumnDto, UpdateColumnDto},
+        dto::{ArchiveQueryDto, ColumnDto, CreateColumnDto, UpdateColumnDto},
         services::ColumnService,
     },
+    presentation::http::etag::{etag, expected_version},
     shared::{
         error::{ApplicationError, ApplicationErrorSchema},
         response::{ApiResponse, ApiResponseSchema},
     },
 };
-use actix_web::{delete, get, post, put, web};
+use actix_web::{CustomizeResponder, HttpRequest, Responder, delete, get, post, put, web};
 use std::sync::Arc;
 use uuid::Uuid;
 
@@ -20,6 +21,8 @@ pub fn configure(cfg: &mut web::ServiceConfig) {
             .service(get_board_columns)
             .service(update_column)
             .service(move_column)
+            .service(archive_column)
+            .service(unarchive_column)
             .service(delete_column),
     );
 }
@@ -67,7 +70,7 @@ async fn create_column(
         ("columnId" = Uuid, Path, description = "Unique identifier of the column")
     ),
     responses(
-        (status = 200, description = "OK - Column data retrieved successfully", body = ApiResponseSchema<ColumnDto>),
+        (status = 200, description = "OK - Column data retrieved successfully", body = ApiResponseSchema<ColumnDto>, headers(("ETag" = String, description = "Current version of the column"))),
         (status = 401, description = "Unauthorized - No active session or session has expired", body = ApplicationErrorSchema),
         (status = 403, description = "Forbidden - User doesn't have access to the board containing this column", body = ApplicationErrorSchema),
         (status = 404, description = "Not Found - Column with the given ID not found", body = ApplicationErrorSchema),
@@ -83,26 +86,30 @@ async fn get_column(
     column_service: web::Data<Arc<ColumnService>>,
     column_id: web::Path<Uuid>,
     user_id: web::ReqData<Uuid>,
-) -> Result<ApiResponse<ColumnDto>, ApplicationError> {
+) -> Result<CustomizeResponder<ApiResponse<ColumnDto>>, ApplicationError> {
     let user_id = user_id.into_inner();
     let column = column_service
         .get_column_by_id(column_id.into_inner(), user_id)
         .await?;
+    let etag = etag(column.version);
 
     Ok(ApiResponse::Found {
         message: "Column data retrieved successfully".to_string(),
         data: column,
         page: None,
         total_pages: None,
-    })
+    }
+    .customize()
+    .insert_header(etag))
 }
 
 #[utoipa::path(
     get,
-    description = "***PROTECTED ENDPOINT***\n\nRetrieves all columns for a specific board, ordered by their position. User must be a member of the board to access this endpoint.",
+    description = "***PROTECTED ENDPOINT***\n\nRetrieves all columns for a specific board, ordered by their position. Archived columns are only included when requested. User must be a member of the board to access this endpoint.",
     path = "/column/board/{boardId}",
     params(
-        ("boardId" = Uuid, Path, description = "Unique identifier of the board")
+        ("boardId" = Uuid, Path, description = "Unique identifier of the board"),
+        ("includeArchived" = Option<bool>, Query, description = "Whether archived columns should be included (default: false)")
     ),
     responses(
         (status = 200, description = "OK - Columns retrieved successfully", body = ApiResponseSchema<Vec<ColumnDto>>),
@@ -119,11 +126,16 @@ async fn get_column(
 async fn get_board_columns(
     column_service: web::Data<Arc<ColumnService>>,
     board_id: web::Path<Uuid>,
+    query: web::Query<ArchiveQueryDto>,
     user_id: web::ReqData<Uuid>,
 ) -> Result<ApiResponse<Vec<ColumnDto>>, ApplicationError> {
     let user_id = user_id.into_inner();
     let columns = column_service
-        .get_board_columns(board_id.into_inner(), user_id)
+        .get_board_columns(
+            board_id.into_inner(),
+            query.include_archived.unwrap_or(false),
+            user_id,
+        )
         .await?;
 
     Ok(ApiResponse::Found {
@@ -139,15 +151,17 @@ async fn get_board_columns(
     description = "***PROTECTED ENDPOINT***\n\nUpdates column information. Only the board owner and moderator can update column details.",
     path = "/column/{columnId}",
     params(
-        ("columnId" = Uuid, Path, description = "Unique identifier of the column")
+        ("columnId" = Uuid, Path, description = "Unique identifier of the column"),
+        ("If-Match" = Option<String>, Header, description = "ETag of the column the change is based on, the change is rejected when the column has been modified since")
     ),
     request_body = UpdateColumnDto,
     responses(
-        (status = 200, description = "OK - Column updated successfully", body = ApiResponseSchema<ColumnDto>),
+        (status = 200, description = "OK - Column updated successfully", body = ApiResponseSchema<ColumnDto>, headers(("ETag" = String, description = "Current version of the column"))),
         (status = 400, description = "Bad Request - Invalid input data", body = ApplicationErrorSchema),
         (status = 401, description = "Unauthorized - No active session or session has expired", body = ApplicationErrorSchema),
         (status = 403, description = "Forbidden - User doesn't have permission to update columns in this board", body = ApplicationErrorSchema),
         (status = 404, description = "Not Found - Column with the given ID not found", body = ApplicationErrorSchema),
+        (status = 412, description = "Precondition Failed - Column has been modified since it was retrieved", body = ApplicationErrorSchema),
         (status = 500, description = "Internal Server Error - Failed to update column", body = ApplicationErrorSchema)
     ),
     tag = "Column",
@@ -161,17 +175,22 @@ async fn update_column(
     dto: web::Json<UpdateColumnDto>,
     column_id: web::Path<Uuid>,
     user_id: web::ReqData<Uuid>,
-) -> Result<ApiResponse<ColumnDto>, ApplicationError> {
+    req: HttpRequest,
+) -> Result<CustomizeResponder<ApiResponse<ColumnDto>>, ApplicationError> {
     let column_id = column_id.into_inner();
     let user_id = user_id.into_inner();
+    let expected_version = expected_version(&req)?;
     let column = column_service
-        .update_column(dto.into_inner(), column_id, user_id)
+        .update_column(dto.into_inner(), column_id, user_id, expected_version)
         .await?;
+    let etag = etag(column.version);
 
     Ok(ApiResponse::Updated {
         message: "Column updated successfully".to_string(),
         data: column,
-    })
+    }
+    .customize()
+    .insert_header(etag))
 }
 
 #[utoipa::path(
@@ -180,13 +199,15 @@ async fn update_column(
     path = "/column/{columnId}/move/{position}",
     params(
         ("columnId" = Uuid, Path, description = "Unique identifier of the column"),
-        ("position" = usize, Path, description = "New position index for the column (0-based)")
+        ("position" = usize, Path, description = "New position index for the column (0-based)"),
+        ("If-Match" = Option<String>, Header, description = "ETag of the column the change is based on, the change is rejected when the column has been modified since")
     ),
     responses(
-        (status = 200, description = "OK - Column moved successfully", body = ApiResponseSchema<ColumnDto>),
+        (status = 200, description = "OK - Column moved successfully", body = ApiResponseSchema<ColumnDto>, headers(("ETag" = String, description = "Current version of the column"))),
         (status = 401, description = "Unauthorized - No active session or session has expired", body = ApplicationErrorSchema),
         (status = 403, description = "Forbidden - User doesn't have permission to reorder columns in this board", body = ApplicationErrorSchema),
         (status = 404, description = "Not Found - Column with the given ID not found", body = ApplicationErrorSchema),
+        (status = 412, description = "Precondition Failed - Column has been modified since it was retrieved", body = ApplicationErrorSchema),
         (status = 500, description = "Internal Server Error - Failed to move column", body = ApplicationErrorSchema)
     ),
     tag = "Column",
@@ -199,17 +220,100 @@ async fn move_column(
     column_service: web::Data<Arc<ColumnService>>,
     path: web::Path<(Uuid, usize)>,
     user_id: web::ReqData<Uuid>,
-) -> Result<ApiResponse<ColumnDto>, ApplicationError> {
+    req: HttpRequest,
+) -> Result<CustomizeResponder<ApiResponse<ColumnDto>>, ApplicationError> {
     let (column_id, position) = path.into_inner();
     let user_id = user_id.into_inner();
+    let expected_version = expected_version(&req)?;
     let column = column_service
-        .move_column(position, column_id, user_id)
+        .move_column(position, column_id, user_id, expected_version)
         .await?;
+    let etag = etag(column.version);
 
     Ok(ApiResponse::Updated {
         message: "Column moved successfully".to_string(),
         data: column,
-    })
+    }
+    .customize()
+    .insert_header(etag))
+}
+
+#[utoipa::path(
+    put,
+    description = "***PROTECTED ENDPOINT***\n\nArchives a column. Archived columns are hidden from listings unless explicitly requested and can be restored later. Only the board owner and moderator can archive or restore columns.",
+    path = "/column/{columnId}/archive",
+    params(
+        ("columnId" = Uuid, Path, description = "Unique identifier of the column")
+    ),
+    responses(
+        (status = 200, description = "OK - Column archived successfully", body = ApiResponseSchema<ColumnDto>, headers(("ETag" = String, description = "Current version of the column"))),
+        (status = 401, description = "Unauthorized - No active session or session has expired", body = ApplicationErrorSchema),
+        (status = 403, description = "Forbidden - User doesn't have permission to archive or restore columns in this board", body = ApplicationErrorSchema),
+        (status = 404, description = "Not Found - Column with the given ID not found", body = ApplicationErrorSchema),
+        (status = 409, description = "Conflict - Column is already archived", body = ApplicationErrorSchema),
+        (status = 500, description = "Internal Server Error - Failed to archive column", body = ApplicationErrorSchema)
+    ),
+    tag = "Column",
+    security(
+        ("session_cookie" = [])
+    )
+)]
+#[put("/{columnId}/archive")]
+async fn archive_column(
+    column_service: web::Data<Arc<ColumnService>>,
+    column_id: web::Path<Uuid>,
+    user_id: web::ReqData<Uuid>,
+) -> Result<CustomizeResponder<ApiResponse<ColumnDto>>, ApplicationError> {
+    let column_id = column_id.into_inner();
+    let user_id = user_id.into_inner();
+    let column = column_service.archive_column(column_id, user_id).await?;
+    let etag = etag(column.version);
+
+    Ok(ApiResponse::Updated {
+        message: "Column archived successfully".to_string(),
+        data: column,
+    }
+    .customize()
+    .insert_header(etag))
+}
+
+#[utoipa::path(
+    put,
+    description = "***PROTECTED ENDPOINT***\n\nRestores an archived column so that it appears in listings again. Only the board owner and moderator can archive or restore columns.",
+    path = "/column/{columnId}/unarchive",
+    params(
+        ("columnId" = Uuid, Path, description = "Unique identifier of the column")
+    ),
+    responses(
+        (status = 200, description = "OK - Column restored successfully", body = ApiResponseSchema<ColumnDto>, headers(("ETag" = String, description = "Current version of the column"))),
+        (status = 401, description = "Unauthorized - No active session or session has expired", body = ApplicationErrorSchema),
+        (status = 403, description = "Forbidden - User doesn't have permission to archive or restore columns in this board", body = ApplicationErrorSchema),
+        (status = 404, description = "Not Found - Column with the given ID not found", body = ApplicationErrorSchema),
+        (status = 409, description = "Conflict - Column is not archived", body = ApplicationErrorSchema),
+        (status = 500, description = "Internal Server Error - Failed to unarchive column", body = ApplicationErrorSchema)
+    ),
+    tag = "Column",
+    security(
+        ("session_cookie" = [])
+    )
+)]
+#[put("/{columnId}/unarchive")]
+async fn unarchive_column(
+    column_service: web::Data<Arc<ColumnService>>,
+    column_id: web::Path<Uuid>,
+    user_id: web::ReqData<Uuid>,
+) -> Result<CustomizeResponder<ApiResponse<ColumnDto>>, ApplicationError> {
+    let column_id = column_id.into_inner();
+    let user_id = user_id.into_inner();
+    let column = column_service.unarchive_column(column_id, user_id).await?;
+    let etag = etag(column.version);
+
+    Ok(ApiResponse::Updated {
+        message: "Column restored successfully".to_string(),
+        data: column,
+    }
+    .customize()
+    .insert_header(etag))
 }
 
 #[utoipa::path(
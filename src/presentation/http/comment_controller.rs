@@ -0,0 +1,250 @@
+use crate::{
+    application::{
+        dto::{CommentDto, CommentRevisionDto, CreateCommentDto, UpdateCommentDto},
+        services::CommentService,
+    },
+    shared::{
+        error::{ApplicationError, ApplicationErrorSchema},
+        response::{ApiResponse, ApiResponseSchema},
+    },
+};
+use actix_web::{delete, get, post, put, web};
+use std::sync::Arc;
+use uuid::Uuid;
+
+pub fn configure(cfg: &mut web::ServiceConfig) {
+    cfg.service(
+        web::scope("/comment")
+            .service(create_comment)
+            .service(get_task_comments)
+            .service(get_comment_history)
+            .service(get_comment)
+            .service(update_comment)
+            .service(delete_comment),
+    );
+}
+
+#[utoipa::path(
+    post,
+    description = "***PROTECTED ENDPOINT***\n\nAdds a comment to a task. Providing a parent comment ID creates a reply within that comment's thread. All board members can comment on tasks.",
+    path = "/comment/",
+    request_body = CreateCommentDto,
+    responses(
+        (status = 201, description = "Created - Comment created successfully", body = ApiResponseSchema<CommentDto>),
+        (status = 400, description = "Bad Request - Invalid input data", body = ApplicationErrorSchema),
+        (status = 401, description = "Unauthorized - No active session or session has expired", body = ApplicationErrorSchema),
+        (status = 403, description = "Forbidden - User doesn't have access to this board", body = ApplicationErrorSchema),
+        (status = 404, description = "Not Found - Task or parent comment with the given ID not found", body = ApplicationErrorSchema),
+        (status = 500, description = "Internal Server Error - Failed to create comment", body = ApplicationErrorSchema)
+    ),
+    tag = "Comment",
+    security(
+        ("session_cookie" = [])
+    )
+)]
+#[post("/")]
+async fn create_comment(
+    comment_service: web::Data<Arc<CommentService>>,
+    dto: web::Json<CreateCommentDto>,
+    user_id: web::ReqData<Uuid>,
+) -> Result<ApiResponse<CommentDto>, ApplicationError> {
+    let user_id = user_id.into_inner();
+    let comment = comment_service
+        .create_comment(dto.into_inner(), user_id)
+        .await?;
+
+    Ok(ApiResponse::Created {
+        message: "Comment created successfully".to_string(),
+        data: comment,
+    })
+}
+
+#[utoipa::path(
+    get,
+    description = "***PROTECTED ENDPOINT***\n\nRetrieves a specific comment by its ID. User must be a member of the board to access this endpoint.",
+    path = "/comment/{commentId}",
+    params(
+        ("commentId" = Uuid, Path, description = "Unique identifier of the comment")
+    ),
+    responses(
+        (status = 200, description = "OK - Comment data retrieved successfully", body = ApiResponseSchema<CommentDto>),
+        (status = 401, description = "Unauthorized - No active session or session has expired", body = ApplicationErrorSchema),
+        (status = 403, description = "Forbidden - User doesn't have access to this board", body = ApplicationErrorSchema),
+        (status = 404, description = "Not Found - Comment with the given ID not found", body = ApplicationErrorSchema),
+        (status = 500, description = "Internal Server Error - Failed to retrieve comment", body = ApplicationErrorSchema)
+    ),
+    tag = "Comment",
+    security(
+        ("session_cookie" = [])
+    )
+)]
+#[get("/{commentId}")]
+async fn get_comment(
+    comment_service: web::Data<Arc<CommentService>>,
+    comment_id: web::Path<Uuid>,
+    user_id: web::ReqData<Uuid>,
+) -> Result<ApiResponse<CommentDto>, ApplicationError> {
+    let comment_id = comment_id.into_inner();
+    let user_id = user_id.into_inner();
+    let comment = comment_service
+        .get_comment_by_id(comment_id, user_id)
+        .await?;
+
+    Ok(ApiResponse::Found {
+        message: "Comment data retrieved successfully".to_string(),
+        data: comment,
+        page: None,
+        total_pages: None,
+    })
+}
+
+#[utoipa::path(
+    get,
+    description = "***PROTECTED ENDPOINT***\n\nRetrieves all comments of a specific task, ordered by creation time. Threads can be rebuilt from the parent comment IDs. User must be a member of the board to access this endpoint.",
+    path = "/comment/task/{taskId}",
+    params(
+        ("taskId" = Uuid, Path, description = "Unique identifier of the task")
+    ),
+    responses(
+        (status = 200, description = "OK - Comments retrieved successfully", body = ApiResponseSchema<Vec<CommentDto>>),
+        (status = 401, description = "Unauthorized - No active session or session has expired", body = ApplicationErrorSchema),
+        (status = 403, description = "Forbidden - User doesn't have access to this board", body = ApplicationErrorSchema),
+        (status = 404, description = "Not Found - Task with the given ID not found", body = ApplicationErrorSchema),
+        (status = 500, description = "Internal Server Error - Failed to retrieve comments", body = ApplicationErrorSchema)
+    ),
+    tag = "Comment",
+    security(
+        ("session_cookie" = [])
+    )
+)]
+#[get("/task/{taskId}")]
+async fn get_task_comments(
+    comment_service: web::Data<Arc<CommentService>>,
+    task_id: web::Path<Uuid>,
+    user_id: web::ReqData<Uuid>,
+) -> Result<ApiResponse<Vec<CommentDto>>, ApplicationError> {
+    let task_id = task_id.into_inner();
+    let user_id = user_id.into_inner();
+    let comments = comment_service.get_task_comments(task_id, user_id).await?;
+
+    Ok(ApiResponse::Found {
+        message: "Comments retrieved successfully".to_string(),
+        data: comments,
+        page: None,
+        total_pages: None,
+    })
+}
+
+#[utoipa::path(
+    get,
+    description = "***PROTECTED ENDPOINT***\n\nRetrieves the edit history of a comment. Each revision holds the content as it was before the corresponding edit, ordered from oldest to newest. User must be a member of the board to access this endpoint.",
+    path = "/comment/{commentId}/history",
+    params(
+        ("commentId" = Uuid, Path, description = "Unique identifier of the comment")
+    ),
+    responses(
+        (status = 200, description = "OK - Comment history retrieved successfully", body = ApiResponseSchema<Vec<CommentRevisionDto>>),
+        (status = 401, description = "Unauthorized - No active session or session has expired", body = ApplicationErrorSchema),
+        (status = 403, description = "Forbidden - User doesn't have access to this board", body = ApplicationErrorSchema),
+        (status = 404, description = "Not Found - Comment with the given ID not found", body = ApplicationErrorSchema),
+        (status = 500, description = "Internal Server Error - Failed to retrieve comment history", body = ApplicationErrorSchema)
+    ),
+    tag = "Comment",
+    security(
+        ("session_cookie" = [])
+    )
+)]
+#[get("/{commentId}/history")]
+async fn get_comment_history(
+    comment_service: web::Data<Arc<CommentService>>,
+    comment_id: web::Path<Uuid>,
+    user_id: web::ReqData<Uuid>,
+) -> Result<ApiResponse<Vec<CommentRevisionDto>>, ApplicationError> {
+    let comment_id = comment_id.into_inner();
+    let user_id = user_id.into_inner();
+    let revisions = comment_service
+        .get_comment_history(comment_id, user_id)
+        .await?;
+
+    Ok(ApiResponse::Found {
+        message: "Comment history retrieved successfully".to_string(),
+        data: revisions,
+        page: None,
+        total_pages: None,
+    })
+}
+
+#[utoipa::path(
+    put,
+    description = "***PROTECTED ENDPOINT***\n\nEdits the content of a comment. The previous content is kept in the comment history. Only the comment author, the board owner and moderators can edit comments.",
+    path = "/comment/{commentId}",
+    params(
+        ("commentId" = Uuid, Path, description = "Unique identifier of the comment")
+    ),
+    request_body = UpdateCommentDto,
+    responses(
+        (status = 200, description = "OK - Comment updated successfully", body = ApiResponseSchema<CommentDto>),
+        (status = 400, description = "Bad Request - Invalid input data", body = ApplicationErrorSchema),
+        (status = 401, description = "Unauthorized - No active session or session has expired", body = ApplicationErrorSchema),
+        (status = 403, description = "Forbidden - Only the author, board owner and moderator can edit this comment", body = ApplicationErrorSchema),
+        (status = 404, description = "Not Found - Comment with the given ID not found", body = ApplicationErrorSchema),
+        (status = 500, description = "Internal Server Error - Failed to update comment", body = ApplicationErrorSchema)
+    ),
+    tag = "Comment",
+    security(
+        ("session_cookie" = [])
+    )
+)]
+#[put("/{commentId}")]
+async fn update_comment(
+    comment_service: web::Data<Arc<CommentService>>,
+    dto: web::Json<UpdateCommentDto>,
+    comment_id: web::Path<Uuid>,
+    user_id: web::ReqData<Uuid>,
+) -> Result<ApiResponse<CommentDto>, ApplicationError> {
+    let comment_id = comment_id.into_inner();
+    let user_id = user_id.into_inner();
+    let comment = comment_service
+        .update_comment(dto.into_inner(), comment_id, user_id)
+        .await?;
+
+    Ok(ApiResponse::Updated {
+        message: "Comment updated successfully".to_string(),
+        data: comment,
+    })
+}
+
+#[utoipa::path(
+    delete,
+    description = "***PROTECTED ENDPOINT***\n\nPermanently deletes a comment together with all of its replies. Only the comment author, the board owner and moderators can delete comments.",
+    path = "/comment/{commentId}",
+    params(
+        ("commentId" = Uuid, Path, description = "Unique identifier of the comment")
+    ),
+    responses(
+        (status = 200, description = "OK - Comment deleted successfully", body = ApiResponseSchema<u64>),
+        (status = 401, description = "Unauthorized - No active session or session has expired", body = ApplicationErrorSchema),
+        (status = 403, description = "Forbidden - Only the author, board owner and moderator can delete this comment", body = ApplicationErrorSchema),
+        (status = 404, description = "Not Found - Comment with the given ID not found", body = ApplicationErrorSchema),
+        (status = 500, description = "Internal Server Error - Failed to delete comment", body = ApplicationErrorSchema)
+    ),
+    tag = "Comment",
+    security(
+        ("session_cookie" = [])
+    )
+)]
+#[delete("/{commentId}")]
+async fn delete_comment(
+    comment_service: web::Data<Arc<CommentService>>,
+    comment_id: web::Path<Uuid>,
+    user_id: web::ReqData<Uuid>,
+) -> Result<ApiResponse<u64>, ApplicationError> {
+    let comment_id = comment_id.into_inner();
+    let user_id = user_id.into_inner();
+    let rows_affected = comment_service.delete_comment(comment_id, user_id).await?;
+
+    Ok(ApiResponse::Deleted {
+        message: "Comment deleted successfully".to_string(),
+        rows_affected,
+    })
+}
@@ -0,0 +1,174 @@
+use crate::{
+    application::{
+        dto::{CreateCustomFieldDto, CustomFieldDto, UpdateCustomFieldDto},
+        services::CustomFieldService,
+    },
+    shared::{
+        error::{ApplicationError, ApplicationErrorSchema},
+        response::{ApiResponse, ApiResponseSchema},
+    },
+};
+use actix_web::{delete, get, post, put, web};
+use std::sync::Arc;
+use uuid::Uuid;
+
+pub fn configure(cfg: &mut web::ServiceConfig) {
+    cfg.service(
+        web::scope("/custom-field")
+            .service(create_custom_field)
+            .service(get_board_custom_fields)
+            .service(update_custom_field)
+            .service(delete_custom_field),
+    );
+}
+
+#[utoipa::path(
+    post,
+    description = "***PROTECTED ENDPOINT***\n\nDefines a custom field on a board. The type (`text`, `number`, `date`, `singleSelect`, `multiSelect` or `user`) can't be changed later, and select fields need at least one option. Field names are unique per board regardless of case. Only board owners can manage custom fields.",
+    path = "/custom-field/",
+    request_body = CreateCustomFieldDto,
+    responses(
+        (status = 201, description = "Created - Custom field created successfully", body = ApiResponseSchema<CustomFieldDto>),
+        (status = 400, description = "Bad Request - Invalid input data", body = ApplicationErrorSchema),
+        (status = 401, description = "Unauthorized - No active session or session has expired", body = ApplicationErrorSchema),
+        (status = 403, description = "Forbidden - User doesn't have permission to manage custom fields", body = ApplicationErrorSchema),
+        (status = 409, description = "Conflict - A custom field with this name already exists on the board", body = ApplicationErrorSchema),
+        (status = 500, description = "Internal Server Error - Failed to create custom field", body = ApplicationErrorSchema)
+    ),
+    tag = "Custom Field",
+    security(
+        ("session_cookie" = [])
+    )
+)]
+#[post("/")]
+async fn create_custom_field(
+    custom_field_service: web::Data<Arc<CustomFieldService>>,
+    dto: web::Json<CreateCustomFieldDto>,
+    user_id: web::ReqData<Uuid>,
+) -> Result<ApiResponse<CustomFieldDto>, ApplicationError> {
+    let user_id = user_id.into_inner();
+    let field = custom_field_service
+        .create_custom_field(dto.into_inner(), user_id)
+        .await?;
+
+    Ok(ApiResponse::Created {
+        message: "Custom field created successfully".to_string(),
+        data: field,
+    })
+}
+
+#[utoipa::path(
+    get,
+    description = "***PROTECTED ENDPOINT***\n\nRetrieves all custom fields of a specific board in the order they were created. User must be a member of the board to access this endpoint.",
+    path = "/custom-field/board/{boardId}",
+    params(
+        ("boardId" = Uuid, Path, description = "Unique identifier of the board")
+    ),
+    responses(
+        (status = 200, description = "OK - Custom fields retrieved successfully", body = ApiResponseSchema<Vec<CustomFieldDto>>),
+        (status = 401, description = "Unauthorized - No active session or session has expired", body = ApplicationErrorSchema),
+        (status = 403, description = "Forbidden - User doesn't have access to this board", body = ApplicationErrorSchema),
+        (status = 500, description = "Internal Server Error - Failed to retrieve custom fields", body = ApplicationErrorSchema)
+    ),
+    tag = "Custom Field",
+    security(
+        ("session_cookie" = [])
+    )
+)]
+#[get("/board/{boardId}")]
+async fn get_board_custom_fields(
+    custom_field_service: web::Data<Arc<CustomFieldService>>,
+    board_id: web::Path<Uuid>,
+    user_id: web::ReqData<Uuid>,
+) -> Result<ApiResponse<Vec<CustomFieldDto>>, ApplicationError> {
+    let board_id = board_id.into_inner();
+    let user_id = user_id.into_inner();
+    let fields = custom_field_service
+        .get_board_custom_fields(board_id, user_id)
+        .await?;
+
+    Ok(ApiResponse::Found {
+        message: "Custom fields retrieved successfully".to_string(),
+        data: fields,
+        page: None,
+        total_pages: None,
+    })
+}
+
+#[utoipa::path(
+    put,
+    description = "***PROTECTED ENDPOINT***\n\nRenames a custom field or replaces the options of a select field. An option can only be removed once no task has it selected. Only board owners can manage custom fields.",
+    path = "/custom-field/{fieldId}",
+    params(
+        ("fieldId" = Uuid, Path, description = "Unique identifier of the custom field")
+    ),
+    request_body = UpdateCustomFieldDto,
+    responses(
+        (status = 200, description = "OK - Custom field updated successfully", body = ApiResponseSchema<CustomFieldDto>),
+        (status = 400, description = "Bad Request - Invalid input data", body = ApplicationErrorSchema),
+        (status = 401, description = "Unauthorized - No active session or session has expired", body = ApplicationErrorSchema),
+        (status = 403, description = "Forbidden - User doesn't have permission to manage custom fields", body = ApplicationErrorSchema),
+        (status = 404, description = "Not Found - Custom field with the given ID not found", body = ApplicationErrorSchema),
+        (status = 409, description = "Conflict - The name is taken or a removed option is still in use", body = ApplicationErrorSchema),
+        (status = 500, description = "Internal Server Error - Failed to update custom field", body = ApplicationErrorSchema)
+    ),
+    tag = "Custom Field",
+    security(
+        ("session_cookie" = [])
+    )
+)]
+#[put("/{fieldId}")]
+async fn update_custom_field(
+    custom_field_service: web::Data<Arc<CustomFieldService>>,
+    field_id: web::Path<Uuid>,
+    dto: web::Json<UpdateCustomFieldDto>,
+    user_id: web::ReqData<Uuid>,
+) -> Result<ApiResponse<CustomFieldDto>, ApplicationError> {
+    let field_id = field_id.into_inner();
+    let user_id = user_id.into_inner();
+    let field = custom_field_service
+        .update_custom_field(field_id, dto.into_inner(), user_id)
+        .await?;
+
+    Ok(ApiResponse::Updated {
+        message: "Custom field updated successfully".to_string(),
+        data: field,
+    })
+}
+
+#[utoipa::path(
+    delete,
+    description = "***PROTECTED ENDPOINT***\n\nDeletes a custom field along with its values on every task. Only board owners can manage custom fields.",
+    path = "/custom-field/{fieldId}",
+    params(
+        ("fieldId" = Uuid, Path, description = "Unique identifier of the custom field")
+    ),
+    responses(
+        (status = 200, description = "OK - Custom field deleted successfully", body = ApiResponseSchema<u64>),
+        (status = 401, description = "Unauthorized - No active session or session has expired", body = ApplicationErrorSchema),
+        (status = 403, description = "Forbidden - User doesn't have permission to manage custom fields", body = ApplicationErrorSchema),
+        (status = 404, description = "Not Found - Custom field with the given ID not found", body = ApplicationErrorSchema),
+        (status = 500, description = "Internal Server Error - Failed to delete custom field", body = ApplicationErrorSchema)
+    ),
+    tag = "Custom Field",
+    security(
+        ("session_cookie" = [])
+    )
+)]
+#[delete("/{fieldId}")]
+async fn delete_custom_field(
+    custom_field_service: web::Data<Arc<CustomFieldService>>,
+    field_id: web::Path<Uuid>,
+    user_id: web::ReqData<Uuid>,
+) -> Result<ApiResponse<u64>, ApplicationError> {
+    let field_id = field_id.into_inner();
+    let user_id = user_id.into_inner();
+    let rows_affected = custom_field_service
+        .delete_custom_field(field_id, user_id)
+        .await?;
+
+    Ok(ApiResponse::Deleted {
+        message: "Custom field deleted successfully".to_string(),
+        rows_affected,
+    })
+}
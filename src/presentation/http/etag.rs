@@ -0,0 +1,28 @@
+use crate::shared::error::ApplicationError;
+use actix_web::{
+    HttpRequest,
+    http::header::{ETag, EntityTag, Header, IfMatch},
+};
+
+/// Entity tag of a board, column or task, derived from its version
+pub fn etag(version: i32) -> ETag {
+    ETag(EntityTag::new_strong(version.to_string()))
+}
+
+/// Reads the version a client expects from the `If-Match` header. A missing header or `*`
+/// matches any version.
+pub fn expected_version(req: &HttpRequest) -> Result<Option<i32>, ApplicationError> {
+    let invalid = || ApplicationError::BadRequest {
+        message: "If-Match must contain a single entity tag previously returned as ETag"
+            .to_string(),
+    };
+
+    match IfMatch::parse(req).map_err(|_| invalid())? {
+        IfMatch::Any => Ok(None),
+        IfMatch::Items(tags) => match tags.as_slice() {
+            [] => Ok(None),
+            [tag] if !tag.weak => tag.tag().parse().map(Some).map_err(|_| invalid()),
+            _ => Err(invalid()),
+        },
+    }
+}
@@ -0,0 +1,170 @@
+use crate::{
+    application::{
+        dto::{CreateLabelDto, LabelDto, UpdateLabelDto},
+        services::LabelService,
+    },
+    shared::{
+        error::{ApplicationError, ApplicationErrorSchema},
+        response::{ApiResponse, ApiResponseSchema},
+    },
+};
+use actix_web::{delete, get, post, put, web};
+use std::sync::Arc;
+use uuid::Uuid;
+
+pub fn configure(cfg: &mut web::ServiceConfig) {
+    cfg.service(
+        web::scope("/label")
+            .service(create_label)
+            .service(get_board_labels)
+            .service(update_label)
+            .service(delete_label),
+    );
+}
+
+#[utoipa::path(
+    post,
+    description = "***PROTECTED ENDPOINT***\n\nCreates a label on a board. Label names are unique per board regardless of case; the colour defaults to a neutral grey. All board members can manage labels.",
+    path = "/label/",
+    request_body = CreateLabelDto,
+    responses(
+        (status = 201, description = "Created - Label created successfully", body = ApiResponseSchema<LabelDto>),
+        (status = 400, description = "Bad Request - Invalid input data", body = ApplicationErrorSchema),
+        (status = 401, description = "Unauthorized - No active session or session has expired", body = ApplicationErrorSchema),
+        (status = 403, description = "Forbidden - User doesn't have access to this board", body = ApplicationErrorSchema),
+        (status = 409, description = "Conflict - A label with this name already exists on the board", body = ApplicationErrorSchema),
+        (status = 500, description = "Internal Server Error - Failed to create label", body = ApplicationErrorSchema)
+    ),
+    tag = "Label",
+    security(
+        ("session_cookie" = [])
+    )
+)]
+#[post("/")]
+async fn create_label(
+    label_service: web::Data<Arc<LabelService>>,
+    dto: web::Json<CreateLabelDto>,
+    user_id: web::ReqData<Uuid>,
+) -> Result<ApiResponse<LabelDto>, ApplicationError> {
+    let user_id = user_id.into_inner();
+    let label = label_service
+        .create_label(dto.into_inner(), user_id)
+        .await?;
+
+    Ok(ApiResponse::Created {
+        message: "Label created successfully".to_string(),
+        data: label,
+    })
+}
+
+#[utoipa::path(
+    get,
+    description = "***PROTECTED ENDPOINT***\n\nRetrieves all labels of a specific board ordered by name. User must be a member of the board to access this endpoint.",
+    path = "/label/board/{boardId}",
+    params(
+        ("boardId" = Uuid, Path, description = "Unique identifier of the board")
+    ),
+    responses(
+        (status = 200, description = "OK - Labels retrieved successfully", body = ApiResponseSchema<Vec<LabelDto>>),
+        (status = 401, description = "Unauthorized - No active session or session has expired", body = ApplicationErrorSchema),
+        (status = 403, description = "Forbidden - User doesn't have access to this board", body = ApplicationErrorSchema),
+        (status = 500, description = "Internal Server Error - Failed to retrieve labels", body = ApplicationErrorSchema)
+    ),
+    tag = "Label",
+    security(
+        ("session_cookie" = [])
+    )
+)]
+#[get("/board/{boardId}")]
+async fn get_board_labels(
+    label_service: web::Data<Arc<LabelService>>,
+    board_id: web::Path<Uuid>,
+    user_id: web::ReqData<Uuid>,
+) -> Result<ApiResponse<Vec<LabelDto>>, ApplicationError> {
+    let board_id = board_id.into_inner();
+    let user_id = user_id.into_inner();
+    let labels = label_service.get_board_labels(board_id, user_id).await?;
+
+    Ok(ApiResponse::Found {
+        message: "Labels retrieved successfully".to_string(),
+        data: labels,
+        page: None,
+        total_pages: None,
+    })
+}
+
+#[utoipa::path(
+    put,
+    description = "***PROTECTED ENDPOINT***\n\nRenames or recolours a label. Tasks reference labels by ID, so the change applies to every task carrying the label at once. All board members can manage labels.",
+    path = "/label/{labelId}",
+    params(
+        ("labelId" = Uuid, Path, description = "Unique identifier of the label")
+    ),
+    request_body = UpdateLabelDto,
+    responses(
+        (status = 200, description = "OK - Label updated successfully", body = ApiResponseSchema<LabelDto>),
+        (status = 400, description = "Bad Request - Invalid input data", body = ApplicationErrorSchema),
+        (status = 401, description = "Unauthorized - No active session or session has expired", body = ApplicationErrorSchema),
+        (status = 403, description = "Forbidden - User doesn't have access to this board", body = ApplicationErrorSchema),
+        (status = 404, description = "Not Found - Label with the given ID not found", body = ApplicationErrorSchema),
+        (status = 409, description = "Conflict - A label with this name already exists on the board", body = ApplicationErrorSchema),
+        (status = 500, description = "Internal Server Error - Failed to update label", body = ApplicationErrorSchema)
+    ),
+    tag = "Label",
+    security(
+        ("session_cookie" = [])
+    )
+)]
+#[put("/{labelId}")]
+async fn update_label(
+    label_service: web::Data<Arc<LabelService>>,
+    label_id: web::Path<Uuid>,
+    dto: web::Json<UpdateLabelDto>,
+    user_id: web::ReqData<Uuid>,
+) -> Result<ApiResponse<LabelDto>, ApplicationError> {
+    let label_id = label_id.into_inner();
+    let user_id = user_id.into_inner();
+    let label = label_service
+        .update_label(label_id, dto.into_inner(), user_id)
+        .await?;
+
+    Ok(ApiResponse::Updated {
+        message: "Label updated successfully".to_string(),
+        data: label,
+    })
+}
+
+#[utoipa::path(
+    delete,
+    description = "***PROTECTED ENDPOINT***\n\nDeletes a label and removes it from every task carrying it. All board members can manage labels.",
+    path = "/label/{labelId}",
+    params(
+        ("labelId" = Uuid, Path, description = "Unique identifier of the label")
+    ),
+    responses(
+        (status = 200, description = "OK - Label deleted successfully", body = ApiResponseSchema<u64>),
+        (status = 401, description = "Unauthorized - No active session or session has expired", body = ApplicationErrorSchema),
+        (status = 403, description = "Forbidden - User doesn't have access to this board", body = ApplicationErrorSchema),
+        (status = 404, description = "Not Found - Label with the given ID not found", body = ApplicationErrorSchema),
+        (status = 500, description = "Internal Server Error - Failed to delete label", body = ApplicationErrorSchema)
+    ),
+    tag = "Label",
+    security(
+        ("session_cookie" = [])
+    )
+)]
+#[delete("/{labelId}")]
+async fn delete_label(
+    label_service: web::Data<Arc<LabelService>>,
+    label_id: web::Path<Uuid>,
+    user_id: web::ReqData<Uuid>,
+) -> Result<ApiResponse<u64>, ApplicationError> {
+    let label_id = label_id.into_inner();
+    let user_id = user_id.into_inner();
+    let rows_affected = label_service.delete_label(label_id, user_id).await?;
+
+    Ok(ApiResponse::Deleted {
+        message: "Label deleted successfully".to_string(),
+        rows_affected,
+    })
+}
@@ -1,17 +1,30 @@
+pub mod attachment_controller;
 pub mod auth_controller;
 pub mod board_controller;
+pub mod checklist_controller;
 pub mod column_controller;
+pub mod comment_controller;
+pub mod custom_field_controller;
+pub mod etag;
+pub mod label_controller;
 pub mod openapi;
 pub mod server;
 pub mod task_controller;
+pub mod time_entry_controller;
 pub mod user_controller;
 pub mod websocket_controller;
 
+pub use attachment_controller::configure as configure_attachment_routes;
 pub use auth_controller::configure as configure_auth_roures;
 pub use board_controller::configure as configure_board_routes;
+pub use checklist_controller::configure as configure_checklist_routes;
 pub use column_controller::configure as configure_column_routes;
+pub use comment_controller::configure as configure_comment_routes;
+pub use custom_field_controller::configure as configure_custom_field_routes;
+pub use label_controller::configure as configure_label_routes;
 pub use openapi::ApiDoc;
 pub use server::configure_server;
 pub use task_controller::configure as configure_task_routes;
+pub use time_entry_controller::configure as configure_time_entry_routes;
 pub use user_controller::configure as configure_user_routes;
 pub use websocket_controller::configure as configure_websocket_routes;
@@ -1,8 +1,21 @@
 use crate::application::dto::{
-    ActivationQueryDto, AddBoardMemberDto, BoardDto, BoardMemberDto, ColumnDto, CreateBoardDto,
-    CreateColumnDto, CreateTaskDto, CreateUserDto, DeleteBoardMemberDto, ForgotPasswordQueryDto,
-    LoginDto, ResendActivationQueryDto, ResetPasswordDto, TaskDto, UpdateBoardDto,
-    UpdateBoardMemberRoleDto, UpdateColumnDto, UpdateTaskDto, UserDto,
+    ActivationQueryDto, AddBoardMemberDto, ArchiveQueryDto, AttachmentDto, BoardActivityDto,
+    BoardActivityPageDto, BoardActivityQueryDto, BoardAnalyticsDto, BoardAnalyticsQueryDto,
+    BoardChangeDto, BoardChangesPageDto, BoardChangesQueryDto, BoardDto, BoardMemberDto,
+    BoardSnapshotColumnDto, BoardSnapshotDto, BulkTaskDto, BulkTaskOperationDto, BulkTaskResultDto,
+    ChecklistDto, ChecklistItemDto, ColumnDto, ColumnTaskCountDto, CommentDto, CommentRevisionDto,
+    CreateBoardDto, CreateChecklistDto, CreateChecklistItemDto, CreateColumnDto, CreateCommentDto,
+    CreateCustomFieldDto, CreateLabelDto, CreateTaskDto, CreateTaskRelationDto, CreateTimeEntryDto,
+    CreateUserDto, CumulativeFlowDayDto, CustomFieldDto, CustomFieldFilterDto,
+    CustomFieldOperatorDto, DailyTimeDto, DeleteBoardMemberDto, DuplicateTaskDto,
+    FlowTimePercentilesDto, ForgotPasswordQueryDto, LabelDto, LoginDto, MemberTombstoneDto,
+    MoveTaskQueryDto, ResendActivationQueryDto, ResetPasswordDto, ResyncRequiredDto, StartTimerDto,
+    TaskDto, TaskFlowMetricsDto, TaskRelationDto, TaskRelationTypeDto, TaskSearchDto,
+    TaskSearchPageDto, TaskTimeDto, TimeEntryDto, TimeReportDto, TimeReportQueryDto, TombstoneDto,
+    UpdateBoardDto, UpdateBoardMemberRoleDto, UpdateChecklistDto, UpdateChecklistItemDto,
+    UpdateColumnDto, UpdateCommentDto, UpdateCustomFieldDto, UpdateLabelDto, UpdateTaskDto,
+    UpdateTimeEntryDto, UploadAttachmentDto, UserDto, UserTimeDto, WebSocketControlMessage,
+    WeeklyThroughputDto,
 };
 use utoipa::{
     Modify, OpenApi,
@@ -29,6 +42,12 @@ use utoipa::{
         crate::presentation::http::board_controller::get_board,
         crate::presentation::http::board_controller::get_user_boards,
         crate::presentation::http::board_controller::update_board,
+        crate::presentation::http::board_controller::get_board_activity,
+        crate::presentation::http::board_controller::get_board_analytics,
+        crate::presentation::http::board_controller::get_board_snapshot,
+        crate::presentation::http::board_controller::get_board_changes,
+        crate::presentation::http::board_controller::archive_board,
+        crate::presentation::http::board_controller::unarchive_board,
         crate::presentation::http::board_controller::delete_board,
         crate::presentation::http::board_controller::remove_board_member,
         crate::presentation::http::board_controller::update_board_member_role,
@@ -40,15 +59,72 @@ use utoipa::{
         crate::presentation::http::column_controller::get_board_columns,
         crate::presentation::http::column_controller::update_column,
         crate::presentation::http::column_controller::move_column,
+        crate::presentation::http::column_controller::archive_column,
+        crate::presentation::http::column_controller::unarchive_column,
         crate::presentation::http::column_controller::delete_column,
 
         // Task endpoints
         crate::presentation::http::task_controller::create_task,
+        crate::presentation::http::task_controller::bulk_update_tasks,
         crate::presentation::http::task_controller::get_task,
         crate::presentation::http::task_controller::get_column_tasks,
+        crate::presentation::http::task_controller::search_tasks,
         crate::presentation::http::task_controller::update_task,
         crate::presentation::http::task_controller::move_task,
+        crate::presentation::http::task_controller::duplicate_task,
+        crate::presentation::http::task_controller::archive_task,
+        crate::presentation::http::task_controller::unarchive_task,
         crate::presentation::http::task_controller::delete_task,
+        crate::presentation::http::task_controller::create_task_relation,
+        crate::presentation::http::task_controller::delete_task_relation,
+
+        // Comment endpoints
+        crate::presentation::http::comment_controller::create_comment,
+        crate::presentation::http::comment_controller::get_comment,
+        crate::presentation::http::comment_controller::get_task_comments,
+        crate::presentation::http::comment_controller::get_comment_history,
+        crate::presentation::http::comment_controller::update_comment,
+        crate::presentation::http::comment_controller::delete_comment,
+
+        // Checklist endpoints
+        crate::presentation::http::checklist_controller::create_checklist,
+        crate::presentation::http::checklist_controller::get_task_checklists,
+        crate::presentation::http::checklist_controller::get_checklist,
+        crate::presentation::http::checklist_controller::update_checklist,
+        crate::presentation::http::checklist_controller::move_checklist,
+        crate::presentation::http::checklist_controller::delete_checklist,
+        crate::presentation::http::checklist_controller::create_checklist_item,
+        crate::presentation::http::checklist_controller::update_checklist_item,
+        crate::presentation::http::checklist_controller::move_checklist_item,
+        crate::presentation::http::checklist_controller::delete_checklist_item,
+
+        // Attachment endpoints
+        crate::presentation::http::attachment_controller::upload_attachment,
+        crate::presentation::http::attachment_controller::get_task_attachments,
+        crate::presentation::http::attachment_controller::download_attachment,
+        crate::presentation::http::attachment_controller::delete_attachment,
+
+        // Label endpoints
+        crate::presentation::http::label_controller::create_label,
+        crate::presentation::http::label_controller::get_board_labels,
+        crate::presentation::http::label_controller::update_label,
+        crate::presentation::http::label_controller::delete_label,
+
+        // Custom field endpoints
+        crate::presentation::http::custom_field_controller::create_custom_field,
+        crate::presentation::http::custom_field_controller::get_board_custom_fields,
+        crate::presentation::http::custom_field_controller::update_custom_field,
+        crate::presentation::http::custom_field_controller::delete_custom_field,
+
+        // Time entry endpoints
+        crate::presentation::http::time_entry_controller::log_time_entry,
+        crate::presentation::http::time_entry_controller::start_timer,
+        crate::presentation::http::time_entry_controller::stop_timer,
+        crate::presentation::http::time_entry_controller::get_task_time_entries,
+        crate::presentation::http::time_entry_controller::get_running_timers,
+        crate::presentation::http::time_entry_controller::get_time_report,
+        crate::presentation::http::time_entry_controller::update_time_entry,
+        crate::presentation::http::time_entry_controller::delete_time_entry,
 
         // Websocket endpoints
         crate::presentation::http::websocket_controller::websocket_handler
@@ -62,6 +138,9 @@ use utoipa::{
             ForgotPasswordQueryDto,
             ResetPasswordDto,
 
+            // Archive DTOs
+            ArchiveQueryDto,
+
             // User DTOs
             UserDto,
             CreateUserDto,
@@ -71,6 +150,30 @@ use utoipa::{
             CreateBoardDto,
             UpdateBoardDto,
 
+            // Board activity DTOs
+            BoardActivityDto,
+            BoardActivityPageDto,
+            BoardActivityQueryDto,
+            // Board analytics DTOs
+            BoardAnalyticsDto,
+            BoardAnalyticsQueryDto,
+            TaskFlowMetricsDto,
+            FlowTimePercentilesDto,
+            WeeklyThroughputDto,
+            CumulativeFlowDayDto,
+            ColumnTaskCountDto,
+
+            // Board snapshot DTOs
+            BoardSnapshotDto,
+            BoardSnapshotColumnDto,
+
+            // Board changes DTOs
+            BoardChangesQueryDto,
+            BoardChangesPageDto,
+            BoardChangeDto,
+            TombstoneDto,
+            MemberTombstoneDto,
+
             // Board member DTOs
             BoardMemberDto,
             AddBoardMemberDto,
@@ -85,7 +188,62 @@ use utoipa::{
             // Task DTOs
             TaskDto,
             CreateTaskDto,
-            UpdateTaskDto
+            UpdateTaskDto,
+            DuplicateTaskDto,
+            MoveTaskQueryDto,
+            BulkTaskDto,
+            BulkTaskOperationDto,
+            BulkTaskResultDto,
+            TaskRelationDto,
+            TaskRelationTypeDto,
+            CreateTaskRelationDto,
+            TaskSearchDto,
+            CustomFieldFilterDto,
+            CustomFieldOperatorDto,
+            TaskSearchPageDto,
+
+            // Comment DTOs
+            CommentDto,
+            CommentRevisionDto,
+            CreateCommentDto,
+            UpdateCommentDto,
+
+            // Checklist DTOs
+            ChecklistDto,
+            ChecklistItemDto,
+            CreateChecklistDto,
+            UpdateChecklistDto,
+            CreateChecklistItemDto,
+            UpdateChecklistItemDto,
+
+            // Attachment DTOs
+            AttachmentDto,
+            UploadAttachmentDto,
+
+            // Label DTOs
+            LabelDto,
+            CreateLabelDto,
+            UpdateLabelDto,
+
+            // Custom field DTOs
+            CustomFieldDto,
+            CreateCustomFieldDto,
+            UpdateCustomFieldDto,
+
+            // Time entry DTOs
+            TimeEntryDto,
+            CreateTimeEntryDto,
+            StartTimerDto,
+            UpdateTimeEntryDto,
+            TimeReportQueryDto,
+            TimeReportDto,
+            UserTimeDto,
+            TaskTimeDto,
+            DailyTimeDto,
+
+            // WebSocket DTOs
+            WebSocketControlMessage,
+            ResyncRequiredDto
         )
     ),
     tags(
@@ -94,6 +252,12 @@ use utoipa::{
         (name = "Board", description = "Board management endpoints."),
         (name = "Column", description = "Column management endpoints."),
         (name = "Task", description = "Task management endpoints."),
+        (name = "Comment", description = "Task comment management endpoints."),
+        (name = "Checklist", description = "Task checklist management endpoints."),
+        (name = "Attachment", description = "Task attachment management endpoints."),
+        (name = "Label", description = "Board label management endpoints."),
+        (name = "Custom Field", description = "Board custom field management endpoints."),
+        (name = "Time Entry", description = "Task time tracking endpoints."),
         (name = "WebSocket", description = "WebSocket management endpoints.")
     ),
     modifiers(&SecurityAddon),
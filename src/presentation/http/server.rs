@@ -1,8 +1,10 @@
 use crate::{
     presentation::{
-        configure_auth_roures, configure_board_routes, configure_column_routes,
-        configure_task_routes, configure_user_routes, configure_websocket_routes, http::ApiDoc,
-        middleware::RequireAuth,
+        configure_attachment_routes, configure_auth_roures, configure_board_routes,
+        configure_checklist_routes, configure_column_routes, configure_comment_routes,
+        configure_custom_field_routes, configure_label_routes, configure_task_routes,
+        configure_time_entry_routes, configure_user_routes, configure_websocket_routes,
+        http::ApiDoc, middleware::RequireAuth,
     },
     shared::{
         config::AppState,
@@ -47,6 +49,15 @@ pub async fn configure_server(
             .app_data(web::Data::new(app_state.board_service.clone()))
             .app_data(web::Data::new(app_state.column_service.clone()))
             .app_data(web::Data::new(app_state.task_service.clone()))
+            .app_data(web::Data::new(app_state.comment_service.clone()))
+            .app_data(web::Data::new(app_state.checklist_service.clone()))
+            .app_data(web::Data::new(app_state.label_service.clone()))
+            .app_data(web::Data::new(app_state.custom_field_service.clone()))
+            .app_data(web::Data::new(app_state.time_entry_service.clone()))
+            .app_data(web::Data::new(app_state.attachment_service.clone()))
+            .app_data(web::Data::new(app_state.board_activity_service.clone()))
+            .app_data(web::Data::new(app_state.board_analytics_service.clone()))
+            .app_data(web::Data::new(app_state.board_sync_service.clone()))
             .app_data(web::Data::new(app_state.websocket_service.clone()))
             .wrap(Logger::default())
             .wrap(RequireAuth)
@@ -66,6 +77,12 @@ pub async fn configure_server(
                     .configure(configure_board_routes)
                     .configure(configure_column_routes)
                     .configure(configure_task_routes)
+                    .configure(configure_comment_routes)
+                    .configure(configure_checklist_routes)
+                    .configure(configure_label_routes)
+                    .configure(configure_custom_field_routes)
+                    .configure(configure_time_entry_routes)
+                    .configure(configure_attachment_routes)
                     .configure(configure_websocket_routes),
             )
     })
@@ -1,14 +1,19 @@
 use crate::{
     application::{
-        dto::{CreateTaskDto, TaskDto, UpdateTaskDto},
+        dto::{
+            ArchiveQueryDto, BulkTaskDto, BulkTaskResultDto, CreateTaskDto, CreateTaskRelationDto,
+            DuplicateTaskDto, MoveTaskQueryDto, TaskDto, TaskRelationDto, TaskSearchDto,
+            TaskSearchPageDto, UpdateTaskDto,
+        },
         services::TaskService,
     },
+    presentation::http::etag::{etag, expected_version},
     shared::{
         error::{ApplicationError, ApplicationErrorSchema},
         response::{ApiResponse, ApiResponseSchema},
     },
 };
-use actix_web::{delete, get, post, put, web};
+use actix_web::{CustomizeResponder, HttpRequest, Responder, delete, get, post, put, web};
 use std::sync::Arc;
 use uuid::Uuid;
 
@@ -16,17 +21,24 @@ pub fn configure(cfg: &mut web::ServiceConfig) {
     cfg.service(
         web::scope("/task")
             .service(create_task)
+            .service(bulk_update_tasks)
             .service(get_task)
             .service(get_column_tasks)
+            .service(search_tasks)
             .service(update_task)
             .service(move_task)
-            .service(delete_task),
+            .service(duplicate_task)
+            .service(archive_task)
+            .service(unarchive_task)
+            .service(delete_task)
+            .service(create_task_relation)
+            .service(delete_task_relation),
     );
 }
 
 #[utoipa::path(
     post,
-    description = "***PROTECTED ENDPOINT***\n\nCreates a new task within a column. The task will be positioned at the end of the column. All board members can create tasks.",
+    description = "***PROTECTED ENDPOINT***\n\nCreates a new task within a column. The task will be positioned at the end of the column. Assignees must be members of the board, and custom field values are keyed by field ID and checked against the field's type. All board members can create tasks. Columns that have reached their WIP limit only accept new tasks from owners and moderators who set `overrideWipLimit`.",
     path = "/task/",
     request_body = CreateTaskDto,
     responses(
@@ -35,6 +47,7 @@ pub fn configure(cfg: &mut web::ServiceConfig) {
         (status = 401, description = "Unauthorized - No active session or session has expired", body = ApplicationErrorSchema),
         (status = 403, description = "Forbidden - User doesn't have access to this board", body = ApplicationErrorSchema),
         (status = 404, description = "Not Found - Column with the given ID not found", body = ApplicationErrorSchema),
+        (status = 409, description = "Conflict - Column has reached its WIP limit", body = ApplicationErrorSchema),
         (status = 500, description = "Internal Server Error - Failed to create task", body = ApplicationErrorSchema)
     ),
     tag = "Task",
@@ -57,15 +70,52 @@ async fn create_task(
     })
 }
 
+#[utoipa::path(
+    post,
+    description = "***PROTECTED ENDPOINT***\n\nApplies one operation (`move`, `archive`, `delete`, `addLabels`, `removeLabels` or `assign`) to up to 100 tasks at once. Labels are only added to tasks of the label's own board. The tasks may belong to several boards the user is a member of, except for `move`, which only moves tasks within their own board and appends them to the end of the column. All changes are applied in a single transaction: if any task can't be changed, none are. Each affected board receives a single `tasksBulkUpdated` event.",
+    path = "/task/bulk",
+    request_body = BulkTaskDto,
+    responses(
+        (status = 200, description = "OK - Tasks updated successfully", body = ApiResponseSchema<BulkTaskResultDto>),
+        (status = 400, description = "Bad Request - Invalid input data or operation not allowed for one of the tasks", body = ApplicationErrorSchema),
+        (status = 401, description = "Unauthorized - No active session or session has expired", body = ApplicationErrorSchema),
+        (status = 403, description = "Forbidden - User doesn't have access to one of the boards", body = ApplicationErrorSchema),
+        (status = 404, description = "Not Found - Task or column with the given ID not found", body = ApplicationErrorSchema),
+        (status = 409, description = "Conflict - Target column has reached its WIP limit or a task is still blocked by open tasks", body = ApplicationErrorSchema),
+        (status = 412, description = "Precondition Failed - One of the tasks was modified concurrently", body = ApplicationErrorSchema),
+        (status = 500, description = "Internal Server Error - Failed to update tasks", body = ApplicationErrorSchema)
+    ),
+    tag = "Task",
+    security(
+        ("session_cookie" = [])
+    )
+)]
+#[post("/bulk")]
+async fn bulk_update_tasks(
+    task_service: web::Data<Arc<TaskService>>,
+    dto: web::Json<BulkTaskDto>,
+    user_id: web::ReqData<Uuid>,
+) -> Result<ApiResponse<BulkTaskResultDto>, ApplicationError> {
+    let user_id = user_id.into_inner();
+    let result = task_service
+        .bulk_update_tasks(dto.into_inner(), user_id)
+        .await?;
+
+    Ok(ApiResponse::Updated {
+        message: "Tasks updated successfully".to_string(),
+        data: result,
+    })
+}
+
 #[utoipa::path(
     get,
-    description = "***PROTECTED ENDPOINT***\n\nRetrieves detailed information about a specific task by its ID. User must be a member of the board to access this endpoint.",
+    description = "***PROTECTED ENDPOINT***\n\nRetrieves detailed information about a specific task by its ID, including its relations to other tasks. User must be a member of the board to access this endpoint.",
     path = "/task/{taskId}",
     params(
         ("taskId" = Uuid, Path, description = "Unique identifier of the task")
     ),
     responses(
-        (status = 200, description = "OK - Task data retrieved successfully", body = ApiResponseSchema<TaskDto>),
+        (status = 200, description = "OK - Task data retrieved successfully", body = ApiResponseSchema<TaskDto>, headers(("ETag" = String, description = "Current version of the task"))),
         (status = 401, description = "Unauthorized - No active session or session has expired", body = ApplicationErrorSchema),
         (status = 403, description = "Forbidden - User doesn't have access to this board", body = ApplicationErrorSchema),
         (status = 404, description = "Not Found - Task with the given ID not found", body = ApplicationErrorSchema),
@@ -81,25 +131,29 @@ async fn get_task(
     task_service: web::Data<Arc<TaskService>>,
     task_id: web::Path<Uuid>,
     user_id: web::ReqData<Uuid>,
-) -> Result<ApiResponse<TaskDto>, ApplicationError> {
+) -> Result<CustomizeResponder<ApiResponse<TaskDto>>, ApplicationError> {
     let task_id = task_id.into_inner();
     let user_id = user_id.into_inner();
     let task = task_service.get_task_by_id(task_id, user_id).await?;
+    let etag = etag(task.version);
 
     Ok(ApiResponse::Found {
         message: "Task data retrieved successfully".to_string(),
         data: task,
         page: None,
         total_pages: None,
-    })
+    }
+    .customize()
+    .insert_header(etag))
 }
 
 #[utoipa::path(
     get,
-    description = "***PROTECTED ENDPOINT***\n\nRetrieves all tasks for a specific column, ordered by their position. User must be a member of the board to access this endpoint.",
+    description = "***PROTECTED ENDPOINT***\n\nRetrieves all tasks for a specific column, ordered by their position. Archived tasks are only included when requested. User must be a member of the board to access this endpoint.",
     path = "/task/column/{columnId}",
     params(
-        ("columnId" = Uuid, Path, description = "Unique identifier of the column")
+        ("columnId" = Uuid, Path, description = "Unique identifier of the column"),
+        ("includeArchived" = Option<bool>, Query, description = "Whether archived tasks should be included (default: false)")
     ),
     responses(
         (status = 200, description = "OK - Tasks retrieved successfully", body = ApiResponseSchema<Vec<TaskDto>>),
@@ -117,11 +171,14 @@ async fn get_task(
 async fn get_column_tasks(
     task_service: web::Data<Arc<TaskService>>,
     column_id: web::Path<Uuid>,
+    query: web::Query<ArchiveQueryDto>,
     user_id: web::ReqData<Uuid>,
 ) -> Result<ApiResponse<Vec<TaskDto>>, ApplicationError> {
     let column_id = column_id.into_inner();
     let user_id = user_id.into_inner();
-    let tasks = task_service.get_column_tasks(column_id, user_id).await?;
+    let tasks = task_service
+        .get_column_tasks(column_id, query.include_archived.unwrap_or(false), user_id)
+        .await?;
 
     Ok(ApiResponse::Found {
         message: "Tasks retrieved successfully".to_string(),
@@ -131,20 +188,63 @@ async fn get_column_tasks(
     })
 }
 
+#[utoipa::path(
+    post,
+    description = "***PROTECTED ENDPOINT***\n\nSearches the tasks of a board, newest first. Every given criterion must match: the text is looked up in titles and descriptions regardless of case, labels and assignees match when the task has any of them, and each custom field filter applies an operator to one field's value. `equals` works for all field types, `contains` for text fields, `gte` and `lte` for number and date fields, `isSet` and `isNotSet` for all. Pages are fetched by passing the returned `nextCursor` as `cursor`. User must be a member of the board to access this endpoint.",
+    path = "/task/board/{boardId}/search",
+    params(
+        ("boardId" = Uuid, Path, description = "Unique identifier of the board")
+    ),
+    request_body = TaskSearchDto,
+    responses(
+        (status = 200, description = "OK - Tasks retrieved successfully", body = ApiResponseSchema<TaskSearchPageDto>),
+        (status = 400, description = "Bad Request - Invalid input data or filter not supported by the custom field", body = ApplicationErrorSchema),
+        (status = 401, description = "Unauthorized - No active session or session has expired", body = ApplicationErrorSchema),
+        (status = 403, description = "Forbidden - User doesn't have access to this board", body = ApplicationErrorSchema),
+        (status = 500, description = "Internal Server Error - Failed to search tasks", body = ApplicationErrorSchema)
+    ),
+    tag = "Task",
+    security(
+        ("session_cookie" = [])
+    )
+)]
+#[post("/board/{boardId}/search")]
+async fn search_tasks(
+    task_service: web::Data<Arc<TaskService>>,
+    board_id: web::Path<Uuid>,
+    dto: web::Json<TaskSearchDto>,
+    user_id: web::ReqData<Uuid>,
+) -> Result<ApiResponse<TaskSearchPageDto>, ApplicationError> {
+    let board_id = board_id.into_inner();
+    let user_id = user_id.into_inner();
+    let page = task_service
+        .search_tasks(board_id, dto.into_inner(), user_id)
+        .await?;
+
+    Ok(ApiResponse::Found {
+        message: "Tasks retrieved successfully".to_string(),
+        data: page,
+        page: None,
+        total_pages: None,
+    })
+}
+
 #[utoipa::path(
     put,
-    description = "***PROTECTED ENDPOINT***\n\nUpdates task information. All board members can update tasks.",
+    description = "***PROTECTED ENDPOINT***\n\nUpdates task information. Providing assignees replaces the current assignment list, and every assignee must be a member of the board. Custom field values are merged into the current ones, where `null` clears a value. Sending `null` as the start date, due date or estimate clears it; the start date must be before the due date. All board members can update tasks.",
     path = "/task/{taskId}",
     params(
-        ("taskId" = Uuid, Path, description = "Unique identifier of the task")
+        ("taskId" = Uuid, Path, description = "Unique identifier of the task"),
+        ("If-Match" = Option<String>, Header, description = "ETag of the task the change is based on, the change is rejected when the task has been modified since")
     ),
     request_body = UpdateTaskDto,
     responses(
-        (status = 200, description = "OK - Task updated successfully", body = ApiResponseSchema<TaskDto>),
+        (status = 200, description = "OK - Task updated successfully", body = ApiResponseSchema<TaskDto>, headers(("ETag" = String, description = "Current version of the task"))),
         (status = 400, description = "Bad Request - Invalid input data", body = ApplicationErrorSchema),
         (status = 401, description = "Unauthorized - No active session or session has expired", body = ApplicationErrorSchema),
         (status = 403, description = "Forbidden - User doesn't have access to this board", body = ApplicationErrorSchema),
         (status = 404, description = "Not Found - Task with the given ID not found", body = ApplicationErrorSchema),
+        (status = 412, description = "Precondition Failed - Task has been modified since it was retrieved", body = ApplicationErrorSchema),
         (status = 500, description = "Internal Server Error - Failed to update task", body = ApplicationErrorSchema)
     ),
     tag = "Task",
@@ -158,33 +258,43 @@ async fn update_task(
     dto: web::Json<UpdateTaskDto>,
     task_id: web::Path<Uuid>,
     user_id: web::ReqData<Uuid>,
-) -> Result<ApiResponse<TaskDto>, ApplicationError> {
+    req: HttpRequest,
+) -> Result<CustomizeResponder<ApiResponse<TaskDto>>, ApplicationError> {
     let task_id = task_id.into_inner();
     let user_id = user_id.into_inner();
+    let expected_version = expected_version(&req)?;
     let task = task_service
-        .update_task(dto.into_inner(), task_id, user_id)
+        .update_task(dto.into_inner(), task_id, user_id, expected_version)
         .await?;
+    let etag = etag(task.version);
 
     Ok(ApiResponse::Updated {
         message: "Task updated successfully".to_string(),
         data: task,
-    })
+    }
+    .customize()
+    .insert_header(etag))
 }
 
 #[utoipa::path(
     put,
-    description = "***PROTECTED ENDPOINT***\n\nMoves a task to a new position within the same column or to a different column. Position is 0-indexed. All board members can move tasks.",
+    description = "***PROTECTED ENDPOINT***\n\nMoves a task to a new position within the same column or to a different column. Position is 0-indexed. All board members can move tasks.\n\nThe target column may belong to another board when the user is a member of both boards. The task then leaves the source board with a `taskRemoved` event and appears on the target board with a `taskCreated` event; assignees who aren't members of the target board are unassigned, all of its task relations are removed and its labels are matched by name on the target board, which creates the ones it doesn't have yet. Custom field values are dropped, as custom fields belong to a single board.\n\nA task cannot be moved into a done column while a task blocking it is still open.",
     path = "/task/{taskId}/move/{columnId}/{position}",
     params(
         ("taskId" = Uuid, Path, description = "Unique identifier of the task"),
         ("columnId" = Uuid, Path, description = "Unique identifier of the column"),
-        ("position" = usize, Path, description = "New position index for the column (0-based)")
+        ("position" = usize, Path, description = "New position index for the column (0-based)"),
+        ("overrideWipLimit" = Option<bool>, Query, description = "Lets owners and moderators move the task into a column that has reached its WIP limit (default: false)"),
+        ("If-Match" = Option<String>, Header, description = "ETag of the task the change is based on, the change is rejected when the task has been modified since")
     ),
     responses(
-        (status = 200, description = "OK - Task moved successfully", body = ApiResponseSchema<TaskDto>),
+        (status = 200, description = "OK - Task moved successfully", body = ApiResponseSchema<TaskDto>, headers(("ETag" = String, description = "Current version of the task"))),
         (status = 401, description = "Unauthorized - No active session or session has expired", body = ApplicationErrorSchema),
         (status = 403, description = "Forbidden - User doesn't have access to this board", body = ApplicationErrorSchema),
         (status = 404, description = "Not Found - Task or column with the given ID not found", body = ApplicationErrorSchema),
+        (status = 409, description = "Conflict - Target column has reached its WIP limit or the task is still blocked by open tasks", body = ApplicationErrorSchema),
+        (status = 412, description = "Precondition Failed - Task has been modified since it was retrieved", body = ApplicationErrorSchema),
+        (status = 413, description = "Payload Too Large - Task attachments would exceed the target board's storage quota", body = ApplicationErrorSchema),
         (status = 500, description = "Internal Server Error - Failed to move task", body = ApplicationErrorSchema)
     ),
     tag = "Task",
@@ -196,20 +306,153 @@ async fn update_task(
 async fn move_task(
     task_service: web::Data<Arc<TaskService>>,
     path: web::Path<(Uuid, Uuid, usize)>,
+    query: web::Query<MoveTaskQueryDto>,
     user_id: web::ReqData<Uuid>,
-) -> Result<ApiResponse<TaskDto>, ApplicationError> {
+    req: HttpRequest,
+) -> Result<CustomizeResponder<ApiResponse<TaskDto>>, ApplicationError> {
     let (task_id, column_id, position) = path.into_inner();
     let user_id = user_id.into_inner();
+    let expected_version = expected_version(&req)?;
     let task = task_service
-        .move_task(position, task_id, column_id, user_id)
+        .move_task(
+            position,
+            task_id,
+            column_id,
+            user_id,
+            expected_version,
+            query.override_wip_limit.unwrap_or(false),
+        )
         .await?;
+    let etag = etag(task.version);
 
     Ok(ApiResponse::Updated {
         message: "Task moved successfully".to_string(),
         data: task,
+    }
+    .customize()
+    .insert_header(etag))
+}
+
+#[utoipa::path(
+    post,
+    description = "***PROTECTED ENDPOINT***\n\nCreates a copy of a task in the given column, which may belong to another board the user is a member of. The copy keeps the title, description and labels, which are matched by name on another board and created there when missing, and is placed at the given 0-based position, or at the end of the column when no position is given. Custom field values are only kept within the same board. Comments, attachments and checklists are copied on request; copied checklist items start out open.",
+    path = "/task/{taskId}/duplicate",
+    params(
+        ("taskId" = Uuid, Path, description = "Unique identifier of the task to copy")
+    ),
+    request_body = DuplicateTaskDto,
+    responses(
+        (status = 201, description = "Created - Task duplicated successfully", body = ApiResponseSchema<TaskDto>),
+        (status = 400, description = "Bad Request - Invalid input data or target column is archived", body = ApplicationErrorSchema),
+        (status = 401, description = "Unauthorized - No active session or session has expired", body = ApplicationErrorSchema),
+        (status = 403, description = "Forbidden - User doesn't have access to the source or target board", body = ApplicationErrorSchema),
+        (status = 404, description = "Not Found - Task or column with the given ID not found", body = ApplicationErrorSchema),
+        (status = 409, description = "Conflict - Target column has reached its WIP limit", body = ApplicationErrorSchema),
+        (status = 413, description = "Payload Too Large - Copied attachments would exceed the target board's storage quota", body = ApplicationErrorSchema),
+        (status = 500, description = "Internal Server Error - Failed to duplicate task", body = ApplicationErrorSchema)
+    ),
+    tag = "Task",
+    security(
+        ("session_cookie" = [])
+    )
+)]
+#[post("/{taskId}/duplicate")]
+async fn duplicate_task(
+    task_service: web::Data<Arc<TaskService>>,
+    task_id: web::Path<Uuid>,
+    dto: web::Json<DuplicateTaskDto>,
+    user_id: web::ReqData<Uuid>,
+) -> Result<ApiResponse<TaskDto>, ApplicationError> {
+    let task_id = task_id.into_inner();
+    let user_id = user_id.into_inner();
+    let task = task_service
+        .duplicate_task(task_id, dto.into_inner(), user_id)
+        .await?;
+
+    Ok(ApiResponse::Created {
+        message: "Task duplicated successfully".to_string(),
+        data: task,
     })
 }
 
+#[utoipa::path(
+    put,
+    description = "***PROTECTED ENDPOINT***\n\nArchives a task. Archived tasks are hidden from listings unless explicitly requested and can be restored later. All board members can archive or restore tasks.",
+    path = "/task/{taskId}/archive",
+    params(
+        ("taskId" = Uuid, Path, description = "Unique identifier of the task")
+    ),
+    responses(
+        (status = 200, description = "OK - Task archived successfully", body = ApiResponseSchema<TaskDto>, headers(("ETag" = String, description = "Current version of the task"))),
+        (status = 401, description = "Unauthorized - No active session or session has expired", body = ApplicationErrorSchema),
+        (status = 403, description = "Forbidden - User doesn't have access to this board", body = ApplicationErrorSchema),
+        (status = 404, description = "Not Found - Task with the given ID not found", body = ApplicationErrorSchema),
+        (status = 409, description = "Conflict - Task is already archived", body = ApplicationErrorSchema),
+        (status = 500, description = "Internal Server Error - Failed to archive task", body = ApplicationErrorSchema)
+    ),
+    tag = "Task",
+    security(
+        ("session_cookie" = [])
+    )
+)]
+#[put("/{taskId}/archive")]
+async fn archive_task(
+    task_service: web::Data<Arc<TaskService>>,
+    task_id: web::Path<Uuid>,
+    user_id: web::ReqData<Uuid>,
+) -> Result<CustomizeResponder<ApiResponse<TaskDto>>, ApplicationError> {
+    let task_id = task_id.into_inner();
+    let user_id = user_id.into_inner();
+    let task = task_service.archive_task(task_id, user_id).await?;
+    let etag = etag(task.version);
+
+    Ok(ApiResponse::Updated {
+        message: "Task archived successfully".to_string(),
+        data: task,
+    }
+    .customize()
+    .insert_header(etag))
+}
+
+#[utoipa::path(
+    put,
+    description = "***PROTECTED ENDPOINT***\n\nRestores an archived task so that it appears in listings again. All board members can archive or restore tasks.",
+    path = "/task/{taskId}/unarchive",
+    params(
+        ("taskId" = Uuid, Path, description = "Unique identifier of the task")
+    ),
+    responses(
+        (status = 200, description = "OK - Task restored successfully", body = ApiResponseSchema<TaskDto>, headers(("ETag" = String, description = "Current version of the task"))),
+        (status = 401, description = "Unauthorized - No active session or session has expired", body = ApplicationErrorSchema),
+        (status = 403, description = "Forbidden - User doesn't have access to this board", body = ApplicationErrorSchema),
+        (status = 404, description = "Not Found - Task with the given ID not found", body = ApplicationErrorSchema),
+        (status = 409, description = "Conflict - Task is not archived", body = ApplicationErrorSchema),
+        (status = 500, description = "Internal Server Error - Failed to unarchive task", body = ApplicationErrorSchema)
+    ),
+    tag = "Task",
+    security(
+        ("session_cookie" = [])
+    )
+)]
+#[put("/{taskId}/unarchive")]
+async fn unarchive_task(
+    task_service: web::Data<Arc<TaskService>>,
+    task_id: web::Path<Uuid>,
+    user_id: web::ReqData<Uuid>,
+) -> Result<CustomizeResponder<ApiResponse<TaskDto>>, ApplicationError> {
+    let task_id = task_id.into_inner();
+    let user_id = user_id.into_inner();
+    let task = task_service.unarchive_task(task_id, user_id).await?;
+    let etag = etag(task.version);
+
+    Ok(ApiResponse::Updated {
+        message: "Task restored successfully".to_string(),
+        data: task,
+    }
+    .customize()
+    .insert_header(etag))
+}
+
 #[utoipa::path(
     delete,
     description = "***PROTECTED ENDPOINT***\n\nPermanently deletes a task. This action cannot be undone. All board members can delete tasks.",
@@ -244,3 +487,82 @@ async fn delete_task(
         rows_affected,
     })
 }
+
+#[utoipa::path(
+    post,
+    description = "***PROTECTED ENDPOINT***\n\nLinks the task to another task of the same board. The relation type is read from the task in the path: `blocks`, `blockedBy`, `duplicates`, `duplicatedBy` or `relatesTo`. Blocking relations may not form a cycle. All board members can relate tasks.",
+    path = "/task/{taskId}/relation",
+    params(
+        ("taskId" = Uuid, Path, description = "Unique identifier of the task")
+    ),
+    request_body = CreateTaskRelationDto,
+    responses(
+        (status = 201, description = "Created - Relation created successfully", body = ApiResponseSchema<TaskRelationDto>),
+        (status = 400, description = "Bad Request - Invalid input data or the tasks are not on the same board", body = ApplicationErrorSchema),
+        (status = 401, description = "Unauthorized - No active session or session has expired", body = ApplicationErrorSchema),
+        (status = 403, description = "Forbidden - User doesn't have access to this board", body = ApplicationErrorSchema),
+        (status = 404, description = "Not Found - Task with the given ID not found", body = ApplicationErrorSchema),
+        (status = 409, description = "Conflict - The tasks are already related this way or the relation would create a blocking cycle", body = ApplicationErrorSchema),
+        (status = 500, description = "Internal Server Error - Failed to create relation", body = ApplicationErrorSchema)
+    ),
+    tag = "Task",
+    security(
+        ("session_cookie" = [])
+    )
+)]
+#[post("/{taskId}/relation")]
+async fn create_task_relation(
+    task_service: web::Data<Arc<TaskService>>,
+    task_id: web::Path<Uuid>,
+    dto: web::Json<CreateTaskRelationDto>,
+    user_id: web::ReqData<Uuid>,
+) -> Result<ApiResponse<TaskRelationDto>, ApplicationError> {
+    let task_id = task_id.into_inner();
+    let user_id = user_id.into_inner();
+    let relation = task_service
+        .create_task_relation(task_id, dto.into_inner(), user_id)
+        .await?;
+
+    Ok(ApiResponse::Created {
+        message: "Relation created successfully".to_string(),
+        data: relation,
+    })
+}
+
+#[utoipa::path(
+    delete,
+    description = "***PROTECTED ENDPOINT***\n\nRemoves a relation the task takes part in. All board members can remove relations.",
+    path = "/task/{taskId}/relation/{relationId}",
+    params(
+        ("taskId" = Uuid, Path, description = "Unique identifier of the task"),
+        ("relationId" = Uuid, Path, description = "Unique identifier of the relation")
+    ),
+    responses(
+        (status = 200, description = "OK - Relation deleted successfully", body = ApiResponseSchema<u64>),
+        (status = 401, description = "Unauthorized - No active session or session has expired", body = ApplicationErrorSchema),
+        (status = 403, description = "Forbidden - User doesn't have access to this board", body = ApplicationErrorSchema),
+        (status = 404, description = "Not Found - Task or relation with the given ID not found", body = ApplicationErrorSchema),
+        (status = 500, description = "Internal Server Error - Failed to delete relation", body = ApplicationErrorSchema)
+    ),
+    tag = "Task",
+    security(
+        ("session_cookie" = [])
+    )
+)]
+#[delete("/{taskId}/relation/{relationId}")]
+async fn delete_task_relation(
+    task_service: web::Data<Arc<TaskService>>,
+    path: web::Path<(Uuid, Uuid)>,
+    user_id: web::ReqData<Uuid>,
+) -> Result<ApiResponse<u64>, ApplicationError> {
+    let (task_id, relation_id) = path.into_inner();
+    let user_id = user_id.into_inner();
+    let rows_affected = task_service
+        .delete_task_relation(task_id, relation_id, user_id)
+        .await?;
+
+    Ok(ApiResponse::Deleted {
+        message: "Relation deleted successfully".to_string(),
+        rows_affected,
+    })
+}
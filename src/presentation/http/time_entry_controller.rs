@@ -0,0 +1,332 @@
+use crate::{
+    application::{
+        dto::{
+            CreateTimeEntryDto, StartTimerDto, TimeEntryDto, TimeReportDto, TimeReportQueryDto,
+            UpdateTimeEntryDto,
+        },
+        services::TimeEntryService,
+    },
+    shared::{
+        error::{ApplicationError, ApplicationErrorSchema},
+        response::{ApiResponse, ApiResponseSchema},
+    },
+};
+use actix_web::{delete, get, post, put, web};
+use std::sync::Arc;
+use uuid::Uuid;
+
+pub fn configure(cfg: &mut web::ServiceConfig) {
+    cfg.service(
+        web::scope("/time-entry")
+            .service(log_time_entry)
+            .service(stop_timer)
+            .service(start_timer)
+            .service(get_task_time_entries)
+            .service(get_running_timers)
+            .service(get_time_report)
+            .service(update_time_entry)
+            .service(delete_time_entry),
+    );
+}
+
+#[utoipa::path(
+    post,
+    description = "***PROTECTED ENDPOINT***\n\nLogs time spent on a task earlier. Either the end or the duration of the entry must be given; entries can't end in the future or span more than 24 hours. User must be a member of the board the task belongs to.",
+    path = "/time-entry/",
+    request_body = CreateTimeEntryDto,
+    responses(
+        (status = 201, description = "Created - Time entry logged successfully", body = ApiResponseSchema<TimeEntryDto>),
+        (status = 400, description = "Bad Request - Invalid input data or the task is archived", body = ApplicationErrorSchema),
+        (status = 401, description = "Unauthorized - No active session or session has expired", body = ApplicationErrorSchema),
+        (status = 403, description = "Forbidden - User doesn't have access to this board", body = ApplicationErrorSchema),
+        (status = 404, description = "Not Found - Task with the given ID not found", body = ApplicationErrorSchema),
+        (status = 500, description = "Internal Server Error - Failed to log time entry", body = ApplicationErrorSchema)
+    ),
+    tag = "Time Entry",
+    security(
+        ("session_cookie" = [])
+    )
+)]
+#[post("/")]
+async fn log_time_entry(
+    time_entry_service: web::Data<Arc<TimeEntryService>>,
+    dto: web::Json<CreateTimeEntryDto>,
+    user_id: web::ReqData<Uuid>,
+) -> Result<ApiResponse<TimeEntryDto>, ApplicationError> {
+    let user_id = user_id.into_inner();
+    let entry = time_entry_service
+        .log_time_entry(dto.into_inner(), user_id)
+        .await?;
+
+    Ok(ApiResponse::Created {
+        message: "Time entry logged successfully".to_string(),
+        data: entry,
+    })
+}
+
+#[utoipa::path(
+    post,
+    description = "***PROTECTED ENDPOINT***\n\nStarts a timer on a task. Each user can only have one running timer at a time; other board members are notified so they can see who is working on what.",
+    path = "/time-entry/task/{taskId}/start",
+    params(
+        ("taskId" = Uuid, Path, description = "Unique identifier of the task")
+    ),
+    request_body = StartTimerDto,
+    responses(
+        (status = 201, description = "Created - Timer started successfully", body = ApiResponseSchema<TimeEntryDto>),
+        (status = 400, description = "Bad Request - Invalid input data or the task is archived", body = ApplicationErrorSchema),
+        (status = 401, description = "Unauthorized - No active session or session has expired", body = ApplicationErrorSchema),
+        (status = 403, description = "Forbidden - User doesn't have access to this board", body = ApplicationErrorSchema),
+        (status = 404, description = "Not Found - Task with the given ID not found", body = ApplicationErrorSchema),
+        (status = 409, description = "Conflict - User already has a running timer", body = ApplicationErrorSchema),
+        (status = 500, description = "Internal Server Error - Failed to start timer", body = ApplicationErrorSchema)
+    ),
+    tag = "Time Entry",
+    security(
+        ("session_cookie" = [])
+    )
+)]
+#[post("/task/{taskId}/start")]
+async fn start_timer(
+    time_entry_service: web::Data<Arc<TimeEntryService>>,
+    task_id: web::Path<Uuid>,
+    dto: web::Json<StartTimerDto>,
+    user_id: web::ReqData<Uuid>,
+) -> Result<ApiResponse<TimeEntryDto>, ApplicationError> {
+    let task_id = task_id.into_inner();
+    let user_id = user_id.into_inner();
+    let entry = time_entry_service
+        .start_timer(task_id, dto.into_inner(), user_id)
+        .await?;
+
+    Ok(ApiResponse::Created {
+        message: "Timer started successfully".to_string(),
+        data: entry,
+    })
+}
+
+#[utoipa::path(
+    post,
+    description = "***PROTECTED ENDPOINT***\n\nStops the running timer of the current user, turning it into a time entry.",
+    path = "/time-entry/stop",
+    responses(
+        (status = 200, description = "OK - Timer stopped successfully", body = ApiResponseSchema<TimeEntryDto>),
+        (status = 401, description = "Unauthorized - No active session or session has expired", body = ApplicationErrorSchema),
+        (status = 404, description = "Not Found - User doesn't have a running timer", body = ApplicationErrorSchema),
+        (status = 500, description = "Internal Server Error - Failed to stop timer", body = ApplicationErrorSchema)
+    ),
+    tag = "Time Entry",
+    security(
+        ("session_cookie" = [])
+    )
+)]
+#[post("/stop")]
+async fn stop_timer(
+    time_entry_service: web::Data<Arc<TimeEntryService>>,
+    user_id: web::ReqData<Uuid>,
+) -> Result<ApiResponse<TimeEntryDto>, ApplicationError> {
+    let user_id = user_id.into_inner();
+    let entry = time_entry_service.stop_timer(user_id).await?;
+
+    Ok(ApiResponse::Updated {
+        message: "Timer stopped successfully".to_string(),
+        data: entry,
+    })
+}
+
+#[utoipa::path(
+    get,
+    description = "***PROTECTED ENDPOINT***\n\nRetrieves all time entries of a specific task, newest first, including running timers. User must be a member of the board the task belongs to.",
+    path = "/time-entry/task/{taskId}",
+    params(
+        ("taskId" = Uuid, Path, description = "Unique identifier of the task")
+    ),
+    responses(
+        (status = 200, description = "OK - Time entries retrieved successfully", body = ApiResponseSchema<Vec<TimeEntryDto>>),
+        (status = 401, description = "Unauthorized - No active session or session has expired", body = ApplicationErrorSchema),
+        (status = 403, description = "Forbidden - User doesn't have access to this board", body = ApplicationErrorSchema),
+        (status = 404, description = "Not Found - Task with the given ID not found", body = ApplicationErrorSchema),
+        (status = 500, description = "Internal Server Error - Failed to retrieve time entries", body = ApplicationErrorSchema)
+    ),
+    tag = "Time Entry",
+    security(
+        ("session_cookie" = [])
+    )
+)]
+#[get("/task/{taskId}")]
+async fn get_task_time_entries(
+    time_entry_service: web::Data<Arc<TimeEntryService>>,
+    task_id: web::Path<Uuid>,
+    user_id: web::ReqData<Uuid>,
+) -> Result<ApiResponse<Vec<TimeEntryDto>>, ApplicationError> {
+    let task_id = task_id.into_inner();
+    let user_id = user_id.into_inner();
+    let entries = time_entry_service
+        .get_task_time_entries(task_id, user_id)
+        .await?;
+
+    Ok(ApiResponse::Found {
+        message: "Time entries retrieved successfully".to_string(),
+        data: entries,
+        page: None,
+        total_pages: None,
+    })
+}
+
+#[utoipa::path(
+    get,
+    description = "***PROTECTED ENDPOINT***\n\nRetrieves the running timers of all members on a specific board. User must be a member of the board to access this endpoint.",
+    path = "/time-entry/board/{boardId}/running",
+    params(
+        ("boardId" = Uuid, Path, description = "Unique identifier of the board")
+    ),
+    responses(
+        (status = 200, description = "OK - Running timers retrieved successfully", body = ApiResponseSchema<Vec<TimeEntryDto>>),
+        (status = 401, description = "Unauthorized - No active session or session has expired", body = ApplicationErrorSchema),
+        (status = 403, description = "Forbidden - User doesn't have access to this board", body = ApplicationErrorSchema),
+        (status = 500, description = "Internal Server Error - Failed to retrieve running timers", body = ApplicationErrorSchema)
+    ),
+    tag = "Time Entry",
+    security(
+        ("session_cookie" = [])
+    )
+)]
+#[get("/board/{boardId}/running")]
+async fn get_running_timers(
+    time_entry_service: web::Data<Arc<TimeEntryService>>,
+    board_id: web::Path<Uuid>,
+    user_id: web::ReqData<Uuid>,
+) -> Result<ApiResponse<Vec<TimeEntryDto>>, ApplicationError> {
+    let board_id = board_id.into_inner();
+    let user_id = user_id.into_inner();
+    let entries = time_entry_service
+        .get_running_timers(board_id, user_id)
+        .await?;
+
+    Ok(ApiResponse::Found {
+        message: "Running timers retrieved successfully".to_string(),
+        data: entries,
+        page: None,
+        total_pages: None,
+    })
+}
+
+#[utoipa::path(
+    get,
+    description = "***PROTECTED ENDPOINT***\n\nRetrieves the time logged on a board within a date range, totalled per user, per task and per day. Days follow UTC and running timers aren't counted. User must be a member of the board to access this endpoint.",
+    path = "/time-entry/board/{boardId}/report",
+    params(
+        ("boardId" = Uuid, Path, description = "Unique identifier of the board"),
+        ("userId" = Option<Uuid>, Query, description = "Only include time logged by this user"),
+        ("from" = Option<String>, Query, description = "First day of the range (YYYY-MM-DD, default: 29 days before `to`)"),
+        ("to" = Option<String>, Query, description = "Last day of the range (YYYY-MM-DD, default: today); the range can't span more than 366 days")
+    ),
+    responses(
+        (status = 200, description = "OK - Time report retrieved successfully", body = ApiResponseSchema<TimeReportDto>),
+        (status = 400, description = "Bad Request - Invalid date range", body = ApplicationErrorSchema),
+        (status = 401, description = "Unauthorized - No active session or session has expired", body = ApplicationErrorSchema),
+        (status = 403, description = "Forbidden - User doesn't have access to this board", body = ApplicationErrorSchema),
+        (status = 500, description = "Internal Server Error - Failed to retrieve time report", body = ApplicationErrorSchema)
+    ),
+    tag = "Time Entry",
+    security(
+        ("session_cookie" = [])
+    )
+)]
+#[get("/board/{boardId}/report")]
+async fn get_time_report(
+    time_entry_service: web::Data<Arc<TimeEntryService>>,
+    board_id: web::Path<Uuid>,
+    query: web::Query<TimeReportQueryDto>,
+    user_id: web::ReqData<Uuid>,
+) -> Result<ApiResponse<TimeReportDto>, ApplicationError> {
+    let board_id = board_id.into_inner();
+    let user_id = user_id.into_inner();
+    let report = time_entry_service
+        .get_time_report(board_id, query.into_inner(), user_id)
+        .await?;
+
+    Ok(ApiResponse::Found {
+        message: "Time report retrieved successfully".to_string(),
+        data: report,
+        page: None,
+        total_pages: None,
+    })
+}
+
+#[utoipa::path(
+    put,
+    description = "***PROTECTED ENDPOINT***\n\nUpdates a time entry. The end of a running timer can only be set by stopping it. Only the author of the entry and board owners or moderators can update it.",
+    path = "/time-entry/{entryId}",
+    params(
+        ("entryId" = Uuid, Path, description = "Unique identifier of the time entry")
+    ),
+    request_body = UpdateTimeEntryDto,
+    responses(
+        (status = 200, description = "OK - Time entry updated successfully", body = ApiResponseSchema<TimeEntryDto>),
+        (status = 400, description = "Bad Request - Invalid input data", body = ApplicationErrorSchema),
+        (status = 401, description = "Unauthorized - No active session or session has expired", body = ApplicationErrorSchema),
+        (status = 403, description = "Forbidden - User doesn't have permission to update this time entry", body = ApplicationErrorSchema),
+        (status = 404, description = "Not Found - Time entry with the given ID not found", body = ApplicationErrorSchema),
+        (status = 500, description = "Internal Server Error - Failed to update time entry", body = ApplicationErrorSchema)
+    ),
+    tag = "Time Entry",
+    security(
+        ("session_cookie" = [])
+    )
+)]
+#[put("/{entryId}")]
+async fn update_time_entry(
+    time_entry_service: web::Data<Arc<TimeEntryService>>,
+    entry_id: web::Path<Uuid>,
+    dto: web::Json<UpdateTimeEntryDto>,
+    user_id: web::ReqData<Uuid>,
+) -> Result<ApiResponse<TimeEntryDto>, ApplicationError> {
+    let entry_id = entry_id.into_inner();
+    let user_id = user_id.into_inner();
+    let entry = time_entry_service
+        .update_time_entry(entry_id, dto.into_inner(), user_id)
+        .await?;
+
+    Ok(ApiResponse::Updated {
+        message: "Time entry updated successfully".to_string(),
+        data: entry,
+    })
+}
+
+#[utoipa::path(
+    delete,
+    description = "***PROTECTED ENDPOINT***\n\nDeletes a time entry, including running timers. Only the author of the entry and board owners or moderators can delete it.",
+    path = "/time-entry/{entryId}",
+    params(
+        ("entryId" = Uuid, Path, description = "Unique identifier of the time entry")
+    ),
+    responses(
+        (status = 200, description = "OK - Time entry deleted successfully", body = ApiResponseSchema<u64>),
+        (status = 401, description = "Unauthorized - No active session or session has expired", body = ApplicationErrorSchema),
+        (status = 403, description = "Forbidden - User doesn't have permission to delete this time entry", body = ApplicationErrorSchema),
+        (status = 404, description = "Not Found - Time entry with the given ID not found", body = ApplicationErrorSchema),
+        (status = 500, description = "Internal Server Error - Failed to delete time entry", body = ApplicationErrorSchema)
+    ),
+    tag = "Time Entry",
+    security(
+        ("session_cookie" = [])
+    )
+)]
+#[delete("/{entryId}")]
+async fn delete_time_entry(
+    time_entry_service: web::Data<Arc<TimeEntryService>>,
+    entry_id: web::Path<Uuid>,
+    user_id: web::ReqData<Uuid>,
+) -> Result<ApiResponse<u64>, ApplicationError> {
+    let entry_id = entry_id.into_inner();
+    let user_id = user_id.into_inner();
+    let rows_affected = time_entry_service
+        .delete_time_entry(entry_id, user_id)
+        .await?;
+
+    Ok(ApiResponse::Deleted {
+        message: "Time entry deleted successfully".to_string(),
+        rows_affected,
+    })
+}
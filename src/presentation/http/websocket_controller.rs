@@ -1,5 +1,5 @@
 use crate::{
-    application::services::WebSocketService,
+    application::{dto::WebSocketQueryDto, services::WebSocketService},
     shared::error::{ApplicationError, ApplicationErrorSchema},
 };
 use actix_web::{
@@ -16,10 +16,11 @@ pub fn configure(cfg: &mut web::ServiceConfig) {
 
 #[utoipa::path(
     get,
-    description = "***PROTECTED ENDPOINT***\n\nEstablishes a WebSocket connection for real-time board updates. The connection enables bidirectional communication for live collaboration features such as task updates, column changes, and member activities. User must be a member of the board to establish the connection.",
+    description = "***PROTECTED ENDPOINT***\n\nEstablishes a WebSocket connection for real-time board updates. The connection enables bidirectional communication for live collaboration features such as task updates, column changes, and member activities. User must be a member of the board to establish the connection.\n\nEvery event is wrapped in an envelope carrying its `id`, `boardId` and a per-board, monotonically increasing `sequence`. Clients that reconnect pass the last sequence they processed as `since` to have missed events replayed before live streaming resumes. If too many events were missed, a `resyncRequired` message with the `latestSequence` is sent instead and the client should reload the board state.",
     path = "/ws/board/{boardId}",
     params(
-        ("boardId" = Uuid, Path, description = "Unique identifier of the board")
+        ("boardId" = Uuid, Path, description = "Unique identifier of the board"),
+        ("since" = Option<i64>, Query, description = "Sequence number of the last event received; later events are replayed first")
     ),
     responses(
         (status = 101, description = "Switching Protocols - WebSocket connection established successfully"),
@@ -39,9 +40,11 @@ async fn websocket_handler(
     req: HttpRequest,
     stream: Payload,
     board_id: web::Path<Uuid>,
+    query: web::Query<WebSocketQueryDto>,
     user_id: web::ReqData<Uuid>,
 ) -> Result<impl Responder, ApplicationError> {
     let board_id = board_id.into_inner();
+    let since = query.into_inner().since;
     let user_id = user_id.into_inner();
 
     websocket_service
@@ -57,7 +60,7 @@ async fn websocket_handler(
 
     actix_web::rt::spawn(async move {
         websocket_service
-            .handle_connection(board_id, user_id, session, msg_stream)
+            .handle_connection(board_id, user_id, since, session, msg_stream)
             .await;
     });
 
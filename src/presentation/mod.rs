@@ -1,9 +1,15 @@
 pub mod http;
 pub mod middleware;
 
+pub use http::configure_attachment_routes;
 pub use http::configure_auth_roures;
 pub use http::configure_board_routes;
+pub use http::configure_checklist_routes;
 pub use http::configure_column_routes;
+pub use http::configure_comment_routes;
+pub use http::configure_custom_field_routes;
+pub use http::configure_label_routes;
 pub use http::configure_task_routes;
+pub use http::configure_time_entry_routes;
 pub use http::configure_user_routes;
 pub use http::configure_websocket_routes;
@@ -1,5 +1,7 @@
 use crate::application::services::{
-    AuthService, BoardService, ColumnService, TaskService, UserService, WebSocketService,
+    AttachmentService, AuthService, BoardActivityService, BoardAnalyticsService, BoardService,
+    BoardSyncService, ChecklistService, ColumnService, CommentService, CustomFieldService,
+    LabelService, TaskService, TimeEntryService, UserService, WebSocketService,
 };
 use std::sync::Arc;
 
@@ -10,25 +12,14 @@ pub struct AppState {
     pub board_service: Arc<BoardService>,
     pub column_service: Arc<ColumnService>,
     pub task_service: Arc<TaskService>,
+    pub comment_service: Arc<CommentService>,
+    pub checklist_service: Arc<ChecklistService>,
+    pub label_service: Arc<LabelService>,
+    pub custom_field_service: Arc<CustomFieldService>,
+    pub time_entry_service: Arc<TimeEntryService>,
+    pub attachment_service: Arc<AttachmentService>,
+    pub board_activity_service: Arc<BoardActivityService>,
+    pub board_analytics_service: Arc<BoardAnalyticsService>,
+    pub board_sync_service: Arc<BoardSyncService>,
     pub websocket_service: Arc<WebSocketService>,
 }
-
-impl AppState {
-    pub fn new(
-        auth_service: Arc<AuthService>,
-        user_service: Arc<UserService>,
-        board_service: Arc<BoardService>,
-        column_service: Arc<ColumnService>,
-        task_service: Arc<TaskService>,
-        websocket_service: Arc<WebSocketService>,
-    ) -> Self {
-        Self {
-            auth_service,
-            user_service,
-            board_service,
-            column_service,
-            task_service,
-            websocket_service,
-        }
-    }
-}
@@ -3,5 +3,6 @@ pub mod startup;
 
 pub use app_state::AppState;
 pub use startup::{
-    initialize_event_bus, initialize_infrastructure, initialize_repositories, initialize_services,
+    initialize_event_bus, initialize_infrastructure, initialize_outbox_dispatcher,
+    initialize_repositories, initialize_services, initialize_storage,
 };
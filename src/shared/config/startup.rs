@@ -1,29 +1,41 @@
 use crate::{
     application::services::{
-        AuthService, BoardService, ColumnService, TaskService, UserService, WebSocketService,
+        AttachmentService, AuthService, BoardActivityService, BoardAnalyticsService, BoardService,
+        BoardSyncService, ChecklistService, ColumnService, CommentService, CustomFieldService,
+        LabelService, TaskService, TimeEntryService, UserService, WebSocketService,
     },
     domain::{
         events::SharedEventBus,
         repositories::{
-            BoardMemberRepository, BoardRepository, ColumnRepository, TaskRepository,
-            UserRepository,
+            AttachmentRepository, BoardActivityRepository, BoardMemberRepository, BoardRepository,
+            ChecklistRepository, ColumnRepository, CommentRepository, CustomFieldRepository,
+            LabelRepository, OutboxRepository, TaskRepository, TimeEntryRepository,
+            UnitOfWorkFactory, UserRepository,
         },
-        services::{EmailService, TokenService},
+        services::{EmailService, StorageService, TokenService},
     },
     infrastructure::{
         cache::RedisTokenService,
         email::SmtpEmailService,
-        event_bus::InMemoryEventBus,
+        event_bus::{InMemoryEventBus, OutboxDispatcher, PostgresEventBus, RedisEventBus},
         persistence::{
-            SeaOrmBoardMemberRepository, SeaOrmBoardRepository, SeaOrmColumnRepository,
-            SeaOrmTaskRepository, SeaOrmUserRepository, database,
+            SeaOrmAttachmentRepository, SeaOrmBoardActivityRepository, SeaOrmBoardMemberRepository,
+            SeaOrmBoardRepository, SeaOrmChecklistRepository, SeaOrmColumnRepository,
+            SeaOrmCommentRepository, SeaOrmCustomFieldRepository, SeaOrmLabelRepository,
+            SeaOrmOutboxRepository, SeaOrmTaskRepository, SeaOrmTimeEntryRepository,
+            SeaOrmUnitOfWorkFactory, SeaOrmUserRepository, database,
         },
+        storage::{LocalStorageService, S3StorageService},
+    },
+    shared::{
+        config::AppState,
+        utils::constants::{EVENT_BUS_BACKEND, REDIS_URL, STORAGE_BACKEND, STORAGE_LOCAL_PATH},
     },
-    shared::{config::AppState, utils::constants::REDIS_URL},
 };
 use redis::Client as RedisClient;
 use sea_orm::DatabaseConnection;
 use std::sync::Arc;
+use tokio::sync::Notify;
 use tracing::info;
 
 pub async fn initialize_infrastructure()
@@ -43,13 +55,23 @@ pub async fn initialize_infrastructure()
     Ok((database, redis_client))
 }
 
-type Repositories = (
-    Arc<dyn UserRepository>,
-    Arc<dyn BoardRepository>,
-    Arc<dyn BoardMemberRepository>,
-    Arc<dyn ColumnRepository>,
-    Arc<dyn TaskRepository>,
-);
+pub struct Repositories {
+    pub user_repository: Arc<dyn UserRepository>,
+    pub board_repository: Arc<dyn BoardRepository>,
+    pub board_member_repository: Arc<dyn BoardMemberRepository>,
+    pub column_repository: Arc<dyn ColumnRepository>,
+    pub task_repository: Arc<dyn TaskRepository>,
+    pub comment_repository: Arc<dyn CommentRepository>,
+    pub checklist_repository: Arc<dyn ChecklistRepository>,
+    pub label_repository: Arc<dyn LabelRepository>,
+    pub custom_field_repository: Arc<dyn CustomFieldRepository>,
+    pub time_entry_repository: Arc<dyn TimeEntryRepository>,
+    pub attachment_repository: Arc<dyn AttachmentRepository>,
+    pub board_activity_repository: Arc<dyn BoardActivityRepository>,
+    pub outbox_repository: Arc<dyn OutboxRepository>,
+    pub unit_of_work_factory: Arc<dyn UnitOfWorkFactory>,
+    pub outbox_notify: Arc<Notify>,
+}
 
 pub fn initialize_repositories(database: DatabaseConnection) -> Repositories {
     let user_repository =
@@ -60,36 +82,126 @@ pub fn initialize_repositories(database: DatabaseConnection) -> Repositories {
         as Arc<dyn BoardMemberRepository>;
     let column_repository =
         Arc::new(SeaOrmColumnRepository::new(database.clone())) as Arc<dyn ColumnRepository>;
-    let task_repository = Arc::new(SeaOrmTaskRepository::new(database)) as Arc<dyn TaskRepository>;
+    let task_repository =
+        Arc::new(SeaOrmTaskRepository::new(database.clone())) as Arc<dyn TaskRepository>;
+    let comment_repository =
+        Arc::new(SeaOrmCommentRepository::new(database.clone())) as Arc<dyn CommentRepository>;
+    let checklist_repository =
+        Arc::new(SeaOrmChecklistRepository::new(database.clone())) as Arc<dyn ChecklistRepository>;
+    let label_repository =
+        Arc::new(SeaOrmLabelRepository::new(database.clone())) as Arc<dyn LabelRepository>;
+    let custom_field_repository = Arc::new(SeaOrmCustomFieldRepository::new(database.clone()))
+        as Arc<dyn CustomFieldRepository>;
+    let time_entry_repository =
+        Arc::new(SeaOrmTimeEntryRepository::new(database.clone())) as Arc<dyn TimeEntryRepository>;
+    let attachment_repository = Arc::new(SeaOrmAttachmentRepository::new(database.clone()))
+        as Arc<dyn AttachmentRepository>;
+    let board_activity_repository = Arc::new(SeaOrmBoardActivityRepository::new(database.clone()))
+        as Arc<dyn BoardActivityRepository>;
+    let outbox_repository =
+        Arc::new(SeaOrmOutboxRepository::new(database.clone())) as Arc<dyn OutboxRepository>;
+    let outbox_notify = Arc::new(Notify::new());
+    let unit_of_work_factory = Arc::new(SeaOrmUnitOfWorkFactory::new(
+        database,
+        outbox_notify.clone(),
+    )) as Arc<dyn UnitOfWorkFactory>;
 
     info!("Successfully initialized repositories");
 
-    (
+    Repositories {
         user_repository,
         board_repository,
         board_member_repository,
         column_repository,
         task_repository,
-    )
+        comment_repository,
+        checklist_repository,
+        label_repository,
+        custom_field_repository,
+        time_entry_repository,
+        attachment_repository,
+        board_activity_repository,
+        outbox_repository,
+        unit_of_work_factory,
+        outbox_notify,
+    }
 }
 
-pub fn initialize_event_bus() -> SharedEventBus {
-    let event_bus = Arc::new(InMemoryEventBus::new()) as SharedEventBus;
+pub async fn initialize_event_bus(
+    database: DatabaseConnection,
+    redis_client: RedisClient,
+    board_activity_repository: Arc<dyn BoardActivityRepository>,
+) -> SharedEventBus {
+    let event_bus = match EVENT_BUS_BACKEND.as_str() {
+        "memory" => Arc::new(InMemoryEventBus::new(board_activity_repository)) as SharedEventBus,
+        "redis" => Arc::new(
+            RedisEventBus::new(redis_client, board_activity_repository)
+                .await
+                .expect("Failed to initialize Redis event bus"),
+        ) as SharedEventBus,
+        "postgres" => Arc::new(
+            PostgresEventBus::new(database, board_activity_repository)
+                .await
+                .expect("Failed to initialize Postgres event bus"),
+        ) as SharedEventBus,
+        backend => panic!("Unsupported EVENT_BUS_BACKEND '{}'", backend),
+    };
 
-    info!("Successfully initialized event bus");
+    info!(
+        "Successfully initialized '{}' event bus",
+        *EVENT_BUS_BACKEND
+    );
 
     event_bus
 }
 
+pub fn initialize_outbox_dispatcher(
+    outbox_repository: Arc<dyn OutboxRepository>,
+    event_bus: SharedEventBus,
+    outbox_notify: Arc<Notify>,
+) {
+    OutboxDispatcher::new(outbox_repository, event_bus, outbox_notify).start();
+
+    info!("Successfully started outbox dispatcher");
+}
+
+pub fn initialize_storage() -> Arc<dyn StorageService> {
+    let storage_service = match STORAGE_BACKEND.as_str() {
+        "local" => Arc::new(LocalStorageService::new(STORAGE_LOCAL_PATH.as_str()))
+            as Arc<dyn StorageService>,
+        "s3" => Arc::new(S3StorageService::new().expect("Failed to initialize S3 storage"))
+            as Arc<dyn StorageService>,
+        backend => panic!("Unsupported STORAGE_BACKEND '{}'", backend),
+    };
+
+    info!("Successfully initialized '{}' storage", *STORAGE_BACKEND);
+
+    storage_service
+}
+
 pub fn initialize_services(
-    user_repository: Arc<dyn UserRepository>,
-    board_repository: Arc<dyn BoardRepository>,
-    board_member_repository: Arc<dyn BoardMemberRepository>,
-    column_repository: Arc<dyn ColumnRepository>,
-    task_repository: Arc<dyn TaskRepository>,
+    repositories: Repositories,
     redis_client: RedisClient,
     event_bus: SharedEventBus,
+    storage_service: Arc<dyn StorageService>,
 ) -> AppState {
+    let Repositories {
+        user_repository,
+        board_repository,
+        board_member_repository,
+        column_repository,
+        task_repository,
+        comment_repository,
+        checklist_repository,
+        label_repository,
+        custom_field_repository,
+        time_entry_repository,
+        attachment_repository,
+        board_activity_repository,
+        unit_of_work_factory,
+        ..
+    } = repositories;
+
     let token_service = Arc::new(RedisTokenService::new(redis_client)) as Arc<dyn TokenService>;
     let email_service =
         Arc::new(SmtpEmailService::new().expect("Failed to initialize email service"))
@@ -103,31 +215,109 @@ pub fn initialize_services(
     let user_service = Arc::new(UserService::new(user_repository.clone()));
     let board_service = Arc::new(BoardService::new(
         user_repository,
-        board_repository,
+        board_repository.clone(),
         board_member_repository.clone(),
-        event_bus.clone(),
+        attachment_repository.clone(),
+        storage_service.clone(),
+        unit_of_work_factory.clone(),
     ));
     let column_service = Arc::new(ColumnService::new(
         column_repository.clone(),
         board_member_repository.clone(),
-        event_bus.clone(),
+        attachment_repository.clone(),
+        storage_service.clone(),
+        unit_of_work_factory.clone(),
     ));
     let task_service = Arc::new(TaskService::new(
+        task_repository.clone(),
+        column_repository.clone(),
+        board_member_repository.clone(),
+        attachment_repository.clone(),
+        label_repository.clone(),
+        custom_field_repository.clone(),
+        storage_service.clone(),
+        unit_of_work_factory.clone(),
+    ));
+    let comment_service = Arc::new(CommentService::new(
+        comment_repository,
+        task_repository.clone(),
+        column_repository.clone(),
+        board_member_repository.clone(),
+        unit_of_work_factory.clone(),
+    ));
+    let checklist_service = Arc::new(ChecklistService::new(
+        checklist_repository,
+        task_repository.clone(),
+        column_repository.clone(),
+        board_member_repository.clone(),
+        unit_of_work_factory.clone(),
+    ));
+    let label_service = Arc::new(LabelService::new(
+        label_repository.clone(),
+        board_member_repository.clone(),
+        unit_of_work_factory.clone(),
+    ));
+    let custom_field_service = Arc::new(CustomFieldService::new(
+        custom_field_repository.clone(),
+        board_member_repository.clone(),
+        unit_of_work_factory.clone(),
+    ));
+    let time_entry_service = Arc::new(TimeEntryService::new(
+        time_entry_repository,
+        task_repository.clone(),
+        column_repository.clone(),
+        board_member_repository.clone(),
+        unit_of_work_factory.clone(),
+    ));
+    let attachment_service = Arc::new(AttachmentService::new(
+        attachment_repository,
+        task_repository.clone(),
+        column_repository.clone(),
+        board_member_repository.clone(),
+        storage_service,
+        unit_of_work_factory.clone(),
+    ));
+    let board_sync_service = Arc::new(BoardSyncService::new(
+        board_repository,
+        board_member_repository.clone(),
+        column_repository.clone(),
         task_repository,
-        column_repository,
+        label_repository,
+        custom_field_repository,
+        board_activity_repository.clone(),
+    ));
+    let board_activity_service = Arc::new(BoardActivityService::new(
+        board_activity_repository.clone(),
         board_member_repository.clone(),
-        event_bus.clone(),
     ));
-    let websocket_service = Arc::new(WebSocketService::new(event_bus, board_member_repository));
+    let board_analytics_service = Arc::new(BoardAnalyticsService::new(
+        board_activity_repository.clone(),
+        board_member_repository.clone(),
+        column_repository,
+    ));
+    let websocket_service = Arc::new(WebSocketService::new(
+        event_bus,
+        board_member_repository,
+        board_activity_repository,
+    ));
 
     info!("Successfully initialized services");
 
-    AppState::new(
+    AppState {
         auth_service,
         user_service,
         board_service,
         column_service,
         task_service,
+        comment_service,
+        checklist_service,
+        label_service,
+        custom_field_service,
+        time_entry_service,
+        attachment_service,
+        board_activity_service,
+        board_analytics_service,
+        board_sync_service,
         websocket_service,
-    )
+    }
 }
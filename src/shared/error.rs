@@ -1,3 +1,4 @@
+use crate::presentation::http::etag::etag;
 use actix_web::{
     HttpResponse, ResponseError,
     body::BoxBody,
@@ -15,6 +16,8 @@ pub struct ApplicationErrorSchema {
     status_code: u16,
     error: String,
     message: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    current_version: Option<i32>,
 }
 
 #[derive(Debug, Display, Error)]
@@ -29,6 +32,13 @@ pub enum ApplicationError {
     NotFound { message: String },
     #[display("Conflict")]
     Conflict { message: String },
+    #[display("Precondition Failed")]
+    PreconditionFailed {
+        message: String,
+        current_version: i32,
+    },
+    #[display("Payload Too Large")]
+    PayloadTooLarge { message: String },
     #[display("Too Many Requests")]
     TooManyRequests { message: String },
     #[display("Internal Server Error")]
@@ -52,6 +62,23 @@ impl From<DbErr> for ApplicationError {
 }
 
 impl ApplicationError {
+    /// Rejects a write based on an outdated version of a resource, e.g. from `If-Match`
+    pub fn ensure_version(
+        resource: &str,
+        expected_version: Option<i32>,
+        current_version: i32,
+    ) -> Result<(), Self> {
+        match expected_version {
+            Some(expected_version) if expected_version != current_version => {
+                Err(ApplicationError::PreconditionFailed {
+                    message: format!("{} has been modified since it was retrieved", resource),
+                    current_version,
+                })
+            }
+            _ => Ok(()),
+        }
+    }
+
     fn message(&self) -> String {
         match self {
             ApplicationError::BadRequest { message } => message.to_owned(),
@@ -59,6 +86,8 @@ impl ApplicationError {
             ApplicationError::Forbidden { message } => message.to_owned(),
             ApplicationError::NotFound { message } => message.to_owned(),
             ApplicationError::Conflict { message } => message.to_owned(),
+            ApplicationError::PreconditionFailed { message, .. } => message.to_owned(),
+            ApplicationError::PayloadTooLarge { message } => message.to_owned(),
             ApplicationError::TooManyRequests { message } => message.to_owned(),
             ApplicationError::InternalError { message } => message.to_owned(),
             ApplicationError::ValidationError { message } => message.to_string(),
@@ -75,6 +104,8 @@ impl ResponseError for ApplicationError {
             ApplicationError::Forbidden { .. } => StatusCode::FORBIDDEN,
             ApplicationError::NotFound { .. } => StatusCode::NOT_FOUND,
             ApplicationError::Conflict { .. } => StatusCode::CONFLICT,
+            ApplicationError::PreconditionFailed { .. } => StatusCode::PRECONDITION_FAILED,
+            ApplicationError::PayloadTooLarge { .. } => StatusCode::PAYLOAD_TOO_LARGE,
             ApplicationError::TooManyRequests { .. } => StatusCode::TOO_MANY_REQUESTS,
             ApplicationError::InternalError { .. } => StatusCode::INTERNAL_SERVER_ERROR,
             ApplicationError::ValidationError { .. } => StatusCode::BAD_REQUEST,
@@ -83,14 +114,27 @@ impl ResponseError for ApplicationError {
     }
 
     fn error_response(&self) -> HttpResponse<BoxBody> {
+        let current_version = match self {
+            ApplicationError::PreconditionFailed {
+                current_version, ..
+            } => Some(*current_version),
+            _ => None,
+        };
+
         let response_body = ApplicationErrorSchema {
             message: self.message(),
             status_code: self.status_code().as_u16(),
             error: self.to_string(),
+            current_version,
         };
 
-        HttpResponse::build(self.status_code())
-            .insert_header(ContentType::json())
-            .json(response_body)
+        let mut response = HttpResponse::build(self.status_code());
+        response.insert_header(ContentType::json());
+
+        if let Some(current_version) = current_version {
+            response.insert_header(etag(current_version));
+        }
+
+        response.json(response_body)
     }
 }
@@ -55,8 +55,61 @@ pub static FROM_EMAIL: LazyLock<String> =
 pub static BASE_URL: LazyLock<String> =
     LazyLock::new(|| env::var("BASE_URL").expect("Missing BASE_URL environment variable"));
 
+// Event bus configuration constants
+pub static EVENT_BUS_BACKEND: LazyLock<String> =
+    LazyLock::new(|| env::var("EVENT_BUS_BACKEND").unwrap_or("memory".to_string()));
+
+// Storage configuration constants
+pub static STORAGE_BACKEND: LazyLock<String> =
+    LazyLock::new(|| env::var("STORAGE_BACKEND").unwrap_or("local".to_string()));
+
+pub static STORAGE_LOCAL_PATH: LazyLock<String> =
+    LazyLock::new(|| env::var("STORAGE_LOCAL_PATH").unwrap_or("storage".to_string()));
+
+pub static S3_BUCKET: LazyLock<String> =
+    LazyLock::new(|| env::var("S3_BUCKET").expect("Missing S3_BUCKET environment variable"));
+
+pub static S3_REGION: LazyLock<String> =
+    LazyLock::new(|| env::var("S3_REGION").unwrap_or("us-east-1".to_string()));
+
+pub static S3_ENDPOINT: LazyLock<Option<String>> = LazyLock::new(|| env::var("S3_ENDPOINT").ok());
+
+pub static S3_ACCESS_KEY: LazyLock<String> = LazyLock::new(|| {
+    env::var("S3_ACCESS_KEY").expect("Missing S3_ACCESS_KEY environment variable")
+});
+
+pub static S3_SECRET_KEY: LazyLock<String> = LazyLock::new(|| {
+    env::var("S3_SECRET_KEY").expect("Missing S3_SECRET_KEY environment variable")
+});
+
+// Attachment configuration constants
+pub static ATTACHMENT_MAX_FILE_SIZE: LazyLock<u64> = LazyLock::new(|| {
+    env::var("ATTACHMENT_MAX_FILE_SIZE")
+        .unwrap_or("10485760".to_string())
+        .parse()
+        .expect("ATTACHMENT_MAX_FILE_SIZE must be a valid u64 number")
+});
+
+pub static ATTACHMENT_BOARD_QUOTA: LazyLock<u64> = LazyLock::new(|| {
+    env::var("ATTACHMENT_BOARD_QUOTA")
+        .unwrap_or("104857600".to_string())
+        .parse()
+        .expect("ATTACHMENT_BOARD_QUOTA must be a valid u64 number")
+});
+
+// WebSocket configuration constants
+pub static WEBSOCKET_MAX_REPLAY_EVENTS: LazyLock<u64> = LazyLock::new(|| {
+    env::var("WEBSOCKET_MAX_REPLAY_EVENTS")
+        .unwrap_or("500".to_string())
+        .parse()
+        .expect("WEBSOCKET_MAX_REPLAY_EVENTS must be a valid u64 number")
+});
+
 // Regular expressions for validation
 pub static RE_ONLY_LETTERS: LazyLock<Regex> = LazyLock::new(|| Regex::new(r"^\p{L}+$").unwrap());
 
 pub static RE_SPECIAL_CHARS: LazyLock<Regex> =
     LazyLock::new(|| Regex::new(r"^.*?[@$!%*?&].*$").unwrap());
+
+pub static RE_HEX_COLOR: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r"^#[0-9a-fA-F]{6}$").unwrap());
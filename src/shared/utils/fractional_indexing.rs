@@ -1,5 +1,12 @@
 use fractional_index::FractionalIndex;
-use serde::Deserialize;
+
+/// Positions are stored as `varchar(50)`
+pub const MAX_POSITION_LENGTH: usize = 50;
+/// Keys longer than this trigger a rebalance before they can hit the column limit
+pub const REBALANCE_THRESHOLD: usize = 40;
+
+// Byte that terminates every encoded fractional index
+const TERMINATOR: u8 = 0x80;
 
 pub struct FractionalIndexGenerator;
 
@@ -53,18 +60,39 @@ impl FractionalIndexGenerator {
         }
     }
 
-    fn from_string(hex_str: &str) -> Result<FractionalIndex, String> {
-        #[derive(Debug, Deserialize)]
-        struct Wrapper {
-            #[serde(with = "fractional_index::stringify")]
-            value: FractionalIndex,
+    pub fn needs_rebalance(position: &str) -> bool {
+        position.len() > REBALANCE_THRESHOLD
+    }
+
+    /// Returns `count` short, ascending keys spread evenly so that later
+    /// inserts have room on both sides of every item.
+    pub fn sequence(count: usize) -> Result<Vec<String>, String> {
+        let slots = u8::MAX as usize;
+
+        if count < slots {
+            let step = slots / (count + 1);
+
+            return (1..=count)
+                .map(|i| {
+                    FractionalIndex::from_bytes(vec![(i * step) as u8, TERMINATOR])
+                        .map(|idx| idx.to_string())
+                        .map_err(|err| format!("Invalid rebalanced key at index {}: {}", i, err))
+                })
+                .collect();
         }
 
-        let json = format!(r#"{{"value":"{}"}}"#, hex_str);
+        let mut current = FractionalIndex::default();
+        let mut positions = Vec::with_capacity(count);
+        for _ in 0..count {
+            positions.push(current.to_string());
+            current = FractionalIndex::new_after(&current);
+        }
 
-        let wrapper: Wrapper = serde_json::from_str(&json)
-            .map_err(|err| format!("Invalid fractional index '{}': {}", hex_str, err))?;
+        Ok(positions)
+    }
 
-        Ok(wrapper.value)
+    fn from_string(hex_str: &str) -> Result<FractionalIndex, String> {
+        FractionalIndex::from_string(hex_str)
+            .map_err(|err| format!("Invalid fractional index '{}': {}", hex_str, err))
     }
 }
@@ -1,5 +1,6 @@
 pub mod argon;
 pub mod constants;
 pub mod fractional_indexing;
+pub mod nullable;
 
 pub use fractional_indexing::FractionalIndexGenerator;
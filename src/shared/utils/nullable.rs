@@ -0,0 +1,11 @@
+use serde::{Deserialize, Deserializer};
+
+/// Tells a field sent as `null` (`Some(None)`) apart from an omitted one (`None`), so updates
+/// can clear optional values. Pair it with `#[serde(default)]`.
+pub fn deserialize<'de, T, D>(deserializer: D) -> Result<Option<Option<T>>, D::Error>
+where
+    T: Deserialize<'de>,
+    D: Deserializer<'de>,
+{
+    Option::<T>::deserialize(deserializer).map(Some)
+}